@@ -1,8 +1,9 @@
 use crate::types::{
     BooleanType, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, ListType,
-    StringType, StructType, Uint16Type, Uint32Type, Uint64Type, Uint8Type,
+    StringType, StructType, TimestampType, Uint16Type, Uint32Type, Uint64Type, Uint8Type,
 };
 use crate::value::Value;
+use serde::{Deserialize, Serialize};
 
 /// Data type abstraction trait
 pub trait DataType: std::fmt::Debug + Send + Sync {
@@ -18,7 +19,7 @@ pub trait DataType: std::fmt::Debug + Send + Sync {
 }
 
 /// Concrete data type definition
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ConcreteDatatype {
     /// Null type
     Null,
@@ -50,4 +51,6 @@ pub enum ConcreteDatatype {
     List(ListType),
     /// Boolean type
     Bool(BooleanType),
+    /// Timestamp with precision
+    Timestamp(TimestampType),
 }
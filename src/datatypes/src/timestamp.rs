@@ -0,0 +1,266 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Precision of a timestamp, i.e. the unit its integer value counts since the Unix epoch
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub enum TimestampPrecision {
+    Second,
+    #[default]
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+impl TimestampPrecision {
+    /// Number of ticks of this precision in one second
+    pub fn ticks_per_second(&self) -> i64 {
+        match self {
+            TimestampPrecision::Second => 1,
+            TimestampPrecision::Millisecond => 1_000,
+            TimestampPrecision::Microsecond => 1_000_000,
+            TimestampPrecision::Nanosecond => 1_000_000_000,
+        }
+    }
+
+    /// Number of nanoseconds in one tick of this precision
+    fn nanos_per_tick(&self) -> i128 {
+        NANOS_PER_SECOND / self.ticks_per_second() as i128
+    }
+
+    /// Short unit name, e.g. `ms`
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimestampPrecision::Second => "s",
+            TimestampPrecision::Millisecond => "ms",
+            TimestampPrecision::Microsecond => "us",
+            TimestampPrecision::Nanosecond => "ns",
+        }
+    }
+
+    /// Parse a unit name (`s`, `ms`, `us`, `ns` or their long forms)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "s" | "second" | "seconds" => Some(TimestampPrecision::Second),
+            "ms" | "millisecond" | "milliseconds" => Some(TimestampPrecision::Millisecond),
+            "us" | "microsecond" | "microseconds" => Some(TimestampPrecision::Microsecond),
+            "ns" | "nanosecond" | "nanoseconds" => Some(TimestampPrecision::Nanosecond),
+            _ => None,
+        }
+    }
+
+    /// Precision matching a SQL fractional-second precision (`TIMESTAMP(3)` is milliseconds)
+    pub fn from_fraction_digits(digits: u64) -> Option<Self> {
+        match digits {
+            0 => Some(TimestampPrecision::Second),
+            3 => Some(TimestampPrecision::Millisecond),
+            6 => Some(TimestampPrecision::Microsecond),
+            9 => Some(TimestampPrecision::Nanosecond),
+            _ => None,
+        }
+    }
+}
+
+/// Point in time stored as ticks since the Unix epoch (UTC) at a given precision
+///
+/// Equality, hashing and ordering compare the instant, so `1s` equals `1000ms`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Timestamp {
+    value: i64,
+    precision: TimestampPrecision,
+}
+
+impl Timestamp {
+    pub fn new(value: i64, precision: TimestampPrecision) -> Self {
+        Self { value, precision }
+    }
+
+    pub fn new_second(value: i64) -> Self {
+        Self::new(value, TimestampPrecision::Second)
+    }
+
+    pub fn new_millisecond(value: i64) -> Self {
+        Self::new(value, TimestampPrecision::Millisecond)
+    }
+
+    /// Ticks since the Unix epoch
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    pub fn precision(&self) -> TimestampPrecision {
+        self.precision
+    }
+
+    /// Current wall-clock time
+    pub fn now(precision: TimestampPrecision) -> Self {
+        Self::from_system_time(SystemTime::now(), precision)
+            .expect("current time fits in a timestamp")
+    }
+
+    /// Nanoseconds since the Unix epoch
+    pub fn to_nanos(&self) -> i128 {
+        self.value as i128 * self.precision.nanos_per_tick()
+    }
+
+    /// Build a timestamp from nanoseconds since the Unix epoch, flooring to `precision`
+    pub fn from_nanos(nanos: i128, precision: TimestampPrecision) -> Option<Self> {
+        let value = nanos.div_euclid(precision.nanos_per_tick());
+        i64::try_from(value)
+            .ok()
+            .map(|value| Self::new(value, precision))
+    }
+
+    /// Convert to another precision, flooring when precision is lost
+    pub fn convert_to(&self, precision: TimestampPrecision) -> Option<Self> {
+        if precision == self.precision {
+            return Some(*self);
+        }
+        Self::from_nanos(self.to_nanos(), precision)
+    }
+
+    pub fn from_system_time(time: SystemTime, precision: TimestampPrecision) -> Option<Self> {
+        let nanos = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_nanos() as i128,
+            Err(before) => -(before.duration().as_nanos() as i128),
+        };
+        Self::from_nanos(nanos, precision)
+    }
+
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let nanos = self.to_nanos();
+        let magnitude = std::time::Duration::new(
+            u64::try_from(nanos.unsigned_abs() / NANOS_PER_SECOND as u128).ok()?,
+            (nanos.unsigned_abs() % NANOS_PER_SECOND as u128) as u32,
+        );
+        if nanos >= 0 {
+            UNIX_EPOCH.checked_add(magnitude)
+        } else {
+            UNIX_EPOCH.checked_sub(magnitude)
+        }
+    }
+
+    /// The timestamp as a UTC date-time
+    pub fn to_datetime(&self) -> Option<OffsetDateTime> {
+        OffsetDateTime::from_unix_timestamp_nanos(self.to_nanos()).ok()
+    }
+
+    pub fn from_datetime(datetime: OffsetDateTime, precision: TimestampPrecision) -> Option<Self> {
+        Self::from_nanos(datetime.unix_timestamp_nanos(), precision)
+    }
+
+    /// Parse an RFC 3339 timestamp such as `2024-05-01T12:30:00.250Z`
+    ///
+    /// A space may separate date and time, a missing offset means UTC and a bare date means
+    /// midnight UTC.
+    pub fn parse_rfc3339(value: &str, precision: TimestampPrecision) -> Option<Self> {
+        let mut text = value.trim().to_string();
+        if text.len() == 10 {
+            text.push_str("T00:00:00");
+        }
+        if text.as_bytes().get(10) == Some(&b' ') {
+            text.replace_range(10..11, "T");
+        }
+        let has_offset = text
+            .get(19..)
+            .is_some_and(|rest| rest.contains(['Z', 'z', '+', '-']));
+        if !has_offset {
+            text.push('Z');
+        }
+        let datetime = OffsetDateTime::parse(&text, &Rfc3339).ok()?;
+        Self::from_datetime(datetime, precision)
+    }
+
+    /// Format as an RFC 3339 string in UTC
+    pub fn to_rfc3339(&self) -> Option<String> {
+        self.to_datetime()?.format(&Rfc3339).ok()
+    }
+}
+
+impl std::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to_rfc3339() {
+            Some(text) => f.write_str(&text),
+            None => write!(f, "{}{}", self.value, self.precision.name()),
+        }
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_nanos() == other.to_nanos()
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_nanos().hash(state);
+    }
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_nanos().cmp(&other.to_nanos())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equality_ignores_precision() {
+        let seconds = Timestamp::new_second(2);
+        let millis = Timestamp::new_millisecond(2_000);
+        assert_eq!(seconds, millis);
+        assert!(Timestamp::new_millisecond(1_999) < seconds);
+        assert_eq!(
+            Timestamp::new_millisecond(-1)
+                .convert_to(TimestampPrecision::Second)
+                .unwrap(),
+            Timestamp::new_second(-1)
+        );
+    }
+
+    #[test]
+    fn rfc3339_roundtrip() {
+        let ts =
+            Timestamp::parse_rfc3339("2024-05-01T12:30:00.250Z", TimestampPrecision::Millisecond)
+                .unwrap();
+        assert_eq!(ts.value(), 1_714_566_600_250);
+        assert_eq!(ts.to_rfc3339().unwrap(), "2024-05-01T12:30:00.25Z");
+
+        let naive =
+            Timestamp::parse_rfc3339("2024-05-01 14:30:00.250", TimestampPrecision::Millisecond)
+                .unwrap();
+        let offset = Timestamp::parse_rfc3339(
+            "2024-05-01T16:30:00.250+02:00",
+            TimestampPrecision::Millisecond,
+        )
+        .unwrap();
+        assert_eq!(naive, offset);
+        assert_eq!(
+            Timestamp::parse_rfc3339("2024-05-01", TimestampPrecision::Second)
+                .unwrap()
+                .value(),
+            1_714_521_600
+        );
+        assert!(Timestamp::parse_rfc3339("yesterday", TimestampPrecision::Second).is_none());
+    }
+}
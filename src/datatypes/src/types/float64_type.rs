@@ -1,8 +1,9 @@
 use crate::datatypes::DataType;
 use crate::value::Value;
+use serde::{Deserialize, Serialize};
 
 /// 64-bit floating point number type
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Float64Type;
 
 impl DataType for Float64Type {
@@ -1,8 +1,9 @@
 use crate::datatypes::DataType;
 use crate::value::Value;
+use serde::{Deserialize, Serialize};
 
 /// 16-bit signed integer type
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Int16Type;
 
 impl DataType for Int16Type {
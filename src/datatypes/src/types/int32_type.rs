@@ -1,8 +1,9 @@
 use crate::datatypes::DataType;
 use crate::value::Value;
+use serde::{Deserialize, Serialize};
 
 /// 32-bit signed integer type
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Int32Type;
 
 impl DataType for Int32Type {
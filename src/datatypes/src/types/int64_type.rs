@@ -1,8 +1,9 @@
 use crate::datatypes::DataType;
 use crate::value::Value;
+use serde::{Deserialize, Serialize};
 
 /// 64-bit signed integer type
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Int64Type;
 
 impl DataType for Int64Type {
@@ -20,6 +21,7 @@ impl DataType for Int64Type {
             Value::Float64(v) => Some(Value::Int64(v as i64)),
             Value::Bool(v) => Some(Value::Int64(if v { 1 } else { 0 })),
             Value::String(s) => s.parse::<i64>().ok().map(Value::Int64),
+            Value::Timestamp(ts) => Some(Value::Int64(ts.value())),
             _ => None,
         }
     }
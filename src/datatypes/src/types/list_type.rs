@@ -3,9 +3,10 @@ use std::sync::Arc;
 use crate::datatypes::ConcreteDatatype;
 use crate::datatypes::DataType;
 use crate::value::{ListValue, Value};
+use serde::{Deserialize, Serialize};
 
 /// List type, containing element type
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ListType {
     /// The type of List's item
     item_type: Arc<ConcreteDatatype>,
@@ -64,6 +65,7 @@ fn get_type_name(dt: &ConcreteDatatype) -> String {
         ConcreteDatatype::Bool(_) => "Boolean",
         ConcreteDatatype::Struct(_) => "Struct",
         ConcreteDatatype::List(_) => "List",
+        ConcreteDatatype::Timestamp(_) => "Timestamp",
     }
     .to_string()
 }
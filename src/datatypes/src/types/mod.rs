@@ -8,6 +8,7 @@ pub mod int8_type;
 pub mod list_type;
 pub mod string_type;
 pub mod struct_type;
+pub mod timestamp_type;
 pub mod uint16_type;
 pub mod uint32_type;
 pub mod uint64_type;
@@ -23,6 +24,7 @@ pub use int8_type::Int8Type;
 pub use list_type::ListType;
 pub use string_type::StringType;
 pub use struct_type::{StructField, StructType};
+pub use timestamp_type::TimestampType;
 pub use uint16_type::Uint16Type;
 pub use uint32_type::Uint32Type;
 pub use uint64_type::Uint64Type;
@@ -1,8 +1,9 @@
 use crate::datatypes::DataType;
 use crate::value::Value;
+use serde::{Deserialize, Serialize};
 
 /// String type
-#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct StringType;
 
 impl DataType for StringType {
@@ -20,6 +21,7 @@ impl DataType for StringType {
             Value::Int64(v) => Some(Value::String(v.to_string())),
             Value::Float64(v) => Some(Value::String(v.to_string())),
             Value::Bool(v) => Some(Value::String(v.to_string())),
+            Value::Timestamp(ts) => ts.to_rfc3339().map(Value::String),
             _ => None,
         }
     }
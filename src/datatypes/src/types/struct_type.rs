@@ -3,9 +3,10 @@ use std::sync::Arc;
 use crate::datatypes::ConcreteDatatype;
 use crate::datatypes::DataType;
 use crate::value::{StructValue, Value};
+use serde::{Deserialize, Serialize};
 
 /// Struct field definition
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct StructField {
     /// Field name
     name: String,
@@ -42,7 +43,7 @@ impl StructField {
 }
 
 /// Struct type, containing field definitions
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct StructType {
     /// Struct fields
     fields: Arc<Vec<StructField>>,
@@ -102,6 +103,7 @@ fn get_type_name(dt: &ConcreteDatatype) -> String {
         ConcreteDatatype::Bool(_) => "Boolean",
         ConcreteDatatype::Struct(_) => "Struct",
         ConcreteDatatype::List(_) => "List",
+        ConcreteDatatype::Timestamp(_) => "Timestamp",
     }
     .to_string()
 }
@@ -121,6 +123,7 @@ fn get_default_value(dt: &ConcreteDatatype) -> Value {
         ConcreteDatatype::Uint64(_) => Value::Uint64(0),
         ConcreteDatatype::String(_) => Value::String(String::new()),
         ConcreteDatatype::Bool(_) => Value::Bool(false),
+        ConcreteDatatype::Timestamp(t) => t.default_value(),
         ConcreteDatatype::Struct(_) => {
             // For nested struct, return empty struct value
             // This is a simplified implementation
@@ -0,0 +1,54 @@
+use crate::datatypes::DataType;
+use crate::timestamp::{Timestamp, TimestampPrecision};
+use crate::value::Value;
+use serde::{Deserialize, Serialize};
+
+/// Timestamp type with a fixed precision
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TimestampType {
+    precision: TimestampPrecision,
+}
+
+impl TimestampType {
+    pub fn new(precision: TimestampPrecision) -> Self {
+        Self { precision }
+    }
+
+    pub fn precision(&self) -> TimestampPrecision {
+        self.precision
+    }
+}
+
+impl DataType for TimestampType {
+    fn name(&self) -> String {
+        format!("Timestamp({})", self.precision.name())
+    }
+
+    fn default_value(&self) -> Value {
+        Value::Timestamp(Timestamp::new(0, self.precision))
+    }
+
+    fn try_cast(&self, from: Value) -> Option<Value> {
+        let ticks = match from {
+            Value::Timestamp(ts) => return ts.convert_to(self.precision).map(Value::Timestamp),
+            // Integers are read as ticks of this type's precision
+            Value::Int8(v) => v as i64,
+            Value::Int16(v) => v as i64,
+            Value::Int32(v) => v as i64,
+            Value::Int64(v) => v,
+            Value::Uint8(v) => v as i64,
+            Value::Uint16(v) => v as i64,
+            Value::Uint32(v) => v as i64,
+            Value::Uint64(v) => i64::try_from(v).ok()?,
+            Value::Float64(v) if v.is_finite() => v as i64,
+            Value::String(s) => match s.trim().parse::<i64>() {
+                Ok(v) => v,
+                Err(_) => {
+                    return Timestamp::parse_rfc3339(&s, self.precision).map(Value::Timestamp)
+                }
+            },
+            _ => return None,
+        };
+        Some(Value::Timestamp(Timestamp::new(ticks, self.precision)))
+    }
+}
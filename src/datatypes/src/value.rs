@@ -2,10 +2,12 @@ use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::datatypes::ConcreteDatatype;
+use crate::timestamp::Timestamp;
 use crate::types::StructType;
+use serde::{Deserialize, Serialize};
 
 /// List value containing items and their datatype
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ListValue {
     items: Vec<Value>,
     /// Inner values datatype, to distinguish empty lists of different datatypes
@@ -42,7 +44,7 @@ impl ListValue {
 }
 
 /// Struct value containing items and field definitions
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct StructValue {
     items: Vec<Value>,
     fields: StructType,
@@ -73,7 +75,7 @@ impl StructValue {
 
 /// Value type for type casting
 /// Should be synchronized with ConcreteDatatype variants
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Value {
     /// Null value representing missing/unknown data
     Null,
@@ -105,6 +107,8 @@ pub enum Value {
     Struct(StructValue),
     /// List value
     List(ListValue),
+    /// Timestamp value
+    Timestamp(Timestamp),
 }
 
 impl Value {
@@ -133,6 +137,9 @@ impl Value {
             Value::List(l) => {
                 ConcreteDatatype::List(crate::types::ListType::new(Arc::new(l.datatype().clone())))
             }
+            Value::Timestamp(ts) => {
+                ConcreteDatatype::Timestamp(crate::types::TimestampType::new(ts.precision()))
+            }
         }
     }
 }
@@ -155,6 +162,7 @@ impl PartialEq for Value {
             (Value::Bool(a), Value::Bool(b)) => a == b,
             (Value::Struct(a), Value::Struct(b)) => a == b,
             (Value::List(a), Value::List(b)) => a == b,
+            (Value::Timestamp(a), Value::Timestamp(b)) => a == b,
             _ => false,
         }
     }
@@ -236,6 +244,10 @@ impl Hash for Value {
                 14_u8.hash(state);
                 v.hash(state);
             }
+            Value::Timestamp(v) => {
+                15_u8.hash(state);
+                v.hash(state);
+            }
         }
     }
 }
@@ -88,7 +88,7 @@ fn test_float32_precision_and_range() {
     // Test with fractional values
     assert_eq!(
         float32_type.try_cast(Value::String("123.456789".to_string())),
-        Some(Value::Float32(123.456789))
+        Some(Value::Float32(123.456_79))
     );
 
     // Test with scientific notation
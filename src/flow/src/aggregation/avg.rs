@@ -0,0 +1,115 @@
+use super::numeric::{expect_numeric_argument, numeric_to_f64, state_f64, state_i64};
+use crate::aggregation::{AggregateAccumulator, AggregateFunction};
+use datatypes::{ConcreteDatatype, Float64Type, Value};
+
+#[derive(Debug, Default)]
+pub struct AvgFunction;
+
+impl AvgFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl AggregateFunction for AvgFunction {
+    fn name(&self) -> &str {
+        "avg"
+    }
+
+    fn return_type(&self, input_types: &[ConcreteDatatype]) -> Result<ConcreteDatatype, String> {
+        if input_types.len() != 1 {
+            return Err(format!(
+                "AVG expects exactly one argument, got {}",
+                input_types.len()
+            ));
+        }
+        expect_numeric_argument("AVG", input_types)?;
+        Ok(ConcreteDatatype::Float64(Float64Type))
+    }
+
+    fn create_accumulator(&self) -> Box<dyn AggregateAccumulator> {
+        Box::new(AvgAccumulator::default())
+    }
+
+    fn supports_incremental(&self) -> bool {
+        true
+    }
+
+    fn supports_merge(&self) -> bool {
+        true
+    }
+
+    fn supports_retract(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct AvgAccumulator {
+    sum: f64,
+    count: i64,
+}
+
+impl AggregateAccumulator for AvgAccumulator {
+    fn update(&mut self, args: &[Value]) -> Result<(), String> {
+        let Some(value) = args.first() else {
+            return Err("AVG expects one argument".to_string());
+        };
+        if let Some(value) = numeric_to_f64("AVG", value)? {
+            self.sum += value;
+            self.count += 1;
+        }
+        Ok(())
+    }
+
+    fn finalize(&self) -> Value {
+        if self.count == 0 {
+            Value::Null
+        } else {
+            Value::Float64(self.sum / self.count as f64)
+        }
+    }
+    fn state(&self) -> Vec<Value> {
+        if self.count == 0 {
+            Vec::new()
+        } else {
+            vec![Value::Float64(self.sum), Value::Int64(self.count)]
+        }
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        *self = Self::default();
+        self.merge(state)
+    }
+
+    fn merge(&mut self, state: Vec<Value>) -> Result<(), String> {
+        match state.as_slice() {
+            [] => Ok(()),
+            [sum, count] => {
+                self.sum += state_f64("AVG", sum)?;
+                self.count += state_i64("AVG", count)?;
+                Ok(())
+            }
+            other => Err(format!(
+                "AVG expects 0 or 2 state values, got {}",
+                other.len()
+            )),
+        }
+    }
+
+    fn retract(&mut self, args: &[Value]) -> Result<(), String> {
+        let Some(value) = args.first() else {
+            return Err("AVG expects one argument".to_string());
+        };
+        if let Some(value) = numeric_to_f64("AVG", value)? {
+            self.count -= 1;
+            // Reset rather than subtract so rounding error does not outlive the window.
+            if self.count <= 0 {
+                *self = Self::default();
+            } else {
+                self.sum -= value;
+            }
+        }
+        Ok(())
+    }
+}
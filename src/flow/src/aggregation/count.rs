@@ -0,0 +1,98 @@
+use super::numeric::state_i64;
+use crate::aggregation::{AggregateAccumulator, AggregateFunction};
+use datatypes::{ConcreteDatatype, Int64Type, Value};
+
+/// `count(col)` counts non-null values; `count(*)` counts rows because its argument evaluates to
+/// the whole row, which is never null.
+#[derive(Debug, Default)]
+pub struct CountFunction;
+
+impl CountFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl AggregateFunction for CountFunction {
+    fn name(&self) -> &str {
+        "count"
+    }
+
+    fn return_type(&self, input_types: &[ConcreteDatatype]) -> Result<ConcreteDatatype, String> {
+        if input_types.len() != 1 {
+            return Err(format!(
+                "COUNT expects exactly one argument, got {}",
+                input_types.len()
+            ));
+        }
+        Ok(ConcreteDatatype::Int64(Int64Type))
+    }
+
+    fn create_accumulator(&self) -> Box<dyn AggregateAccumulator> {
+        Box::new(CountAccumulator::default())
+    }
+
+    fn supports_incremental(&self) -> bool {
+        true
+    }
+
+    fn supports_merge(&self) -> bool {
+        true
+    }
+
+    fn supports_retract(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct CountAccumulator {
+    count: i64,
+}
+
+impl AggregateAccumulator for CountAccumulator {
+    fn update(&mut self, args: &[Value]) -> Result<(), String> {
+        let Some(value) = args.first() else {
+            return Err("COUNT expects one argument".to_string());
+        };
+        if !value.is_null() {
+            self.count = self.count.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    fn finalize(&self) -> Value {
+        Value::Int64(self.count)
+    }
+    fn state(&self) -> Vec<Value> {
+        vec![Value::Int64(self.count)]
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        self.count = 0;
+        self.merge(state)
+    }
+
+    fn merge(&mut self, state: Vec<Value>) -> Result<(), String> {
+        if state.len() > 1 {
+            return Err(format!(
+                "COUNT expects at most 1 state value, got {}",
+                state.len()
+            ));
+        }
+        if let Some(value) = state.first() {
+            self.count = self.count.saturating_add(state_i64("COUNT", value)?);
+        }
+        Ok(())
+    }
+
+    fn retract(&mut self, args: &[Value]) -> Result<(), String> {
+        let Some(value) = args.first() else {
+            return Err("COUNT expects one argument".to_string());
+        };
+        if !value.is_null() {
+            self.count = self.count.saturating_sub(1);
+        }
+        Ok(())
+    }
+}
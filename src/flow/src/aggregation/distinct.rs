@@ -0,0 +1,117 @@
+use crate::aggregation::AggregateAccumulator;
+use datatypes::Value;
+use std::collections::HashSet;
+
+/// Wraps the accumulator of an `agg(DISTINCT ...)` call and feeds it each argument tuple once.
+///
+/// The distinct tuples double as the checkpoint state: restoring or merging replays the tuples the
+/// inner accumulator has not seen yet, so partial states never count a value twice.
+pub struct DistinctAccumulator {
+    inner: Box<dyn AggregateAccumulator>,
+    seen: HashSet<Vec<Value>>,
+    /// `seen` in first-seen order, so replays keep order-sensitive aggregates stable.
+    ordered: Vec<Vec<Value>>,
+}
+
+impl DistinctAccumulator {
+    pub fn new(inner: Box<dyn AggregateAccumulator>) -> Self {
+        Self {
+            inner,
+            seen: HashSet::new(),
+            ordered: Vec::new(),
+        }
+    }
+
+    fn offer(&mut self, args: Vec<Value>) -> Result<(), String> {
+        if self.seen.contains(&args) {
+            return Ok(());
+        }
+        self.inner.update(&args)?;
+        self.seen.insert(args.clone());
+        self.ordered.push(args);
+        Ok(())
+    }
+}
+
+impl AggregateAccumulator for DistinctAccumulator {
+    fn update(&mut self, args: &[Value]) -> Result<(), String> {
+        self.offer(args.to_vec())
+    }
+
+    fn finalize(&self) -> Value {
+        self.inner.finalize()
+    }
+    /// `[arity, tuple_0..., tuple_1..., ...]`, or empty before the first row.
+    fn state(&self) -> Vec<Value> {
+        let Some(arity) = self.ordered.first().map(Vec::len) else {
+            return Vec::new();
+        };
+        let mut state = Vec::with_capacity(1 + arity * self.ordered.len());
+        state.push(Value::Int64(arity as i64));
+        for args in &self.ordered {
+            state.extend(args.iter().cloned());
+        }
+        state
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        self.inner.restore(Vec::new())?;
+        self.seen.clear();
+        self.ordered.clear();
+        self.merge(state)
+    }
+
+    fn merge(&mut self, state: Vec<Value>) -> Result<(), String> {
+        let Some((arity, values)) = state.split_first() else {
+            return Ok(());
+        };
+        let arity = match arity {
+            Value::Int64(arity) if *arity > 0 => *arity as usize,
+            other => {
+                return Err(format!(
+                    "DISTINCT state must start with a positive arity, got {:?}",
+                    other
+                ))
+            }
+        };
+        if values.len() % arity != 0 {
+            return Err(format!(
+                "DISTINCT state holds {} values, not a multiple of arity {}",
+                values.len(),
+                arity
+            ));
+        }
+        for args in values.chunks(arity) {
+            self.offer(args.to_vec())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregation::{AggregateFunction, SumFunction};
+
+    fn distinct_sum(values: &[i64]) -> DistinctAccumulator {
+        let mut acc = DistinctAccumulator::new(SumFunction::new().create_accumulator());
+        for value in values {
+            acc.update(&[Value::Int64(*value)]).unwrap();
+        }
+        acc
+    }
+
+    #[test]
+    fn merging_partials_keeps_values_distinct() {
+        let mut left = distinct_sum(&[1, 2, 2]);
+        assert_eq!(left.finalize(), Value::Int64(3));
+
+        left.merge(distinct_sum(&[2, 3]).state()).unwrap();
+        assert_eq!(left.finalize(), Value::Int64(6));
+
+        let mut restored = DistinctAccumulator::new(SumFunction::new().create_accumulator());
+        restored.restore(left.state()).unwrap();
+        restored.update(&[Value::Int64(3)]).unwrap();
+        assert_eq!(restored.finalize(), Value::Int64(6));
+    }
+}
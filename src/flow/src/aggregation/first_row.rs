@@ -0,0 +1,85 @@
+use super::{AggregateAccumulator, AggregateFunction};
+use datatypes::{ConcreteDatatype, Value};
+
+pub struct FirstRowFunction;
+
+impl FirstRowFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for FirstRowFunction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct FirstRowAccumulator {
+    first: Option<Value>,
+}
+
+impl AggregateAccumulator for FirstRowAccumulator {
+    fn update(&mut self, args: &[Value]) -> Result<(), String> {
+        let value = args
+            .first()
+            .ok_or_else(|| "first_row expects exactly 1 argument".to_string())?;
+        if self.first.is_none() {
+            self.first = Some(value.clone());
+        }
+        Ok(())
+    }
+
+    fn finalize(&self) -> Value {
+        self.first.clone().unwrap_or(Value::Null)
+    }
+    fn state(&self) -> Vec<Value> {
+        self.first.iter().cloned().collect()
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        if state.len() > 1 {
+            return Err(format!(
+                "first_row expects at most 1 state value, got {}",
+                state.len()
+            ));
+        }
+        self.first = state.into_iter().next();
+        Ok(())
+    }
+
+    fn merge(&mut self, state: Vec<Value>) -> Result<(), String> {
+        if self.first.is_none() {
+            self.first = state.into_iter().next();
+        }
+        Ok(())
+    }
+}
+
+impl AggregateFunction for FirstRowFunction {
+    fn name(&self) -> &str {
+        "first_row"
+    }
+
+    fn return_type(&self, input_types: &[ConcreteDatatype]) -> Result<ConcreteDatatype, String> {
+        if input_types.len() != 1 {
+            return Err(format!(
+                "first_row expects exactly 1 argument, got {}",
+                input_types.len()
+            ));
+        }
+        Ok(input_types[0].clone())
+    }
+
+    fn create_accumulator(&self) -> Box<dyn AggregateAccumulator> {
+        Box::new(FirstRowAccumulator { first: None })
+    }
+
+    fn supports_incremental(&self) -> bool {
+        true
+    }
+
+    fn supports_merge(&self) -> bool {
+        true
+    }
+}
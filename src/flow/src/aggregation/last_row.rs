@@ -31,6 +31,27 @@ impl AggregateAccumulator for LastRowAccumulator {
     fn finalize(&self) -> Value {
         self.last.clone().unwrap_or(Value::Null)
     }
+    fn state(&self) -> Vec<Value> {
+        self.last.iter().cloned().collect()
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        if state.len() > 1 {
+            return Err(format!(
+                "last_row expects at most 1 state value, got {}",
+                state.len()
+            ));
+        }
+        self.last = state.into_iter().next();
+        Ok(())
+    }
+
+    fn merge(&mut self, state: Vec<Value>) -> Result<(), String> {
+        if let Some(last) = state.into_iter().last() {
+            self.last = Some(last);
+        }
+        Ok(())
+    }
 }
 
 impl AggregateFunction for LastRowFunction {
@@ -55,4 +76,8 @@ impl AggregateFunction for LastRowFunction {
     fn supports_incremental(&self) -> bool {
         true
     }
+
+    fn supports_merge(&self) -> bool {
+        true
+    }
 }
@@ -0,0 +1,150 @@
+use crate::aggregation::{AggregateAccumulator, AggregateFunction};
+use crate::expr::func::BinaryFunc;
+use datatypes::{ConcreteDatatype, Value};
+
+#[derive(Debug, Default)]
+pub struct MinFunction;
+
+impl MinFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl AggregateFunction for MinFunction {
+    fn name(&self) -> &str {
+        "min"
+    }
+
+    fn return_type(&self, input_types: &[ConcreteDatatype]) -> Result<ConcreteDatatype, String> {
+        single_argument_type("MIN", input_types)
+    }
+
+    fn create_accumulator(&self) -> Box<dyn AggregateAccumulator> {
+        Box::new(ExtremumAccumulator::new("MIN", BinaryFunc::Lt))
+    }
+
+    fn supports_incremental(&self) -> bool {
+        true
+    }
+
+    fn supports_merge(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MaxFunction;
+
+impl MaxFunction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl AggregateFunction for MaxFunction {
+    fn name(&self) -> &str {
+        "max"
+    }
+
+    fn return_type(&self, input_types: &[ConcreteDatatype]) -> Result<ConcreteDatatype, String> {
+        single_argument_type("MAX", input_types)
+    }
+
+    fn create_accumulator(&self) -> Box<dyn AggregateAccumulator> {
+        Box::new(ExtremumAccumulator::new("MAX", BinaryFunc::Gt))
+    }
+
+    fn supports_incremental(&self) -> bool {
+        true
+    }
+
+    fn supports_merge(&self) -> bool {
+        true
+    }
+}
+
+fn single_argument_type(
+    name: &str,
+    input_types: &[ConcreteDatatype],
+) -> Result<ConcreteDatatype, String> {
+    if input_types.len() != 1 {
+        return Err(format!(
+            "{name} expects exactly one argument, got {}",
+            input_types.len()
+        ));
+    }
+    Ok(input_types[0].clone())
+}
+
+/// Keeps the non-null value that wins `replace_when(candidate, current)`.
+#[derive(Debug, Clone)]
+struct ExtremumAccumulator {
+    name: &'static str,
+    replace_when: BinaryFunc,
+    current: Option<Value>,
+}
+
+impl ExtremumAccumulator {
+    fn new(name: &'static str, replace_when: BinaryFunc) -> Self {
+        Self {
+            name,
+            replace_when,
+            current: None,
+        }
+    }
+
+    fn offer(&mut self, candidate: Value) -> Result<(), String> {
+        if candidate.is_null() {
+            return Ok(());
+        }
+        let replace = match &self.current {
+            Some(current) => {
+                self.replace_when
+                    .eval_binary(candidate.clone(), current.clone())
+                    .map_err(|err| err.to_string())?
+                    == Value::Bool(true)
+            }
+            None => true,
+        };
+        if replace {
+            self.current = Some(candidate);
+        }
+        Ok(())
+    }
+}
+
+impl AggregateAccumulator for ExtremumAccumulator {
+    fn update(&mut self, args: &[Value]) -> Result<(), String> {
+        let Some(value) = args.first() else {
+            return Err(format!("{} expects one argument", self.name));
+        };
+        self.offer(value.clone())
+    }
+
+    fn finalize(&self) -> Value {
+        self.current.clone().unwrap_or(Value::Null)
+    }
+    fn state(&self) -> Vec<Value> {
+        self.current.iter().cloned().collect()
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        if state.len() > 1 {
+            return Err(format!(
+                "{} expects at most 1 state value, got {}",
+                self.name,
+                state.len()
+            ));
+        }
+        self.current = state.into_iter().next();
+        Ok(())
+    }
+
+    fn merge(&mut self, state: Vec<Value>) -> Result<(), String> {
+        for value in state {
+            self.offer(value)?;
+        }
+        Ok(())
+    }
+}
@@ -1,9 +1,24 @@
+mod avg;
+mod count;
+mod distinct;
+mod first_row;
 mod last_row;
+mod min_max;
 mod ndv;
+mod numeric;
+mod percentile;
 mod registry;
 mod sum;
+mod variance;
 
+pub use avg::AvgFunction;
+pub use count::CountFunction;
+pub use distinct::DistinctAccumulator;
+pub use first_row::FirstRowFunction;
 pub use last_row::LastRowFunction;
+pub use min_max::{MaxFunction, MinFunction};
 pub use ndv::NdvFunction;
+pub use percentile::PercentileFunction;
 pub use registry::{AggregateAccumulator, AggregateFunction, AggregateFunctionRegistry};
 pub use sum::SumFunction;
+pub use variance::VarianceFunction;
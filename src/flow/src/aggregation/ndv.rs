@@ -29,6 +29,10 @@ impl AggregateFunction for NdvFunction {
     fn create_accumulator(&self) -> Box<dyn AggregateAccumulator> {
         Box::new(NdvAccumulator::default())
     }
+
+    fn supports_merge(&self) -> bool {
+        true
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -51,4 +55,17 @@ impl AggregateAccumulator for NdvAccumulator {
     fn finalize(&self) -> Value {
         Value::Int64(i64::try_from(self.distinct_values.len()).unwrap_or(i64::MAX))
     }
+    fn state(&self) -> Vec<Value> {
+        self.distinct_values.iter().cloned().collect()
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        self.distinct_values = state.into_iter().collect();
+        Ok(())
+    }
+
+    fn merge(&mut self, state: Vec<Value>) -> Result<(), String> {
+        self.distinct_values.extend(state);
+        Ok(())
+    }
 }
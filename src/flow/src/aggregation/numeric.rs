@@ -0,0 +1,70 @@
+use datatypes::{ConcreteDatatype, Value};
+
+/// Validate the single numeric argument taken by `avg`, `stddev`, `percentile` and friends.
+pub(super) fn expect_numeric_argument(
+    name: &str,
+    input_types: &[ConcreteDatatype],
+) -> Result<(), String> {
+    let Some(input_type) = input_types.first() else {
+        return Err(format!("{name} expects a numeric argument"));
+    };
+    match input_type {
+        ConcreteDatatype::Int8(_)
+        | ConcreteDatatype::Int16(_)
+        | ConcreteDatatype::Int32(_)
+        | ConcreteDatatype::Int64(_)
+        | ConcreteDatatype::Uint8(_)
+        | ConcreteDatatype::Uint16(_)
+        | ConcreteDatatype::Uint32(_)
+        | ConcreteDatatype::Uint64(_)
+        | ConcreteDatatype::Float32(_)
+        | ConcreteDatatype::Float64(_) => Ok(()),
+        other => Err(format!("{name} does not support type {:?}", other)),
+    }
+}
+
+/// Widen a numeric value to `f64`; nulls yield `None`.
+pub(super) fn numeric_to_f64(name: &str, value: &Value) -> Result<Option<f64>, String> {
+    let widened = match value {
+        Value::Null => return Ok(None),
+        Value::Float32(v) => *v as f64,
+        Value::Float64(v) => *v,
+        Value::Int8(v) => *v as f64,
+        Value::Int16(v) => *v as f64,
+        Value::Int32(v) => *v as f64,
+        Value::Int64(v) => *v as f64,
+        Value::Uint8(v) => *v as f64,
+        Value::Uint16(v) => *v as f64,
+        Value::Uint32(v) => *v as f64,
+        Value::Uint64(v) => *v as f64,
+        other => {
+            return Err(format!(
+                "{name} expects a numeric argument, got {:?}",
+                other
+            ))
+        }
+    };
+    Ok(Some(widened))
+}
+
+/// Read a `Float64` state value written by one of the numeric accumulators.
+pub(super) fn state_f64(name: &str, value: &Value) -> Result<f64, String> {
+    match value {
+        Value::Float64(v) => Ok(*v),
+        other => Err(format!(
+            "{name} expects a Float64 state value, got {:?}",
+            other
+        )),
+    }
+}
+
+/// Read an `Int64` state value written by one of the numeric accumulators.
+pub(super) fn state_i64(name: &str, value: &Value) -> Result<i64, String> {
+    match value {
+        Value::Int64(v) => Ok(*v),
+        other => Err(format!(
+            "{name} expects an Int64 state value, got {:?}",
+            other
+        )),
+    }
+}
@@ -0,0 +1,272 @@
+use super::numeric::{expect_numeric_argument, numeric_to_f64, state_f64};
+use crate::aggregation::{AggregateAccumulator, AggregateFunction};
+use datatypes::{ConcreteDatatype, Float64Type, Value};
+
+/// Centroids left after compression. Accumulators compress once they hold twice this many, so
+/// results are exact for groups of up to `2 * MAX_CENTROIDS` values.
+const MAX_CENTROIDS: usize = 1024;
+
+/// Approximate `percentile(x, p)` for `p` in `[0, 1]`, and `median(x)` as `percentile(x, 0.5)`.
+///
+/// Values are kept as weighted centroids. Once there are more than [`MAX_CENTROIDS`], neighbouring
+/// centroids are merged pairwise, so memory stays bounded and the answer degrades gracefully.
+/// Ranks interpolate linearly between neighbours, matching `percentile_cont` on exact input.
+#[derive(Debug)]
+pub struct PercentileFunction {
+    name: &'static str,
+    fraction: Option<f64>,
+}
+
+impl PercentileFunction {
+    /// `percentile(x, p)`; `p` is read from the first row of each group.
+    pub fn percentile() -> Self {
+        Self {
+            name: "percentile",
+            fraction: None,
+        }
+    }
+
+    /// `median(x)`.
+    pub fn median() -> Self {
+        Self {
+            name: "median",
+            fraction: Some(0.5),
+        }
+    }
+
+    fn arity(&self) -> usize {
+        if self.fraction.is_some() {
+            1
+        } else {
+            2
+        }
+    }
+}
+
+impl AggregateFunction for PercentileFunction {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn return_type(&self, input_types: &[ConcreteDatatype]) -> Result<ConcreteDatatype, String> {
+        if input_types.len() != self.arity() {
+            return Err(format!(
+                "{} expects exactly {} argument(s), got {}",
+                self.name,
+                self.arity(),
+                input_types.len()
+            ));
+        }
+        expect_numeric_argument(self.name, input_types)?;
+        Ok(ConcreteDatatype::Float64(Float64Type))
+    }
+
+    fn create_accumulator(&self) -> Box<dyn AggregateAccumulator> {
+        Box::new(PercentileAccumulator {
+            name: self.name,
+            arity: self.arity(),
+            fraction: self.fraction,
+            centroids: Vec::new(),
+        })
+    }
+
+    fn supports_incremental(&self) -> bool {
+        true
+    }
+
+    fn supports_merge(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Debug, Clone)]
+struct PercentileAccumulator {
+    name: &'static str,
+    arity: usize,
+    fraction: Option<f64>,
+    centroids: Vec<Centroid>,
+}
+
+impl PercentileAccumulator {
+    fn set_fraction(&mut self, value: &Value) -> Result<(), String> {
+        if self.fraction.is_some() {
+            return Ok(());
+        }
+        match numeric_to_f64(self.name, value)? {
+            Some(fraction) if (0.0..=1.0).contains(&fraction) => {
+                self.fraction = Some(fraction);
+                Ok(())
+            }
+            Some(fraction) => Err(format!(
+                "{} fraction must be between 0 and 1, got {fraction}",
+                self.name
+            )),
+            None => Err(format!("{} fraction must not be null", self.name)),
+        }
+    }
+
+    fn push(&mut self, centroid: Centroid) {
+        self.centroids.push(centroid);
+        if self.centroids.len() > 2 * MAX_CENTROIDS {
+            self.compress();
+        }
+    }
+
+    fn compress(&mut self) {
+        self.centroids.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+        while self.centroids.len() > MAX_CENTROIDS {
+            self.centroids = self
+                .centroids
+                .chunks(2)
+                .map(|pair| {
+                    let weight: f64 = pair.iter().map(|c| c.weight).sum();
+                    let mean = pair.iter().map(|c| c.mean * c.weight).sum::<f64>() / weight;
+                    Centroid { mean, weight }
+                })
+                .collect();
+        }
+    }
+
+    fn quantile(&self, fraction: f64) -> Option<f64> {
+        let mut centroids = self.centroids.clone();
+        centroids.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        if centroids.is_empty() || total <= 0.0 {
+            return None;
+        }
+        // Each centroid sits at the middle rank of the values it absorbed.
+        let target = fraction * (total - 1.0);
+        let mut seen = 0.0;
+        let mut previous: Option<(f64, f64)> = None;
+        for centroid in &centroids {
+            let rank = seen + (centroid.weight - 1.0) / 2.0;
+            if rank >= target {
+                return Some(match previous {
+                    Some((prev_rank, prev_mean)) if rank > prev_rank => {
+                        let t = (target - prev_rank) / (rank - prev_rank);
+                        prev_mean + t * (centroid.mean - prev_mean)
+                    }
+                    _ => centroid.mean,
+                });
+            }
+            previous = Some((rank, centroid.mean));
+            seen += centroid.weight;
+        }
+        previous.map(|(_, mean)| mean)
+    }
+}
+
+impl AggregateAccumulator for PercentileAccumulator {
+    fn update(&mut self, args: &[Value]) -> Result<(), String> {
+        if args.len() != self.arity {
+            return Err(format!(
+                "{} expects {} argument(s), got {}",
+                self.name,
+                self.arity,
+                args.len()
+            ));
+        }
+        if let Some(fraction) = args.get(1) {
+            self.set_fraction(fraction)?;
+        }
+        if let Some(value) = numeric_to_f64(self.name, &args[0])? {
+            self.push(Centroid {
+                mean: value,
+                weight: 1.0,
+            });
+        }
+        Ok(())
+    }
+
+    fn finalize(&self) -> Value {
+        self.fraction
+            .and_then(|fraction| self.quantile(fraction))
+            .map(Value::Float64)
+            .unwrap_or(Value::Null)
+    }
+    /// `[fraction, mean_0, weight_0, mean_1, weight_1, ...]`, with a null fraction before any row.
+    fn state(&self) -> Vec<Value> {
+        let mut state = Vec::with_capacity(1 + 2 * self.centroids.len());
+        state.push(self.fraction.map(Value::Float64).unwrap_or(Value::Null));
+        for centroid in &self.centroids {
+            state.push(Value::Float64(centroid.mean));
+            state.push(Value::Float64(centroid.weight));
+        }
+        state
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        self.centroids.clear();
+        self.merge(state)
+    }
+
+    fn merge(&mut self, state: Vec<Value>) -> Result<(), String> {
+        let Some((fraction, centroids)) = state.split_first() else {
+            return Ok(());
+        };
+        if centroids.len() % 2 != 0 {
+            return Err(format!(
+                "{} expects mean/weight pairs in its state, got {} values",
+                self.name,
+                centroids.len()
+            ));
+        }
+        if !fraction.is_null() {
+            self.set_fraction(fraction)?;
+        }
+        for pair in centroids.chunks(2) {
+            self.push(Centroid {
+                mean: state_f64(self.name, &pair[0])?,
+                weight: state_f64(self.name, &pair[1])?,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_below_capacity() {
+        let mut acc = PercentileFunction::percentile().create_accumulator();
+        for value in [15, 20, 35, 40, 50] {
+            acc.update(&[Value::Int64(value), Value::Float64(0.4)])
+                .unwrap();
+        }
+        acc.update(&[Value::Null, Value::Float64(0.4)]).unwrap();
+        assert_eq!(acc.finalize(), Value::Float64(29.0));
+
+        let mut median = PercentileFunction::median().create_accumulator();
+        assert_eq!(median.finalize(), Value::Null);
+        median.merge(acc.state()).unwrap();
+        assert_eq!(median.finalize(), Value::Float64(35.0));
+    }
+
+    #[test]
+    fn approximates_beyond_capacity() {
+        let mut acc = PercentileFunction::median().create_accumulator();
+        for value in 0..10_000 {
+            acc.update(&[Value::Int64(value)]).unwrap();
+        }
+        let Value::Float64(median) = acc.finalize() else {
+            panic!("expected Float64 median");
+        };
+        assert!((median - 4999.5).abs() < 10.0, "median was {median}");
+    }
+
+    #[test]
+    fn rejects_out_of_range_fraction() {
+        let mut acc = PercentileFunction::percentile().create_accumulator();
+        assert!(acc
+            .update(&[Value::Int64(1), Value::Float64(95.0)])
+            .is_err());
+    }
+}
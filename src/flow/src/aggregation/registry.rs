@@ -1,4 +1,7 @@
-use crate::aggregation::{LastRowFunction, NdvFunction, SumFunction};
+use crate::aggregation::{
+    AvgFunction, CountFunction, FirstRowFunction, LastRowFunction, MaxFunction, MinFunction,
+    NdvFunction, PercentileFunction, SumFunction, VarianceFunction,
+};
 use datatypes::{ConcreteDatatype, Value};
 use parser::aggregate_registry::AggregateRegistry;
 use std::collections::HashMap;
@@ -7,6 +10,30 @@ use std::sync::{Arc, RwLock};
 pub trait AggregateAccumulator: Send + Sync {
     fn update(&mut self, args: &[Value]) -> Result<(), String>;
     fn finalize(&self) -> Value;
+    /// Intermediate state captured by checkpoints; accumulators without state keep the default.
+    fn state(&self) -> Vec<Value> {
+        Vec::new()
+    }
+    /// Restore the intermediate state produced by [`AggregateAccumulator::state`].
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        if state.is_empty() {
+            Ok(())
+        } else {
+            Err("accumulator does not support restoring state".to_string())
+        }
+    }
+    /// Fold in the [`AggregateAccumulator::state`] of another accumulator of the same function.
+    ///
+    /// Partial states are merged in arrival order: `last_row` keeps the later one, `first_row` the
+    /// earlier one.
+    fn merge(&mut self, _state: Vec<Value>) -> Result<(), String> {
+        Err("accumulator does not support merging".to_string())
+    }
+    /// Undo an earlier [`AggregateAccumulator::update`] with the same arguments, so sliding
+    /// windows can expire rows without rebuilding the accumulator.
+    fn retract(&mut self, _args: &[Value]) -> Result<(), String> {
+        Err("accumulator does not support retraction".to_string())
+    }
 }
 
 pub trait AggregateFunction: Send + Sync {
@@ -17,6 +44,14 @@ pub trait AggregateFunction: Send + Sync {
     fn supports_incremental(&self) -> bool {
         false
     }
+    /// Whether accumulators implement [`AggregateAccumulator::merge`].
+    fn supports_merge(&self) -> bool {
+        false
+    }
+    /// Whether accumulators implement [`AggregateAccumulator::retract`].
+    fn supports_retract(&self) -> bool {
+        false
+    }
 }
 
 pub struct AggregateFunctionRegistry {
@@ -68,10 +103,41 @@ impl AggregateFunctionRegistry {
             .unwrap_or(false)
     }
 
+    /// Check if the given aggregate function can merge partial accumulator states.
+    pub fn supports_merge(&self, name: &str) -> bool {
+        self.functions
+            .read()
+            .expect("aggregate function registry poisoned")
+            .get(&name.to_lowercase())
+            .map(|f| f.supports_merge())
+            .unwrap_or(false)
+    }
+
+    /// Check if the given aggregate function can retract rows it has already accumulated.
+    pub fn supports_retract(&self, name: &str) -> bool {
+        self.functions
+            .read()
+            .expect("aggregate function registry poisoned")
+            .get(&name.to_lowercase())
+            .map(|f| f.supports_retract())
+            .unwrap_or(false)
+    }
+
     fn register_builtin_functions(&self) {
         self.register_function(Arc::new(SumFunction::new()));
+        self.register_function(Arc::new(CountFunction::new()));
+        self.register_function(Arc::new(AvgFunction::new()));
+        self.register_function(Arc::new(MinFunction::new()));
+        self.register_function(Arc::new(MaxFunction::new()));
+        self.register_function(Arc::new(FirstRowFunction::new()));
         self.register_function(Arc::new(LastRowFunction::new()));
         self.register_function(Arc::new(NdvFunction::new()));
+        self.register_function(Arc::new(VarianceFunction::variance()));
+        self.register_function(Arc::new(VarianceFunction::var_samp()));
+        self.register_function(Arc::new(VarianceFunction::stddev()));
+        self.register_function(Arc::new(VarianceFunction::stddev_samp()));
+        self.register_function(Arc::new(PercentileFunction::percentile()));
+        self.register_function(Arc::new(PercentileFunction::median()));
     }
 }
 
@@ -132,4 +198,18 @@ mod tests {
         assert!(!registry.supports_incremental("dummy"));
         assert!(!registry.supports_incremental("nonexistent"));
     }
+
+    #[test]
+    fn test_supports_retract_lookup() {
+        let registry = AggregateFunctionRegistry::with_builtins();
+        for name in ["sum", "count", "avg", "variance", "stddev_samp"] {
+            assert!(registry.supports_retract(name), "{name} should retract");
+        }
+        for name in ["min", "max", "last_row", "ndv", "median", "nonexistent"] {
+            assert!(
+                !registry.supports_retract(name),
+                "{name} should not retract"
+            );
+        }
+    }
 }
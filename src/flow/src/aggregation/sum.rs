@@ -1,3 +1,4 @@
+use super::numeric::state_i64;
 use crate::aggregation::{AggregateAccumulator, AggregateFunction};
 use crate::expr::func::BinaryFunc;
 use datatypes::{ConcreteDatatype, Value};
@@ -57,11 +58,21 @@ impl AggregateFunction for SumFunction {
     fn supports_incremental(&self) -> bool {
         true
     }
+
+    fn supports_merge(&self) -> bool {
+        true
+    }
+
+    fn supports_retract(&self) -> bool {
+        true
+    }
 }
 
 #[derive(Debug, Default, Clone)]
 struct SumAccumulator {
     acc: Option<Value>,
+    /// Non-null values folded into `acc`, so retracting the last one yields null again.
+    count: i64,
 }
 
 impl SumAccumulator {
@@ -84,6 +95,9 @@ impl AggregateAccumulator for SumAccumulator {
         let Some(value) = args.first() else {
             return Err("SUM expects one argument".to_string());
         };
+        if !value.is_null() {
+            self.count += 1;
+        }
         self.acc = Self::add_values(self.acc.take(), value.clone())?;
         Ok(())
     }
@@ -91,4 +105,73 @@ impl AggregateAccumulator for SumAccumulator {
     fn finalize(&self) -> Value {
         self.acc.clone().unwrap_or(Value::Null)
     }
+    /// `[sum, count]`, or empty before the first non-null value.
+    fn state(&self) -> Vec<Value> {
+        match &self.acc {
+            Some(acc) => vec![acc.clone(), Value::Int64(self.count)],
+            None => Vec::new(),
+        }
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        *self = Self::default();
+        self.merge(state)
+    }
+
+    fn merge(&mut self, state: Vec<Value>) -> Result<(), String> {
+        match state.as_slice() {
+            [] => Ok(()),
+            [acc, count] => {
+                self.acc = Self::add_values(self.acc.take(), acc.clone())?;
+                self.count += state_i64("SUM", count)?;
+                Ok(())
+            }
+            other => Err(format!(
+                "SUM expects 0 or 2 state values, got {}",
+                other.len()
+            )),
+        }
+    }
+
+    fn retract(&mut self, args: &[Value]) -> Result<(), String> {
+        let Some(value) = args.first() else {
+            return Err("SUM expects one argument".to_string());
+        };
+        if value.is_null() {
+            return Ok(());
+        }
+        let Some(acc) = self.acc.take() else {
+            return Err("SUM cannot retract a value it never saw".to_string());
+        };
+        self.count -= 1;
+        if self.count > 0 {
+            self.acc = Some(
+                BinaryFunc::Sub
+                    .eval_binary(acc, value.clone())
+                    .map_err(|err| err.to_string())?,
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retract_returns_to_null_after_last_value() {
+        let mut acc = SumFunction::new().create_accumulator();
+        for value in [Value::Int64(3), Value::Null, Value::Int64(4)] {
+            acc.update(&[value]).unwrap();
+        }
+        acc.retract(&[Value::Int64(3)]).unwrap();
+        assert_eq!(acc.finalize(), Value::Int64(4));
+
+        let mut restored = SumFunction::new().create_accumulator();
+        restored.restore(acc.state()).unwrap();
+        restored.retract(&[Value::Null]).unwrap();
+        restored.retract(&[Value::Int64(4)]).unwrap();
+        assert_eq!(restored.finalize(), Value::Null);
+    }
 }
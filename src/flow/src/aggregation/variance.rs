@@ -0,0 +1,267 @@
+use super::numeric::{expect_numeric_argument, numeric_to_f64, state_f64, state_i64};
+use crate::aggregation::{AggregateAccumulator, AggregateFunction};
+use datatypes::{ConcreteDatatype, Float64Type, Value};
+
+/// Variance and standard deviation, population or sample, over numeric values.
+///
+/// Accumulators keep Welford's running mean and sum of squared deviations, so partial states
+/// from different panes can be combined without revisiting rows.
+#[derive(Debug)]
+pub struct VarianceFunction {
+    name: &'static str,
+    sample: bool,
+    sqrt: bool,
+}
+
+impl VarianceFunction {
+    /// `variance(x)`: population variance.
+    pub fn variance() -> Self {
+        Self {
+            name: "variance",
+            sample: false,
+            sqrt: false,
+        }
+    }
+
+    /// `var_samp(x)`: sample variance, null for fewer than two values.
+    pub fn var_samp() -> Self {
+        Self {
+            name: "var_samp",
+            sample: true,
+            sqrt: false,
+        }
+    }
+
+    /// `stddev(x)`: population standard deviation.
+    pub fn stddev() -> Self {
+        Self {
+            name: "stddev",
+            sample: false,
+            sqrt: true,
+        }
+    }
+
+    /// `stddev_samp(x)`: sample standard deviation, null for fewer than two values.
+    pub fn stddev_samp() -> Self {
+        Self {
+            name: "stddev_samp",
+            sample: true,
+            sqrt: true,
+        }
+    }
+}
+
+impl AggregateFunction for VarianceFunction {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn return_type(&self, input_types: &[ConcreteDatatype]) -> Result<ConcreteDatatype, String> {
+        if input_types.len() != 1 {
+            return Err(format!(
+                "{} expects exactly one argument, got {}",
+                self.name,
+                input_types.len()
+            ));
+        }
+        expect_numeric_argument(self.name, input_types)?;
+        Ok(ConcreteDatatype::Float64(Float64Type))
+    }
+
+    fn create_accumulator(&self) -> Box<dyn AggregateAccumulator> {
+        Box::new(VarianceAccumulator {
+            name: self.name,
+            sample: self.sample,
+            sqrt: self.sqrt,
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+        })
+    }
+
+    fn supports_incremental(&self) -> bool {
+        true
+    }
+
+    fn supports_merge(&self) -> bool {
+        true
+    }
+
+    fn supports_retract(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+struct VarianceAccumulator {
+    name: &'static str,
+    sample: bool,
+    sqrt: bool,
+    count: i64,
+    mean: f64,
+    /// Sum of squared deviations from `mean`.
+    m2: f64,
+}
+
+impl VarianceAccumulator {
+    /// Chan et al.'s pairwise update; a single value is the `count = 1, m2 = 0` case.
+    fn combine(&mut self, count: i64, mean: f64, m2: f64) {
+        if count == 0 {
+            return;
+        }
+        let total = self.count + count;
+        let delta = mean - self.mean;
+        self.mean += delta * count as f64 / total as f64;
+        self.m2 += m2 + delta * delta * self.count as f64 * count as f64 / total as f64;
+        self.count = total;
+    }
+
+    /// Welford's update run backwards: removes a single value from the running moments.
+    fn remove(&mut self, value: f64) {
+        if self.count <= 1 {
+            self.count = 0;
+            self.mean = 0.0;
+            self.m2 = 0.0;
+            return;
+        }
+        let remaining = self.count - 1;
+        let mean = (self.mean * self.count as f64 - value) / remaining as f64;
+        self.m2 = (self.m2 - (value - self.mean) * (value - mean)).max(0.0);
+        self.mean = mean;
+        self.count = remaining;
+    }
+}
+
+impl AggregateAccumulator for VarianceAccumulator {
+    fn update(&mut self, args: &[Value]) -> Result<(), String> {
+        let Some(value) = args.first() else {
+            return Err(format!("{} expects one argument", self.name));
+        };
+        if let Some(value) = numeric_to_f64(self.name, value)? {
+            self.combine(1, value, 0.0);
+        }
+        Ok(())
+    }
+
+    fn finalize(&self) -> Value {
+        let divisor = if self.sample {
+            self.count - 1
+        } else {
+            self.count
+        };
+        if divisor <= 0 {
+            return Value::Null;
+        }
+        let variance = self.m2 / divisor as f64;
+        Value::Float64(if self.sqrt { variance.sqrt() } else { variance })
+    }
+    fn state(&self) -> Vec<Value> {
+        if self.count == 0 {
+            Vec::new()
+        } else {
+            vec![
+                Value::Int64(self.count),
+                Value::Float64(self.mean),
+                Value::Float64(self.m2),
+            ]
+        }
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        self.count = 0;
+        self.mean = 0.0;
+        self.m2 = 0.0;
+        self.merge(state)
+    }
+
+    fn merge(&mut self, state: Vec<Value>) -> Result<(), String> {
+        match state.as_slice() {
+            [] => Ok(()),
+            [count, mean, m2] => {
+                self.combine(
+                    state_i64(self.name, count)?,
+                    state_f64(self.name, mean)?,
+                    state_f64(self.name, m2)?,
+                );
+                Ok(())
+            }
+            other => Err(format!(
+                "{} expects 0 or 3 state values, got {}",
+                self.name,
+                other.len()
+            )),
+        }
+    }
+
+    fn retract(&mut self, args: &[Value]) -> Result<(), String> {
+        let Some(value) = args.first() else {
+            return Err(format!("{} expects one argument", self.name));
+        };
+        if let Some(value) = numeric_to_f64(self.name, value)? {
+            self.remove(value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(function: &VarianceFunction, values: &[i64]) -> Box<dyn AggregateAccumulator> {
+        let mut acc = function.create_accumulator();
+        for value in values {
+            acc.update(&[Value::Int64(*value)]).unwrap();
+        }
+        acc
+    }
+
+    fn as_f64(value: Value) -> f64 {
+        match value {
+            Value::Float64(v) => v,
+            other => panic!("expected Float64, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn merged_partials_match_a_single_pass() {
+        let values = [2, 4, 4, 4, 5, 5, 7, 9];
+        for function in [
+            VarianceFunction::variance(),
+            VarianceFunction::var_samp(),
+            VarianceFunction::stddev(),
+            VarianceFunction::stddev_samp(),
+        ] {
+            let whole = as_f64(feed(&function, &values).finalize());
+            let mut left = feed(&function, &values[..3]);
+            left.merge(feed(&function, &values[3..]).state()).unwrap();
+            assert!((as_f64(left.finalize()) - whole).abs() < 1e-9);
+        }
+        assert_eq!(
+            as_f64(feed(&VarianceFunction::stddev(), &values).finalize()),
+            2.0
+        );
+    }
+
+    #[test]
+    fn retracting_matches_a_pass_without_the_value() {
+        let function = VarianceFunction::var_samp();
+        let mut acc = feed(&function, &[1, 2, 4, 4, 5, 5, 7, 9]);
+        acc.retract(&[Value::Int64(1)]).unwrap();
+        acc.retract(&[Value::Null]).unwrap();
+        let expected = as_f64(feed(&function, &[2, 4, 4, 5, 5, 7, 9]).finalize());
+        assert!((as_f64(acc.finalize()) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sample_variants_need_two_values() {
+        let function = VarianceFunction::var_samp();
+        let mut acc = feed(&function, &[3]);
+        acc.update(&[Value::Null]).unwrap();
+        assert_eq!(acc.finalize(), Value::Null);
+        assert_eq!(
+            feed(&VarianceFunction::variance(), &[3]).finalize(),
+            Value::Float64(0.0)
+        );
+    }
+}
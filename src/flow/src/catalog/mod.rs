@@ -1,8 +1,16 @@
+use crate::connector::{FileFraming, FileReadMode, KafkaStartOffset};
 use datatypes::Schema;
 use serde_json::{Map as JsonMap, Value as JsonValue};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
+mod table;
+
+pub use table::{
+    decode_table_csv, decode_table_json, load_table_file, LookupTable, TableError, TableFileFormat,
+    TableSource, TableStreamProps,
+};
+
 /// Errors that can occur when mutating the catalog.
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum CatalogError {
@@ -19,6 +27,14 @@ pub enum StreamProps {
     Mqtt(MqttStreamProps),
     /// Stream is backed by an in-memory mock connector (tests only).
     Mock(MockStreamProps),
+    /// Stream replays or tails payloads stored in local files.
+    File(FileStreamProps),
+    /// Stream fed by payloads posted to the HTTP ingest endpoint.
+    Http(HttpStreamProps),
+    /// Stream consuming a Kafka topic.
+    Kafka(KafkaStreamProps),
+    /// Lookup table kept in memory; only usable as the table side of a JOIN.
+    Table(TableStreamProps),
 }
 
 /// Supported stream types recognized by the catalog.
@@ -28,6 +44,14 @@ pub enum StreamType {
     Mqtt,
     /// Stream backed by a mock source.
     Mock,
+    /// Stream backed by local files.
+    File,
+    /// Stream backed by HTTP push.
+    Http,
+    /// Stream backed by a Kafka topic.
+    Kafka,
+    /// Lookup table.
+    Table,
 }
 
 /// Properties for MQTT-backed streams.
@@ -66,6 +90,92 @@ impl MqttStreamProps {
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct MockStreamProps {}
 
+/// Properties for file-backed streams.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileStreamProps {
+    /// A file path, or a pattern whose last component may contain `*` and `?`.
+    pub path: String,
+    pub framing: FileFraming,
+    pub mode: FileReadMode,
+}
+
+impl FileStreamProps {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            framing: FileFraming::default(),
+            mode: FileReadMode::default(),
+        }
+    }
+
+    pub fn with_framing(mut self, framing: FileFraming) -> Self {
+        self.framing = framing;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: FileReadMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// Properties for HTTP push streams.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HttpStreamProps {
+    /// Path under the ingest endpoint that payloads are posted to.
+    pub path: String,
+}
+
+impl HttpStreamProps {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+/// Properties for Kafka-backed streams.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct KafkaStreamProps {
+    pub brokers: String,
+    pub topic: String,
+    /// Consumer group; defaults to one per pipeline and stream.
+    pub group_id: Option<String>,
+    /// Partitions to read; empty lets the consumer group assign them.
+    pub partitions: Vec<i32>,
+    pub start_offset: KafkaStartOffset,
+    /// Extra librdkafka consumer properties.
+    pub properties: Vec<(String, String)>,
+}
+
+impl KafkaStreamProps {
+    pub fn new(brokers: impl Into<String>, topic: impl Into<String>) -> Self {
+        Self {
+            brokers: brokers.into(),
+            topic: topic.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_group_id(mut self, group_id: impl Into<String>) -> Self {
+        self.group_id = Some(group_id.into());
+        self
+    }
+
+    pub fn with_partitions(mut self, partitions: Vec<i32>) -> Self {
+        self.partitions = partitions;
+        self
+    }
+
+    pub fn with_start_offset(mut self, start_offset: KafkaStartOffset) -> Self {
+        self.start_offset = start_offset;
+        self
+    }
+
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.push((key.into(), value.into()));
+        self
+    }
+}
+
 /// Complete definition for a stream tracked by the catalog.
 #[derive(Debug, Clone)]
 pub struct StreamDefinition {
@@ -111,6 +221,10 @@ impl StreamDefinition {
         let stream_type = match props {
             StreamProps::Mqtt(_) => StreamType::Mqtt,
             StreamProps::Mock(_) => StreamType::Mock,
+            StreamProps::File(_) => StreamType::File,
+            StreamProps::Http(_) => StreamType::Http,
+            StreamProps::Kafka(_) => StreamType::Kafka,
+            StreamProps::Table(_) => StreamType::Table,
         };
         Self {
             id: id.into(),
@@ -150,6 +264,14 @@ impl StreamDefinition {
     pub fn eventtime(&self) -> Option<&EventtimeDefinition> {
         self.eventtime.as_ref()
     }
+
+    /// Table properties when this definition describes a lookup table.
+    pub fn table_props(&self) -> Option<&TableStreamProps> {
+        match &self.props {
+            StreamProps::Table(props) => Some(props),
+            _ => None,
+        }
+    }
 }
 
 /// Configuration describing which decoder should be used for a stream's payloads.
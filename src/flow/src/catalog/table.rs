@@ -0,0 +1,260 @@
+//! Lookup tables: static or slowly changing datasets that streams can `JOIN` against.
+//!
+//! A table lives in the catalog next to streams (see [`super::StreamProps::Table`]). Its rows are
+//! held in a shared [`LookupTable`] handle, so replacing the rows is picked up by running
+//! pipelines on their next lookup without a restart.
+
+use crate::codec::{CsvDecoder, JsonDecoder, RecordDecoder};
+use crate::model::Tuple;
+use datatypes::Schema;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// Errors raised while loading table rows.
+#[derive(Debug, thiserror::Error)]
+pub enum TableError {
+    #[error("failed to read table file {path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("invalid table data: {0}")]
+    InvalidData(String),
+    #[error("table {0} is not backed by a file and cannot be reloaded")]
+    NotReloadable(String),
+    #[error("stream {0} is not a table")]
+    NotATable(String),
+}
+
+/// File formats a table can be loaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFileFormat {
+    /// Comma separated values with a header row naming the columns.
+    Csv,
+    /// A JSON array of objects (a single object is accepted as a one-row table).
+    Json,
+}
+
+impl TableFileFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Infer the format from a file extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Self::parse)
+    }
+}
+
+/// Where the rows of a table come from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableSource {
+    /// Rows are loaded from a local file and can be reloaded from it.
+    File {
+        path: PathBuf,
+        format: TableFileFormat,
+    },
+    /// Rows are pushed through the API.
+    Api,
+}
+
+/// Properties for table definitions.
+#[derive(Debug, Clone)]
+pub struct TableStreamProps {
+    pub source: TableSource,
+    data: LookupTable,
+}
+
+impl TableStreamProps {
+    pub fn new(source: TableSource) -> Self {
+        Self {
+            source,
+            data: LookupTable::default(),
+        }
+    }
+
+    pub fn file(path: impl Into<PathBuf>, format: TableFileFormat) -> Self {
+        Self::new(TableSource::File {
+            path: path.into(),
+            format,
+        })
+    }
+
+    pub fn api() -> Self {
+        Self::new(TableSource::Api)
+    }
+
+    /// Shared handle to the in-memory rows.
+    pub fn data(&self) -> &LookupTable {
+        &self.data
+    }
+}
+
+impl PartialEq for TableStreamProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source && self.data.same_table(&other.data)
+    }
+}
+
+impl Eq for TableStreamProps {}
+
+/// Shared in-memory rows of a table.
+///
+/// Clones share the same storage; [`LookupTable::replace`] swaps in a new snapshot while readers
+/// holding the previous one keep using it until they take a fresh snapshot.
+#[derive(Clone, Default)]
+pub struct LookupTable {
+    rows: Arc<RwLock<Arc<Vec<Tuple>>>>,
+}
+
+impl LookupTable {
+    /// Current rows of the table.
+    pub fn snapshot(&self) -> Arc<Vec<Tuple>> {
+        Arc::clone(&self.rows.read().expect("lookup table poisoned"))
+    }
+
+    /// Replace all rows of the table.
+    pub fn replace(&self, rows: Vec<Tuple>) {
+        *self.rows.write().expect("lookup table poisoned") = Arc::new(rows);
+    }
+
+    pub fn len(&self) -> usize {
+        self.snapshot().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether both handles point to the same table storage.
+    pub fn same_table(&self, other: &LookupTable) -> bool {
+        Arc::ptr_eq(&self.rows, &other.rows)
+    }
+}
+
+impl fmt::Debug for LookupTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LookupTable")
+            .field("rows", &self.len())
+            .finish()
+    }
+}
+
+/// Load table rows from a local file.
+pub fn load_table_file(
+    table_name: &str,
+    schema: Arc<Schema>,
+    path: &Path,
+    format: TableFileFormat,
+) -> Result<Vec<Tuple>, TableError> {
+    let content = std::fs::read_to_string(path).map_err(|source| TableError::Io {
+        path: path.display().to_string(),
+        source,
+    })?;
+    match format {
+        TableFileFormat::Json => decode_table_json(table_name, schema, content.as_bytes()),
+        TableFileFormat::Csv => decode_table_csv(table_name, schema, &content),
+    }
+}
+
+/// Decode table rows from a JSON array of objects.
+pub fn decode_table_json(
+    table_name: &str,
+    schema: Arc<Schema>,
+    payload: &[u8],
+) -> Result<Vec<Tuple>, TableError> {
+    let decoder = JsonDecoder::new(table_name, schema, JsonMap::new());
+    let batch = decoder
+        .decode(payload)
+        .map_err(|err| TableError::InvalidData(err.to_string()))?;
+    Ok(batch.rows().to_vec())
+}
+
+/// Decode table rows from CSV text whose first line names the columns.
+///
+/// Cells are converted by the CSV codec, so an empty cell is NULL, columns missing from the
+/// header are NULL for every row and a cell that does not parse as its column type is an error.
+pub fn decode_table_csv(
+    table_name: &str,
+    schema: Arc<Schema>,
+    content: &str,
+) -> Result<Vec<Tuple>, TableError> {
+    let mut props = JsonMap::new();
+    props.insert("header".to_string(), JsonValue::Bool(true));
+    let batch = CsvDecoder::new(table_name, schema, props)
+        .and_then(|decoder| decoder.decode(content.as_bytes()))
+        .map_err(|err| TableError::InvalidData(err.to_string()))?;
+    Ok(batch.rows().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datatypes::{ColumnSchema, ConcreteDatatype, Int64Type, StringType, Value};
+
+    fn schema() -> Arc<Schema> {
+        Arc::new(Schema::new(vec![
+            ColumnSchema::new(
+                "devices".to_string(),
+                "id".to_string(),
+                ConcreteDatatype::Int64(Int64Type),
+            ),
+            ColumnSchema::new(
+                "devices".to_string(),
+                "name".to_string(),
+                ConcreteDatatype::String(StringType),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn decode_csv_rows_by_header() {
+        let rows = decode_table_csv(
+            "devices",
+            schema(),
+            "name,id,ignored\n\"kitchen, north\",1,x\nhall,2,y\n,3,z\n",
+        )
+        .unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[0].value_by_name("devices", "id"),
+            Some(&Value::Int64(1))
+        );
+        assert_eq!(
+            rows[0].value_by_name("devices", "name"),
+            Some(&Value::String("kitchen, north".to_string()))
+        );
+        assert_eq!(rows[2].value_by_name("devices", "name"), Some(&Value::Null));
+
+        let err = decode_table_csv("devices", schema(), "id,name\nabc,x\n").unwrap_err();
+        assert!(err.to_string().contains("invalid integer"), "{err}");
+    }
+
+    #[test]
+    fn lookup_table_replace_is_visible_to_clones() {
+        let table = LookupTable::default();
+        let reader = table.clone();
+        let before = reader.snapshot();
+
+        let rows = decode_table_json(
+            "devices",
+            schema(),
+            br#"[{"id": 1, "name": "a"}, {"id": 2, "name": "b"}]"#,
+        )
+        .unwrap();
+        table.replace(rows);
+
+        assert!(before.is_empty());
+        assert_eq!(reader.len(), 2);
+        assert!(reader.same_table(&table));
+    }
+}
@@ -0,0 +1,221 @@
+//! CSV dialect shared by the CSV decoder and encoder: record splitting and cell quoting.
+
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+/// A parsed CSV cell. Quoted cells keep an empty string distinct from a missing value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CsvCell {
+    pub text: String,
+    pub quoted: bool,
+}
+
+/// Delimiter and quote character, read from the codec `delimiter` and `quote` props.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CsvDialect {
+    pub delimiter: char,
+    pub quote: char,
+}
+
+impl Default for CsvDialect {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quote: '"',
+        }
+    }
+}
+
+impl CsvDialect {
+    pub fn from_props(props: &JsonMap<String, JsonValue>) -> Result<Self, String> {
+        let defaults = Self::default();
+        let delimiter = char_prop(props, "delimiter", defaults.delimiter)?;
+        let quote = char_prop(props, "quote", defaults.quote)?;
+        if delimiter == quote {
+            return Err(format!(
+                "csv delimiter and quote must differ, both are {delimiter:?}"
+            ));
+        }
+        if matches!(delimiter, '\r' | '\n') || matches!(quote, '\r' | '\n') {
+            return Err("csv delimiter and quote cannot be line breaks".to_string());
+        }
+        Ok(Self { delimiter, quote })
+    }
+
+    /// Split a payload into records of cells.
+    ///
+    /// Records end at `\n` or `\r\n` outside quotes; quoted cells may span lines and escape the
+    /// quote character by doubling it. Blank lines are skipped.
+    pub fn split_records(&self, text: &str) -> Result<Vec<Vec<CsvCell>>, String> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut cell = String::new();
+        let mut quoted = false;
+        let mut in_quotes = false;
+        let mut chars = text.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if in_quotes {
+                if ch == self.quote {
+                    if chars.peek() == Some(&self.quote) {
+                        cell.push(ch);
+                        chars.next();
+                    } else {
+                        in_quotes = false;
+                    }
+                } else {
+                    cell.push(ch);
+                }
+                continue;
+            }
+            match ch {
+                c if c == self.quote && cell.is_empty() && !quoted => {
+                    in_quotes = true;
+                    quoted = true;
+                }
+                c if c == self.delimiter => {
+                    record.push(CsvCell {
+                        text: std::mem::take(&mut cell),
+                        quoted: std::mem::take(&mut quoted),
+                    });
+                }
+                '\r' if chars.peek() == Some(&'\n') => {}
+                '\n' => {
+                    finish_record(&mut records, &mut record, &mut cell, &mut quoted);
+                }
+                _ => cell.push(ch),
+            }
+        }
+        if in_quotes {
+            return Err(format!(
+                "unterminated quoted csv cell in record {}",
+                records.len() + 1
+            ));
+        }
+        finish_record(&mut records, &mut record, &mut cell, &mut quoted);
+        Ok(records)
+    }
+
+    /// Append `cell` to `out`, quoting it when it contains the delimiter, the quote or a line
+    /// break.
+    pub fn write_cell(&self, out: &mut String, cell: &str) {
+        let needs_quotes = cell
+            .chars()
+            .any(|c| c == self.delimiter || c == self.quote || c == '\n' || c == '\r');
+        if !needs_quotes {
+            out.push_str(cell);
+            return;
+        }
+        out.push(self.quote);
+        for c in cell.chars() {
+            if c == self.quote {
+                out.push(c);
+            }
+            out.push(c);
+        }
+        out.push(self.quote);
+    }
+
+    /// Append one record terminated by `\n`.
+    pub fn write_record<'a>(&self, out: &mut String, cells: impl IntoIterator<Item = &'a str>) {
+        for (idx, cell) in cells.into_iter().enumerate() {
+            if idx > 0 {
+                out.push(self.delimiter);
+            }
+            self.write_cell(out, cell);
+        }
+        out.push('\n');
+    }
+}
+
+fn finish_record(
+    records: &mut Vec<Vec<CsvCell>>,
+    record: &mut Vec<CsvCell>,
+    cell: &mut String,
+    quoted: &mut bool,
+) {
+    let blank_line = record.is_empty() && cell.is_empty() && !*quoted;
+    if !blank_line {
+        record.push(CsvCell {
+            text: std::mem::take(cell),
+            quoted: std::mem::take(quoted),
+        });
+        records.push(std::mem::take(record));
+    }
+}
+
+fn char_prop(props: &JsonMap<String, JsonValue>, key: &str, default: char) -> Result<char, String> {
+    match props.get(key) {
+        None | Some(JsonValue::Null) => Ok(default),
+        Some(JsonValue::String(text)) => {
+            let text = if text == "\\t" { "\t" } else { text.as_str() };
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(c),
+                _ => Err(format!(
+                    "csv `{key}` must be a single character, got {text:?}"
+                )),
+            }
+        }
+        Some(other) => Err(format!("csv `{key}` must be a string, got {other}")),
+    }
+}
+
+/// Read an optional boolean prop.
+pub(crate) fn bool_prop(
+    props: &JsonMap<String, JsonValue>,
+    key: &str,
+    default: bool,
+) -> Result<bool, String> {
+    match props.get(key) {
+        None | Some(JsonValue::Null) => Ok(default),
+        Some(JsonValue::Bool(value)) => Ok(*value),
+        Some(other) => Err(format!("csv `{key}` must be a boolean, got {other}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(records: Vec<Vec<CsvCell>>) -> Vec<Vec<String>> {
+        records
+            .into_iter()
+            .map(|record| record.into_iter().map(|cell| cell.text).collect())
+            .collect()
+    }
+
+    #[test]
+    fn split_records_handles_quotes_and_line_breaks() {
+        let dialect = CsvDialect::default();
+        let records = dialect
+            .split_records("a,\"b,\"\"c\"\"\"\r\n\n\"multi\nline\",\n")
+            .expect("split");
+        assert_eq!(
+            texts(records),
+            vec![
+                vec!["a".to_string(), "b,\"c\"".to_string()],
+                vec!["multi\nline".to_string(), String::new()],
+            ]
+        );
+        assert!(dialect.split_records("\"open").is_err());
+    }
+
+    #[test]
+    fn write_record_quotes_only_when_needed() {
+        let mut props = JsonMap::new();
+        props.insert("delimiter".to_string(), JsonValue::String(";".to_string()));
+        let dialect = CsvDialect::from_props(&props).expect("dialect");
+        let mut out = String::new();
+        dialect.write_record(&mut out, ["plain", "a;b", "say \"hi\"", "x,y"]);
+        assert_eq!(out, "plain;\"a;b\";\"say \"\"hi\"\"\";x,y\n");
+        let records = dialect.split_records(&out).expect("split");
+        assert_eq!(
+            texts(records),
+            vec![vec![
+                "plain".to_string(),
+                "a;b".to_string(),
+                "say \"hi\"".to_string(),
+                "x,y".to_string()
+            ]]
+        );
+    }
+}
@@ -0,0 +1,421 @@
+//! Decoder turning CSV records into a RecordBatch, one row per record.
+
+use super::{json_to_value_with_datatype, CodecError, RecordDecoder};
+use crate::codec::csv::{bool_prop, CsvCell, CsvDialect};
+use crate::model::{Message, RecordBatch, Tuple};
+use datatypes::{ConcreteDatatype, Schema, Value};
+use serde_json::{Map as JsonMap, Number as JsonNumber, Value as JsonValue};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Where the cell at a CSV position goes.
+#[derive(Debug, Clone)]
+enum CsvTarget {
+    /// Schema column at this index, converted to its datatype.
+    Schema(usize),
+    /// Column outside the schema, kept as a string like extra JSON keys.
+    Extra(Arc<str>),
+    /// Position that is not mapped to any column.
+    Skip,
+}
+
+/// Decoder for CSV payloads; every record of a payload becomes one row.
+///
+/// Props:
+/// - `delimiter` / `quote`: single characters, `,` and `"` by default (`"\t"` for tabs).
+/// - `header`: the first record of every payload names the columns.
+/// - `columns`: column name per CSV position, `null` to skip a position. Takes precedence over
+///   the header; without either, cells map to the schema columns in order.
+///
+/// Empty unquoted cells decode to `NULL`; a cell that does not parse as its column datatype fails
+/// the payload.
+pub struct CsvDecoder {
+    stream_name: String,
+    schema: Arc<Schema>,
+    schema_keys: Vec<Arc<str>>,
+    dialect: CsvDialect,
+    has_header: bool,
+    columns: Option<Vec<Option<String>>>,
+}
+
+impl CsvDecoder {
+    pub fn new(
+        stream_name: impl Into<String>,
+        schema: Arc<Schema>,
+        props: JsonMap<String, JsonValue>,
+    ) -> Result<Self, CodecError> {
+        let dialect = CsvDialect::from_props(&props).map_err(CodecError::Other)?;
+        let has_header = bool_prop(&props, "header", false).map_err(CodecError::Other)?;
+        let columns = match props.get("columns") {
+            None | Some(JsonValue::Null) => None,
+            Some(JsonValue::Array(items)) => Some(
+                items
+                    .iter()
+                    .map(|item| match item {
+                        JsonValue::Null => Ok(None),
+                        JsonValue::String(name) => Ok(Some(name.clone())),
+                        other => Err(CodecError::Other(format!(
+                            "csv `columns` entries must be strings or null, got {other}"
+                        ))),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            Some(other) => {
+                return Err(CodecError::Other(format!(
+                    "csv `columns` must be an array, got {other}"
+                )))
+            }
+        };
+        let schema_keys = schema
+            .column_schemas()
+            .iter()
+            .map(|col| Arc::<str>::from(col.name.as_str()))
+            .collect();
+        Ok(Self {
+            stream_name: stream_name.into(),
+            schema,
+            schema_keys,
+            dialect,
+            has_header,
+            columns,
+        })
+    }
+
+    fn target(&self, name: &str) -> CsvTarget {
+        match self.schema_keys.iter().position(|key| key.as_ref() == name) {
+            Some(idx) => CsvTarget::Schema(idx),
+            None => CsvTarget::Extra(Arc::from(name)),
+        }
+    }
+
+    fn layout(&self, header: Option<&[CsvCell]>) -> Vec<CsvTarget> {
+        if let Some(columns) = &self.columns {
+            return columns
+                .iter()
+                .map(|name| match name {
+                    Some(name) => self.target(name),
+                    None => CsvTarget::Skip,
+                })
+                .collect();
+        }
+        match header {
+            Some(header) => header
+                .iter()
+                .map(|cell| self.target(cell.text.trim()))
+                .collect(),
+            None => (0..self.schema_keys.len()).map(CsvTarget::Schema).collect(),
+        }
+    }
+
+    fn decode_tuples(
+        &self,
+        payload: &[u8],
+        projection: Option<&[String]>,
+    ) -> Result<Vec<Tuple>, CodecError> {
+        let text = String::from_utf8(payload.to_vec())?;
+        let mut records = self
+            .dialect
+            .split_records(&text)
+            .map_err(CodecError::Other)?
+            .into_iter();
+        let header = if self.has_header {
+            match records.next() {
+                Some(header) => Some(header),
+                None => return Ok(Vec::new()),
+            }
+        } else {
+            None
+        };
+        let layout = self.layout(header.as_deref());
+        let projection_set =
+            projection.map(|columns| columns.iter().map(String::as_str).collect::<HashSet<_>>());
+
+        let columns = self.schema.column_schemas();
+        let mut tuples = Vec::with_capacity(records.len());
+        for (record_no, record) in records.enumerate() {
+            if record.len() != layout.len() {
+                return Err(CodecError::Other(format!(
+                    "csv record {} has {} cells, expected {}",
+                    record_no + 1,
+                    record.len(),
+                    layout.len()
+                )));
+            }
+            let mut values = vec![Value::Null; columns.len()];
+            let mut extra_keys = Vec::new();
+            let mut extra_values = Vec::new();
+            for (target, cell) in layout.iter().zip(record) {
+                match target {
+                    CsvTarget::Schema(idx) => {
+                        let column = &columns[*idx];
+                        let should_decode = projection_set
+                            .as_ref()
+                            .map(|set| set.contains(column.name.as_str()))
+                            .unwrap_or(true);
+                        if should_decode {
+                            values[*idx] =
+                                csv_cell_to_value(&cell, &column.data_type).map_err(|err| {
+                                    CodecError::Other(format!(
+                                        "csv record {} column {}: {err}",
+                                        record_no + 1,
+                                        column.name
+                                    ))
+                                })?;
+                        }
+                    }
+                    CsvTarget::Extra(name) => {
+                        extra_keys.push(name.clone());
+                        extra_values.push(Arc::new(if is_missing(&cell) {
+                            Value::Null
+                        } else {
+                            Value::String(cell.text)
+                        }));
+                    }
+                    CsvTarget::Skip => {}
+                }
+            }
+            let mut keys = self.schema_keys.clone();
+            keys.extend(extra_keys);
+            let mut values: Vec<Arc<Value>> = values.into_iter().map(Arc::new).collect();
+            values.extend(extra_values);
+            let message = Arc::new(Message::new(
+                Arc::<str>::from(self.stream_name.as_str()),
+                keys,
+                values,
+            ));
+            tuples.push(Tuple::new(vec![message]));
+        }
+        Ok(tuples)
+    }
+}
+
+impl RecordDecoder for CsvDecoder {
+    fn decode(&self, payload: &[u8]) -> Result<RecordBatch, CodecError> {
+        self.decode_with_projection(payload, None)
+    }
+
+    fn decode_tuple(&self, payload: &[u8]) -> Result<Tuple, CodecError> {
+        let mut tuples = self.decode_tuples(payload, None)?;
+        match tuples.len() {
+            0 => Err(CodecError::Other(
+                "CSV payload did not contain any records".to_string(),
+            )),
+            1 => Ok(tuples.remove(0)),
+            _ => Err(CodecError::Other(
+                "CSV payload contained multiple rows; expected a single record".to_string(),
+            )),
+        }
+    }
+
+    fn decode_with_projection(
+        &self,
+        payload: &[u8],
+        projection: Option<&[String]>,
+    ) -> Result<RecordBatch, CodecError> {
+        let tuples = self.decode_tuples(payload, projection)?;
+        if tuples.is_empty() {
+            return Ok(RecordBatch::empty());
+        }
+        Ok(RecordBatch::new(tuples)?)
+    }
+}
+
+fn is_missing(cell: &CsvCell) -> bool {
+    cell.text.is_empty() && !cell.quoted
+}
+
+/// Convert a cell through its JSON form so CSV and JSON streams agree on datatype coercion.
+fn csv_cell_to_value(cell: &CsvCell, datatype: &ConcreteDatatype) -> Result<Value, String> {
+    if is_missing(cell) {
+        return Ok(Value::Null);
+    }
+    let text = cell.text.as_str();
+    let trimmed = text.trim();
+    let json = match datatype {
+        ConcreteDatatype::Null => return Ok(Value::Null),
+        ConcreteDatatype::String(_) => JsonValue::String(text.to_string()),
+        ConcreteDatatype::Bool(_) => match trimmed.to_ascii_lowercase().as_str() {
+            "true" => JsonValue::Bool(true),
+            "false" => JsonValue::Bool(false),
+            _ => return Err(format!("invalid boolean {trimmed:?}")),
+        },
+        ConcreteDatatype::Int8(_)
+        | ConcreteDatatype::Int16(_)
+        | ConcreteDatatype::Int32(_)
+        | ConcreteDatatype::Int64(_) => trimmed
+            .parse::<i64>()
+            .map(|v| JsonValue::Number(v.into()))
+            .map_err(|_| format!("invalid integer {trimmed:?}"))?,
+        ConcreteDatatype::Uint8(_)
+        | ConcreteDatatype::Uint16(_)
+        | ConcreteDatatype::Uint32(_)
+        | ConcreteDatatype::Uint64(_) => trimmed
+            .parse::<u64>()
+            .map(|v| JsonValue::Number(v.into()))
+            .map_err(|_| format!("invalid unsigned integer {trimmed:?}"))?,
+        ConcreteDatatype::Float32(_) | ConcreteDatatype::Float64(_) => trimmed
+            .parse::<f64>()
+            .ok()
+            .and_then(JsonNumber::from_f64)
+            .map(JsonValue::Number)
+            .ok_or_else(|| format!("invalid float {trimmed:?}"))?,
+        ConcreteDatatype::Timestamp(_) => match trimmed.parse::<i64>() {
+            Ok(ticks) => JsonValue::Number(ticks.into()),
+            Err(_) => JsonValue::String(trimmed.to_string()),
+        },
+        ConcreteDatatype::Struct(_) | ConcreteDatatype::List(_) => {
+            serde_json::from_str(trimmed).map_err(|err| format!("invalid JSON cell: {err}"))?
+        }
+    };
+    match json_to_value_with_datatype(&json, datatype) {
+        Value::Null if matches!(datatype, ConcreteDatatype::Timestamp(_)) => {
+            Err(format!("invalid timestamp {trimmed:?}"))
+        }
+        Value::Null => Err(format!("cell {trimmed:?} does not match the column type")),
+        value => Ok(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datatypes::{ColumnSchema, Float64Type, Int64Type, StringType};
+
+    fn schema() -> Arc<Schema> {
+        Arc::new(Schema::new(vec![
+            ColumnSchema::new(
+                "orders".to_string(),
+                "id".to_string(),
+                ConcreteDatatype::Int64(Int64Type),
+            ),
+            ColumnSchema::new(
+                "orders".to_string(),
+                "status".to_string(),
+                ConcreteDatatype::String(StringType),
+            ),
+            ColumnSchema::new(
+                "orders".to_string(),
+                "amount".to_string(),
+                ConcreteDatatype::Float64(Float64Type),
+            ),
+        ]))
+    }
+
+    fn props(json: serde_json::Value) -> JsonMap<String, JsonValue> {
+        json.as_object().cloned().expect("props object")
+    }
+
+    fn column(batch: &RecordBatch, name: &str) -> Vec<Option<Value>> {
+        batch
+            .rows()
+            .iter()
+            .map(|row| row.value_by_name("orders", name).cloned())
+            .collect()
+    }
+
+    #[test]
+    fn csv_decoder_maps_cells_in_schema_order() {
+        let decoder = CsvDecoder::new("orders", schema(), JsonMap::new()).expect("decoder");
+        let batch = decoder
+            .decode(b"1,\"ok, paid\",2.5\n2,,\n3,\"\",\n")
+            .expect("decode");
+        assert_eq!(
+            column(&batch, "id"),
+            vec![
+                Some(Value::Int64(1)),
+                Some(Value::Int64(2)),
+                Some(Value::Int64(3))
+            ]
+        );
+        assert_eq!(
+            column(&batch, "status"),
+            vec![
+                Some(Value::String("ok, paid".to_string())),
+                Some(Value::Null),
+                Some(Value::String(String::new())),
+            ]
+        );
+        assert_eq!(
+            column(&batch, "amount"),
+            vec![
+                Some(Value::Float64(2.5)),
+                Some(Value::Null),
+                Some(Value::Null)
+            ]
+        );
+    }
+
+    #[test]
+    fn csv_decoder_uses_header_and_column_mapping() {
+        let decoder = CsvDecoder::new(
+            "orders",
+            schema(),
+            props(serde_json::json!({"header": true, "delimiter": ";"})),
+        )
+        .expect("decoder");
+        let batch = decoder
+            .decode(b"status;id;origin\nok;7;edge\n")
+            .expect("decode");
+        let row = &batch.rows()[0];
+        assert_eq!(row.value_by_name("orders", "id"), Some(&Value::Int64(7)));
+        assert_eq!(
+            row.value_by_name("orders", "status"),
+            Some(&Value::String("ok".to_string()))
+        );
+        assert_eq!(row.value_by_name("orders", "amount"), Some(&Value::Null));
+        assert_eq!(
+            row.value_by_name("orders", "origin"),
+            Some(&Value::String("edge".to_string()))
+        );
+
+        let decoder = CsvDecoder::new(
+            "orders",
+            schema(),
+            props(serde_json::json!({"columns": ["amount", null, "id"]})),
+        )
+        .expect("decoder");
+        let tuple = decoder
+            .decode_tuple(b"1.5,ignored,9")
+            .expect("decode tuple");
+        assert_eq!(tuple.value_by_name("orders", "id"), Some(&Value::Int64(9)));
+        assert_eq!(
+            tuple.value_by_name("orders", "amount"),
+            Some(&Value::Float64(1.5))
+        );
+        assert_eq!(tuple.value_by_name("orders", "status"), Some(&Value::Null));
+    }
+
+    #[test]
+    fn csv_decoder_skips_pruned_columns() {
+        let decoder = CsvDecoder::new("orders", schema(), JsonMap::new()).expect("decoder");
+        let projection = vec!["id".to_string()];
+        let batch = decoder
+            .decode_with_projection(b"4,ok,1.0\n", Some(&projection))
+            .expect("decode");
+        let row = &batch.rows()[0];
+        assert_eq!(row.value_by_name("orders", "id"), Some(&Value::Int64(4)));
+        assert_eq!(row.value_by_name("orders", "status"), Some(&Value::Null));
+        assert_eq!(row.value_by_name("orders", "amount"), Some(&Value::Null));
+    }
+
+    #[test]
+    fn csv_decoder_rejects_ragged_records_bad_cells_and_bad_props() {
+        let decoder = CsvDecoder::new("orders", schema(), JsonMap::new()).expect("decoder");
+        let err = decoder.decode(b"1,ok\n").expect_err("short record");
+        assert!(format!("{err}").contains("has 2 cells, expected 3"));
+        let err = decoder
+            .decode(b"1,ok,2.5\n2,ok,lots\n")
+            .expect_err("unparsable cell");
+        assert_eq!(
+            err.to_string(),
+            "csv record 2 column amount: invalid float \"lots\""
+        );
+
+        assert!(CsvDecoder::new(
+            "orders",
+            schema(),
+            props(serde_json::json!({"delimiter": "::"}))
+        )
+        .is_err());
+    }
+}
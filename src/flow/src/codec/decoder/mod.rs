@@ -3,11 +3,16 @@
 use crate::model::{CollectionError, Message, RecordBatch, Tuple};
 use crate::planner::decode_projection::{DecodeProjection, ProjectionNode};
 use datatypes::{
-    ConcreteDatatype, ListType, ListValue, Schema, StructField, StructType, StructValue, Value,
+    ConcreteDatatype, DataType, ListType, ListValue, Schema, StructField, StructType, StructValue,
+    TimestampType, Value,
 };
 use serde_json::{Map as JsonMap, Value as JsonValue};
 use std::sync::Arc;
 
+mod csv;
+
+pub use csv::CsvDecoder;
+
 /// Errors that can occur while decoding payloads.
 #[derive(thiserror::Error, Debug)]
 pub enum CodecError {
@@ -514,6 +519,7 @@ fn json_to_value_with_datatype(value: &JsonValue, datatype: &ConcreteDatatype) -
         | ConcreteDatatype::Float32(_)
         | ConcreteDatatype::Float64(_)
         | ConcreteDatatype::String(_) => json_to_value(value),
+        ConcreteDatatype::Timestamp(timestamp_type) => json_to_timestamp(value, timestamp_type),
         ConcreteDatatype::List(list_type) => json_to_list_value_with_datatype(value, list_type),
         ConcreteDatatype::Struct(struct_type) => {
             json_to_struct_value_with_datatype(value, struct_type)
@@ -549,6 +555,7 @@ fn json_to_value_with_datatype_and_projection(
         | ConcreteDatatype::Float32(_)
         | ConcreteDatatype::Float64(_)
         | ConcreteDatatype::String(_) => json_to_value(value),
+        ConcreteDatatype::Timestamp(timestamp_type) => json_to_timestamp(value, timestamp_type),
         ConcreteDatatype::List(list_type) => {
             json_to_list_value_with_datatype_and_projection(value, list_type, projection)
         }
@@ -558,6 +565,19 @@ fn json_to_value_with_datatype_and_projection(
     }
 }
 
+/// Numbers are ticks of the column precision; strings are either ticks or RFC 3339 text.
+fn json_to_timestamp(value: &JsonValue, timestamp_type: &TimestampType) -> Value {
+    let raw = match value {
+        JsonValue::Number(n) => match n.as_i64() {
+            Some(i) => Value::Int64(i),
+            None => return Value::Null,
+        },
+        JsonValue::String(s) => Value::String(s.clone()),
+        _ => return Value::Null,
+    };
+    timestamp_type.try_cast(raw).unwrap_or(Value::Null)
+}
+
 fn json_to_list_value_with_datatype(value: &JsonValue, list_type: &ListType) -> Value {
     let JsonValue::Array(items) = value else {
         return Value::Null;
@@ -669,7 +689,7 @@ mod tests {
     use super::*;
     use datatypes::{
         ColumnSchema, ConcreteDatatype, Int64Type, Schema, StringType, StructField, StructType,
-        Value,
+        Timestamp, Value,
     };
     use serde_json::Map as JsonMap;
 
@@ -714,6 +734,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn json_decoder_reads_timestamp_ticks_and_rfc3339() {
+        let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+            "orders".to_string(),
+            "ts".to_string(),
+            ConcreteDatatype::Timestamp(TimestampType::default()),
+        )]));
+        let decoder = JsonDecoder::new("orders", schema, JsonMap::new());
+        let payload = br#"[{"ts":1500},{"ts":"1970-01-01T00:00:02.5Z"},{"ts":true}]"#.as_ref();
+        let batch = decoder.decode(payload).expect("decode batch");
+        let values: Vec<_> = batch
+            .rows()
+            .iter()
+            .map(|row| row.value_by_name("orders", "ts").cloned())
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                Some(Value::Timestamp(Timestamp::new_millisecond(1_500))),
+                Some(Value::Timestamp(Timestamp::new_millisecond(2_500))),
+                Some(Value::Null),
+            ]
+        );
+    }
+
     #[test]
     fn json_decoder_rejects_multiple_rows_for_tuple() {
         let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
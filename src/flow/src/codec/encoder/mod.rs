@@ -3,6 +3,10 @@
 use crate::model::{Collection, Tuple};
 use serde_json::{Map as JsonMap, Number as JsonNumber, Value as JsonValue};
 
+mod csv;
+
+pub use csv::CsvEncoder;
+
 /// Errors that can occur during encoding.
 #[derive(thiserror::Error, Debug)]
 pub enum EncodeError {
@@ -175,6 +179,10 @@ fn value_to_json(value: &Value) -> JsonValue {
             let values = list.items().iter().map(value_to_json).collect::<Vec<_>>();
             JsonValue::Array(values)
         }
+        Value::Timestamp(ts) => match ts.to_rfc3339() {
+            Some(text) => JsonValue::String(text),
+            None => JsonValue::Number(JsonNumber::from(ts.value())),
+        },
     }
 }
 
@@ -1,7 +1,10 @@
+mod csv;
 pub mod decoder;
 pub mod encoder;
 pub mod registry;
 
-pub use decoder::{CodecError, JsonDecoder, RecordDecoder};
-pub use encoder::{CollectionEncoder, CollectionEncoderStream, EncodeError, JsonEncoder};
+pub use decoder::{CodecError, CsvDecoder, JsonDecoder, RecordDecoder};
+pub use encoder::{
+    CollectionEncoder, CollectionEncoderStream, CsvEncoder, EncodeError, JsonEncoder,
+};
 pub use registry::{DecoderRegistry, EncoderRegistry};
@@ -1,8 +1,8 @@
-use super::decoder::{JsonDecoder, RecordDecoder};
+use super::decoder::{CsvDecoder, JsonDecoder, RecordDecoder};
 use super::encoder::CollectionEncoder;
 use super::CodecError;
 use crate::catalog::StreamDecoderConfig;
-use crate::codec::encoder::JsonEncoder;
+use crate::codec::encoder::{CsvEncoder, JsonEncoder};
 use crate::planner::sink::SinkEncoderConfig;
 use datatypes::Schema;
 use std::collections::HashMap;
@@ -83,6 +83,16 @@ impl DecoderRegistry {
                 )) as Arc<_>)
             }),
         );
+        self.register_decoder(
+            "csv",
+            Arc::new(|config, schema, stream_name| {
+                Ok(Arc::new(CsvDecoder::new(
+                    stream_name.to_string(),
+                    schema,
+                    config.props().clone(),
+                )?) as Arc<_>)
+            }),
+        );
     }
 }
 
@@ -166,5 +176,14 @@ impl EncoderRegistry {
             }),
             true,
         );
+        self.register_encoder(
+            "csv",
+            Arc::new(|config| {
+                let encoder = CsvEncoder::new(config.kind().to_string(), config.props().clone())
+                    .map_err(|err| CodecError::Other(err.to_string()))?;
+                Ok(Arc::new(encoder) as Arc<_>)
+            }),
+            true,
+        );
     }
 }
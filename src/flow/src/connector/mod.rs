@@ -64,9 +64,15 @@ pub use mqtt_client::{
     MqttClientManager, SharedMqttClient, SharedMqttClientConfig, SharedMqttEvent,
 };
 pub use registry::ConnectorRegistry;
+pub use sink::file::{FileSinkConfig, FileSinkConnector, FileSyncPolicy};
+pub use sink::http::{HttpSinkConfig, HttpSinkConnector};
+pub use sink::kafka::{KafkaSinkConfig, KafkaSinkConnector};
 pub use sink::mock::{MockSinkConnector, MockSinkHandle};
 pub use sink::mqtt::{MqttSinkConfig, MqttSinkConnector};
 pub use sink::{SinkConnector, SinkConnectorError};
+pub use source::file::{FileFraming, FileReadMode, FileSourceConfig, FileSourceConnector};
+pub use source::http::{push_http_payload, HttpPushError, HttpSourceConfig, HttpSourceConnector};
+pub use source::kafka::{KafkaSourceConfig, KafkaSourceConnector, KafkaStartOffset};
 pub use source::mock::{
     get_mock_source_handle, register_mock_source_handle, take_mock_source_handle,
 };
@@ -1,3 +1,6 @@
+use super::sink::file::FileSinkConnector;
+use super::sink::http::HttpSinkConnector;
+use super::sink::kafka::KafkaSinkConnector;
 use super::sink::mqtt::MqttSinkConnector;
 use super::sink::nop::NopSinkConnector;
 use super::sink::SinkConnector;
@@ -83,6 +86,48 @@ impl ConnectorRegistry {
             }),
         );
 
+        self.register_sink_factory(
+            "file",
+            Arc::new(|sink_id, config, _| match config {
+                SinkConnectorConfig::File(file_cfg) => Ok(Box::new(FileSinkConnector::new(
+                    sink_id.to_string(),
+                    file_cfg.clone(),
+                ))),
+                other => Err(ConnectorError::Other(format!(
+                    "connector `{sink_id}` expected file config but received {:?}",
+                    other.kind()
+                ))),
+            }),
+        );
+
+        self.register_sink_factory(
+            "http",
+            Arc::new(|sink_id, config, _| match config {
+                SinkConnectorConfig::Http(http_cfg) => Ok(Box::new(HttpSinkConnector::new(
+                    sink_id.to_string(),
+                    http_cfg.clone(),
+                ))),
+                other => Err(ConnectorError::Other(format!(
+                    "connector `{sink_id}` expected HTTP config but received {:?}",
+                    other.kind()
+                ))),
+            }),
+        );
+
+        self.register_sink_factory(
+            "kafka",
+            Arc::new(|sink_id, config, _| match config {
+                SinkConnectorConfig::Kafka(kafka_cfg) => Ok(Box::new(KafkaSinkConnector::new(
+                    sink_id.to_string(),
+                    kafka_cfg.clone(),
+                ))),
+                other => Err(ConnectorError::Other(format!(
+                    "connector `{sink_id}` expected Kafka config but received {:?}",
+                    other.kind()
+                ))),
+            }),
+        );
+
         self.register_sink_factory(
             "nop",
             Arc::new(|sink_id, config, _| match config {
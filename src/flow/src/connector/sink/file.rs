@@ -0,0 +1,330 @@
+//! File sink connector that appends payloads to a local file with rotation.
+
+use super::{SinkConnector, SinkConnectorError};
+use async_trait::async_trait;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// When written data is forced to disk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FileSyncPolicy {
+    /// Leave flushing to the operating system; files are still synced on rotation and close.
+    #[default]
+    Never,
+    /// Sync after every payload.
+    Always,
+    /// Sync at most once per interval.
+    Interval(Duration),
+}
+
+/// Configuration for the file sink connector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSinkConfig {
+    pub sink_name: String,
+    pub path: PathBuf,
+    /// Terminate every payload with a newline.
+    pub newline_delimited: bool,
+    /// Rotate before the active file would grow beyond this many bytes.
+    pub max_file_bytes: Option<u64>,
+    /// Rotate once the active file has been open this long (checked on write).
+    pub rotate_interval: Option<Duration>,
+    /// Gzip rotated files.
+    pub compress: bool,
+    pub sync: FileSyncPolicy,
+}
+
+impl FileSinkConfig {
+    pub fn new(sink_name: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            sink_name: sink_name.into(),
+            path: path.into(),
+            newline_delimited: true,
+            max_file_bytes: None,
+            rotate_interval: None,
+            compress: false,
+            sync: FileSyncPolicy::Never,
+        }
+    }
+
+    pub fn with_newline_delimited(mut self, newline_delimited: bool) -> Self {
+        self.newline_delimited = newline_delimited;
+        self
+    }
+
+    pub fn with_max_file_bytes(mut self, max_file_bytes: u64) -> Self {
+        self.max_file_bytes = Some(max_file_bytes);
+        self
+    }
+
+    pub fn with_rotate_interval(mut self, rotate_interval: Duration) -> Self {
+        self.rotate_interval = Some(rotate_interval);
+        self
+    }
+
+    pub fn with_compress(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    pub fn with_sync(mut self, sync: FileSyncPolicy) -> Self {
+        self.sync = sync;
+        self
+    }
+}
+
+struct ActiveFile {
+    file: File,
+    bytes: u64,
+    opened_at: Instant,
+    last_sync: Instant,
+}
+
+/// Appends every payload to `path`.
+///
+/// Rotated files are renamed to `<path>.<unix millis>` (plus `.gz` when compressed) next to the
+/// active file, which is then recreated. When the rename fails, writes keep going to the active
+/// file and rotation is tried again on the next write. File I/O runs on the blocking pool, and
+/// I/O failures are reported as unavailable so the sink retries or buffers the payload.
+pub struct FileSinkConnector {
+    id: String,
+    config: FileSinkConfig,
+    active: Option<ActiveFile>,
+}
+
+impl FileSinkConnector {
+    pub fn new(id: impl Into<String>, config: FileSinkConfig) -> Self {
+        Self {
+            id: id.into(),
+            config,
+            active: None,
+        }
+    }
+
+    fn io_error(&self, action: &str, err: io::Error) -> SinkConnectorError {
+        SinkConnectorError::Unavailable(format!(
+            "file sink `{}` failed to {action} {}: {err}",
+            self.id,
+            self.config.path.display()
+        ))
+    }
+
+    /// Run `task` on the blocking pool.
+    async fn blocking<T: Send + 'static>(
+        &self,
+        task: impl FnOnce() -> T + Send + 'static,
+    ) -> Result<T, SinkConnectorError> {
+        tokio::task::spawn_blocking(task).await.map_err(|err| {
+            SinkConnectorError::Other(format!("file sink `{}` task failed: {err}", self.id))
+        })
+    }
+
+    async fn open(&self) -> Result<ActiveFile, SinkConnectorError> {
+        let path = self.config.path.clone();
+        self.blocking(move || open_file(&path))
+            .await?
+            .map_err(|(action, err)| self.io_error(action, err))
+    }
+
+    /// Sync the active file to disk, if one is open.
+    async fn sync_active(&mut self) -> Result<(), SinkConnectorError> {
+        let Some(active) = self.active.take() else {
+            return Ok(());
+        };
+        let (active, synced) = self
+            .blocking(move || {
+                let synced = active.file.sync_all();
+                (active, synced)
+            })
+            .await?;
+        self.active = Some(active);
+        synced.map_err(|err| self.io_error("sync", err))
+    }
+
+    fn needs_rotation(&self, active: &ActiveFile, incoming: u64) -> bool {
+        if active.bytes == 0 {
+            return false;
+        }
+        let too_large = self
+            .config
+            .max_file_bytes
+            .is_some_and(|max| active.bytes + incoming > max);
+        let too_old = self
+            .config
+            .rotate_interval
+            .is_some_and(|interval| active.opened_at.elapsed() >= interval);
+        too_large || too_old
+    }
+
+    async fn rotate(&mut self) -> Result<(), SinkConnectorError> {
+        self.sync_active().await?;
+        let path = self.config.path.clone();
+        let (rotated, renamed) = self
+            .blocking(move || {
+                let rotated = rotated_path(&path);
+                let renamed = fs::rename(&path, &rotated);
+                (rotated, renamed)
+            })
+            .await?;
+        if let Err(err) = renamed {
+            // Keep appending to the current file; rotation is tried again on the next write.
+            tracing::warn!(
+                connector_id = %self.id,
+                file = %rotated.display(),
+                error = %err,
+                "failed to rotate file sink"
+            );
+            return Ok(());
+        }
+        self.active = None;
+        if self.config.compress {
+            let source = rotated.clone();
+            if let Err(err) = self.blocking(move || gzip_file(&source)).await? {
+                // The uncompressed file is kept, so nothing is lost.
+                tracing::warn!(
+                    connector_id = %self.id,
+                    file = %rotated.display(),
+                    error = %err,
+                    "failed to compress rotated file"
+                );
+            }
+        }
+        tracing::info!(connector_id = %self.id, file = %rotated.display(), "rotated file sink");
+        self.active = Some(self.open().await?);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SinkConnector for FileSinkConnector {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn ready(&mut self) -> Result<(), SinkConnectorError> {
+        if self.active.is_none() {
+            self.active = Some(self.open().await?);
+        }
+        tracing::info!(
+            connector_id = %self.id,
+            path = %self.config.path.display(),
+            "file sink ready"
+        );
+        Ok(())
+    }
+
+    async fn send(&mut self, payload: &[u8]) -> Result<(), SinkConnectorError> {
+        let mut record = Vec::with_capacity(payload.len() + 1);
+        record.extend_from_slice(payload);
+        if self.config.newline_delimited {
+            record.push(b'\n');
+        }
+        // Reopen after a failed open, e.g. when the file could not be recreated on rotation.
+        let active = match self.active.take() {
+            Some(active) => active,
+            None => self.open().await?,
+        };
+        let needs_rotation = self.needs_rotation(&active, record.len() as u64);
+        self.active = Some(active);
+        if needs_rotation {
+            self.rotate().await?;
+        }
+
+        let sync = self.config.sync;
+        let Some(mut active) = self.active.take() else {
+            return Err(SinkConnectorError::Unavailable(format!(
+                "file sink `{}` is not open",
+                self.id
+            )));
+        };
+        let (active, written) = self
+            .blocking(move || {
+                let written = write_record(&mut active, &record, sync);
+                (active, written)
+            })
+            .await?;
+        self.active = Some(active);
+        written.map_err(|err| self.io_error("write", err))
+    }
+
+    async fn close(&mut self) -> Result<(), SinkConnectorError> {
+        self.sync_active().await?;
+        self.active = None;
+        tracing::info!(connector_id = %self.id, "file sink closed");
+        Ok(())
+    }
+}
+
+/// Open `path` for appending, creating it and its directory if needed.
+///
+/// Errors carry the step that failed, for the error message.
+fn open_file(path: &Path) -> Result<ActiveFile, (&'static str, io::Error)> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent).map_err(|err| ("create directory for", err))?;
+        }
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| ("open", err))?;
+    let bytes = file.metadata().map_err(|err| ("stat", err))?.len();
+    let now = Instant::now();
+    Ok(ActiveFile {
+        file,
+        bytes,
+        opened_at: now,
+        last_sync: now,
+    })
+}
+
+/// Append `record` to the active file and sync it when `sync` says it is due.
+fn write_record(active: &mut ActiveFile, record: &[u8], sync: FileSyncPolicy) -> io::Result<()> {
+    active.file.write_all(record)?;
+    active.bytes += record.len() as u64;
+    let due = match sync {
+        FileSyncPolicy::Never => false,
+        FileSyncPolicy::Always => true,
+        FileSyncPolicy::Interval(interval) => active.last_sync.elapsed() >= interval,
+    };
+    if due {
+        active.file.sync_data()?;
+        active.last_sync = Instant::now();
+    }
+    Ok(())
+}
+
+fn rotated_path(path: &Path) -> PathBuf {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    let base = format!("{}.{millis}", path.display());
+    let mut candidate = PathBuf::from(&base);
+    let mut suffix = 1;
+    while candidate.exists() || gz_path(&candidate).exists() {
+        candidate = PathBuf::from(format!("{base}-{suffix}"));
+        suffix += 1;
+    }
+    candidate
+}
+
+fn gz_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".gz");
+    PathBuf::from(name)
+}
+
+/// Compress `path` into `<path>.gz` and remove the original.
+fn gzip_file(path: &Path) -> io::Result<()> {
+    let target = gz_path(path);
+    let mut input = File::open(path)?;
+    let mut encoder = GzEncoder::new(File::create(&target)?, Compression::default());
+    io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?.sync_all()?;
+    fs::remove_file(path)
+}
@@ -0,0 +1,145 @@
+//! HTTP webhook sink connector that POSTs every payload to a URL.
+
+use super::{SinkConnector, SinkConnectorError};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::time::Duration;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Configuration for the HTTP sink connector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpSinkConfig {
+    pub sink_name: String,
+    pub url: String,
+    /// Headers sent with every request, in order.
+    pub headers: Vec<(String, String)>,
+    /// Limit for a whole request, from connecting until the response body is read.
+    pub timeout: Duration,
+}
+
+impl HttpSinkConfig {
+    pub fn new(sink_name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            sink_name: sink_name.into(),
+            url: url.into(),
+            headers: Vec::new(),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Whether a header with `name` is configured, ignoring case.
+    pub fn has_header(&self, name: &str) -> bool {
+        self.headers
+            .iter()
+            .any(|(header, _)| header.eq_ignore_ascii_case(name))
+    }
+}
+
+/// POSTs payloads to a webhook.
+///
+/// Connection failures, timeouts, `429` and `5xx` responses are reported as
+/// [`SinkConnectorError::Unavailable`] so the sink retries or buffers them; other non-`2xx`
+/// responses are rejections of the payload itself.
+pub struct HttpSinkConnector {
+    id: String,
+    config: HttpSinkConfig,
+    client: Option<reqwest::Client>,
+}
+
+impl HttpSinkConnector {
+    pub fn new(id: impl Into<String>, config: HttpSinkConfig) -> Self {
+        Self {
+            id: id.into(),
+            config,
+            client: None,
+        }
+    }
+
+    fn build_client(&self) -> Result<reqwest::Client, SinkConnectorError> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.config.headers {
+            let name = HeaderName::from_bytes(name.as_bytes()).map_err(|err| {
+                SinkConnectorError::Other(format!("invalid http sink header `{name}`: {err}"))
+            })?;
+            let value = HeaderValue::from_str(value).map_err(|err| {
+                SinkConnectorError::Other(format!("invalid value for header `{name}`: {err}"))
+            })?;
+            headers.append(name, value);
+        }
+        reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(self.config.timeout)
+            .build()
+            .map_err(|err| SinkConnectorError::Other(format!("failed to build http client: {err}")))
+    }
+}
+
+#[async_trait]
+impl SinkConnector for HttpSinkConnector {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn ready(&mut self) -> Result<(), SinkConnectorError> {
+        reqwest::Url::parse(&self.config.url).map_err(|err| {
+            SinkConnectorError::Other(format!(
+                "invalid http sink url `{}`: {err}",
+                self.config.url
+            ))
+        })?;
+        if self.client.is_none() {
+            self.client = Some(self.build_client()?);
+        }
+        tracing::info!(connector_id = %self.id, url = %self.config.url, "http sink ready");
+        Ok(())
+    }
+
+    async fn send(&mut self, payload: &[u8]) -> Result<(), SinkConnectorError> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            SinkConnectorError::Unavailable(format!("http sink `{}` is not ready", self.id))
+        })?;
+        let response = client
+            .post(&self.config.url)
+            .body(payload.to_vec())
+            .send()
+            .await
+            .map_err(|err| {
+                let message = format!("http sink `{}` request failed: {err}", self.id);
+                if err.is_connect() || err.is_timeout() || err.is_request() {
+                    SinkConnectorError::Unavailable(message)
+                } else {
+                    SinkConnectorError::Other(message)
+                }
+            })?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let message = format!(
+            "http sink `{}` received {status} from {}",
+            self.id, self.config.url
+        );
+        if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Err(SinkConnectorError::Unavailable(message))
+        } else {
+            Err(SinkConnectorError::Other(message))
+        }
+    }
+
+    async fn close(&mut self) -> Result<(), SinkConnectorError> {
+        self.client = None;
+        tracing::info!(connector_id = %self.id, "http sink closed");
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+//! Kafka sink connector producing encoded payloads to a topic.
+
+use super::{SinkConnector, SinkConnectorError};
+use async_trait::async_trait;
+use rdkafka::config::ClientConfig;
+use rdkafka::error::{KafkaError, RDKafkaErrorCode};
+use rdkafka::message::{Header, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use serde_json::Value as JsonValue;
+use std::time::Duration;
+
+const DEFAULT_DELIVERY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Record key and value.
+type KafkaRecord = (Option<String>, Vec<u8>);
+
+/// Configuration for the Kafka sink connector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KafkaSinkConfig {
+    pub sink_name: String,
+    /// Comma-separated `host:port` bootstrap servers.
+    pub brokers: String,
+    pub topic: String,
+    /// Partition to produce to; by default the partitioner picks one from the key.
+    pub partition: Option<i32>,
+    /// Column whose value becomes the record key.
+    pub key_column: Option<String>,
+    /// Headers attached to every record, in order.
+    pub headers: Vec<(String, String)>,
+    /// How long the producer may spend delivering a record, retries included.
+    pub delivery_timeout: Duration,
+    /// Extra librdkafka client properties, applied last.
+    pub properties: Vec<(String, String)>,
+}
+
+impl KafkaSinkConfig {
+    pub fn new(
+        sink_name: impl Into<String>,
+        brokers: impl Into<String>,
+        topic: impl Into<String>,
+    ) -> Self {
+        Self {
+            sink_name: sink_name.into(),
+            brokers: brokers.into(),
+            topic: topic.into(),
+            partition: None,
+            key_column: None,
+            headers: Vec::new(),
+            delivery_timeout: DEFAULT_DELIVERY_TIMEOUT,
+            properties: Vec::new(),
+        }
+    }
+
+    pub fn with_partition(mut self, partition: i32) -> Self {
+        self.partition = Some(partition);
+        self
+    }
+
+    pub fn with_key_column(mut self, column: impl Into<String>) -> Self {
+        self.key_column = Some(column.into());
+        self
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn with_delivery_timeout(mut self, timeout: Duration) -> Self {
+        self.delivery_timeout = timeout;
+        self
+    }
+
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.push((key.into(), value.into()));
+        self
+    }
+}
+
+/// Produces every payload as one record.
+///
+/// With a key column the payload must be JSON: an array is split into one record per row, each
+/// keyed by the row's column value, and a single object becomes one keyed record.
+pub struct KafkaSinkConnector {
+    id: String,
+    config: KafkaSinkConfig,
+    producer: Option<FutureProducer>,
+}
+
+impl KafkaSinkConnector {
+    pub fn new(id: impl Into<String>, config: KafkaSinkConfig) -> Self {
+        Self {
+            id: id.into(),
+            config,
+            producer: None,
+        }
+    }
+
+    /// Split a payload into `(key, value)` records according to the key column.
+    fn records(&self, payload: &[u8]) -> Result<Vec<KafkaRecord>, SinkConnectorError> {
+        let Some(column) = &self.config.key_column else {
+            return Ok(vec![(None, payload.to_vec())]);
+        };
+        let parsed: JsonValue = serde_json::from_slice(payload).map_err(|err| {
+            SinkConnectorError::Other(format!(
+                "kafka sink `{}` needs JSON payloads to read key column `{column}`: {err}",
+                self.id
+            ))
+        })?;
+        let rows = match parsed {
+            JsonValue::Array(rows) => rows,
+            row => vec![row],
+        };
+        rows.into_iter()
+            .map(|row| {
+                let key = match row.get(column) {
+                    None | Some(JsonValue::Null) => None,
+                    Some(JsonValue::String(key)) => Some(key.clone()),
+                    Some(other) => Some(other.to_string()),
+                };
+                let value = serde_json::to_vec(&row)
+                    .map_err(|err| SinkConnectorError::Other(err.to_string()))?;
+                Ok((key, value))
+            })
+            .collect()
+    }
+
+    fn headers(&self) -> Option<OwnedHeaders> {
+        if self.config.headers.is_empty() {
+            return None;
+        }
+        let headers =
+            self.config
+                .headers
+                .iter()
+                .fold(OwnedHeaders::new(), |headers, (key, value)| {
+                    headers.insert(Header {
+                        key,
+                        value: Some(value.as_bytes()),
+                    })
+                });
+        Some(headers)
+    }
+}
+
+#[async_trait]
+impl SinkConnector for KafkaSinkConnector {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn ready(&mut self) -> Result<(), SinkConnectorError> {
+        if self.producer.is_none() {
+            let mut client_config = ClientConfig::new();
+            client_config
+                .set("bootstrap.servers", &self.config.brokers)
+                .set(
+                    "message.timeout.ms",
+                    self.config.delivery_timeout.as_millis().to_string(),
+                );
+            for (key, value) in &self.config.properties {
+                client_config.set(key, value);
+            }
+            let producer = client_config.create().map_err(|err| {
+                SinkConnectorError::Other(format!(
+                    "kafka sink `{}` failed to create producer: {err}",
+                    self.id
+                ))
+            })?;
+            self.producer = Some(producer);
+        }
+        tracing::info!(
+            connector_id = %self.id,
+            topic = %self.config.topic,
+            "kafka sink ready"
+        );
+        Ok(())
+    }
+
+    async fn send(&mut self, payload: &[u8]) -> Result<(), SinkConnectorError> {
+        let records = self.records(payload)?;
+        let headers = self.headers();
+        let producer = self.producer.as_ref().ok_or_else(|| {
+            SinkConnectorError::Unavailable(format!("kafka sink `{}` is not ready", self.id))
+        })?;
+        for (key, value) in &records {
+            let mut record = FutureRecord::<String, Vec<u8>>::to(&self.config.topic).payload(value);
+            if let Some(key) = key {
+                record = record.key(key);
+            }
+            if let Some(partition) = self.config.partition {
+                record = record.partition(partition);
+            }
+            if let Some(headers) = &headers {
+                record = record.headers(headers.clone());
+            }
+            producer
+                .send(record, self.config.delivery_timeout)
+                .await
+                .map_err(|(err, _)| delivery_error(&self.id, err))?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<(), SinkConnectorError> {
+        self.producer = None;
+        tracing::info!(connector_id = %self.id, "kafka sink closed");
+        Ok(())
+    }
+}
+
+/// Records the broker rejects outright are not worth retrying; anything else is transient.
+fn delivery_error(id: &str, err: KafkaError) -> SinkConnectorError {
+    let message = format!("kafka sink `{id}` failed to deliver: {err}");
+    match err.rdkafka_error_code() {
+        Some(
+            RDKafkaErrorCode::MessageSizeTooLarge
+            | RDKafkaErrorCode::InvalidMessage
+            | RDKafkaErrorCode::InvalidMessageSize
+            | RDKafkaErrorCode::InvalidRecord
+            | RDKafkaErrorCode::TopicAuthorizationFailed
+            | RDKafkaErrorCode::UnknownPartition,
+        ) => SinkConnectorError::Other(message),
+        _ => SinkConnectorError::Unavailable(message),
+    }
+}
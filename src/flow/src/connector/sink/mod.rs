@@ -33,6 +33,9 @@ pub enum SinkConnectorError {
     Other(String),
 }
 
+pub mod file;
+pub mod http;
+pub mod kafka;
 pub mod mock;
 pub mod mqtt;
 pub mod nop;
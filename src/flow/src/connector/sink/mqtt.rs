@@ -105,7 +105,9 @@ impl SinkClient {
                 .client()
                 .publish(topic.to_string(), qos, retain, payload)
                 .await
-                .map_err(|err| SinkConnectorError::Other(format!("mqtt publish error: {err}"))),
+                .map_err(|err| {
+                    SinkConnectorError::Unavailable(format!("mqtt publish error: {err}"))
+                }),
             SinkClient::Standalone(standalone) => {
                 standalone.publish(topic, qos, retain, payload).await
             }
@@ -146,7 +148,7 @@ impl StandaloneMqttClient {
         self.client
             .publish(topic.to_string(), qos, retain, payload)
             .await
-            .map_err(|err| SinkConnectorError::Other(format!("mqtt publish error: {err}")))
+            .map_err(|err| SinkConnectorError::Unavailable(format!("mqtt publish error: {err}")))
     }
 
     async fn shutdown(self) -> Result<(), SinkConnectorError> {
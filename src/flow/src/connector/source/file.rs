@@ -0,0 +1,425 @@
+//! File source connector that replays or tails payloads stored in local files.
+
+use crate::connector::{ConnectorError, ConnectorEvent, ConnectorStream, SourceConnector};
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::ReceiverStream;
+
+const READ_CHUNK: usize = 64 * 1024;
+
+/// How payloads are delimited inside a file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FileFraming {
+    /// One payload per line; empty lines are skipped.
+    #[default]
+    Lines,
+    /// Every payload is preceded by its length as a big-endian `u32`.
+    LengthPrefixed,
+}
+
+impl FileFraming {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "lines" => Some(FileFraming::Lines),
+            "length_prefixed" => Some(FileFraming::LengthPrefixed),
+            _ => None,
+        }
+    }
+}
+
+/// How the matched files are consumed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FileReadMode {
+    /// Read every file once, then end the stream.
+    #[default]
+    Once,
+    /// Read every file, then keep polling for appended data and new files.
+    Follow { poll_interval: Duration },
+    /// Like `Once`, but emit at most `payloads_per_second` payloads per second.
+    Replay { payloads_per_second: u32 },
+}
+
+/// Configuration for the file source connector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSourceConfig {
+    pub source_name: String,
+    /// A file path, or a pattern whose last component may contain `*` and `?`.
+    pub path: String,
+    pub framing: FileFraming,
+    pub mode: FileReadMode,
+}
+
+impl FileSourceConfig {
+    pub fn new(source_name: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            source_name: source_name.into(),
+            path: path.into(),
+            framing: FileFraming::Lines,
+            mode: FileReadMode::Once,
+        }
+    }
+
+    pub fn with_framing(mut self, framing: FileFraming) -> Self {
+        self.framing = framing;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: FileReadMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// Source connector reading payloads from the files matching a path or pattern, in name order.
+pub struct FileSourceConnector {
+    id: String,
+    config: FileSourceConfig,
+    subscribed: bool,
+    reader: Option<JoinHandle<()>>,
+}
+
+impl FileSourceConnector {
+    pub fn new(id: impl Into<String>, config: FileSourceConfig) -> Self {
+        Self {
+            id: id.into(),
+            config,
+            subscribed: false,
+            reader: None,
+        }
+    }
+}
+
+impl SourceConnector for FileSourceConnector {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn subscribe(&mut self) -> Result<ConnectorStream, ConnectorError> {
+        if self.subscribed {
+            return Err(ConnectorError::AlreadySubscribed(self.id.clone()));
+        }
+        self.subscribed = true;
+        let (sender, receiver) = mpsc::channel(256);
+        let reader = FileReader {
+            id: self.id.clone(),
+            config: self.config.clone(),
+            sender,
+            cursors: Vec::new(),
+        };
+        tracing::info!(connector_id = %self.id, path = %self.config.path, "file source starting");
+        self.reader = Some(tokio::spawn(reader.run()));
+        Ok(Box::pin(ReceiverStream::new(receiver)))
+    }
+
+    fn close(&mut self) -> Result<(), ConnectorError> {
+        if let Some(reader) = self.reader.take() {
+            reader.abort();
+        }
+        tracing::info!(connector_id = %self.id, "file source closed");
+        Ok(())
+    }
+}
+
+/// Read position within one matched file.
+#[derive(Default)]
+struct FileCursor {
+    path: PathBuf,
+    offset: u64,
+    /// Bytes read past the last complete payload
+    pending: Vec<u8>,
+}
+
+struct FileReader {
+    id: String,
+    config: FileSourceConfig,
+    sender: mpsc::Sender<Result<ConnectorEvent, ConnectorError>>,
+    cursors: Vec<FileCursor>,
+}
+
+impl FileReader {
+    async fn run(mut self) {
+        let follow = match self.config.mode {
+            FileReadMode::Follow { poll_interval } => Some(poll_interval),
+            _ => None,
+        };
+        let pace = match self.config.mode {
+            FileReadMode::Replay {
+                payloads_per_second,
+            } if payloads_per_second > 0 => Some(Duration::from_secs(1) / payloads_per_second),
+            _ => None,
+        };
+        let mut pacer = pace.map(tokio::time::interval);
+
+        loop {
+            if let Err(err) = self.discover_files().await {
+                if self.sender.send(Err(err)).await.is_err() {
+                    return;
+                }
+            }
+            for index in 0..self.cursors.len() {
+                // One chunk at a time, so the channel's backpressure bounds what is held in
+                // memory however large the file is.
+                loop {
+                    let chunk = match self.read_chunk(index, follow.is_none()).await {
+                        Ok(chunk) => chunk,
+                        Err(err) => {
+                            if self.sender.send(Err(err)).await.is_err() {
+                                return;
+                            }
+                            break;
+                        }
+                    };
+                    for payload in chunk.payloads {
+                        if let Some(pacer) = pacer.as_mut() {
+                            pacer.tick().await;
+                        }
+                        if self
+                            .sender
+                            .send(Ok(ConnectorEvent::Payload(payload)))
+                            .await
+                            .is_err()
+                        {
+                            return;
+                        }
+                    }
+                    if chunk.eof {
+                        break;
+                    }
+                }
+            }
+
+            let Some(poll_interval) = follow else {
+                let _ = self.sender.send(Ok(ConnectorEvent::EndOfStream)).await;
+                tracing::info!(connector_id = %self.id, "file source reached end of input");
+                return;
+            };
+            tokio::select! {
+                _ = tokio::time::sleep(poll_interval) => {}
+                _ = self.sender.closed() => return,
+            }
+        }
+    }
+
+    /// Add cursors for matching files not seen yet.
+    async fn discover_files(&mut self) -> Result<(), ConnectorError> {
+        let pattern = self.config.path.clone();
+        let files = tokio::task::spawn_blocking(move || matching_files(&pattern))
+            .await
+            .map_err(|err| ConnectorError::Other(format!("file discovery failed: {err}")))??;
+        for path in files {
+            if !self.cursors.iter().any(|cursor| cursor.path == path) {
+                tracing::info!(connector_id = %self.id, file = %path.display(), "file source reading");
+                self.cursors.push(FileCursor {
+                    path,
+                    offset: 0,
+                    pending: Vec::new(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Read the next chunk of the file at `index` on the blocking pool.
+    async fn read_chunk(&mut self, index: usize, at_end: bool) -> Result<Chunk, ConnectorError> {
+        let mut cursor = std::mem::take(&mut self.cursors[index]);
+        let path = cursor.path.clone();
+        let framing = self.config.framing;
+        let (cursor, chunk) = tokio::task::spawn_blocking(move || {
+            let chunk = read_payloads(&mut cursor, framing, at_end);
+            (cursor, chunk)
+        })
+        .await
+        .map_err(|err| {
+            ConnectorError::Other(format!("failed to read {}: {err}", path.display()))
+        })?;
+        self.cursors[index] = cursor;
+        chunk
+    }
+}
+
+/// Payloads split from one chunk of a file.
+struct Chunk {
+    payloads: Vec<Vec<u8>>,
+    /// Nothing more to read for now
+    eof: bool,
+}
+
+/// Read up to `READ_CHUNK` bytes appended to a file since the last call and split them into
+/// payloads.
+///
+/// With `at_end` the file is not expected to grow, so once it is read to the end a trailing line
+/// without a newline is a payload too. A file that shrank is assumed truncated and read again
+/// from the start.
+fn read_payloads(
+    cursor: &mut FileCursor,
+    framing: FileFraming,
+    at_end: bool,
+) -> Result<Chunk, ConnectorError> {
+    let io_error = |err: std::io::Error| {
+        ConnectorError::Other(format!("failed to read {}: {err}", cursor.path.display()))
+    };
+    let mut file = match File::open(&cursor.path) {
+        Ok(file) => file,
+        // Rotated away between discovery and reading; it is not coming back.
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(Chunk {
+                payloads: Vec::new(),
+                eof: true,
+            })
+        }
+        Err(err) => return Err(io_error(err)),
+    };
+    let len = file.metadata().map_err(io_error)?.len();
+    if len < cursor.offset {
+        cursor.offset = 0;
+        cursor.pending.clear();
+    }
+    file.seek(SeekFrom::Start(cursor.offset))
+        .map_err(io_error)?;
+    let mut chunk = vec![0u8; READ_CHUNK];
+    let read = file.read(&mut chunk).map_err(io_error)?;
+    cursor.offset += read as u64;
+    cursor.pending.extend_from_slice(&chunk[..read]);
+    let eof = read == 0 || cursor.offset >= len;
+    let at_end = at_end && eof;
+
+    let mut payloads = Vec::new();
+    let mut consumed = 0;
+    match framing {
+        FileFraming::Lines => {
+            while let Some(newline) = cursor.pending[consumed..]
+                .iter()
+                .position(|byte| *byte == b'\n')
+            {
+                push_line(&mut payloads, &cursor.pending[consumed..consumed + newline]);
+                consumed += newline + 1;
+            }
+            if at_end && consumed < cursor.pending.len() {
+                push_line(&mut payloads, &cursor.pending[consumed..]);
+                consumed = cursor.pending.len();
+            }
+        }
+        FileFraming::LengthPrefixed => {
+            while cursor.pending.len() - consumed >= 4 {
+                let header: [u8; 4] = cursor.pending[consumed..consumed + 4]
+                    .try_into()
+                    .expect("four byte header");
+                let size = u32::from_be_bytes(header) as usize;
+                let end = consumed + 4 + size;
+                if end > cursor.pending.len() {
+                    break;
+                }
+                payloads.push(cursor.pending[consumed + 4..end].to_vec());
+                consumed = end;
+            }
+            if at_end && consumed < cursor.pending.len() {
+                tracing::warn!(
+                    file = %cursor.path.display(),
+                    bytes = cursor.pending.len() - consumed,
+                    "ignoring truncated length-prefixed payload"
+                );
+                consumed = cursor.pending.len();
+            }
+        }
+    }
+    cursor.pending.drain(..consumed);
+    Ok(Chunk { payloads, eof })
+}
+
+fn push_line(payloads: &mut Vec<Vec<u8>>, line: &[u8]) {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    if !line.is_empty() {
+        payloads.push(line.to_vec());
+    }
+}
+
+/// Files matching `pattern`, sorted by path; only the last path component may hold wildcards.
+fn matching_files(pattern: &str) -> Result<Vec<PathBuf>, ConnectorError> {
+    let path = Path::new(pattern);
+    let file_pattern = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| ConnectorError::Other(format!("invalid file source path `{pattern}`")))?;
+    if !file_pattern.contains(['*', '?']) {
+        return Ok(if path.is_file() {
+            vec![path.to_path_buf()]
+        } else {
+            Vec::new()
+        });
+    }
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(ConnectorError::Other(format!(
+                "failed to list {}: {err}",
+                dir.display()
+            )))
+        }
+    };
+    let mut files = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| wildcard_match(file_pattern, name))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+/// Match `name` against a pattern where `*` is any run of characters and `?` any one character.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wildcard_match;
+
+    #[test]
+    fn wildcard_patterns() {
+        assert!(wildcard_match("*.log", "a.log"));
+        assert!(wildcard_match("part-??.json", "part-01.json"));
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("a*b*c", "axxbyyc"));
+        assert!(!wildcard_match("*.log", "a.json"));
+        assert!(!wildcard_match("part-?.json", "part-01.json"));
+    }
+}
@@ -0,0 +1,152 @@
+//! HTTP push source: payloads are `POST`ed to the manager and routed to streams by path.
+//!
+//! The connector does not listen on its own. Subscribing registers it under its configured path
+//! in a process-wide route table, and the HTTP server hands every request body to
+//! [`push_http_payload`], which forwards it to all connectors registered for that path.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::connector::{ConnectorError, ConnectorEvent, ConnectorStream, SourceConnector};
+
+type PayloadSender = mpsc::Sender<Result<ConnectorEvent, ConnectorError>>;
+/// Senders of the subscribed connectors per normalized path.
+type RouteTable = HashMap<String, Vec<PayloadSender>>;
+
+static HTTP_ROUTES: Lazy<RwLock<RouteTable>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Configuration for the HTTP push source connector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpSourceConfig {
+    pub source_name: String,
+    /// Path the payloads are posted to, relative to the ingest endpoint.
+    pub path: String,
+}
+
+impl HttpSourceConfig {
+    pub fn new(source_name: impl Into<String>, path: impl AsRef<str>) -> Self {
+        Self {
+            source_name: source_name.into(),
+            path: normalize_http_path(path.as_ref()),
+        }
+    }
+}
+
+/// Source connector receiving the bodies posted to its path.
+pub struct HttpSourceConnector {
+    id: String,
+    config: HttpSourceConfig,
+    /// Sender registered in the route table; connector ids are not unique across pipelines,
+    /// so the route is removed by channel identity.
+    route: Option<PayloadSender>,
+    subscribed: bool,
+}
+
+impl HttpSourceConnector {
+    pub fn new(id: impl Into<String>, config: HttpSourceConfig) -> Self {
+        Self {
+            id: id.into(),
+            config,
+            route: None,
+            subscribed: false,
+        }
+    }
+
+    fn unregister(&mut self) {
+        let Some(route) = self.route.take() else {
+            return;
+        };
+        let mut routes = HTTP_ROUTES.write().expect("http route table poisoned");
+        if let Some(subscribers) = routes.get_mut(&self.config.path) {
+            subscribers.retain(|sender| !sender.same_channel(&route));
+            if subscribers.is_empty() {
+                routes.remove(&self.config.path);
+            }
+        }
+    }
+}
+
+impl SourceConnector for HttpSourceConnector {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn subscribe(&mut self) -> Result<ConnectorStream, ConnectorError> {
+        if self.subscribed {
+            return Err(ConnectorError::AlreadySubscribed(self.id.clone()));
+        }
+        self.subscribed = true;
+        let (sender, receiver) = mpsc::channel(256);
+        HTTP_ROUTES
+            .write()
+            .expect("http route table poisoned")
+            .entry(self.config.path.clone())
+            .or_default()
+            .push(sender.clone());
+        self.route = Some(sender);
+        tracing::info!(connector_id = %self.id, path = %self.config.path, "http source starting");
+        Ok(Box::pin(ReceiverStream::new(receiver)))
+    }
+
+    fn close(&mut self) -> Result<(), ConnectorError> {
+        self.unregister();
+        tracing::info!(connector_id = %self.id, "http source closed");
+        Ok(())
+    }
+}
+
+impl Drop for HttpSourceConnector {
+    fn drop(&mut self) {
+        self.unregister();
+    }
+}
+
+/// Deliver a posted body to every connector subscribed to `path`.
+///
+/// Waits while a subscriber's buffer is full, so a slow pipeline slows down its clients instead
+/// of dropping payloads. Returns the number of connectors the payload was delivered to.
+pub async fn push_http_payload(path: &str, payload: Vec<u8>) -> Result<usize, HttpPushError> {
+    let path = normalize_http_path(path);
+    let subscribers = HTTP_ROUTES
+        .read()
+        .expect("http route table poisoned")
+        .get(&path)
+        .map(|subscribers| subscribers.to_vec())
+        .unwrap_or_default();
+    if subscribers.is_empty() {
+        return Err(HttpPushError::NoRoute(path));
+    }
+    let mut delivered = 0;
+    for sender in subscribers {
+        if sender
+            .send(Ok(ConnectorEvent::Payload(payload.clone())))
+            .await
+            .is_ok()
+        {
+            delivered += 1;
+        }
+    }
+    if delivered == 0 {
+        return Err(HttpPushError::Closed(path));
+    }
+    Ok(delivered)
+}
+
+/// Paths are compared with a single leading slash and without a trailing one.
+fn normalize_http_path(path: &str) -> String {
+    format!("/{}", path.trim_matches('/'))
+}
+
+/// Errors returned by [`push_http_payload`].
+#[derive(thiserror::Error, Debug)]
+pub enum HttpPushError {
+    /// No running pipeline reads from the path.
+    #[error("no http source listens on {0}")]
+    NoRoute(String),
+    /// Every source on the path stopped consuming.
+    #[error("http sources on {0} are closed")]
+    Closed(String),
+}
@@ -0,0 +1,234 @@
+//! Kafka source connector consuming a topic as part of a consumer group.
+
+use crate::connector::{ConnectorError, ConnectorEvent, ConnectorStream, SourceConnector};
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{Consumer, StreamConsumer};
+use rdkafka::message::Message;
+use rdkafka::{Offset, TopicPartitionList};
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot};
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::StreamExt;
+
+/// Where a consumer group starts reading partitions it has no committed offset for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KafkaStartOffset {
+    Earliest,
+    #[default]
+    Latest,
+}
+
+impl KafkaStartOffset {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "earliest" => Some(KafkaStartOffset::Earliest),
+            "latest" => Some(KafkaStartOffset::Latest),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            KafkaStartOffset::Earliest => "earliest",
+            KafkaStartOffset::Latest => "latest",
+        }
+    }
+}
+
+/// Configuration for the Kafka source connector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KafkaSourceConfig {
+    pub source_name: String,
+    /// Comma-separated `host:port` bootstrap servers.
+    pub brokers: String,
+    pub topic: String,
+    pub group_id: String,
+    /// Partitions to read; empty lets the consumer group balance the topic's partitions.
+    pub partitions: Vec<i32>,
+    pub start_offset: KafkaStartOffset,
+    /// Extra librdkafka client properties, applied last.
+    pub properties: Vec<(String, String)>,
+}
+
+impl KafkaSourceConfig {
+    pub fn new(
+        source_name: impl Into<String>,
+        brokers: impl Into<String>,
+        topic: impl Into<String>,
+        group_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            source_name: source_name.into(),
+            brokers: brokers.into(),
+            topic: topic.into(),
+            group_id: group_id.into(),
+            partitions: Vec::new(),
+            start_offset: KafkaStartOffset::default(),
+            properties: Vec::new(),
+        }
+    }
+
+    pub fn with_partitions(mut self, partitions: Vec<i32>) -> Self {
+        self.partitions = partitions;
+        self
+    }
+
+    pub fn with_start_offset(mut self, start_offset: KafkaStartOffset) -> Self {
+        self.start_offset = start_offset;
+        self
+    }
+
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.push((key.into(), value.into()));
+        self
+    }
+}
+
+/// Emits the value of every record consumed from the topic.
+///
+/// A record's offset is stored when the data source takes it from the connector, and stored
+/// offsets are committed to the consumer group periodically and on close. Records still moving
+/// through the pipeline when it stops or crashes are not replayed on restart, so delivery is
+/// at-most-once.
+pub struct KafkaSourceConnector {
+    id: String,
+    config: KafkaSourceConfig,
+    subscribed: bool,
+    shutdown_tx: Option<oneshot::Sender<()>>,
+}
+
+impl KafkaSourceConnector {
+    pub fn new(id: impl Into<String>, config: KafkaSourceConfig) -> Self {
+        Self {
+            id: id.into(),
+            config,
+            subscribed: false,
+            shutdown_tx: None,
+        }
+    }
+
+    fn build_consumer(&self) -> Result<StreamConsumer, ConnectorError> {
+        let mut client_config = ClientConfig::new();
+        client_config
+            .set("bootstrap.servers", &self.config.brokers)
+            .set("group.id", &self.config.group_id)
+            .set("enable.auto.commit", "true")
+            .set("enable.auto.offset.store", "false")
+            .set("auto.offset.reset", self.config.start_offset.as_str());
+        for (key, value) in &self.config.properties {
+            client_config.set(key, value);
+        }
+        let consumer: StreamConsumer = client_config
+            .create()
+            .map_err(|err| ConnectorError::Connection(err.to_string()))?;
+
+        if self.config.partitions.is_empty() {
+            consumer
+                .subscribe(&[self.config.topic.as_str()])
+                .map_err(|err| ConnectorError::Connection(err.to_string()))?;
+        } else {
+            let mut assignment = TopicPartitionList::new();
+            for partition in &self.config.partitions {
+                assignment
+                    .add_partition_offset(&self.config.topic, *partition, Offset::Stored)
+                    .map_err(|err| ConnectorError::Connection(err.to_string()))?;
+            }
+            consumer
+                .assign(&assignment)
+                .map_err(|err| ConnectorError::Connection(err.to_string()))?;
+        }
+        Ok(consumer)
+    }
+}
+
+impl SourceConnector for KafkaSourceConnector {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn subscribe(&mut self) -> Result<ConnectorStream, ConnectorError> {
+        if self.subscribed {
+            return Err(ConnectorError::AlreadySubscribed(self.id.clone()));
+        }
+        let consumer = Arc::new(self.build_consumer()?);
+        self.subscribed = true;
+
+        let (sender, receiver) = mpsc::channel(256);
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        self.shutdown_tx = Some(shutdown_tx);
+        tokio::spawn(run_consumer_loop(
+            self.id.clone(),
+            Arc::clone(&consumer),
+            sender,
+            shutdown_rx,
+        ));
+        tracing::info!(
+            connector_id = %self.id,
+            topic = %self.config.topic,
+            group_id = %self.config.group_id,
+            "kafka source starting"
+        );
+        // Store the offset only once the record leaves the channel, not when it is queued.
+        let connector_id = self.id.clone();
+        let stream = ReceiverStream::new(receiver).map(move |record| {
+            let record = record?;
+            if let Err(err) =
+                consumer.store_offset(&record.topic, record.partition, record.offset)
+            {
+                tracing::warn!(connector_id = %connector_id, error = %err, "failed to store kafka offset");
+            }
+            Ok(ConnectorEvent::Payload(record.payload))
+        });
+        Ok(Box::pin(stream))
+    }
+
+    fn close(&mut self) -> Result<(), ConnectorError> {
+        if let Some(tx) = self.shutdown_tx.take() {
+            let _ = tx.send(());
+        }
+        tracing::info!(connector_id = %self.id, "kafka source closed");
+        Ok(())
+    }
+}
+
+/// A consumed record waiting for the data source to take it.
+struct KafkaRecord {
+    topic: String,
+    partition: i32,
+    offset: i64,
+    payload: Vec<u8>,
+}
+
+async fn run_consumer_loop(
+    connector_id: String,
+    consumer: Arc<StreamConsumer>,
+    sender: mpsc::Sender<Result<KafkaRecord, ConnectorError>>,
+    mut shutdown_rx: oneshot::Receiver<()>,
+) {
+    loop {
+        let record = tokio::select! {
+            _ = &mut shutdown_rx => break,
+            message = consumer.recv() => match message {
+                Ok(message) => KafkaRecord {
+                    topic: message.topic().to_string(),
+                    partition: message.partition(),
+                    offset: message.offset(),
+                    payload: message.payload().unwrap_or_default().to_vec(),
+                },
+                // librdkafka recovers from broker errors on its own; report and keep polling.
+                Err(err) => {
+                    if sender.send(Err(ConnectorError::Connection(err.to_string()))).await.is_err() {
+                        break;
+                    }
+                    continue;
+                }
+            },
+        };
+        if sender.send(Ok(record)).await.is_err() {
+            break;
+        }
+    }
+    // The consumer leaves the group and commits the stored offsets once the stream drops it too.
+    drop(consumer);
+    tracing::info!(connector_id = %connector_id, "kafka consumer stopped");
+}
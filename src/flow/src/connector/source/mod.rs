@@ -1,2 +1,5 @@
+pub mod file;
+pub mod http;
+pub mod kafka;
 pub mod mock;
 pub mod mqtt;
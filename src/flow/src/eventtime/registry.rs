@@ -1,4 +1,4 @@
-use datatypes::Value;
+use datatypes::{Timestamp, TimestampPrecision, Value};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -32,6 +32,8 @@ pub trait EventtimeTypeParser: Send + Sync {
 pub enum BuiltinEventtimeType {
     UnixtimestampSeconds,
     UnixtimestampMillis,
+    /// Timestamp columns, or RFC 3339 strings
+    Timestamp,
 }
 
 impl BuiltinEventtimeType {
@@ -39,6 +41,7 @@ impl BuiltinEventtimeType {
         match self {
             BuiltinEventtimeType::UnixtimestampSeconds => "unixtimestamp_s",
             BuiltinEventtimeType::UnixtimestampMillis => "unixtimestamp_ms",
+            BuiltinEventtimeType::Timestamp => "timestamp",
         }
     }
 }
@@ -106,6 +109,10 @@ impl EventtimeTypeRegistry {
             BuiltinEventtimeType::UnixtimestampMillis.key(),
             Arc::new(UnixtimestampMillisParser),
         );
+        self.register(
+            BuiltinEventtimeType::Timestamp.key(),
+            Arc::new(TimestampParser),
+        );
     }
 }
 
@@ -113,6 +120,9 @@ struct UnixtimestampSecondsParser;
 
 impl EventtimeTypeParser for UnixtimestampSecondsParser {
     fn parse(&self, value: &Value) -> Result<SystemTime, EventtimeParseError> {
+        if let Value::Timestamp(ts) = value {
+            return timestamp_to_system_time(ts);
+        }
         let seconds = parse_integer(value)?;
         let seconds = u64::try_from(seconds).map_err(|_| {
             EventtimeParseError::new(format!(
@@ -129,6 +139,9 @@ struct UnixtimestampMillisParser;
 
 impl EventtimeTypeParser for UnixtimestampMillisParser {
     fn parse(&self, value: &Value) -> Result<SystemTime, EventtimeParseError> {
+        if let Value::Timestamp(ts) = value {
+            return timestamp_to_system_time(ts);
+        }
         let millis = parse_integer(value)?;
         let millis = u64::try_from(millis).map_err(|_| {
             EventtimeParseError::new(format!(
@@ -147,6 +160,40 @@ impl EventtimeTypeParser for UnixtimestampMillisParser {
     }
 }
 
+struct TimestampParser;
+
+impl EventtimeTypeParser for TimestampParser {
+    fn parse(&self, value: &Value) -> Result<SystemTime, EventtimeParseError> {
+        match value {
+            Value::Timestamp(ts) => timestamp_to_system_time(ts),
+            Value::String(v) => Timestamp::parse_rfc3339(v, TimestampPrecision::Nanosecond)
+                .ok_or_else(|| {
+                    EventtimeParseError::new(format!(
+                        "eventtime string `{v}` is not an RFC 3339 timestamp"
+                    ))
+                })
+                .and_then(|ts| timestamp_to_system_time(&ts)),
+            Value::Null => Err(EventtimeParseError::new(
+                "eventtime value is null".to_string(),
+            )),
+            other => Err(EventtimeParseError::new(format!(
+                "timestamp eventtime expects a timestamp, got {:?}",
+                other.datatype()
+            ))),
+        }
+    }
+}
+
+fn timestamp_to_system_time(ts: &Timestamp) -> Result<SystemTime, EventtimeParseError> {
+    if ts.value() < 0 {
+        return Err(EventtimeParseError::new(format!(
+            "eventtime timestamp {ts} is before the Unix epoch"
+        )));
+    }
+    ts.to_system_time()
+        .ok_or_else(|| EventtimeParseError::new(format!("eventtime timestamp {ts} overflow")))
+}
+
 fn parse_integer(value: &Value) -> Result<i128, EventtimeParseError> {
     match value {
         Value::Null => Err(EventtimeParseError::new(
@@ -182,6 +229,7 @@ mod tests {
         assert_eq!(
             registry.list(),
             vec![
+                "timestamp".to_string(),
                 "unixtimestamp_ms".to_string(),
                 "unixtimestamp_s".to_string()
             ]
@@ -210,6 +258,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parses_timestamp_values_and_strings() {
+        let registry = EventtimeTypeRegistry::with_builtin_types();
+        let parser = registry.resolve("timestamp").unwrap();
+        let expected = UNIX_EPOCH + Duration::from_millis(1500);
+        assert_eq!(
+            parser
+                .parse(&Value::Timestamp(Timestamp::new_millisecond(1500)))
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            parser
+                .parse(&Value::String("1970-01-01T00:00:01.5Z".to_string()))
+                .unwrap(),
+            expected
+        );
+        assert!(parser.parse(&Value::Int64(1500)).is_err());
+
+        let millis = registry.resolve("unixtimestamp_ms").unwrap();
+        assert_eq!(
+            millis
+                .parse(&Value::Timestamp(Timestamp::new_second(2)))
+                .unwrap(),
+            UNIX_EPOCH + Duration::from_secs(2)
+        );
+    }
+
     #[test]
     fn unknown_type_lists_available() {
         let registry = EventtimeTypeRegistry::with_builtin_types();
@@ -0,0 +1,609 @@
+use crate::expr::custom_func::{check_arity, CustomFunc};
+use crate::expr::func::{BinaryFunc, EvalError};
+use datatypes::Value;
+use std::cmp::Ordering;
+
+/// Numeric argument after promotion: integers widen to Int64, floats to Float64.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+impl Number {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Int8(v) => Some(Self::Int(*v as i64)),
+            Value::Int16(v) => Some(Self::Int(*v as i64)),
+            Value::Int32(v) => Some(Self::Int(*v as i64)),
+            Value::Int64(v) => Some(Self::Int(*v)),
+            Value::Uint8(v) => Some(Self::Int(*v as i64)),
+            Value::Uint16(v) => Some(Self::Int(*v as i64)),
+            Value::Uint32(v) => Some(Self::Int(*v as i64)),
+            Value::Uint64(v) => Some(
+                i64::try_from(*v)
+                    .map(Self::Int)
+                    .unwrap_or(Self::Float(*v as f64)),
+            ),
+            Value::Float32(v) => Some(Self::Float(*v as f64)),
+            Value::Float64(v) => Some(Self::Float(*v)),
+            _ => None,
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Self::Int(v) => v as f64,
+            Self::Float(v) => v,
+        }
+    }
+
+    fn into_value(self) -> Value {
+        match self {
+            Self::Int(v) => Value::Int64(v),
+            Self::Float(v) => Value::Float64(v),
+        }
+    }
+
+    /// Convert back to the type of `input`, saturating integers to its range.
+    fn into_value_like(self, input: &Value) -> Value {
+        fn clamp<T: TryFrom<i64> + Copy>(v: i64, min: T, max: T) -> T
+        where
+            i64: From<T>,
+        {
+            T::try_from(v.clamp(i64::from(min), i64::from(max))).unwrap_or(max)
+        }
+        match (input, self) {
+            (Value::Int8(_), Self::Int(v)) => Value::Int8(clamp(v, i8::MIN, i8::MAX)),
+            (Value::Int16(_), Self::Int(v)) => Value::Int16(clamp(v, i16::MIN, i16::MAX)),
+            (Value::Int32(_), Self::Int(v)) => Value::Int32(clamp(v, i32::MIN, i32::MAX)),
+            (Value::Uint8(_), Self::Int(v)) => Value::Uint8(clamp(v, 0, u8::MAX)),
+            (Value::Uint16(_), Self::Int(v)) => Value::Uint16(clamp(v, 0, u16::MAX)),
+            (Value::Uint32(_), Self::Int(v)) => Value::Uint32(clamp(v, 0, u32::MAX)),
+            (Value::Uint64(_), Self::Int(v)) => Value::Uint64(v.max(0) as u64),
+            // Uint64 values beyond i64 are carried as floats; `as` saturates.
+            (Value::Uint64(_), Self::Float(v)) => Value::Uint64(v as u64),
+            (Value::Float32(_), Self::Float(v)) => Value::Float32(v as f32),
+            (_, number) => number.into_value(),
+        }
+    }
+}
+
+/// Check arity and that every argument is numeric or NULL.
+fn validate_numeric(args: &[Value], min: usize, max: usize) -> Result<(), EvalError> {
+    check_arity(args, min, max)?;
+    for (idx, arg) in args.iter().enumerate() {
+        if !arg.is_null() && Number::from_value(arg).is_none() {
+            return Err(EvalError::TypeMismatch {
+                expected: "numeric".to_string(),
+                actual: format!("{:?} at argument {}", arg, idx),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Numeric arguments of a validated call, or `None` when any of them is NULL.
+fn numbers(args: &[Value]) -> Option<Vec<Number>> {
+    args.iter().map(Number::from_value).collect()
+}
+
+fn out_of_domain(func: &str, domain: &str, value: f64) -> EvalError {
+    EvalError::TypeMismatch {
+        expected: format!("{} argument {}", func, domain),
+        actual: value.to_string(),
+    }
+}
+
+/// `abs(x)`
+#[derive(Debug, Clone)]
+pub struct AbsFunc;
+
+impl CustomFunc for AbsFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_numeric(args, 1, 1)
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        let Some(numbers) = numbers(args) else {
+            return Ok(Value::Null);
+        };
+        let result = match numbers[0] {
+            Number::Int(v) => Number::Int(v.saturating_abs()),
+            // Uint64 beyond i64 arrives as a float but is never negative.
+            Number::Float(_) if matches!(args[0], Value::Uint64(_)) => return Ok(args[0].clone()),
+            Number::Float(v) => Number::Float(v.abs()),
+        };
+        Ok(result.into_value_like(&args[0]))
+    }
+
+    fn name(&self) -> &str {
+        "abs"
+    }
+}
+
+/// `sign(x)`: -1, 0 or 1, of the same type as `x`
+#[derive(Debug, Clone)]
+pub struct SignFunc;
+
+impl CustomFunc for SignFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_numeric(args, 1, 1)
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        let Some(numbers) = numbers(args) else {
+            return Ok(Value::Null);
+        };
+        let result = match numbers[0] {
+            Number::Int(v) => Number::Int(v.signum()),
+            Number::Float(v) if v == 0.0 || v.is_nan() => Number::Float(v),
+            Number::Float(v) => Number::Float(v.signum()),
+        };
+        Ok(result.into_value_like(&args[0]))
+    }
+
+    fn name(&self) -> &str {
+        "sign"
+    }
+}
+
+/// How `RoundFunc` rounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Nearest, halves away from zero
+    Round,
+    Ceil,
+    Floor,
+}
+
+/// `round(x [, n])`, `ceil(x)`, `floor(x)`
+///
+/// Integers are returned unchanged, except by `round` with a negative `n`, which rounds to
+/// `10^-n`; `round` keeps `n` decimal places of floats (0 by default). The result has the type
+/// of `x`.
+#[derive(Debug, Clone)]
+pub struct RoundFunc(pub RoundingMode);
+
+impl RoundFunc {
+    fn apply(&self, v: f64) -> f64 {
+        match self.0 {
+            RoundingMode::Round => v.round(),
+            RoundingMode::Ceil => v.ceil(),
+            RoundingMode::Floor => v.floor(),
+        }
+    }
+}
+
+impl CustomFunc for RoundFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        let max = if self.0 == RoundingMode::Round { 2 } else { 1 };
+        validate_numeric(args, 1, max)?;
+        if let Some(Number::Float(_)) = args.get(1).and_then(Number::from_value) {
+            return Err(EvalError::TypeMismatch {
+                expected: "integer".to_string(),
+                actual: format!("{:?} at argument 1", args[1]),
+            });
+        }
+        Ok(())
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        let Some(numbers) = numbers(args) else {
+            return Ok(Value::Null);
+        };
+        let places = match numbers.get(1) {
+            Some(Number::Int(n)) => (*n).clamp(-308, 308) as i32,
+            _ => 0,
+        };
+        let result = match numbers[0] {
+            Number::Int(_) if places >= 0 => return Ok(args[0].clone()),
+            Number::Int(v) => match 10i64.checked_pow(places.unsigned_abs()) {
+                Some(unit) => {
+                    let rounded = (v as f64 / unit as f64).round() as i64;
+                    Number::Int(rounded.saturating_mul(unit))
+                }
+                None => Number::Int(0),
+            },
+            // Uint64 beyond i64 arrives as a float but is still an integer.
+            Number::Float(_) if places >= 0 && matches!(args[0], Value::Uint64(_)) => {
+                return Ok(args[0].clone())
+            }
+            Number::Float(v) if places == 0 => Number::Float(self.apply(v)),
+            Number::Float(v) => {
+                let scale = 10f64.powi(places);
+                let scaled = self.apply(v * scale) / scale;
+                Number::Float(if scaled.is_finite() { scaled } else { v })
+            }
+        };
+        Ok(result.into_value_like(&args[0]))
+    }
+
+    fn name(&self) -> &str {
+        match self.0 {
+            RoundingMode::Round => "round",
+            RoundingMode::Ceil => "ceil",
+            RoundingMode::Floor => "floor",
+        }
+    }
+}
+
+/// Single-argument floating point functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatOp {
+    Sqrt,
+    Exp,
+    Ln,
+    Log10,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+}
+
+/// `sqrt`, `exp`, `ln`, `log10`, `sin`, `cos`, `tan`, `asin`, `acos`, `atan` of one number,
+/// returning Float64. Arguments outside the function's domain are an error.
+#[derive(Debug, Clone)]
+pub struct FloatFunc(pub FloatOp);
+
+impl CustomFunc for FloatFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_numeric(args, 1, 1)
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        let Some(numbers) = numbers(args) else {
+            return Ok(Value::Null);
+        };
+        let x = numbers[0].as_f64();
+        let result = match self.0 {
+            FloatOp::Sqrt if x < 0.0 => return Err(out_of_domain("sqrt", ">= 0", x)),
+            FloatOp::Sqrt => x.sqrt(),
+            FloatOp::Exp => x.exp(),
+            FloatOp::Ln if x <= 0.0 => return Err(out_of_domain("ln", "> 0", x)),
+            FloatOp::Ln => x.ln(),
+            FloatOp::Log10 if x <= 0.0 => return Err(out_of_domain("log10", "> 0", x)),
+            FloatOp::Log10 => x.log10(),
+            FloatOp::Sin => x.sin(),
+            FloatOp::Cos => x.cos(),
+            FloatOp::Tan => x.tan(),
+            FloatOp::Asin | FloatOp::Acos if !(-1.0..=1.0).contains(&x) => {
+                return Err(out_of_domain(self.name(), "in [-1, 1]", x))
+            }
+            FloatOp::Asin => x.asin(),
+            FloatOp::Acos => x.acos(),
+            FloatOp::Atan => x.atan(),
+        };
+        Ok(Value::Float64(result))
+    }
+
+    fn name(&self) -> &str {
+        match self.0 {
+            FloatOp::Sqrt => "sqrt",
+            FloatOp::Exp => "exp",
+            FloatOp::Ln => "ln",
+            FloatOp::Log10 => "log10",
+            FloatOp::Sin => "sin",
+            FloatOp::Cos => "cos",
+            FloatOp::Tan => "tan",
+            FloatOp::Asin => "asin",
+            FloatOp::Acos => "acos",
+            FloatOp::Atan => "atan",
+        }
+    }
+}
+
+/// `atan2(y, x)`
+#[derive(Debug, Clone)]
+pub struct Atan2Func;
+
+impl CustomFunc for Atan2Func {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_numeric(args, 2, 2)
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        let Some(numbers) = numbers(args) else {
+            return Ok(Value::Null);
+        };
+        Ok(Value::Float64(
+            numbers[0].as_f64().atan2(numbers[1].as_f64()),
+        ))
+    }
+
+    fn name(&self) -> &str {
+        "atan2"
+    }
+}
+
+/// `pow(x, y)`
+///
+/// Integer powers with a non-negative integer exponent stay Int64 and saturate like integer
+/// arithmetic; everything else is computed as Float64.
+#[derive(Debug, Clone)]
+pub struct PowFunc;
+
+impl CustomFunc for PowFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_numeric(args, 2, 2)
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        let Some(numbers) = numbers(args) else {
+            return Ok(Value::Null);
+        };
+        Ok(match (numbers[0], numbers[1]) {
+            (Number::Int(base), Number::Int(exp)) if exp >= 0 => {
+                let exp = u32::try_from(exp).unwrap_or(u32::MAX);
+                Value::Int64(base.saturating_pow(exp))
+            }
+            (base, exp) => Value::Float64(base.as_f64().powf(exp.as_f64())),
+        })
+    }
+
+    fn name(&self) -> &str {
+        "pow"
+    }
+}
+
+/// `greatest(x, ...)` / `least(x, ...)`
+///
+/// NULL arguments are ignored; the result is NULL only when all arguments are NULL. Numbers
+/// are promoted to Int64, or to Float64 when any of them is a float. Other arguments must all
+/// have the same type (e.g. strings or timestamps).
+#[derive(Debug, Clone)]
+pub struct ExtremumFunc {
+    pub greatest: bool,
+}
+
+impl ExtremumFunc {
+    fn keep_candidate(&self, ordering: Ordering) -> bool {
+        if self.greatest {
+            ordering == Ordering::Greater
+        } else {
+            ordering == Ordering::Less
+        }
+    }
+}
+
+impl CustomFunc for ExtremumFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        check_arity(args, 1, usize::MAX)?;
+        let mut present = args.iter().enumerate().filter(|(_, arg)| !arg.is_null());
+        let Some((_, first)) = present.next() else {
+            return Ok(());
+        };
+        let numeric = Number::from_value(first).is_some();
+        for (idx, arg) in present {
+            let compatible = if numeric {
+                Number::from_value(arg).is_some()
+            } else {
+                std::mem::discriminant(arg) == std::mem::discriminant(first)
+            };
+            if !compatible {
+                return Err(EvalError::TypeMismatch {
+                    expected: format!("arguments comparable with {:?}", first),
+                    actual: format!("{:?} at argument {}", arg, idx),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        let present: Vec<&Value> = args.iter().filter(|arg| !arg.is_null()).collect();
+        if present.is_empty() {
+            return Ok(Value::Null);
+        }
+
+        if let Some(numbers) = present
+            .iter()
+            .map(|arg| Number::from_value(arg))
+            .collect::<Option<Vec<_>>>()
+        {
+            let any_float = numbers.iter().any(|n| matches!(n, Number::Float(_)));
+            let mut best = numbers[0];
+            for candidate in &numbers[1..] {
+                let ordering = if any_float {
+                    candidate.as_f64().total_cmp(&best.as_f64())
+                } else {
+                    match (candidate, best) {
+                        (Number::Int(a), Number::Int(b)) => a.cmp(&b),
+                        _ => unreachable!("all integers"),
+                    }
+                };
+                if self.keep_candidate(ordering) {
+                    best = *candidate;
+                }
+            }
+            return Ok(if any_float {
+                Value::Float64(best.as_f64())
+            } else {
+                best.into_value()
+            });
+        }
+
+        let mut best = present[0];
+        for candidate in &present[1..] {
+            let ordering = BinaryFunc::compare_values(candidate, best).ok_or_else(|| {
+                EvalError::TypeMismatch {
+                    expected: "comparable arguments".to_string(),
+                    actual: format!("{:?} and {:?}", candidate, best),
+                }
+            })?;
+            if self.keep_candidate(ordering) {
+                best = candidate;
+            }
+        }
+        Ok(best.clone())
+    }
+
+    fn name(&self) -> &str {
+        if self.greatest {
+            "greatest"
+        } else {
+            "least"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(func: &dyn CustomFunc, args: Vec<Value>) -> Value {
+        func.eval_row(&args).expect("eval")
+    }
+
+    #[test]
+    fn abs_sign_and_rounding() {
+        assert_eq!(call(&AbsFunc, vec![Value::Int32(-4)]), Value::Int32(4));
+        assert_eq!(
+            call(&AbsFunc, vec![Value::Int8(i8::MIN)]),
+            Value::Int8(i8::MAX)
+        );
+        assert_eq!(
+            call(&AbsFunc, vec![Value::Uint64(u64::MAX - 1)]),
+            Value::Uint64(u64::MAX - 1)
+        );
+        assert_eq!(
+            call(&AbsFunc, vec![Value::Float32(-0.5)]),
+            Value::Float32(0.5)
+        );
+        assert_eq!(
+            call(&AbsFunc, vec![Value::Float64(-1.5)]),
+            Value::Float64(1.5)
+        );
+        assert_eq!(call(&AbsFunc, vec![Value::Null]), Value::Null);
+        assert!(AbsFunc.validate_row(&[Value::String("1".into())]).is_err());
+        assert_eq!(call(&SignFunc, vec![Value::Int64(-9)]), Value::Int64(-1));
+        assert_eq!(call(&SignFunc, vec![Value::Int16(-9)]), Value::Int16(-1));
+        assert_eq!(call(&SignFunc, vec![Value::Uint8(9)]), Value::Uint8(1));
+        assert_eq!(
+            call(&SignFunc, vec![Value::Float64(0.0)]),
+            Value::Float64(0.0)
+        );
+
+        let round = RoundFunc(RoundingMode::Round);
+        assert_eq!(call(&round, vec![Value::Float64(2.5)]), Value::Float64(3.0));
+        assert_eq!(call(&round, vec![Value::Float32(2.5)]), Value::Float32(3.0));
+        assert_eq!(
+            call(&round, vec![Value::Int32(1250), Value::Int64(-2)]),
+            Value::Int32(1300)
+        );
+        assert_eq!(
+            call(&round, vec![Value::Int8(120), Value::Int64(-2)]),
+            Value::Int8(100)
+        );
+        assert_eq!(
+            call(&round, vec![Value::Float64(21.4567), Value::Int64(2)]),
+            Value::Float64(21.46)
+        );
+        assert_eq!(
+            call(&round, vec![Value::Int64(1250), Value::Int64(-2)]),
+            Value::Int64(1300)
+        );
+        assert_eq!(
+            call(&round, vec![Value::Int64(7), Value::Int64(2)]),
+            Value::Int64(7)
+        );
+        assert!(round
+            .validate_row(&[Value::Float64(1.0), Value::Float64(1.0)])
+            .is_err());
+        assert_eq!(
+            call(&RoundFunc(RoundingMode::Ceil), vec![Value::Float32(1.2)]),
+            Value::Float32(2.0)
+        );
+        assert_eq!(
+            call(&RoundFunc(RoundingMode::Floor), vec![Value::Float64(-1.2)]),
+            Value::Float64(-2.0)
+        );
+        assert!(RoundFunc(RoundingMode::Floor)
+            .validate_row(&[Value::Float64(1.0), Value::Int64(1)])
+            .is_err());
+    }
+
+    #[test]
+    fn float_functions_and_pow() {
+        assert_eq!(
+            call(&FloatFunc(FloatOp::Sqrt), vec![Value::Int64(16)]),
+            Value::Float64(4.0)
+        );
+        assert!(FloatFunc(FloatOp::Sqrt)
+            .eval_row(&[Value::Int64(-1)])
+            .is_err());
+        assert!(FloatFunc(FloatOp::Ln).eval_row(&[Value::Int64(0)]).is_err());
+        assert!(FloatFunc(FloatOp::Acos)
+            .eval_row(&[Value::Float64(1.5)])
+            .is_err());
+        assert_eq!(
+            call(&FloatFunc(FloatOp::Log10), vec![Value::Uint32(1000)]),
+            Value::Float64(3.0)
+        );
+        assert_eq!(
+            call(&FloatFunc(FloatOp::Cos), vec![Value::Int64(0)]),
+            Value::Float64(1.0)
+        );
+        assert_eq!(
+            call(&Atan2Func, vec![Value::Int64(0), Value::Int64(1)]),
+            Value::Float64(0.0)
+        );
+        assert_eq!(
+            call(&PowFunc, vec![Value::Int64(2), Value::Int64(10)]),
+            Value::Int64(1024)
+        );
+        assert_eq!(
+            call(&PowFunc, vec![Value::Int64(2), Value::Int64(-1)]),
+            Value::Float64(0.5)
+        );
+        assert_eq!(
+            call(&PowFunc, vec![Value::Int64(10), Value::Int64(100)]),
+            Value::Int64(i64::MAX)
+        );
+        assert_eq!(
+            call(&PowFunc, vec![Value::Float64(4.0), Value::Float64(0.5)]),
+            Value::Float64(2.0)
+        );
+    }
+
+    #[test]
+    fn greatest_and_least() {
+        let greatest = ExtremumFunc { greatest: true };
+        let least = ExtremumFunc { greatest: false };
+        assert_eq!(
+            call(
+                &greatest,
+                vec![Value::Int32(3), Value::Null, Value::Int64(7)]
+            ),
+            Value::Int64(7)
+        );
+        assert_eq!(
+            call(&greatest, vec![Value::Int64(3), Value::Float64(2.5)]),
+            Value::Float64(3.0)
+        );
+        assert_eq!(
+            call(&least, vec![Value::Int64(3), Value::Float64(2.5)]),
+            Value::Float64(2.5)
+        );
+        assert_eq!(
+            call(
+                &least,
+                vec![Value::String("pear".into()), Value::String("apple".into())]
+            ),
+            Value::String("apple".into())
+        );
+        assert_eq!(call(&least, vec![Value::Null, Value::Null]), Value::Null);
+        assert!(greatest
+            .validate_row(&[Value::Int64(1), Value::String("a".into())])
+            .is_err());
+    }
+}
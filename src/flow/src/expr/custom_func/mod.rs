@@ -1,10 +1,23 @@
+pub mod math_func;
+pub mod regex_func;
 pub mod registry;
 pub mod string_func;
+pub mod time_func;
 
 use crate::expr::func::EvalError;
 use datatypes::Value;
+pub use math_func::{
+    AbsFunc, Atan2Func, ExtremumFunc, FloatFunc, FloatOp, PowFunc, RoundFunc, RoundingMode,
+    SignFunc,
+};
+pub use regex_func::{RegexpExtractFunc, RegexpMatchFunc, RegexpReplaceFunc, SimilarToFunc};
 pub use registry::{CustomFuncRegistry, CustomFuncRegistryError};
-pub use string_func::ConcatFunc;
+use std::sync::Arc;
+pub use string_func::{
+    ConcatFunc, EndsWithFunc, FormatFunc, LengthFunc, LikeFunc, LowerFunc, PadFunc, ReplaceFunc,
+    SplitFunc, StartsWithFunc, SubstringFunc, TrimFunc, TrimSide, UpperFunc,
+};
+pub use time_func::{DateTruncFunc, ExtractFunc, NowFunc, ToTimestampFunc};
 
 /// Custom function that can be implemented by users
 /// This trait allows users to define their own functions for evaluation
@@ -33,4 +46,32 @@ pub trait CustomFunc: Send + Sync + std::fmt::Debug {
 
     /// Get the function name for debugging purposes
     fn name(&self) -> &str;
+
+    /// Specialize the function for one call site when its expression is planned.
+    ///
+    /// `literals` has one entry per argument, holding the value of constant arguments and
+    /// `None` for the others, so a function can do per-expression work up front (e.g. compile
+    /// a literal pattern). Returning `Ok(None)` keeps using this instance.
+    fn prepare(
+        &self,
+        _literals: &[Option<&Value>],
+    ) -> Result<Option<Arc<dyn CustomFunc>>, EvalError> {
+        Ok(None)
+    }
+}
+
+/// Check that a call passes between `min` and `max` arguments.
+pub(crate) fn check_arity(args: &[Value], min: usize, max: usize) -> Result<(), EvalError> {
+    if args.len() < min || args.len() > max {
+        let expected = if min == max {
+            format!("{} arguments", min)
+        } else {
+            format!("{} to {} arguments", min, max)
+        };
+        return Err(EvalError::TypeMismatch {
+            expected,
+            actual: format!("{} arguments", args.len()),
+        });
+    }
+    Ok(())
 }
@@ -0,0 +1,492 @@
+use crate::expr::custom_func::{check_arity, CustomFunc};
+use crate::expr::func::EvalError;
+use datatypes::Value;
+use regex::Regex;
+use std::sync::{Arc, Mutex};
+
+/// Compiled pattern of one call site.
+///
+/// A literal pattern is compiled once by `CustomFunc::prepare`; a pattern computed per row
+/// keeps its most recent compilation, so repeated values are not recompiled.
+#[derive(Debug, Default)]
+struct PatternCache {
+    literal: Option<Arc<Regex>>,
+    recent: Mutex<Option<Arc<Regex>>>,
+}
+
+impl PatternCache {
+    fn literal(regex: Regex) -> Self {
+        Self {
+            literal: Some(Arc::new(regex)),
+            recent: Mutex::new(None),
+        }
+    }
+
+    fn get(
+        &self,
+        source: impl FnOnce() -> Result<String, EvalError>,
+    ) -> Result<Arc<Regex>, EvalError> {
+        if let Some(regex) = &self.literal {
+            return Ok(Arc::clone(regex));
+        }
+        let source = source()?;
+        let mut recent = self.recent.lock().expect("regex cache poisoned");
+        if let Some(regex) = recent.as_ref().filter(|regex| regex.as_str() == source) {
+            return Ok(Arc::clone(regex));
+        }
+        let regex = Arc::new(compile(&source)?);
+        *recent = Some(Arc::clone(&regex));
+        Ok(regex)
+    }
+}
+
+fn compile(source: &str) -> Result<Regex, EvalError> {
+    Regex::new(source).map_err(|err| EvalError::TypeMismatch {
+        expected: "valid regular expression".to_string(),
+        actual: format!("{:?}: {}", source, err),
+    })
+}
+
+/// Check arity and that every argument is a string or NULL.
+fn validate_strings(args: &[Value], min: usize, max: usize) -> Result<(), EvalError> {
+    check_arity(args, min, max)?;
+    for (idx, arg) in args.iter().enumerate() {
+        if !matches!(arg, Value::String(_) | Value::Null) {
+            return Err(EvalError::TypeMismatch {
+                expected: "String".to_string(),
+                actual: format!("{:?} at argument {}", arg, idx),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn str_arg(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// Literal string arguments at `positions`, or `None` when any of them is not a literal.
+fn literal_strs<'a>(literals: &[Option<&'a Value>], positions: &[usize]) -> Option<Vec<&'a str>> {
+    positions
+        .iter()
+        .map(|idx| literals.get(*idx).copied().flatten().and_then(str_arg))
+        .collect()
+}
+
+/// Regex source for a pattern and the `regexp_match` flags (`i` for case-insensitive).
+fn flagged_source(pattern: &str, flags: Option<&str>) -> Result<String, EvalError> {
+    match flags.unwrap_or("") {
+        "" => Ok(pattern.to_string()),
+        "i" => Ok(format!("(?i){}", pattern)),
+        other => Err(EvalError::TypeMismatch {
+            expected: "regexp flags '' or 'i'".to_string(),
+            actual: other.to_string(),
+        }),
+    }
+}
+
+/// `regexp_match(s, pattern [, flags])`: whether `pattern` matches anywhere in `s`
+///
+/// `flags` is `'i'` for a case-insensitive match. The SQL operators `~`, `~*`, `!~` and `!~*`
+/// map onto this function.
+#[derive(Debug, Default)]
+pub struct RegexpMatchFunc {
+    cache: PatternCache,
+}
+
+impl CustomFunc for RegexpMatchFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_strings(args, 2, 3)
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        let (Some(text), Some(pattern)) = (str_arg(&args[0]), str_arg(&args[1])) else {
+            return Ok(Value::Null);
+        };
+        let flags = match args.get(2) {
+            Some(Value::Null) => return Ok(Value::Null),
+            other => other.and_then(str_arg),
+        };
+        let regex = self.cache.get(|| flagged_source(pattern, flags))?;
+        Ok(Value::Bool(regex.is_match(text)))
+    }
+
+    fn name(&self) -> &str {
+        "regexp_match"
+    }
+
+    fn prepare(
+        &self,
+        literals: &[Option<&Value>],
+    ) -> Result<Option<Arc<dyn CustomFunc>>, EvalError> {
+        let positions: &[usize] = if literals.len() > 2 { &[1, 2] } else { &[1] };
+        let cache = match literal_strs(literals, positions) {
+            Some(values) => PatternCache::literal(compile(&flagged_source(
+                values[0],
+                values.get(1).copied(),
+            )?)?),
+            None => PatternCache::default(),
+        };
+        Ok(Some(Arc::new(Self { cache })))
+    }
+}
+
+/// `regexp_extract(s, pattern [, group])`: text of capture `group` (0, the whole match, by
+/// default) in the first match of `pattern`, or NULL when nothing matches
+#[derive(Debug, Default)]
+pub struct RegexpExtractFunc {
+    cache: PatternCache,
+}
+
+impl RegexpExtractFunc {
+    /// Group argument; `None` when it is NULL.
+    fn group(value: Option<&Value>) -> Result<Option<usize>, EvalError> {
+        match value {
+            None => Ok(Some(0)),
+            Some(Value::Null) => Ok(None),
+            Some(value) => {
+                let group = match value {
+                    Value::Int8(v) => usize::try_from(*v).ok(),
+                    Value::Int16(v) => usize::try_from(*v).ok(),
+                    Value::Int32(v) => usize::try_from(*v).ok(),
+                    Value::Int64(v) => usize::try_from(*v).ok(),
+                    Value::Uint8(v) => Some(*v as usize),
+                    Value::Uint16(v) => Some(*v as usize),
+                    Value::Uint32(v) => usize::try_from(*v).ok(),
+                    Value::Uint64(v) => usize::try_from(*v).ok(),
+                    _ => None,
+                };
+                group.map(Some).ok_or_else(|| EvalError::TypeMismatch {
+                    expected: "non-negative integer group".to_string(),
+                    actual: format!("{:?} at argument 2", value),
+                })
+            }
+        }
+    }
+
+    fn check_group(regex: &Regex, group: usize) -> Result<(), EvalError> {
+        if group >= regex.captures_len() {
+            return Err(EvalError::IndexOutOfBounds {
+                index: group,
+                length: regex.captures_len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl CustomFunc for RegexpExtractFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        check_arity(args, 2, 3)?;
+        validate_strings(&args[..2], 2, 2)?;
+        Self::group(args.get(2)).map(|_| ())
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        let (Some(text), Some(pattern), Some(group)) = (
+            str_arg(&args[0]),
+            str_arg(&args[1]),
+            Self::group(args.get(2))?,
+        ) else {
+            return Ok(Value::Null);
+        };
+        let regex = self.cache.get(|| Ok(pattern.to_string()))?;
+        Self::check_group(&regex, group)?;
+        Ok(regex
+            .captures(text)
+            .and_then(|captures| captures.get(group))
+            .map(|m| Value::String(m.as_str().to_string()))
+            .unwrap_or(Value::Null))
+    }
+
+    fn name(&self) -> &str {
+        "regexp_extract"
+    }
+
+    fn prepare(
+        &self,
+        literals: &[Option<&Value>],
+    ) -> Result<Option<Arc<dyn CustomFunc>>, EvalError> {
+        let cache = match literal_strs(literals, &[1]) {
+            Some(values) => {
+                let regex = compile(values[0])?;
+                if let Some(Some(group)) = literals.get(2) {
+                    if let Some(group) = Self::group(Some(group))? {
+                        Self::check_group(&regex, group)?;
+                    }
+                }
+                PatternCache::literal(regex)
+            }
+            None => PatternCache::default(),
+        };
+        Ok(Some(Arc::new(Self { cache })))
+    }
+}
+
+/// `regexp_replace(s, pattern, replacement)`: replace every match of `pattern`
+///
+/// `replacement` may refer to capture groups as `$1` or `${name}`.
+#[derive(Debug, Default)]
+pub struct RegexpReplaceFunc {
+    cache: PatternCache,
+}
+
+impl CustomFunc for RegexpReplaceFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_strings(args, 3, 3)
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        let (Some(text), Some(pattern), Some(replacement)) =
+            (str_arg(&args[0]), str_arg(&args[1]), str_arg(&args[2]))
+        else {
+            return Ok(Value::Null);
+        };
+        let regex = self.cache.get(|| Ok(pattern.to_string()))?;
+        Ok(Value::String(
+            regex.replace_all(text, replacement).into_owned(),
+        ))
+    }
+
+    fn name(&self) -> &str {
+        "regexp_replace"
+    }
+
+    fn prepare(
+        &self,
+        literals: &[Option<&Value>],
+    ) -> Result<Option<Arc<dyn CustomFunc>>, EvalError> {
+        let cache = match literal_strs(literals, &[1]) {
+            Some(values) => PatternCache::literal(compile(values[0])?),
+            None => PatternCache::default(),
+        };
+        Ok(Some(Arc::new(Self { cache })))
+    }
+}
+
+/// `similar_to(s, pattern [, escape])`: SQL `SIMILAR TO`
+///
+/// The whole of `s` must match `pattern`, where `%` and `_` are the LIKE wildcards, `|`, `*`,
+/// `+`, `?`, `{m,n}`, `( )` and `[ ]` have their regular expression meaning and every other
+/// character is literal. The escape character (`\` by default, none when empty) makes the
+/// next character literal.
+#[derive(Debug, Default)]
+pub struct SimilarToFunc {
+    cache: PatternCache,
+}
+
+impl SimilarToFunc {
+    fn escape(arg: Option<&str>) -> Option<char> {
+        match arg {
+            Some(escape) => escape.chars().next(),
+            None => Some('\\'),
+        }
+    }
+
+    /// Translate a SIMILAR TO pattern into an anchored regular expression.
+    fn to_regex(pattern: &str, escape: Option<char>) -> Result<String, EvalError> {
+        let mut out = String::from("^(?:");
+        let mut chars = pattern.chars();
+        let mut in_class = false;
+        while let Some(c) = chars.next() {
+            if Some(c) == escape {
+                let Some(escaped) = chars.next() else {
+                    return Err(EvalError::TypeMismatch {
+                        expected: "SIMILAR TO pattern not ending with the escape character"
+                            .to_string(),
+                        actual: pattern.to_string(),
+                    });
+                };
+                out.push_str(&regex::escape(&escaped.to_string()));
+                continue;
+            }
+            if in_class {
+                if c == ']' {
+                    in_class = false;
+                }
+                out.push(c);
+                continue;
+            }
+            match c {
+                '%' => out.push_str(".*"),
+                '_' => out.push('.'),
+                '[' => {
+                    in_class = true;
+                    out.push(c);
+                }
+                '|' | '*' | '+' | '?' | '{' | '}' | '(' | ')' => out.push(c),
+                other => out.push_str(&regex::escape(&other.to_string())),
+            }
+        }
+        out.push_str(")$");
+        Ok(out)
+    }
+}
+
+impl CustomFunc for SimilarToFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_strings(args, 2, 3)?;
+        if let Some(Value::String(escape)) = args.get(2) {
+            if escape.chars().count() > 1 {
+                return Err(EvalError::TypeMismatch {
+                    expected: "single-character escape".to_string(),
+                    actual: escape.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if args.iter().any(Value::is_null) {
+            return Ok(Value::Null);
+        }
+        let (Some(text), Some(pattern)) = (str_arg(&args[0]), str_arg(&args[1])) else {
+            return Ok(Value::Null);
+        };
+        let escape = Self::escape(args.get(2).and_then(str_arg));
+        let regex = self.cache.get(|| Self::to_regex(pattern, escape))?;
+        Ok(Value::Bool(regex.is_match(text)))
+    }
+
+    fn name(&self) -> &str {
+        "similar_to"
+    }
+
+    fn prepare(
+        &self,
+        literals: &[Option<&Value>],
+    ) -> Result<Option<Arc<dyn CustomFunc>>, EvalError> {
+        let positions: &[usize] = if literals.len() > 2 { &[1, 2] } else { &[1] };
+        let cache = match literal_strs(literals, positions) {
+            Some(values) => {
+                let escape = Self::escape(values.get(1).copied());
+                PatternCache::literal(compile(&Self::to_regex(values[0], escape)?)?)
+            }
+            None => PatternCache::default(),
+        };
+        Ok(Some(Arc::new(Self { cache })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(text: &str) -> Value {
+        Value::String(text.to_string())
+    }
+
+    /// Prepare `func` with the given literal arguments, as planning does, and evaluate it.
+    fn call(func: &dyn CustomFunc, args: Vec<Value>, literal: &[bool]) -> Value {
+        let literals: Vec<Option<&Value>> = args
+            .iter()
+            .zip(literal)
+            .map(|(arg, literal)| literal.then_some(arg))
+            .collect();
+        let prepared = func.prepare(&literals).expect("prepare").expect("prepared");
+        prepared.eval_row(&args).expect("eval")
+    }
+
+    #[test]
+    fn regexp_match_with_literal_and_dynamic_patterns() {
+        let func = RegexpMatchFunc::default();
+        assert_eq!(
+            call(
+                &func,
+                vec![s("dev-0042/rack-7"), s(r"rack-\d+")],
+                &[false, true]
+            ),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            call(
+                &func,
+                vec![s("DEV-1"), s("^dev"), s("i")],
+                &[false, false, true]
+            ),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            call(&func, vec![s("dev-1"), s("^rack")], &[false, false]),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            call(&func, vec![Value::Null, s("x")], &[false, true]),
+            Value::Null
+        );
+        assert!(func.prepare(&[None, Some(&s("(unclosed"))]).is_err());
+        assert!(func.prepare(&[None, Some(&s("x")), Some(&s("g"))]).is_err());
+    }
+
+    #[test]
+    fn regexp_extract_and_replace() {
+        let extract = RegexpExtractFunc::default();
+        let pattern = s(r"dev-(\d+)/rack-(?P<rack>\d+)");
+        assert_eq!(
+            call(
+                &extract,
+                vec![s("dev-0042/rack-7"), pattern.clone(), Value::Int64(1)],
+                &[false, true, true]
+            ),
+            s("0042")
+        );
+        assert_eq!(
+            call(
+                &extract,
+                vec![s("dev-0042/rack-7"), pattern.clone()],
+                &[false, false]
+            ),
+            s("dev-0042/rack-7")
+        );
+        assert_eq!(
+            call(
+                &extract,
+                vec![s("unknown"), pattern.clone(), Value::Int64(2)],
+                &[false, true, true]
+            ),
+            Value::Null
+        );
+        assert!(extract
+            .prepare(&[None, Some(&pattern), Some(&Value::Int64(3))])
+            .is_err());
+
+        let replace = RegexpReplaceFunc::default();
+        assert_eq!(
+            call(
+                &replace,
+                vec![s("dev-0042/rack-7"), s(r"(\w+)-0*(\d+)"), s("$1=$2")],
+                &[false, true, true]
+            ),
+            s("dev=42/rack=7")
+        );
+    }
+
+    #[test]
+    fn similar_to_translates_sql_patterns() {
+        let similar = SimilarToFunc::default();
+        let matches =
+            |text: &str, pattern: &str| call(&similar, vec![s(text), s(pattern)], &[false, true]);
+        assert_eq!(matches("dev-42", "dev-[0-9]+"), Value::Bool(true));
+        assert_eq!(matches("dev-42/x", "dev-[0-9]+"), Value::Bool(false));
+        assert_eq!(matches("rack.7", "(dev|rack)._"), Value::Bool(true));
+        assert_eq!(matches("rackx7", "rack.7"), Value::Bool(false));
+        assert_eq!(matches("a%b", r"a\%b"), Value::Bool(true));
+        assert_eq!(matches("ab", "a%"), Value::Bool(true));
+        assert_eq!(
+            call(
+                &similar,
+                vec![s("a_b"), s("a#_b"), s("#")],
+                &[false, false, false]
+            ),
+            Value::Bool(true)
+        );
+    }
+}
@@ -1,4 +1,10 @@
-use super::{ConcatFunc, CustomFunc};
+use super::{
+    AbsFunc, Atan2Func, ConcatFunc, CustomFunc, DateTruncFunc, EndsWithFunc, ExtractFunc,
+    ExtremumFunc, FloatFunc, FloatOp, FormatFunc, LengthFunc, LikeFunc, LowerFunc, NowFunc,
+    PadFunc, PowFunc, RegexpExtractFunc, RegexpMatchFunc, RegexpReplaceFunc, ReplaceFunc,
+    RoundFunc, RoundingMode, SignFunc, SimilarToFunc, SplitFunc, StartsWithFunc, SubstringFunc,
+    ToTimestampFunc, TrimFunc, TrimSide, UpperFunc,
+};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
@@ -70,6 +76,57 @@ impl CustomFuncRegistry {
 
     fn register_builtin_functions(&self) {
         let _ = self.register_function(Arc::new(ConcatFunc));
+        let _ = self.register_function(Arc::new(LowerFunc));
+        let _ = self.register_function(Arc::new(UpperFunc));
+        let _ = self.register_function(Arc::new(TrimFunc(TrimSide::Both)));
+        let _ = self.register_function(Arc::new(TrimFunc(TrimSide::Leading)));
+        let _ = self.register_function(Arc::new(TrimFunc(TrimSide::Trailing)));
+        let _ = self.register_function(Arc::new(LengthFunc));
+        let _ = self.register_function(Arc::new(SubstringFunc));
+        let _ = self.register_function(Arc::new(ReplaceFunc));
+        let _ = self.register_function(Arc::new(SplitFunc));
+        let _ = self.register_function(Arc::new(StartsWithFunc));
+        let _ = self.register_function(Arc::new(EndsWithFunc));
+        let _ = self.register_function(Arc::new(PadFunc { left: true }));
+        let _ = self.register_function(Arc::new(PadFunc { left: false }));
+        let _ = self.register_function(Arc::new(FormatFunc));
+        let _ = self.register_function(Arc::new(LikeFunc {
+            case_insensitive: false,
+        }));
+        let _ = self.register_function(Arc::new(LikeFunc {
+            case_insensitive: true,
+        }));
+        let _ = self.register_function(Arc::new(AbsFunc));
+        let _ = self.register_function(Arc::new(SignFunc));
+        let _ = self.register_function(Arc::new(RoundFunc(RoundingMode::Round)));
+        let _ = self.register_function(Arc::new(RoundFunc(RoundingMode::Ceil)));
+        let _ = self.register_function(Arc::new(RoundFunc(RoundingMode::Floor)));
+        for op in [
+            FloatOp::Sqrt,
+            FloatOp::Exp,
+            FloatOp::Ln,
+            FloatOp::Log10,
+            FloatOp::Sin,
+            FloatOp::Cos,
+            FloatOp::Tan,
+            FloatOp::Asin,
+            FloatOp::Acos,
+            FloatOp::Atan,
+        ] {
+            let _ = self.register_function(Arc::new(FloatFunc(op)));
+        }
+        let _ = self.register_function(Arc::new(Atan2Func));
+        let _ = self.register_function(Arc::new(PowFunc));
+        let _ = self.register_function(Arc::new(ExtremumFunc { greatest: true }));
+        let _ = self.register_function(Arc::new(ExtremumFunc { greatest: false }));
+        let _ = self.register_function(Arc::new(RegexpMatchFunc::default()));
+        let _ = self.register_function(Arc::new(RegexpExtractFunc::default()));
+        let _ = self.register_function(Arc::new(RegexpReplaceFunc::default()));
+        let _ = self.register_function(Arc::new(SimilarToFunc::default()));
+        let _ = self.register_function(Arc::new(NowFunc));
+        let _ = self.register_function(Arc::new(ToTimestampFunc));
+        let _ = self.register_function(Arc::new(DateTruncFunc));
+        let _ = self.register_function(Arc::new(ExtractFunc));
     }
 }
 
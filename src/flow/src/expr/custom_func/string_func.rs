@@ -1,6 +1,7 @@
-use crate::expr::custom_func::CustomFunc;
+use crate::expr::custom_func::{check_arity, CustomFunc};
 use crate::expr::func::EvalError;
-use datatypes::Value;
+use datatypes::{ConcreteDatatype, ListValue, StringType, Value};
+use std::sync::Arc;
 
 /// Custom implementation of the concat function
 /// This function concatenates exactly 2 String arguments
@@ -43,3 +44,767 @@ impl CustomFunc for ConcatFunc {
         "concat"
     }
 }
+
+/// Kind of a string function argument checked by `validate_args`.
+#[derive(Debug, Clone, Copy)]
+enum ArgKind {
+    String,
+    Integer,
+}
+
+/// Check arity and argument kinds; NULL is accepted in every position.
+fn validate_args(args: &[Value], min: usize, kinds: &[ArgKind]) -> Result<(), EvalError> {
+    check_arity(args, min, kinds.len())?;
+    for (idx, (arg, kind)) in args.iter().zip(kinds).enumerate() {
+        let valid = match (kind, arg) {
+            (_, Value::Null) => true,
+            (ArgKind::String, Value::String(_)) => true,
+            (ArgKind::Integer, value) => integer_value(value).is_some(),
+            _ => false,
+        };
+        if !valid {
+            return Err(EvalError::TypeMismatch {
+                expected: format!("{:?}", kind),
+                actual: format!("{:?} at argument {}", arg, idx),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn integer_value(value: &Value) -> Option<i64> {
+    match value {
+        Value::Int8(v) => Some(*v as i64),
+        Value::Int16(v) => Some(*v as i64),
+        Value::Int32(v) => Some(*v as i64),
+        Value::Int64(v) => Some(*v),
+        Value::Uint8(v) => Some(*v as i64),
+        Value::Uint16(v) => Some(*v as i64),
+        Value::Uint32(v) => Some(*v as i64),
+        Value::Uint64(v) => i64::try_from(*v).ok(),
+        _ => None,
+    }
+}
+
+/// String argument of a validated call.
+fn str_at(args: &[Value], idx: usize) -> &str {
+    match &args[idx] {
+        Value::String(s) => s,
+        _ => unreachable!("validated as string"),
+    }
+}
+
+/// Integer argument of a validated call.
+fn int_at(args: &[Value], idx: usize) -> i64 {
+    integer_value(&args[idx]).expect("validated as integer")
+}
+
+fn any_null(args: &[Value]) -> bool {
+    args.iter().any(Value::is_null)
+}
+
+/// `lower(s)`
+#[derive(Debug, Clone)]
+pub struct LowerFunc;
+
+impl CustomFunc for LowerFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(args, 1, &[ArgKind::String])
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        Ok(Value::String(str_at(args, 0).to_lowercase()))
+    }
+
+    fn name(&self) -> &str {
+        "lower"
+    }
+}
+
+/// `upper(s)`
+#[derive(Debug, Clone)]
+pub struct UpperFunc;
+
+impl CustomFunc for UpperFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(args, 1, &[ArgKind::String])
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        Ok(Value::String(str_at(args, 0).to_uppercase()))
+    }
+
+    fn name(&self) -> &str {
+        "upper"
+    }
+}
+
+/// Which end(s) of the string `TrimFunc` strips.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrimSide {
+    Both,
+    Leading,
+    Trailing,
+}
+
+/// `trim(s [, chars])`, `ltrim(s [, chars])`, `rtrim(s [, chars])`
+///
+/// Strips any of `chars` (whitespace by default) from the start and/or end of `s`.
+#[derive(Debug, Clone)]
+pub struct TrimFunc(pub TrimSide);
+
+impl CustomFunc for TrimFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(args, 1, &[ArgKind::String, ArgKind::String])
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        let s = str_at(args, 0);
+        let trimmed = match args.get(1) {
+            None => match self.0 {
+                TrimSide::Both => s.trim(),
+                TrimSide::Leading => s.trim_start(),
+                TrimSide::Trailing => s.trim_end(),
+            },
+            Some(_) => {
+                let chars: Vec<char> = str_at(args, 1).chars().collect();
+                let strip = |c: char| chars.contains(&c);
+                match self.0 {
+                    TrimSide::Both => s.trim_matches(strip),
+                    TrimSide::Leading => s.trim_start_matches(strip),
+                    TrimSide::Trailing => s.trim_end_matches(strip),
+                }
+            }
+        };
+        Ok(Value::String(trimmed.to_string()))
+    }
+
+    fn name(&self) -> &str {
+        match self.0 {
+            TrimSide::Both => "trim",
+            TrimSide::Leading => "ltrim",
+            TrimSide::Trailing => "rtrim",
+        }
+    }
+}
+
+/// `length(s)`: number of characters
+#[derive(Debug, Clone)]
+pub struct LengthFunc;
+
+impl CustomFunc for LengthFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(args, 1, &[ArgKind::String])
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        Ok(Value::Int64(str_at(args, 0).chars().count() as i64))
+    }
+
+    fn name(&self) -> &str {
+        "length"
+    }
+}
+
+/// `substring(s, start [, len])`
+///
+/// `start` is 1-based and may be before the first character, in which case `len` still counts
+/// from `start`. Without `len` the rest of the string is returned.
+#[derive(Debug, Clone)]
+pub struct SubstringFunc;
+
+impl CustomFunc for SubstringFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(
+            args,
+            2,
+            &[ArgKind::String, ArgKind::Integer, ArgKind::Integer],
+        )?;
+        if let Some(len) = args.get(2).and_then(integer_value) {
+            if len < 0 {
+                return Err(EvalError::TypeMismatch {
+                    expected: "non-negative substring length".to_string(),
+                    actual: len.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        let start = int_at(args, 1).saturating_sub(1);
+        let end = args
+            .get(2)
+            .map(|_| start.saturating_add(int_at(args, 2)))
+            .unwrap_or(i64::MAX);
+        let skip = start.max(0) as usize;
+        let take = end.saturating_sub(start.max(0)).max(0) as usize;
+        Ok(Value::String(
+            str_at(args, 0).chars().skip(skip).take(take).collect(),
+        ))
+    }
+
+    fn name(&self) -> &str {
+        "substring"
+    }
+}
+
+/// `replace(s, from, to)`: replace every occurrence of `from`
+#[derive(Debug, Clone)]
+pub struct ReplaceFunc;
+
+impl CustomFunc for ReplaceFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(
+            args,
+            3,
+            &[ArgKind::String, ArgKind::String, ArgKind::String],
+        )
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        let (s, from, to) = (str_at(args, 0), str_at(args, 1), str_at(args, 2));
+        if from.is_empty() {
+            return Ok(Value::String(s.to_string()));
+        }
+        Ok(Value::String(s.replace(from, to)))
+    }
+
+    fn name(&self) -> &str {
+        "replace"
+    }
+}
+
+/// `split(s, delimiter)`: list of the parts between delimiters; an empty delimiter splits
+/// into characters
+#[derive(Debug, Clone)]
+pub struct SplitFunc;
+
+impl CustomFunc for SplitFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(args, 2, &[ArgKind::String, ArgKind::String])
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        let (s, delimiter) = (str_at(args, 0), str_at(args, 1));
+        let parts: Vec<Value> = if delimiter.is_empty() {
+            s.chars().map(|c| Value::String(c.to_string())).collect()
+        } else {
+            s.split(delimiter)
+                .map(|part| Value::String(part.to_string()))
+                .collect()
+        };
+        Ok(Value::List(ListValue::new(
+            parts,
+            Arc::new(ConcreteDatatype::String(StringType)),
+        )))
+    }
+
+    fn name(&self) -> &str {
+        "split"
+    }
+}
+
+/// `starts_with(s, prefix)`
+#[derive(Debug, Clone)]
+pub struct StartsWithFunc;
+
+impl CustomFunc for StartsWithFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(args, 2, &[ArgKind::String, ArgKind::String])
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        Ok(Value::Bool(str_at(args, 0).starts_with(str_at(args, 1))))
+    }
+
+    fn name(&self) -> &str {
+        "starts_with"
+    }
+}
+
+/// `ends_with(s, suffix)`
+#[derive(Debug, Clone)]
+pub struct EndsWithFunc;
+
+impl CustomFunc for EndsWithFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(args, 2, &[ArgKind::String, ArgKind::String])
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        Ok(Value::Bool(str_at(args, 0).ends_with(str_at(args, 1))))
+    }
+
+    fn name(&self) -> &str {
+        "ends_with"
+    }
+}
+
+/// `lpad(s, len [, fill])` / `rpad(s, len [, fill])`
+///
+/// Pads `s` to `len` characters with `fill` (a space by default), repeated as needed, on the
+/// left or right. Strings longer than `len` are truncated to their first `len` characters.
+#[derive(Debug, Clone)]
+pub struct PadFunc {
+    pub left: bool,
+}
+
+impl CustomFunc for PadFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(
+            args,
+            2,
+            &[ArgKind::String, ArgKind::Integer, ArgKind::String],
+        )
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        let s = str_at(args, 0);
+        let len = int_at(args, 1).max(0) as usize;
+        let fill = if args.len() > 2 { str_at(args, 2) } else { " " };
+        let chars = s.chars().count();
+        if chars >= len {
+            return Ok(Value::String(s.chars().take(len).collect()));
+        }
+        if fill.is_empty() {
+            return Ok(Value::String(s.to_string()));
+        }
+        let padding: String = fill.chars().cycle().take(len - chars).collect();
+        Ok(Value::String(if self.left {
+            padding + s
+        } else {
+            format!("{s}{padding}")
+        }))
+    }
+
+    fn name(&self) -> &str {
+        if self.left {
+            "lpad"
+        } else {
+            "rpad"
+        }
+    }
+}
+
+/// `format(fmt, args...)`
+///
+/// Every `%s` in `fmt` is replaced by the next argument as text (NULL as an empty string) and
+/// `%%` by a literal `%`.
+#[derive(Debug, Clone)]
+pub struct FormatFunc;
+
+impl FormatFunc {
+    /// Split `fmt` into literal text and `%s` placeholders (`None`).
+    fn parse(fmt: &str) -> Result<Vec<Option<String>>, EvalError> {
+        let mut pieces = Vec::new();
+        let mut literal = String::new();
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('%') => literal.push('%'),
+                Some('s') => {
+                    pieces.push(Some(std::mem::take(&mut literal)));
+                    pieces.push(None);
+                }
+                other => {
+                    return Err(EvalError::TypeMismatch {
+                        expected: "format placeholder %s or %%".to_string(),
+                        actual: format!("%{}", other.map(String::from).unwrap_or_default()),
+                    })
+                }
+            }
+        }
+        pieces.push(Some(literal));
+        Ok(pieces)
+    }
+}
+
+impl CustomFunc for FormatFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        check_arity(args, 1, usize::MAX)?;
+        validate_args(&args[..1], 1, &[ArgKind::String])?;
+        if let Value::String(fmt) = &args[0] {
+            let placeholders = Self::parse(fmt)?.iter().filter(|p| p.is_none()).count();
+            if placeholders > args.len() - 1 {
+                return Err(EvalError::TypeMismatch {
+                    expected: format!("{} format arguments", placeholders),
+                    actual: format!("{} format arguments", args.len() - 1),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if args[0].is_null() {
+            return Ok(Value::Null);
+        }
+        let mut values = args[1..].iter();
+        let mut out = String::new();
+        for piece in Self::parse(str_at(args, 0))? {
+            match piece {
+                Some(literal) => out.push_str(&literal),
+                None => out.push_str(&value_to_text(values.next().expect("validated count"))),
+            }
+        }
+        Ok(Value::String(out))
+    }
+
+    fn name(&self) -> &str {
+        "format"
+    }
+}
+
+fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Bool(v) => v.to_string(),
+        Value::Float32(v) => v.to_string(),
+        Value::Float64(v) => v.to_string(),
+        Value::Timestamp(ts) => ts.to_rfc3339().unwrap_or_else(|| ts.value().to_string()),
+        other => integer_value(other)
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| format!("{:?}", other)),
+    }
+}
+
+/// `like(s, pattern [, escape])` / `ilike(...)`: SQL `LIKE` / `ILIKE`
+///
+/// `%` matches any run of characters and `_` a single one; the escape character (`\` by
+/// default, none when empty) makes the next pattern character literal.
+#[derive(Debug, Clone)]
+pub struct LikeFunc {
+    pub case_insensitive: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LikeToken {
+    Any,
+    One,
+    Literal(char),
+}
+
+impl LikeFunc {
+    fn escape(args: &[Value]) -> Option<char> {
+        match args.get(2) {
+            Some(Value::String(escape)) => escape.chars().next(),
+            _ => Some('\\'),
+        }
+    }
+
+    fn compile(pattern: &str, escape: Option<char>) -> Result<Vec<LikeToken>, EvalError> {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            let token = match c {
+                c if Some(c) == escape => match chars.next() {
+                    Some(escaped) => LikeToken::Literal(escaped),
+                    None => {
+                        return Err(EvalError::TypeMismatch {
+                            expected: "LIKE pattern not ending with the escape character"
+                                .to_string(),
+                            actual: pattern.to_string(),
+                        })
+                    }
+                },
+                '%' => LikeToken::Any,
+                '_' => LikeToken::One,
+                c => LikeToken::Literal(c),
+            };
+            tokens.push(token);
+        }
+        Ok(tokens)
+    }
+
+    /// Greedy wildcard matching, backtracking to the last `%` on a mismatch.
+    fn matches(text: &[char], tokens: &[LikeToken]) -> bool {
+        let (mut t, mut p) = (0, 0);
+        let mut backtrack: Option<(usize, usize)> = None;
+        while t < text.len() {
+            match tokens.get(p) {
+                Some(LikeToken::Any) => {
+                    backtrack = Some((p, t));
+                    p += 1;
+                    continue;
+                }
+                Some(LikeToken::One) => {
+                    t += 1;
+                    p += 1;
+                    continue;
+                }
+                Some(LikeToken::Literal(c)) if *c == text[t] => {
+                    t += 1;
+                    p += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            match backtrack {
+                Some((star, resume)) => {
+                    p = star + 1;
+                    t = resume + 1;
+                    backtrack = Some((star, resume + 1));
+                }
+                None => return false,
+            }
+        }
+        tokens[p..].iter().all(|token| *token == LikeToken::Any)
+    }
+}
+
+impl CustomFunc for LikeFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        validate_args(
+            args,
+            2,
+            &[ArgKind::String, ArgKind::String, ArgKind::String],
+        )?;
+        if let Some(Value::String(escape)) = args.get(2) {
+            if escape.chars().count() > 1 {
+                return Err(EvalError::TypeMismatch {
+                    expected: "single-character escape".to_string(),
+                    actual: escape.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if any_null(args) {
+            return Ok(Value::Null);
+        }
+        let mut tokens = Self::compile(str_at(args, 1), Self::escape(args))?;
+        let text: Vec<char> = if self.case_insensitive {
+            // Fold case after compiling so the escape character is matched as written.
+            tokens = tokens
+                .into_iter()
+                .flat_map(|token| match token {
+                    LikeToken::Literal(c) => c.to_lowercase().map(LikeToken::Literal).collect(),
+                    other => vec![other],
+                })
+                .collect();
+            str_at(args, 0).to_lowercase().chars().collect()
+        } else {
+            str_at(args, 0).chars().collect()
+        };
+        Ok(Value::Bool(Self::matches(&text, &tokens)))
+    }
+
+    fn name(&self) -> &str {
+        if self.case_insensitive {
+            "ilike"
+        } else {
+            "like"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(text: &str) -> Value {
+        Value::String(text.to_string())
+    }
+
+    fn call(func: &dyn CustomFunc, args: Vec<Value>) -> Value {
+        func.eval_row(&args).expect("eval")
+    }
+
+    #[test]
+    fn case_trim_and_length() {
+        assert_eq!(call(&LowerFunc, vec![s("AbC")]), s("abc"));
+        assert_eq!(call(&UpperFunc, vec![s("äb")]), s("ÄB"));
+        assert_eq!(call(&TrimFunc(TrimSide::Both), vec![s("  x ")]), s("x"));
+        assert_eq!(call(&TrimFunc(TrimSide::Leading), vec![s("  x ")]), s("x "));
+        assert_eq!(
+            call(&TrimFunc(TrimSide::Trailing), vec![s("xxaxy"), s("xy")]),
+            s("xxa")
+        );
+        assert_eq!(call(&LengthFunc, vec![s("héllo")]), Value::Int64(5));
+        assert_eq!(call(&LengthFunc, vec![Value::Null]), Value::Null);
+        assert!(LengthFunc.validate_row(&[Value::Int64(1)]).is_err());
+    }
+
+    #[test]
+    fn substring_replace_and_split() {
+        let sub = |args: Vec<Value>| call(&SubstringFunc, args);
+        assert_eq!(
+            sub(vec![s("flowing"), Value::Int64(2), Value::Int64(3)]),
+            s("low")
+        );
+        assert_eq!(sub(vec![s("flowing"), Value::Int64(5)]), s("ing"));
+        assert_eq!(
+            sub(vec![s("flowing"), Value::Int64(-1), Value::Int64(4)]),
+            s("fl")
+        );
+        assert!(SubstringFunc
+            .validate_row(&[s("x"), Value::Int64(1), Value::Int64(-1)])
+            .is_err());
+        assert_eq!(
+            call(&ReplaceFunc, vec![s("a-b-c"), s("-"), s("+")]),
+            s("a+b+c")
+        );
+        assert_eq!(
+            call(&SplitFunc, vec![s("a,b,,c"), s(",")]),
+            Value::List(ListValue::new(
+                vec![s("a"), s("b"), s(""), s("c")],
+                Arc::new(ConcreteDatatype::String(StringType)),
+            ))
+        );
+    }
+
+    #[test]
+    fn affixes_padding_and_format() {
+        assert_eq!(
+            call(&StartsWithFunc, vec![s("sensor-1"), s("sensor")]),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            call(&EndsWithFunc, vec![s("sensor-1"), s("2")]),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            call(
+                &PadFunc { left: true },
+                vec![s("7"), Value::Int64(3), s("0")]
+            ),
+            s("007")
+        );
+        assert_eq!(
+            call(
+                &PadFunc { left: false },
+                vec![s("ab"), Value::Int64(5), s("xy")]
+            ),
+            s("abxyx")
+        );
+        assert_eq!(
+            call(&PadFunc { left: true }, vec![s("abcdef"), Value::Int64(3)]),
+            s("abc")
+        );
+        assert_eq!(
+            call(
+                &FormatFunc,
+                vec![
+                    s("%s is %s%% (%s)"),
+                    s("cpu"),
+                    Value::Int64(93),
+                    Value::Null
+                ]
+            ),
+            s("cpu is 93% ()")
+        );
+        assert!(FormatFunc.validate_row(&[s("%s %s"), s("one")]).is_err());
+        assert!(FormatFunc.validate_row(&[s("%d"), s("one")]).is_err());
+    }
+
+    #[test]
+    fn like_patterns() {
+        let like = |text: &str, pattern: &str| {
+            call(
+                &LikeFunc {
+                    case_insensitive: false,
+                },
+                vec![s(text), s(pattern)],
+            )
+        };
+        assert_eq!(like("sensor-17", "sensor-%"), Value::Bool(true));
+        assert_eq!(like("sensor-17", "%-1_"), Value::Bool(true));
+        assert_eq!(like("sensor-17", "%-1"), Value::Bool(false));
+        assert_eq!(like("a%b", "a\\%b"), Value::Bool(true));
+        assert_eq!(like("axb", "a\\%b"), Value::Bool(false));
+        assert_eq!(like("abcabd", "%ab_"), Value::Bool(true));
+        assert_eq!(
+            call(
+                &LikeFunc {
+                    case_insensitive: true
+                },
+                vec![s("Sensor"), s("sEN%")]
+            ),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            call(
+                &LikeFunc {
+                    case_insensitive: false
+                },
+                vec![s("a_b"), s("a#_b"), s("#")]
+            ),
+            Value::Bool(true)
+        );
+        // An upper-case escape character still escapes under ILIKE.
+        let ilike = |text: &str, pattern: &str, escape: &str| {
+            call(
+                &LikeFunc {
+                    case_insensitive: true,
+                },
+                vec![s(text), s(pattern), s(escape)],
+            )
+        };
+        assert_eq!(ilike("A%B", "aX%b", "X"), Value::Bool(true));
+        assert_eq!(ilike("AzB", "aX%b", "X"), Value::Bool(false));
+        assert_eq!(
+            call(
+                &LikeFunc {
+                    case_insensitive: false
+                },
+                vec![Value::Null, s("%")]
+            ),
+            Value::Null
+        );
+        assert!(LikeFunc {
+            case_insensitive: false
+        }
+        .eval_row(&[s("a"), s("a\\")])
+        .is_err());
+    }
+}
@@ -0,0 +1,342 @@
+use crate::expr::custom_func::{check_arity, CustomFunc};
+use crate::expr::func::EvalError;
+use datatypes::{DataType, Timestamp, TimestampPrecision, TimestampType, Value};
+use time::{Date, Month, OffsetDateTime, Time};
+
+const NANOS_PER_MICRO: i128 = 1_000;
+const NANOS_PER_MILLI: i128 = 1_000_000;
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_MINUTE: i128 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: i128 = 60 * NANOS_PER_MINUTE;
+const NANOS_PER_DAY: i128 = 24 * NANOS_PER_HOUR;
+/// 1970-01-01 was a Thursday; shifting by this many days aligns weeks to Monday.
+const EPOCH_DAYS_AFTER_MONDAY: i128 = 3;
+
+fn string_arg(value: &Value, idx: usize) -> Result<&str, EvalError> {
+    match value {
+        Value::String(s) => Ok(s),
+        other => Err(EvalError::TypeMismatch {
+            expected: "String".to_string(),
+            actual: format!("{:?} at argument {}", other, idx),
+        }),
+    }
+}
+
+/// Accept a timestamp or anything `CAST(... AS TIMESTAMP)` accepts.
+fn timestamp_arg(value: &Value, idx: usize) -> Result<Timestamp, EvalError> {
+    match TimestampType::default().try_cast(value.clone()) {
+        Some(Value::Timestamp(ts)) => Ok(ts),
+        _ => Err(EvalError::TypeMismatch {
+            expected: "Timestamp".to_string(),
+            actual: format!("{:?} at argument {}", value, idx),
+        }),
+    }
+}
+
+fn to_datetime(ts: &Timestamp) -> Result<OffsetDateTime, EvalError> {
+    ts.to_datetime().ok_or_else(|| EvalError::CastFailed {
+        from: format!("{:?}", ts),
+        to: "date-time".to_string(),
+    })
+}
+
+fn from_nanos(nanos: i128, precision: TimestampPrecision) -> Result<Value, EvalError> {
+    Timestamp::from_nanos(nanos, precision)
+        .map(Value::Timestamp)
+        .ok_or_else(|| EvalError::CastFailed {
+            from: format!("{}ns", nanos),
+            to: format!("Timestamp({})", precision.name()),
+        })
+}
+
+/// `now()`: the current wall-clock time in milliseconds
+#[derive(Debug, Clone)]
+pub struct NowFunc;
+
+impl CustomFunc for NowFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        check_arity(args, 0, 0)
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        Ok(Value::Timestamp(Timestamp::now(
+            TimestampPrecision::Millisecond,
+        )))
+    }
+
+    fn name(&self) -> &str {
+        "now"
+    }
+}
+
+/// `to_timestamp(value [, precision])`
+///
+/// Strings are parsed as RFC 3339; integers are ticks of `precision` (`s`, `ms`, `us`, `ns`,
+/// default `ms`), which is also the precision of the result.
+#[derive(Debug, Clone)]
+pub struct ToTimestampFunc;
+
+impl CustomFunc for ToTimestampFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        check_arity(args, 1, 2)?;
+        if let Some(precision) = args.get(1) {
+            let precision = string_arg(precision, 1)?;
+            if TimestampPrecision::parse(precision).is_none() {
+                return Err(EvalError::TypeMismatch {
+                    expected: "timestamp precision (s, ms, us, ns)".to_string(),
+                    actual: precision.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if args[0].is_null() {
+            return Ok(Value::Null);
+        }
+        let precision = match args.get(1) {
+            Some(Value::String(precision)) => {
+                TimestampPrecision::parse(precision).expect("validated precision")
+            }
+            _ => TimestampPrecision::Millisecond,
+        };
+        TimestampType::new(precision)
+            .try_cast(args[0].clone())
+            .ok_or_else(|| EvalError::CastFailed {
+                from: format!("{:?}", args[0]),
+                to: format!("Timestamp({})", precision.name()),
+            })
+    }
+
+    fn name(&self) -> &str {
+        "to_timestamp"
+    }
+}
+
+/// `date_trunc(unit, ts)`: round a timestamp down to the start of `unit` (UTC)
+///
+/// Supported units are `microsecond`, `millisecond`, `second`, `minute`, `hour`, `day`, `week`
+/// (starting Monday), `month`, `quarter` and `year`.
+#[derive(Debug, Clone)]
+pub struct DateTruncFunc;
+
+impl DateTruncFunc {
+    fn truncate(unit: &str, ts: &Timestamp) -> Result<Value, EvalError> {
+        let nanos = ts.to_nanos();
+        let floor = |step: i128| nanos.div_euclid(step) * step;
+        let truncated = match unit.to_ascii_lowercase().as_str() {
+            "microsecond" | "microseconds" => floor(NANOS_PER_MICRO),
+            "millisecond" | "milliseconds" => floor(NANOS_PER_MILLI),
+            "second" | "seconds" => floor(NANOS_PER_SECOND),
+            "minute" | "minutes" => floor(NANOS_PER_MINUTE),
+            "hour" | "hours" => floor(NANOS_PER_HOUR),
+            "day" | "days" => floor(NANOS_PER_DAY),
+            "week" | "weeks" => {
+                let shift = EPOCH_DAYS_AFTER_MONDAY * NANOS_PER_DAY;
+                (nanos + shift).div_euclid(7 * NANOS_PER_DAY) * 7 * NANOS_PER_DAY - shift
+            }
+            calendar @ ("month" | "months" | "quarter" | "quarters" | "year" | "years") => {
+                let date = to_datetime(ts)?.date();
+                let month = match calendar {
+                    "month" | "months" => date.month(),
+                    "quarter" | "quarters" => {
+                        let first = (u8::from(date.month()) - 1) / 3 * 3 + 1;
+                        Month::try_from(first).expect("valid month")
+                    }
+                    _ => Month::January,
+                };
+                let start = Date::from_calendar_date(date.year(), month, 1)
+                    .expect("first day of month is valid");
+                start
+                    .with_time(Time::MIDNIGHT)
+                    .assume_utc()
+                    .unix_timestamp_nanos()
+            }
+            other => {
+                return Err(EvalError::NotImplemented {
+                    feature: format!("date_trunc unit '{}'", other),
+                })
+            }
+        };
+        from_nanos(truncated, ts.precision())
+    }
+}
+
+impl CustomFunc for DateTruncFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        check_arity(args, 2, 2)?;
+        string_arg(&args[0], 0)?;
+        Ok(())
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if args[1].is_null() {
+            return Ok(Value::Null);
+        }
+        let unit = string_arg(&args[0], 0)?;
+        Self::truncate(unit, &timestamp_arg(&args[1], 1)?)
+    }
+
+    fn name(&self) -> &str {
+        "date_trunc"
+    }
+}
+
+/// `extract(field, ts)`, also reached through `EXTRACT(field FROM ts)`
+///
+/// Returns an Int64 for `year`, `quarter`, `month`, `week` (ISO), `day`, `dow` (Sunday is 0),
+/// `doy`, `hour`, `minute`, `second`, the sub-second `millisecond`/`microsecond`/`nanosecond`
+/// and `epoch` (whole seconds since 1970-01-01 UTC).
+#[derive(Debug, Clone)]
+pub struct ExtractFunc;
+
+impl ExtractFunc {
+    fn extract(field: &str, ts: &Timestamp) -> Result<Value, EvalError> {
+        let nanos = ts.to_nanos();
+        let subsec = nanos.rem_euclid(NANOS_PER_SECOND);
+        let value = match field.to_ascii_lowercase().as_str() {
+            "epoch" => nanos.div_euclid(NANOS_PER_SECOND),
+            "millisecond" | "milliseconds" => subsec / NANOS_PER_MILLI,
+            "microsecond" | "microseconds" => subsec / NANOS_PER_MICRO,
+            "nanosecond" | "nanoseconds" => subsec,
+            other => {
+                let datetime = to_datetime(ts)?;
+                match other {
+                    "year" | "years" => datetime.year() as i128,
+                    "quarter" => (u8::from(datetime.month()) as i128 - 1) / 3 + 1,
+                    "month" | "months" => u8::from(datetime.month()) as i128,
+                    "week" | "isoweek" => datetime.iso_week() as i128,
+                    "day" | "days" => datetime.day() as i128,
+                    "dow" | "dayofweek" => datetime.weekday().number_days_from_sunday() as i128,
+                    "doy" | "dayofyear" => datetime.ordinal() as i128,
+                    "hour" | "hours" => datetime.hour() as i128,
+                    "minute" | "minutes" => datetime.minute() as i128,
+                    "second" | "seconds" => datetime.second() as i128,
+                    _ => {
+                        return Err(EvalError::NotImplemented {
+                            feature: format!("extract field '{}'", other),
+                        })
+                    }
+                }
+            }
+        };
+        Ok(Value::Int64(value as i64))
+    }
+}
+
+impl CustomFunc for ExtractFunc {
+    fn validate_row(&self, args: &[Value]) -> Result<(), EvalError> {
+        check_arity(args, 2, 2)?;
+        string_arg(&args[0], 0)?;
+        Ok(())
+    }
+
+    fn eval_row(&self, args: &[Value]) -> Result<Value, EvalError> {
+        self.validate_row(args)?;
+        if args[1].is_null() {
+            return Ok(Value::Null);
+        }
+        let field = string_arg(&args[0], 0)?;
+        Self::extract(field, &timestamp_arg(&args[1], 1)?)
+    }
+
+    fn name(&self) -> &str {
+        "extract"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(text: &str) -> Value {
+        Value::Timestamp(Timestamp::parse_rfc3339(text, TimestampPrecision::Millisecond).unwrap())
+    }
+
+    fn string(value: &str) -> Value {
+        Value::String(value.to_string())
+    }
+
+    #[test]
+    fn date_trunc_units() {
+        let input = ts("2024-05-16T13:47:21.345Z");
+        let cases = [
+            ("second", "2024-05-16T13:47:21Z"),
+            ("minute", "2024-05-16T13:47:00Z"),
+            ("hour", "2024-05-16T13:00:00Z"),
+            ("day", "2024-05-16T00:00:00Z"),
+            ("week", "2024-05-13T00:00:00Z"),
+            ("month", "2024-05-01T00:00:00Z"),
+            ("quarter", "2024-04-01T00:00:00Z"),
+            ("year", "2024-01-01T00:00:00Z"),
+        ];
+        for (unit, expected) in cases {
+            assert_eq!(
+                DateTruncFunc
+                    .eval_row(&[string(unit), input.clone()])
+                    .unwrap(),
+                ts(expected),
+                "unit {unit}"
+            );
+        }
+        assert!(DateTruncFunc
+            .eval_row(&[string("fortnight"), input])
+            .is_err());
+    }
+
+    #[test]
+    fn extract_fields() {
+        let input = ts("2024-05-16T13:47:21.345Z");
+        let cases = [
+            ("year", 2024),
+            ("quarter", 2),
+            ("month", 5),
+            ("day", 16),
+            ("dow", 4),
+            ("doy", 137),
+            ("hour", 13),
+            ("minute", 47),
+            ("second", 21),
+            ("millisecond", 345),
+            ("epoch", 1_715_867_241),
+        ];
+        for (field, expected) in cases {
+            assert_eq!(
+                ExtractFunc
+                    .eval_row(&[string(field), input.clone()])
+                    .unwrap(),
+                Value::Int64(expected),
+                "field {field}"
+            );
+        }
+        assert_eq!(
+            ExtractFunc
+                .eval_row(&[string("year"), Value::Null])
+                .unwrap(),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn to_timestamp_parses_strings_and_ticks() {
+        assert_eq!(
+            ToTimestampFunc
+                .eval_row(&[string("2024-05-16T13:47:21.345Z")])
+                .unwrap(),
+            ts("2024-05-16T13:47:21.345Z")
+        );
+        assert_eq!(
+            ToTimestampFunc
+                .eval_row(&[Value::Int64(90), string("s")])
+                .unwrap(),
+            Value::Timestamp(Timestamp::new_second(90))
+        );
+        assert!(ToTimestampFunc
+            .eval_row(&[Value::Int64(90), string("fortnight")])
+            .is_err());
+    }
+}
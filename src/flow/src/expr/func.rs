@@ -1,5 +1,6 @@
 use datatypes::{
-    ConcreteDatatype, DataType, Float32Type, Float64Type, Int64Type, StringType, Value,
+    ConcreteDatatype, DataType, Float32Type, Float64Type, Int64Type, StringType, Timestamp,
+    TimestampType, Value,
 };
 
 /// Unary function that takes one argument
@@ -9,10 +10,14 @@ pub enum UnaryFunc {
     Not,
     /// Check if value is null
     IsNull,
+    /// Check if value is not null
+    IsNotNull,
     /// Check if value is true
     IsTrue,
     /// Check if value is false
     IsFalse,
+    /// Arithmetic negation
+    Neg,
     /// Cast to a specific type
     Cast(ConcreteDatatype),
 }
@@ -24,6 +29,8 @@ impl UnaryFunc {
             Self::Not => {
                 if let Value::Bool(bool) = arg {
                     Ok(Value::Bool(!bool))
+                } else if arg.is_null() {
+                    Ok(Value::Null)
                 } else {
                     Err(EvalError::TypeMismatch {
                         expected: "Bool".to_string(),
@@ -32,9 +39,12 @@ impl UnaryFunc {
                 }
             }
             Self::IsNull => Ok(Value::Bool(arg.is_null())),
+            Self::IsNotNull => Ok(Value::Bool(!arg.is_null())),
             Self::IsTrue => {
                 if let Value::Bool(bool) = arg {
                     Ok(Value::Bool(bool))
+                } else if arg.is_null() {
+                    Ok(Value::Bool(false))
                 } else {
                     Err(EvalError::TypeMismatch {
                         expected: "Bool".to_string(),
@@ -45,6 +55,8 @@ impl UnaryFunc {
             Self::IsFalse => {
                 if let Value::Bool(bool) = arg {
                     Ok(Value::Bool(!bool))
+                } else if arg.is_null() {
+                    Ok(Value::Bool(false))
                 } else {
                     Err(EvalError::TypeMismatch {
                         expected: "Bool".to_string(),
@@ -52,6 +64,7 @@ impl UnaryFunc {
                     })
                 }
             }
+            Self::Neg => Self::eval_neg(arg),
             Self::Cast(to) => {
                 // Use the DataType's try_cast method for proper type casting
                 match to {
@@ -139,6 +152,13 @@ impl UnaryFunc {
                                 to: format!("{:?}", to),
                             })
                     }
+                    ConcreteDatatype::Timestamp(t) => {
+                        t.try_cast(arg.clone())
+                            .ok_or_else(|| EvalError::CastFailed {
+                                from: format!("{:?}", arg),
+                                to: format!("{:?}", to),
+                            })
+                    }
                     _ => {
                         // For unsupported types like Struct and List, fall back to basic casting
                         let arg_clone = arg.clone();
@@ -190,6 +210,38 @@ impl UnaryFunc {
     }
 }
 
+impl UnaryFunc {
+    /// Negate a number. Signed integers saturate, unsigned integers become Int64 (as in
+    /// subtraction) and other values are cast to Int64 or Float64 first.
+    fn eval_neg(arg: Value) -> Result<Value, EvalError> {
+        match arg {
+            Value::Null => Ok(Value::Null),
+            Value::Int8(v) => Ok(Value::Int8(v.saturating_neg())),
+            Value::Int16(v) => Ok(Value::Int16(v.saturating_neg())),
+            Value::Int32(v) => Ok(Value::Int32(v.saturating_neg())),
+            Value::Int64(v) => Ok(Value::Int64(v.saturating_neg())),
+            Value::Float32(v) => Ok(Value::Float32(-v)),
+            Value::Float64(v) => Ok(Value::Float64(-v)),
+            Value::Uint8(v) => Ok(Value::Int64(-(v as i64))),
+            Value::Uint16(v) => Ok(Value::Int64(-(v as i64))),
+            Value::Uint32(v) => Ok(Value::Int64(-(v as i64))),
+            Value::Uint64(v) => Ok(Value::Int64(0i64.saturating_sub_unsigned(v))),
+            other => {
+                if let Some(v) = BinaryFunc::try_cast_to_int64(&other) {
+                    Ok(Value::Int64(v.saturating_neg()))
+                } else if let Some(v) = BinaryFunc::try_cast_to_float64(&other) {
+                    Ok(Value::Float64(-v))
+                } else {
+                    Err(EvalError::TypeMismatch {
+                        expected: "numeric".to_string(),
+                        actual: format!("{:?}", other),
+                    })
+                }
+            }
+        }
+    }
+}
+
 /// Binary function that takes two arguments
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BinaryFunc {
@@ -215,9 +267,75 @@ pub enum BinaryFunc {
     Div,
     /// Modulo
     Mod,
+    /// Logical AND
+    And,
+    /// Logical OR
+    Or,
+    /// Logical XOR
+    Xor,
+    /// NULL-safe inequality: never NULL, and two NULLs are not distinct
+    IsDistinctFrom,
+    /// NULL-safe equality: never NULL, and two NULLs are not distinct
+    IsNotDistinctFrom,
 }
 
 impl BinaryFunc {
+    /// Result decided by the left operand alone (`FALSE AND x`, `TRUE OR x`), in which case the
+    /// right operand does not need to be evaluated.
+    pub fn short_circuit(&self, left: &Value) -> Option<Value> {
+        match (self, left) {
+            (Self::And, Value::Bool(false)) => Some(Value::Bool(false)),
+            (Self::Or, Value::Bool(true)) => Some(Value::Bool(true)),
+            _ => None,
+        }
+    }
+
+    /// Logical operands are booleans, with `NULL` standing for unknown.
+    fn logical_operand(value: &Value) -> Result<Option<bool>, EvalError> {
+        match value {
+            Value::Null => Ok(None),
+            Value::Bool(b) => Ok(Some(*b)),
+            other => Err(EvalError::TypeMismatch {
+                expected: "Bool".to_string(),
+                actual: format!("{:?}", other),
+            }),
+        }
+    }
+
+    /// Evaluate AND/OR/XOR with SQL three-valued logic.
+    fn eval_logical(&self, left: &Value, right: &Value) -> Result<Value, EvalError> {
+        let left = Self::logical_operand(left)?;
+        let right = Self::logical_operand(right)?;
+        let result = match self {
+            Self::And => match (left, right) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            Self::Or => match (left, right) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            Self::Xor => left.zip(right).map(|(a, b)| a != b),
+            _ => unreachable!("not a logical operator: {:?}", self),
+        };
+        Ok(result.map(Value::Bool).unwrap_or(Value::Null))
+    }
+
+    /// Whether two values differ, treating NULL as a comparable value. Values of different
+    /// types are cast to a common type first, as for `<` and `>`.
+    fn is_distinct(left: &Value, right: &Value) -> bool {
+        match (left.is_null(), right.is_null()) {
+            (true, true) => false,
+            (true, false) | (false, true) => true,
+            (false, false) => match Self::compare_values(left, right) {
+                Some(ordering) => ordering != std::cmp::Ordering::Equal,
+                None => left != right,
+            },
+        }
+    }
+
     /// Try to cast a value to Int64
     fn try_cast_to_int64(value: &Value) -> Option<i64> {
         let int64_type = Int64Type;
@@ -254,8 +372,63 @@ impl BinaryFunc {
         })
     }
 
+    /// Try to cast a value to a Timestamp with the precision of `like`
+    fn try_cast_to_timestamp(value: &Value, like: &Timestamp) -> Option<Timestamp> {
+        let timestamp_type = TimestampType::new(like.precision());
+        timestamp_type
+            .try_cast(value.clone())
+            .and_then(|v| match v {
+                Value::Timestamp(ts) => Some(ts),
+                _ => None,
+            })
+    }
+
+    /// Timestamp arithmetic: `ts ± ticks` shifts by ticks of the timestamp's precision and
+    /// `ts - ts` is the difference in ticks of the finer precision.
+    /// Returns `None` when neither side is a timestamp.
+    fn eval_timestamp_arithmetic(
+        &self,
+        left: &Value,
+        right: &Value,
+    ) -> Option<Result<Value, EvalError>> {
+        let shift = |ts: &Timestamp, ticks: i64| {
+            Value::Timestamp(Timestamp::new(
+                ts.value().saturating_add(ticks),
+                ts.precision(),
+            ))
+        };
+        let ticks = |value: &Value| match value {
+            Value::String(_) | Value::Timestamp(_) => None,
+            other => Self::try_cast_to_int64(other),
+        };
+        let mismatch = || EvalError::TypeMismatch {
+            expected: "Timestamp and integer, or two Timestamps".to_string(),
+            actual: format!("{:?} and {:?}", left, right),
+        };
+        match (self, left, right) {
+            (Self::Sub, Value::Timestamp(a), Value::Timestamp(b)) => {
+                let precision = a.precision().max(b.precision());
+                let diff = a.convert_to(precision).zip(b.convert_to(precision));
+                Some(
+                    diff.map(|(a, b)| Value::Int64(a.value().saturating_sub(b.value())))
+                        .ok_or_else(mismatch),
+                )
+            }
+            (Self::Add, Value::Timestamp(ts), other) | (Self::Add, other, Value::Timestamp(ts)) => {
+                Some(ticks(other).map(|n| shift(ts, n)).ok_or_else(mismatch))
+            }
+            (Self::Sub, Value::Timestamp(ts), other) => Some(
+                ticks(other)
+                    .map(|n| shift(ts, n.saturating_neg()))
+                    .ok_or_else(mismatch),
+            ),
+            (_, Value::Timestamp(_), _) | (_, _, Value::Timestamp(_)) => Some(Err(mismatch())),
+            _ => None,
+        }
+    }
+
     /// Compare two values by trying to cast them to comparable types
-    fn compare_values(left: &Value, right: &Value) -> Option<std::cmp::Ordering> {
+    pub(crate) fn compare_values(left: &Value, right: &Value) -> Option<std::cmp::Ordering> {
         // Null values are not comparable
         if left.is_null() || right.is_null() {
             return None;
@@ -276,6 +449,14 @@ impl BinaryFunc {
             (Value::Uint64(a), Value::Uint64(b)) => Some(a.cmp(b)),
             (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
             (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+            (Value::Timestamp(a), Value::Timestamp(b)) => Some(a.cmp(b)),
+            // Timestamps compare against strings as RFC 3339 text and against integers as ticks
+            (Value::Timestamp(a), Value::String(_)) => {
+                Self::try_cast_to_timestamp(right, a).map(|b| a.cmp(&b))
+            }
+            (Value::String(_), Value::Timestamp(b)) => {
+                Self::try_cast_to_timestamp(left, b).map(|a| a.cmp(b))
+            }
             // If types don't match, try to cast to a common type
             _ => {
                 // Try Int64 first
@@ -360,6 +541,9 @@ impl BinaryFunc {
     /// Evaluate a binary function with pre-evaluated arguments
     pub fn eval_binary(&self, left: Value, right: Value) -> Result<Value, EvalError> {
         match self {
+            Self::And | Self::Or | Self::Xor => self.eval_logical(&left, &right),
+            Self::IsDistinctFrom => Ok(Value::Bool(Self::is_distinct(&left, &right))),
+            Self::IsNotDistinctFrom => Ok(Value::Bool(!Self::is_distinct(&left, &right))),
             Self::Eq => {
                 // Null == Null is true, Null == anything else is false
                 if left.is_null() && right.is_null() {
@@ -406,6 +590,9 @@ impl BinaryFunc {
                 if left.is_null() || right.is_null() {
                     return Ok(Value::Null);
                 }
+                if let Some(result) = self.eval_timestamp_arithmetic(&left, &right) {
+                    return result;
+                }
                 // If types match, handle directly
                 match (&left, &right) {
                     (Value::Int8(a), Value::Int8(b)) => {
@@ -482,6 +669,9 @@ impl BinaryFunc {
                 if left.is_null() || right.is_null() {
                     return Ok(Value::Null);
                 }
+                if let Some(result) = self.eval_timestamp_arithmetic(&left, &right) {
+                    return result;
+                }
                 // If types match, handle directly
                 match (&left, &right) {
                     (Value::Int8(a), Value::Int8(b)) => {
@@ -814,8 +1004,8 @@ impl BinaryFunc {
 
 #[cfg(test)]
 mod tests {
-    use super::BinaryFunc;
-    use datatypes::Value;
+    use super::{BinaryFunc, UnaryFunc};
+    use datatypes::{ConcreteDatatype, Timestamp, TimestampPrecision, TimestampType, Value};
 
     #[test]
     fn arithmetic_ops_propagate_null() {
@@ -839,6 +1029,142 @@ mod tests {
         }
     }
 
+    #[test]
+    fn distinct_from_treats_null_as_a_value() {
+        let distinct = |left, right| {
+            BinaryFunc::IsDistinctFrom
+                .eval_binary(left, right)
+                .expect("distinct")
+        };
+        assert_eq!(distinct(Value::Null, Value::Null), Value::Bool(false));
+        assert_eq!(distinct(Value::Null, Value::Int64(1)), Value::Bool(true));
+        assert_eq!(
+            distinct(Value::Int32(1), Value::Int64(1)),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            distinct(Value::String("a".into()), Value::String("b".into())),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            BinaryFunc::IsNotDistinctFrom
+                .eval_binary(Value::Null, Value::Null)
+                .expect("not distinct"),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            UnaryFunc::IsNotNull
+                .eval_unary(Value::Null)
+                .expect("is not null"),
+            Value::Bool(false)
+        );
+    }
+
+    #[test]
+    fn neg_keeps_numeric_type() {
+        let neg = |value| UnaryFunc::Neg.eval_unary(value).expect("neg");
+        assert_eq!(neg(Value::Float64(2.5)), Value::Float64(-2.5));
+        assert_eq!(neg(Value::Int32(7)), Value::Int32(-7));
+        assert_eq!(neg(Value::Int64(i64::MIN)), Value::Int64(i64::MAX));
+        assert_eq!(neg(Value::Uint8(3)), Value::Int64(-3));
+        assert_eq!(neg(Value::Null), Value::Null);
+        assert!(UnaryFunc::Neg
+            .eval_unary(Value::String("abc".to_string()))
+            .is_err());
+    }
+
+    #[test]
+    fn logical_ops_follow_three_valued_logic() {
+        let t = || Value::Bool(true);
+        let f = || Value::Bool(false);
+        let cases = [
+            (BinaryFunc::And, t(), t(), t()),
+            (BinaryFunc::And, t(), f(), f()),
+            (BinaryFunc::And, Value::Null, f(), f()),
+            (BinaryFunc::And, t(), Value::Null, Value::Null),
+            (BinaryFunc::Or, f(), f(), f()),
+            (BinaryFunc::Or, Value::Null, t(), t()),
+            (BinaryFunc::Or, f(), Value::Null, Value::Null),
+            (BinaryFunc::Xor, t(), f(), t()),
+            (BinaryFunc::Xor, t(), t(), f()),
+            (BinaryFunc::Xor, t(), Value::Null, Value::Null),
+        ];
+        for (func, left, right, expected) in cases {
+            assert_eq!(
+                func.eval_binary(left.clone(), right.clone()).unwrap(),
+                expected,
+                "{func:?}({left:?}, {right:?})"
+            );
+        }
+        assert!(BinaryFunc::And.eval_binary(t(), Value::Int64(1)).is_err());
+        assert_eq!(BinaryFunc::And.short_circuit(&f()), Some(f()));
+        assert_eq!(BinaryFunc::Or.short_circuit(&t()), Some(t()));
+        assert_eq!(BinaryFunc::And.short_circuit(&Value::Null), None);
+        assert_eq!(UnaryFunc::Not.eval_unary(Value::Null).unwrap(), Value::Null);
+        assert_eq!(UnaryFunc::IsTrue.eval_unary(Value::Null).unwrap(), f());
+    }
+
+    #[test]
+    fn timestamp_arithmetic_and_comparison() {
+        let ts = Value::Timestamp(Timestamp::new_millisecond(10_000));
+        assert_eq!(
+            BinaryFunc::Add
+                .eval_binary(ts.clone(), Value::Int64(500))
+                .unwrap(),
+            Value::Timestamp(Timestamp::new_millisecond(10_500))
+        );
+        assert_eq!(
+            BinaryFunc::Sub
+                .eval_binary(ts.clone(), Value::Timestamp(Timestamp::new_second(4)))
+                .unwrap(),
+            Value::Int64(6_000)
+        );
+        assert!(BinaryFunc::Add
+            .eval_binary(ts.clone(), Value::String("x".to_string()))
+            .is_err());
+        assert_eq!(
+            BinaryFunc::Gt
+                .eval_binary(
+                    ts.clone(),
+                    Value::String("1970-01-01T00:00:09Z".to_string())
+                )
+                .unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            BinaryFunc::Eq
+                .eval_binary(ts, Value::Timestamp(Timestamp::new_second(10)))
+                .unwrap(),
+            Value::Bool(true)
+        );
+    }
+
+    #[test]
+    fn cast_to_and_from_timestamp() {
+        let to_seconds = UnaryFunc::Cast(ConcreteDatatype::Timestamp(TimestampType::new(
+            TimestampPrecision::Second,
+        )));
+        assert_eq!(
+            to_seconds
+                .eval_unary(Value::String("1970-01-01T00:01:00Z".to_string()))
+                .unwrap(),
+            Value::Timestamp(Timestamp::new_second(60))
+        );
+        assert_eq!(
+            to_seconds
+                .eval_unary(Value::Timestamp(Timestamp::new_millisecond(61_500)))
+                .unwrap(),
+            Value::Timestamp(Timestamp::new_second(61))
+        );
+        assert!(to_seconds.eval_unary(Value::Bool(true)).is_err());
+        assert_eq!(
+            UnaryFunc::Cast(ConcreteDatatype::String(datatypes::StringType))
+                .eval_unary(Value::Timestamp(Timestamp::new_second(60)))
+                .unwrap(),
+            Value::String("1970-01-01T00:01:00Z".to_string())
+        );
+    }
+
     #[test]
     fn null_division_does_not_error() {
         assert_eq!(
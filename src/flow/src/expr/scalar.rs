@@ -48,6 +48,22 @@ pub enum ScalarExpr {
         /// The arguments to the function
         args: Vec<ScalarExpr>,
     },
+    /// A searched CASE expression
+    Case {
+        /// `(condition, result)` pairs; the first condition evaluating to TRUE selects its result
+        branches: Vec<(ScalarExpr, ScalarExpr)>,
+        /// Result when no condition is TRUE; NULL when absent
+        else_expr: Option<Box<ScalarExpr>>,
+    },
+    /// COALESCE / IFNULL: the first argument that is not NULL; arguments after it are not
+    /// evaluated
+    Coalesce(Vec<ScalarExpr>),
+    /// NULLIF: NULL when `expr` is not distinct from `other`, otherwise `expr`
+    NullIf {
+        expr: Box<ScalarExpr>,
+        /// Only evaluated when `expr` is not NULL
+        other: Box<ScalarExpr>,
+    },
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -136,6 +152,9 @@ impl ScalarExpr {
             }
             ScalarExpr::CallBinary { func, expr1, expr2 } => {
                 let left = expr1.eval_with_tuple(tuple)?;
+                if let Some(result) = func.short_circuit(&left) {
+                    return Ok(result);
+                }
                 let right = expr2.eval_with_tuple(tuple)?;
                 func.eval_binary(left, right)
             }
@@ -192,6 +211,47 @@ impl ScalarExpr {
                 func.validate_row(&row_args)?;
                 func.eval_row(&row_args)
             }
+            ScalarExpr::Case {
+                branches,
+                else_expr,
+            } => {
+                for (condition, result) in branches {
+                    match condition.eval_with_tuple(tuple)? {
+                        Value::Bool(true) => return result.eval_with_tuple(tuple),
+                        Value::Bool(false) | Value::Null => {}
+                        other => {
+                            return Err(EvalError::TypeMismatch {
+                                expected: "Bool".to_string(),
+                                actual: format!("{:?}", other),
+                            })
+                        }
+                    }
+                }
+                match else_expr {
+                    Some(else_expr) => else_expr.eval_with_tuple(tuple),
+                    None => Ok(Value::Null),
+                }
+            }
+            ScalarExpr::Coalesce(args) => {
+                for arg in args {
+                    let value = arg.eval_with_tuple(tuple)?;
+                    if !value.is_null() {
+                        return Ok(value);
+                    }
+                }
+                Ok(Value::Null)
+            }
+            ScalarExpr::NullIf { expr, other } => {
+                let value = expr.eval_with_tuple(tuple)?;
+                if value.is_null() {
+                    return Ok(Value::Null);
+                }
+                let other = other.eval_with_tuple(tuple)?;
+                match BinaryFunc::IsDistinctFrom.eval_binary(value.clone(), other)? {
+                    Value::Bool(false) => Ok(Value::Null),
+                    _ => Ok(value),
+                }
+            }
         }
     }
 
@@ -332,6 +392,12 @@ impl std::fmt::Debug for ScalarExpr {
             ScalarExpr::CallFunc { func, args } => {
                 write!(f, "CallFunc({}, {:?})", func.name(), args)
             }
+            ScalarExpr::Case {
+                branches,
+                else_expr,
+            } => write!(f, "Case({:?}, {:?})", branches, else_expr),
+            ScalarExpr::Coalesce(args) => write!(f, "Coalesce({:?})", args),
+            ScalarExpr::NullIf { expr, other } => write!(f, "NullIf({:?}, {:?})", expr, other),
         }
     }
 }
@@ -398,6 +464,27 @@ impl PartialEq for ScalarExpr {
                 ScalarExpr::CallFunc { func: fa, args: aa },
                 ScalarExpr::CallFunc { func: fb, args: ab },
             ) => fa.name() == fb.name() && aa == ab,
+            (
+                ScalarExpr::Case {
+                    branches: ba,
+                    else_expr: ea,
+                },
+                ScalarExpr::Case {
+                    branches: bb,
+                    else_expr: eb,
+                },
+            ) => ba == bb && ea == eb,
+            (ScalarExpr::Coalesce(aa), ScalarExpr::Coalesce(ab)) => aa == ab,
+            (
+                ScalarExpr::NullIf {
+                    expr: ea,
+                    other: oa,
+                },
+                ScalarExpr::NullIf {
+                    expr: eb,
+                    other: ob,
+                },
+            ) => ea == eb && oa == ob,
             _ => false,
         }
     }
@@ -1,10 +1,14 @@
-use super::custom_func::CustomFuncRegistry;
+use super::custom_func::{CustomFunc, CustomFuncRegistry};
 use super::func::{BinaryFunc, UnaryFunc};
 use super::scalar::ScalarExpr;
-use datatypes::{BooleanType, ConcreteDatatype, Float64Type, Int64Type, Schema, StringType, Value};
+use datatypes::{
+    BooleanType, ConcreteDatatype, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+    Int8Type, Schema, StringType, TimestampPrecision, TimestampType, Uint16Type, Uint32Type,
+    Uint64Type, Uint8Type, Value,
+};
 use sqlparser::ast::{
-    BinaryOperator, Expr, Function, FunctionArg, FunctionArgExpr, Ident, UnaryOperator,
-    Value as SqlValue,
+    BinaryOperator, DataType as SqlDataType, DateTimeField, Expr, Function, FunctionArg,
+    FunctionArgExpr, Ident, TrimWhereField, UnaryOperator, Value as SqlValue,
 };
 use std::sync::{Arc, OnceLock};
 
@@ -144,6 +148,62 @@ fn convert_sql_value_to_scalar(value: &SqlValue) -> Result<ScalarExpr, Conversio
     }
 }
 
+/// Convert a SQL type name (as used in `CAST`) to a ConcreteDatatype
+fn convert_sql_datatype(data_type: &SqlDataType) -> Result<ConcreteDatatype, ConversionError> {
+    match data_type {
+        SqlDataType::Bool | SqlDataType::Boolean => Ok(ConcreteDatatype::Bool(BooleanType)),
+        SqlDataType::TinyInt(_) => Ok(ConcreteDatatype::Int8(Int8Type)),
+        SqlDataType::SmallInt(_) | SqlDataType::Int2(_) => Ok(ConcreteDatatype::Int16(Int16Type)),
+        SqlDataType::Int(_) | SqlDataType::Integer(_) | SqlDataType::Int4(_) => {
+            Ok(ConcreteDatatype::Int32(Int32Type))
+        }
+        SqlDataType::BigInt(_) | SqlDataType::Int8(_) | SqlDataType::Int64 => {
+            Ok(ConcreteDatatype::Int64(Int64Type))
+        }
+        SqlDataType::UnsignedTinyInt(_) => Ok(ConcreteDatatype::Uint8(Uint8Type)),
+        SqlDataType::UnsignedSmallInt(_) | SqlDataType::UnsignedInt2(_) => {
+            Ok(ConcreteDatatype::Uint16(Uint16Type))
+        }
+        SqlDataType::UnsignedInt(_)
+        | SqlDataType::UnsignedInteger(_)
+        | SqlDataType::UnsignedInt4(_) => Ok(ConcreteDatatype::Uint32(Uint32Type)),
+        SqlDataType::UnsignedBigInt(_) | SqlDataType::UnsignedInt8(_) => {
+            Ok(ConcreteDatatype::Uint64(Uint64Type))
+        }
+        SqlDataType::Real | SqlDataType::Float4 => Ok(ConcreteDatatype::Float32(Float32Type)),
+        SqlDataType::Float(_)
+        | SqlDataType::Float8
+        | SqlDataType::Float64
+        | SqlDataType::Double
+        | SqlDataType::DoublePrecision => Ok(ConcreteDatatype::Float64(Float64Type)),
+        SqlDataType::Char(_)
+        | SqlDataType::Character(_)
+        | SqlDataType::Varchar(_)
+        | SqlDataType::CharVarying(_)
+        | SqlDataType::CharacterVarying(_)
+        | SqlDataType::Text
+        | SqlDataType::String(_) => Ok(ConcreteDatatype::String(StringType)),
+        SqlDataType::Timestamp(digits, _) | SqlDataType::Datetime(digits) => {
+            let precision = match digits {
+                None => TimestampPrecision::default(),
+                Some(digits) => {
+                    TimestampPrecision::from_fraction_digits(*digits).ok_or_else(|| {
+                        ConversionError::TypeConversionError(format!(
+                            "Unsupported timestamp precision: {} (expected 0, 3, 6 or 9)",
+                            digits
+                        ))
+                    })?
+                }
+            };
+            Ok(ConcreteDatatype::Timestamp(TimestampType::new(precision)))
+        }
+        _ => Err(ConversionError::TypeConversionError(format!(
+            "Unsupported cast type: {}",
+            data_type
+        ))),
+    }
+}
+
 /// Convert SQL BinaryOperator to flow BinaryFunc
 fn convert_binary_op(op: &BinaryOperator) -> Result<BinaryFunc, ConversionError> {
     use sqlparser::ast::BinaryOperator;
@@ -160,17 +220,32 @@ fn convert_binary_op(op: &BinaryOperator) -> Result<BinaryFunc, ConversionError>
         BinaryOperator::LtEq => Ok(BinaryFunc::Lte),
         BinaryOperator::Gt => Ok(BinaryFunc::Gt),
         BinaryOperator::GtEq => Ok(BinaryFunc::Gte),
+        BinaryOperator::And => Ok(BinaryFunc::And),
+        BinaryOperator::Or => Ok(BinaryFunc::Or),
+        BinaryOperator::Xor => Ok(BinaryFunc::Xor),
         _ => Err(ConversionError::UnsupportedOperator(format!("{:?}", op))),
     }
 }
 
+/// Map the PostgreSQL regex operators `~`, `~*`, `!~` and `!~*` to the `regexp_match` flags
+/// and whether the result is negated.
+fn regex_match_operator(op: &BinaryOperator) -> Option<(Option<&'static str>, bool)> {
+    match op {
+        BinaryOperator::PGRegexMatch => Some((None, false)),
+        BinaryOperator::PGRegexIMatch => Some((Some("i"), false)),
+        BinaryOperator::PGRegexNotMatch => Some((None, true)),
+        BinaryOperator::PGRegexNotIMatch => Some((Some("i"), true)),
+        _ => None,
+    }
+}
+
 /// Convert SQL UnaryOperator to flow UnaryFunc
 fn convert_unary_op(op: &UnaryOperator) -> Result<UnaryFunc, ConversionError> {
     use sqlparser::ast::UnaryOperator;
 
     match op {
         UnaryOperator::Not => Ok(UnaryFunc::Not),
-        UnaryOperator::Minus => Ok(UnaryFunc::Cast(ConcreteDatatype::Int64(Int64Type))),
+        UnaryOperator::Minus => Ok(UnaryFunc::Neg),
         _ => Err(ConversionError::UnsupportedOperator(format!("{:?}", op))),
     }
 }
@@ -226,6 +301,17 @@ fn convert_expr_to_scalar_internal(
             let left_expr = convert_expr_to_scalar_internal(left, bindings, custom_func_registry)?;
             let right_expr =
                 convert_expr_to_scalar_internal(right, bindings, custom_func_registry)?;
+            if let Some((flags, negated)) = regex_match_operator(op) {
+                let func = registered_func(custom_func_registry, "regexp_match", "~")?;
+                let mut args = vec![left_expr, right_expr];
+                if let Some(flags) = flags {
+                    args.push(ScalarExpr::Literal(
+                        Value::String(flags.to_string()),
+                        ConcreteDatatype::String(StringType),
+                    ));
+                }
+                return Ok(negate_if(prepare_call(func, args)?, negated));
+            }
             let binary_func = convert_binary_op(op)?;
 
             Ok(ScalarExpr::CallBinary {
@@ -252,6 +338,258 @@ fn convert_expr_to_scalar_internal(
             convert_function_call(name, args, bindings, custom_func_registry)
         }
 
+        // Type casts like CAST(a AS TIMESTAMP)
+        Expr::Cast {
+            expr: operand,
+            data_type,
+            ..
+        } => {
+            let operand_expr =
+                convert_expr_to_scalar_internal(operand, bindings, custom_func_registry)?;
+            Ok(ScalarExpr::CallUnary {
+                func: UnaryFunc::Cast(convert_sql_datatype(data_type)?),
+                expr: Box::new(operand_expr),
+            })
+        }
+
+        // Typed literals like TIMESTAMP '2024-01-01 00:00:00'
+        Expr::TypedString { data_type, value } => {
+            let datatype = convert_sql_datatype(data_type)?;
+            let literal = UnaryFunc::Cast(datatype.clone())
+                .eval_unary(Value::String(value.clone()))
+                .map_err(|err| ConversionError::TypeConversionError(err.to_string()))?;
+            Ok(ScalarExpr::Literal(literal, datatype))
+        }
+
+        // EXTRACT(field FROM ts) maps onto the `extract` function
+        Expr::Extract {
+            field,
+            expr: operand,
+        } => {
+            let func = registered_func(custom_func_registry, "extract", "EXTRACT")?;
+            let operand_expr =
+                convert_expr_to_scalar_internal(operand, bindings, custom_func_registry)?;
+            prepare_call(
+                func,
+                vec![
+                    ScalarExpr::Literal(
+                        Value::String(field.to_string().to_lowercase()),
+                        ConcreteDatatype::String(StringType),
+                    ),
+                    operand_expr,
+                ],
+            )
+        }
+
+        // a [NOT] LIKE pattern [ESCAPE c] maps onto the `like` function
+        Expr::Like {
+            negated,
+            expr: operand,
+            pattern,
+            escape_char,
+        } => convert_like_expression(
+            "like",
+            operand,
+            pattern,
+            *escape_char,
+            *negated,
+            bindings,
+            custom_func_registry,
+        ),
+
+        // a [NOT] ILIKE pattern [ESCAPE c] maps onto the `ilike` function
+        Expr::ILike {
+            negated,
+            expr: operand,
+            pattern,
+            escape_char,
+        } => convert_like_expression(
+            "ilike",
+            operand,
+            pattern,
+            *escape_char,
+            *negated,
+            bindings,
+            custom_func_registry,
+        ),
+
+        // a [NOT] SIMILAR TO pattern [ESCAPE c] maps onto the `similar_to` function
+        Expr::SimilarTo {
+            negated,
+            expr: operand,
+            pattern,
+            escape_char,
+        } => convert_like_expression(
+            "similar_to",
+            operand,
+            pattern,
+            *escape_char,
+            *negated,
+            bindings,
+            custom_func_registry,
+        ),
+
+        // SUBSTRING(s FROM start FOR len) maps onto the `substring` function
+        Expr::Substring {
+            expr: operand,
+            substring_from,
+            substring_for,
+            ..
+        } => {
+            let func = registered_func(custom_func_registry, "substring", "SUBSTRING")?;
+            let mut args = vec![convert_expr_to_scalar_internal(
+                operand,
+                bindings,
+                custom_func_registry,
+            )?];
+            args.push(match substring_from {
+                Some(from) => {
+                    convert_expr_to_scalar_internal(from, bindings, custom_func_registry)?
+                }
+                None => ScalarExpr::Literal(Value::Int64(1), ConcreteDatatype::Int64(Int64Type)),
+            });
+            if let Some(len) = substring_for {
+                args.push(convert_expr_to_scalar_internal(
+                    len,
+                    bindings,
+                    custom_func_registry,
+                )?);
+            }
+            prepare_call(func, args)
+        }
+
+        // TRIM([BOTH | LEADING | TRAILING] [chars FROM] s) maps onto `trim`/`ltrim`/`rtrim`
+        Expr::Trim {
+            expr: operand,
+            trim_where,
+            trim_what,
+            trim_characters,
+        } => {
+            let name = match trim_where {
+                Some(TrimWhereField::Leading) => "ltrim",
+                Some(TrimWhereField::Trailing) => "rtrim",
+                Some(TrimWhereField::Both) | None => "trim",
+            };
+            let func = registered_func(custom_func_registry, name, "TRIM")?;
+            let chars = match (trim_what, trim_characters.as_deref()) {
+                (Some(what), _) => Some(what.as_ref()),
+                (None, Some([chars])) => Some(chars),
+                (None, None) => None,
+                (None, Some(_)) => {
+                    return Err(ConversionError::UnsupportedExpression(format!(
+                        "TRIM accepts a single characters argument: {}",
+                        expr
+                    )))
+                }
+            };
+            let mut args = vec![convert_expr_to_scalar_internal(
+                operand,
+                bindings,
+                custom_func_registry,
+            )?];
+            if let Some(chars) = chars {
+                args.push(convert_expr_to_scalar_internal(
+                    chars,
+                    bindings,
+                    custom_func_registry,
+                )?);
+            }
+            prepare_call(func, args)
+        }
+
+        // CEIL(x) / FLOOR(x) map onto the `ceil` / `floor` functions
+        Expr::Ceil {
+            expr: operand,
+            field,
+        }
+        | Expr::Floor {
+            expr: operand,
+            field,
+        } => {
+            let name = if matches!(expr, Expr::Ceil { .. }) {
+                "ceil"
+            } else {
+                "floor"
+            };
+            if *field != DateTimeField::NoDateTime {
+                return Err(ConversionError::UnsupportedExpression(format!(
+                    "{} TO {} is not supported",
+                    name.to_uppercase(),
+                    field
+                )));
+            }
+            let func = registered_func(custom_func_registry, name, &name.to_uppercase())?;
+            let operand_expr =
+                convert_expr_to_scalar_internal(operand, bindings, custom_func_registry)?;
+            prepare_call(func, vec![operand_expr])
+        }
+
+        // IS [NOT] NULL / TRUE / FALSE / UNKNOWN
+        Expr::IsNull(operand) | Expr::IsUnknown(operand) => convert_is_expression(
+            operand,
+            UnaryFunc::IsNull,
+            false,
+            bindings,
+            custom_func_registry,
+        ),
+        Expr::IsNotNull(operand) | Expr::IsNotUnknown(operand) => convert_is_expression(
+            operand,
+            UnaryFunc::IsNotNull,
+            false,
+            bindings,
+            custom_func_registry,
+        ),
+        Expr::IsTrue(operand) => convert_is_expression(
+            operand,
+            UnaryFunc::IsTrue,
+            false,
+            bindings,
+            custom_func_registry,
+        ),
+        Expr::IsNotTrue(operand) => convert_is_expression(
+            operand,
+            UnaryFunc::IsTrue,
+            true,
+            bindings,
+            custom_func_registry,
+        ),
+        Expr::IsFalse(operand) => convert_is_expression(
+            operand,
+            UnaryFunc::IsFalse,
+            false,
+            bindings,
+            custom_func_registry,
+        ),
+        Expr::IsNotFalse(operand) => convert_is_expression(
+            operand,
+            UnaryFunc::IsFalse,
+            true,
+            bindings,
+            custom_func_registry,
+        ),
+
+        // a IS [NOT] DISTINCT FROM b
+        Expr::IsDistinctFrom(left, right) | Expr::IsNotDistinctFrom(left, right) => {
+            let func = if matches!(expr, Expr::IsDistinctFrom(..)) {
+                BinaryFunc::IsDistinctFrom
+            } else {
+                BinaryFunc::IsNotDistinctFrom
+            };
+            Ok(ScalarExpr::CallBinary {
+                func,
+                expr1: Box::new(convert_expr_to_scalar_internal(
+                    left,
+                    bindings,
+                    custom_func_registry,
+                )?),
+                expr2: Box::new(convert_expr_to_scalar_internal(
+                    right,
+                    bindings,
+                    custom_func_registry,
+                )?),
+            })
+        }
+
         // Parenthesized expressions like (a + b)
         Expr::Nested(inner_expr) => {
             convert_expr_to_scalar_internal(inner_expr, bindings, custom_func_registry)
@@ -536,6 +874,10 @@ fn convert_function_call(
     custom_func_registry: &CustomFuncRegistry,
 ) -> Result<ScalarExpr, ConversionError> {
     let function_name = name.to_string().to_lowercase();
+    if let Some(expr) = convert_null_function(&function_name, args, bindings, custom_func_registry)?
+    {
+        return Ok(expr);
+    }
     let custom_func = custom_func_registry.get(&function_name).ok_or_else(|| {
         ConversionError::UnsupportedExpression(format!(
             "Unknown function: '{}'. Available custom functions: {:?}",
@@ -598,12 +940,146 @@ fn convert_function_call(
         }
     }
 
+    prepare_call(custom_func, scalar_args)
+}
+
+/// Convert the NULL-handling functions COALESCE, IFNULL and NULLIF, which are native
+/// expressions rather than custom functions so that later arguments are evaluated lazily.
+/// Returns `None` for any other function name.
+fn convert_null_function(
+    function_name: &str,
+    args: &[FunctionArg],
+    bindings: &SchemaBinding,
+    custom_func_registry: &CustomFuncRegistry,
+) -> Result<Option<ScalarExpr>, ConversionError> {
+    let arity = match function_name {
+        "coalesce" => 1..=usize::MAX,
+        "ifnull" | "nullif" => 2..=2,
+        _ => return Ok(None),
+    };
+    let mut operands = Vec::with_capacity(args.len());
+    for arg in args {
+        match arg {
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => operands.push(
+                convert_expr_to_scalar_internal(expr, bindings, custom_func_registry)?,
+            ),
+            other => {
+                return Err(ConversionError::UnsupportedExpression(format!(
+                    "{} does not accept argument {}",
+                    function_name.to_uppercase(),
+                    other
+                )))
+            }
+        }
+    }
+    if !arity.contains(&operands.len()) {
+        return Err(ConversionError::UnsupportedExpression(format!(
+            "{} called with {} arguments",
+            function_name.to_uppercase(),
+            operands.len()
+        )));
+    }
+    if function_name == "nullif" {
+        let other = operands.pop().expect("two arguments");
+        let expr = operands.pop().expect("two arguments");
+        return Ok(Some(ScalarExpr::NullIf {
+            expr: Box::new(expr),
+            other: Box::new(other),
+        }));
+    }
+    Ok(Some(ScalarExpr::Coalesce(operands)))
+}
+
+/// Build a function call, letting the function specialize itself for the literal arguments
+/// (see `CustomFunc::prepare`).
+fn prepare_call(
+    func: Arc<dyn CustomFunc>,
+    args: Vec<ScalarExpr>,
+) -> Result<ScalarExpr, ConversionError> {
+    let literals: Vec<Option<&Value>> = args
+        .iter()
+        .map(|arg| match arg {
+            ScalarExpr::Literal(value, _) => Some(value),
+            _ => None,
+        })
+        .collect();
+    let prepared = func
+        .prepare(&literals)
+        .map_err(|err| ConversionError::TypeConversionError(format!("{}: {}", func.name(), err)))?;
     Ok(ScalarExpr::CallFunc {
-        func: custom_func,
-        args: scalar_args,
+        func: prepared.unwrap_or(func),
+        args,
+    })
+}
+
+/// Look up the custom function backing a dedicated SQL syntax such as EXTRACT or LIKE.
+fn registered_func(
+    custom_func_registry: &CustomFuncRegistry,
+    name: &str,
+    syntax: &str,
+) -> Result<Arc<dyn CustomFunc>, ConversionError> {
+    custom_func_registry.get(name).ok_or_else(|| {
+        ConversionError::UnsupportedExpression(format!(
+            "{} requires the '{}' function to be registered",
+            syntax, name
+        ))
     })
 }
 
+fn negate_if(expr: ScalarExpr, negated: bool) -> ScalarExpr {
+    if negated {
+        ScalarExpr::CallUnary {
+            func: UnaryFunc::Not,
+            expr: Box::new(expr),
+        }
+    } else {
+        expr
+    }
+}
+
+/// Convert an IS predicate, negating it for the IS NOT forms that have no `UnaryFunc` of their
+/// own.
+fn convert_is_expression(
+    expr: &Expr,
+    func: UnaryFunc,
+    negated: bool,
+    bindings: &SchemaBinding,
+    custom_func_registry: &CustomFuncRegistry,
+) -> Result<ScalarExpr, ConversionError> {
+    let operand = convert_expr_to_scalar_internal(expr, bindings, custom_func_registry)?;
+    Ok(negate_if(
+        ScalarExpr::CallUnary {
+            func,
+            expr: Box::new(operand),
+        },
+        negated,
+    ))
+}
+
+/// Convert [NOT] LIKE / ILIKE / SIMILAR TO expression
+fn convert_like_expression(
+    name: &str,
+    expr: &Expr,
+    pattern: &Expr,
+    escape_char: Option<char>,
+    negated: bool,
+    bindings: &SchemaBinding,
+    custom_func_registry: &CustomFuncRegistry,
+) -> Result<ScalarExpr, ConversionError> {
+    let func = registered_func(custom_func_registry, name, &name.to_uppercase())?;
+    let mut args = vec![
+        convert_expr_to_scalar_internal(expr, bindings, custom_func_registry)?,
+        convert_expr_to_scalar_internal(pattern, bindings, custom_func_registry)?,
+    ];
+    if let Some(escape) = escape_char {
+        args.push(ScalarExpr::Literal(
+            Value::String(escape.to_string()),
+            ConcreteDatatype::String(StringType),
+        ));
+    }
+    Ok(negate_if(prepare_call(func, args)?, negated))
+}
+
 /// Convert BETWEEN expression
 fn convert_between_expression(
     expr: &Expr,
@@ -630,7 +1106,7 @@ fn convert_between_expression(
     };
 
     let between_expr = ScalarExpr::CallBinary {
-        func: BinaryFunc::Mul, // Using Mul as AND logic
+        func: BinaryFunc::And,
         expr1: Box::new(lower_bound),
         expr2: Box::new(upper_bound),
     };
@@ -673,7 +1149,7 @@ fn convert_in_list_expression(
 
         result_expr = match result_expr {
             Some(prev) => Some(ScalarExpr::CallBinary {
-                func: BinaryFunc::Add, // Using Add as OR logic
+                func: BinaryFunc::Or,
                 expr1: Box::new(prev),
                 expr2: Box::new(comparison),
             }),
@@ -694,6 +1170,9 @@ fn convert_in_list_expression(
 }
 
 /// Convert CASE expression
+///
+/// A simple CASE (`CASE x WHEN v THEN ...`) is lowered to a searched CASE comparing the operand
+/// with every WHEN value.
 fn convert_case_expression(
     operand: &Option<Box<Expr>>,
     conditions: &[Expr],
@@ -702,46 +1181,37 @@ fn convert_case_expression(
     bindings: &SchemaBinding,
     custom_func_registry: &CustomFuncRegistry,
 ) -> Result<ScalarExpr, ConversionError> {
-    // For simplicity, convert to a chain of IF-THEN-ELSE
-    // In a real implementation, you might want to handle this more efficiently
-    let mut current_expr = if let Some(else_expr) = else_result {
-        convert_expr_to_scalar_internal(else_expr, bindings, custom_func_registry)?
-    } else {
-        ScalarExpr::Literal(Value::Null, ConcreteDatatype::Int64(Int64Type))
-    };
-
-    // Process conditions in reverse order
-    for i in (0..conditions.len()).rev() {
-        let condition = &conditions[i];
-        let result = &results[i];
-
-        let condition_expr = if let Some(operand_expr) = operand {
-            // Simple CASE: operand WHEN value THEN result
-            let operand_scalar =
-                convert_expr_to_scalar_internal(operand_expr, bindings, custom_func_registry)?;
-            let value_scalar =
-                convert_expr_to_scalar_internal(condition, bindings, custom_func_registry)?;
-            ScalarExpr::CallBinary {
+    let operand_expr = operand
+        .as_ref()
+        .map(|operand| convert_expr_to_scalar_internal(operand, bindings, custom_func_registry))
+        .transpose()?;
+
+    let mut branches = Vec::with_capacity(conditions.len());
+    for (condition, result) in conditions.iter().zip(results) {
+        let condition_expr =
+            convert_expr_to_scalar_internal(condition, bindings, custom_func_registry)?;
+        let condition_expr = match &operand_expr {
+            Some(operand_expr) => ScalarExpr::CallBinary {
                 func: BinaryFunc::Eq,
-                expr1: Box::new(operand_scalar),
-                expr2: Box::new(value_scalar),
-            }
-        } else {
-            // Searched CASE: WHEN condition THEN result
-            convert_expr_to_scalar_internal(condition, bindings, custom_func_registry)?
+                expr1: Box::new(operand_expr.clone()),
+                expr2: Box::new(condition_expr),
+            },
+            None => condition_expr,
         };
-
         let result_expr = convert_expr_to_scalar_internal(result, bindings, custom_func_registry)?;
-
-        // This is a simplified implementation - in practice you'd need proper conditional evaluation
-        current_expr = ScalarExpr::CallBinary {
-            func: BinaryFunc::Add, // Using Add as a placeholder for conditional logic
-            expr1: Box::new(condition_expr),
-            expr2: Box::new(result_expr),
-        };
+        branches.push((condition_expr, result_expr));
     }
 
-    Ok(current_expr)
+    let else_expr = else_result
+        .as_ref()
+        .map(|else_expr| convert_expr_to_scalar_internal(else_expr, bindings, custom_func_registry))
+        .transpose()?
+        .map(Box::new);
+
+    Ok(ScalarExpr::Case {
+        branches,
+        else_expr,
+    })
 }
 
 /// Extract expressions from SQL SELECT statement
@@ -794,30 +1264,6 @@ pub fn extract_select_expressions(sql: &str) -> Result<Vec<ScalarExpr>, Conversi
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn convert_identifier_wildcard_becomes_scalar_wildcard() {
-        let expr = Expr::Identifier(Ident::new("*"));
-        let scalar = convert_expr_to_scalar(&expr).expect("conversion");
-        assert!(matches!(scalar, ScalarExpr::Wildcard { source_name: None }));
-    }
-
-    #[test]
-    fn convert_compound_wildcard_tracks_prefix() {
-        let expr = Expr::CompoundIdentifier(vec![Ident::new("orders"), Ident::new("*")]);
-        let scalar = convert_expr_to_scalar(&expr).expect("conversion");
-        match scalar {
-            ScalarExpr::Wildcard {
-                source_name: Some(prefix),
-            } => assert_eq!(prefix, "orders"),
-            other => panic!("unexpected scalar expr: {:?}", other),
-        }
-    }
-}
-
 /// Convert SelectStmt to ScalarExpr with aliases
 pub fn convert_select_stmt_to_scalar(
     select_stmt: &parser::SelectStmt,
@@ -861,3 +1307,27 @@ impl Default for StreamSqlConverter {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_identifier_wildcard_becomes_scalar_wildcard() {
+        let expr = Expr::Identifier(Ident::new("*"));
+        let scalar = convert_expr_to_scalar(&expr).expect("conversion");
+        assert!(matches!(scalar, ScalarExpr::Wildcard { source_name: None }));
+    }
+
+    #[test]
+    fn convert_compound_wildcard_tracks_prefix() {
+        let expr = Expr::CompoundIdentifier(vec![Ident::new("orders"), Ident::new("*")]);
+        let scalar = convert_expr_to_scalar(&expr).expect("conversion");
+        match scalar {
+            ScalarExpr::Wildcard {
+                source_name: Some(prefix),
+            } => assert_eq!(prefix, "orders"),
+            other => panic!("unexpected scalar expr: {:?}", other),
+        }
+    }
+}
@@ -3,15 +3,20 @@ use std::sync::{Arc, Mutex};
 
 use crate::aggregation::AggregateFunction;
 use crate::aggregation::AggregateFunctionRegistry;
-use crate::catalog::{Catalog, CatalogError, StreamDefinition, StreamProps};
+use crate::catalog::{
+    decode_table_json, load_table_file, Catalog, CatalogError, StreamDefinition, StreamProps,
+    TableError, TableSource,
+};
 use crate::codec::{CodecError, DecoderRegistry, EncoderRegistry};
 use crate::connector::{
     ConnectorError, ConnectorRegistry, MqttClientManager, SharedMqttClientConfig,
 };
 use crate::eventtime::EventtimeTypeRegistry;
 use crate::expr::custom_func::{CustomFunc, CustomFuncRegistry, CustomFuncRegistryError};
-use crate::pipeline::{PipelineDefinition, PipelineError, PipelineManager, PipelineSnapshot};
-use crate::processor::ProcessorPipeline;
+use crate::pipeline::{
+    PipelineDefinition, PipelineError, PipelineManager, PipelineRuntimeStatus, PipelineSnapshot,
+};
+use crate::processor::{PipelineCheckpoint, ProcessorPipeline};
 use crate::shared_stream::{
     registry as shared_stream_registry, SharedStreamConfig, SharedStreamError, SharedStreamInfo,
     SharedStreamRegistry,
@@ -119,6 +124,18 @@ impl FlowInstance {
         definition: StreamDefinition,
         shared: bool,
     ) -> Result<StreamRuntimeInfo, FlowInstanceError> {
+        if let Some(props) = definition.table_props() {
+            if shared {
+                return Err(FlowInstanceError::Invalid(format!(
+                    "table {} cannot be created as a shared stream",
+                    definition.id()
+                )));
+            }
+            if let TableSource::File { path, format } = &props.source {
+                let rows = load_table_file(definition.id(), definition.schema(), path, *format)?;
+                props.data().replace(rows);
+            }
+        }
         let stored = self.catalog.insert(definition)?;
         let shared_info = if shared {
             match self.ensure_shared_stream(stored.clone()).await {
@@ -182,6 +199,45 @@ impl FlowInstance {
         Ok(())
     }
 
+    /// Reload a file-backed table from its source file. Running pipelines see the new rows on
+    /// their next lookup. Returns the number of rows loaded.
+    pub fn reload_table(&self, name: &str) -> Result<usize, FlowInstanceError> {
+        let definition = self
+            .catalog
+            .get(name)
+            .ok_or_else(|| CatalogError::NotFound(name.to_string()))?;
+        let props = definition
+            .table_props()
+            .ok_or_else(|| TableError::NotATable(name.to_string()))?;
+        let TableSource::File { path, format } = &props.source else {
+            return Err(TableError::NotReloadable(name.to_string()).into());
+        };
+        let rows = load_table_file(name, definition.schema(), path, *format)?;
+        let count = rows.len();
+        props.data().replace(rows);
+        Ok(count)
+    }
+
+    /// Replace all rows of a table with the rows of a JSON array of objects.
+    /// Returns the number of rows stored.
+    pub fn replace_table_rows(
+        &self,
+        name: &str,
+        payload: &[u8],
+    ) -> Result<usize, FlowInstanceError> {
+        let definition = self
+            .catalog
+            .get(name)
+            .ok_or_else(|| CatalogError::NotFound(name.to_string()))?;
+        let props = definition
+            .table_props()
+            .ok_or_else(|| TableError::NotATable(name.to_string()))?;
+        let rows = decode_table_json(name, definition.schema(), payload)?;
+        let count = rows.len();
+        props.data().replace(rows);
+        Ok(count)
+    }
+
     /// Register a shared MQTT client that can be referenced by connector keys.
     pub async fn create_shared_mqtt_client(
         &self,
@@ -248,21 +304,85 @@ impl FlowInstance {
         self.pipeline_manager.start_pipeline(id)
     }
 
+    /// Gracefully stop a running pipeline without removing it.
+    pub async fn stop_pipeline(&self, id: &str) -> Result<(), PipelineError> {
+        self.pipeline_manager.stop_pipeline(id).await
+    }
+
+    /// Stop a pipeline if it is running and start it again.
+    pub async fn restart_pipeline(&self, id: &str) -> Result<(), PipelineError> {
+        self.pipeline_manager.restart_pipeline(id).await
+    }
+
+    /// Rebuild an existing pipeline from an updated definition.
+    pub async fn update_pipeline(
+        &self,
+        definition: PipelineDefinition,
+    ) -> Result<PipelineSnapshot, PipelineError> {
+        self.pipeline_manager.update_pipeline(definition).await
+    }
+
+    /// Rebuild an existing pipeline, also returning the logical plan IR of the new runtime.
+    pub async fn update_pipeline_with_logical_ir(
+        &self,
+        definition: PipelineDefinition,
+    ) -> Result<(PipelineSnapshot, Vec<u8>), PipelineError> {
+        self.pipeline_manager
+            .update_pipeline_with_logical_ir(definition)
+            .await
+    }
+
     /// Stop and delete a pipeline.
     pub async fn delete_pipeline(&self, id: &str) -> Result<(), PipelineError> {
         self.pipeline_manager.delete_pipeline(id).await
     }
 
+    /// Capture the processor state of a running pipeline.
+    pub async fn checkpoint_pipeline(&self, id: &str) -> Result<PipelineCheckpoint, PipelineError> {
+        self.pipeline_manager.checkpoint_pipeline(id).await
+    }
+
+    /// Resume a pipeline from a previous checkpoint before it is started.
+    pub fn restore_pipeline_checkpoint(
+        &self,
+        id: &str,
+        checkpoint: PipelineCheckpoint,
+    ) -> Result<(), PipelineError> {
+        self.pipeline_manager
+            .restore_pipeline_checkpoint(id, checkpoint)
+    }
+
+    /// Retrieve the snapshot of a single pipeline.
+    pub fn get_pipeline(&self, id: &str) -> Option<PipelineSnapshot> {
+        self.pipeline_manager.get(id)
+    }
+
     /// Retrieve pipeline snapshots.
     pub fn list_pipelines(&self) -> Vec<PipelineSnapshot> {
         self.pipeline_manager.list()
     }
 
+    /// Status and per-processor counters of a pipeline.
+    pub fn pipeline_status(&self, id: &str) -> Result<PipelineRuntimeStatus, PipelineError> {
+        self.pipeline_manager.pipeline_status(id)
+    }
+
     /// Explain an existing pipeline by id (logical + physical plans).
     pub fn explain_pipeline(&self, id: &str) -> Result<PipelineExplain, PipelineError> {
         self.pipeline_manager.explain_pipeline(id)
     }
 
+    /// Explain a pipeline definition without creating it; see
+    /// [`PipelineManager::explain_definition`].
+    pub fn explain_definition(
+        &self,
+        definition: &PipelineDefinition,
+        streams: &[String],
+    ) -> Result<PipelineExplain, PipelineError> {
+        self.pipeline_manager
+            .explain_definition(definition, streams)
+    }
+
     /// Build a processor pipeline directly without registering it.
     pub fn build_pipeline(
         &self,
@@ -410,6 +530,18 @@ impl FlowInstance {
             StreamProps::Mock(_) => Err(FlowInstanceError::Invalid(
                 "mock stream props cannot be used to create shared streams".to_string(),
             )),
+            StreamProps::File(_) => Err(FlowInstanceError::Invalid(
+                "file stream props cannot be used to create shared streams".to_string(),
+            )),
+            StreamProps::Http(_) => Err(FlowInstanceError::Invalid(
+                "http stream props cannot be used to create shared streams".to_string(),
+            )),
+            StreamProps::Kafka(_) => Err(FlowInstanceError::Invalid(
+                "kafka stream props cannot be used to create shared streams".to_string(),
+            )),
+            StreamProps::Table(_) => Err(FlowInstanceError::Invalid(
+                "table props cannot be used to create shared streams".to_string(),
+            )),
         }
     }
 }
@@ -438,6 +570,8 @@ pub enum FlowInstanceError {
     Connector(#[from] ConnectorError),
     #[error(transparent)]
     Codec(#[from] CodecError),
+    #[error(transparent)]
+    Table(#[from] TableError),
     #[error("{0}")]
     Invalid(String),
 }
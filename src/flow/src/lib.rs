@@ -14,17 +14,18 @@ pub mod stateful;
 
 pub use aggregation::AggregateFunctionRegistry;
 pub use catalog::{
-    Catalog, CatalogError, EventtimeDefinition, MqttStreamProps, StreamDecoderConfig,
-    StreamDefinition, StreamProps, StreamType,
+    Catalog, CatalogError, EventtimeDefinition, FileStreamProps, HttpStreamProps, KafkaStreamProps,
+    LookupTable, MqttStreamProps, StreamDecoderConfig, StreamDefinition, StreamProps, StreamType,
+    TableError, TableFileFormat, TableSource, TableStreamProps,
 };
 pub use codec::{
-    CodecError, CollectionEncoder, CollectionEncoderStream, DecoderRegistry, EncodeError,
-    EncoderRegistry, JsonDecoder, JsonEncoder, RecordDecoder,
+    CodecError, CollectionEncoder, CollectionEncoderStream, CsvDecoder, CsvEncoder,
+    DecoderRegistry, EncodeError, EncoderRegistry, JsonDecoder, JsonEncoder, RecordDecoder,
 };
 pub use datatypes::{
     BooleanType, ColumnSchema, ConcreteDatatype, Float32Type, Float64Type, Int16Type, Int32Type,
-    Int64Type, Int8Type, ListType, Schema, StringType, StructField, StructType, Uint16Type,
-    Uint32Type, Uint64Type, Uint8Type,
+    Int64Type, Int8Type, ListType, Schema, StringType, StructField, StructType, Timestamp,
+    TimestampPrecision, TimestampType, Uint16Type, Uint32Type, Uint64Type, Uint8Type,
 };
 pub use eventtime::{
     BuiltinEventtimeType, EventtimeParseError, EventtimeTypeParser, EventtimeTypeRegistry,
@@ -38,8 +39,9 @@ pub use expr::{
 pub use instance::{FlowInstance, FlowInstanceError, StreamRuntimeInfo};
 pub use model::{Collection, RecordBatch};
 pub use pipeline::{
-    MqttSinkProps, PipelineDefinition, PipelineError, PipelineManager, PipelineOptions,
-    PipelineSnapshot, PipelineStatus, PlanCacheOptions, SinkDefinition, SinkProps, SinkType,
+    ErrorOptions, FileSinkProps, HttpSinkProps, KafkaSinkProps, MqttSinkProps, PipelineDefinition,
+    PipelineError, PipelineManager, PipelineOptions, PipelineRuntimeStatus, PipelineSnapshot,
+    PipelineStatus, PlanCacheOptions, SinkDefinition, SinkProps, SinkType,
 };
 pub use planner::create_physical_plan;
 pub use planner::explain::{ExplainReport, ExplainRow, PipelineExplain};
@@ -50,10 +52,11 @@ pub use planner::optimize_logical_plan;
 pub use planner::optimize_physical_plan;
 pub use planner::sink::{
     CommonSinkProps, NopSinkConfig, PipelineSink, PipelineSinkConnector, SinkConnectorConfig,
-    SinkEncoderConfig,
+    SinkEncoderConfig, SinkRetryProps,
 };
 pub use processor::{
-    ControlSignal, ControlSourceProcessor, DataSourceProcessor, Processor, ProcessorError,
+    ControlSignal, ControlSourceProcessor, DataSourceProcessor, ErrorPolicy, PipelineCheckpoint,
+    PipelineMetrics, Processor, ProcessorError, ProcessorErrorRecord, ProcessorMetrics,
     ResultCollectProcessor, SinkProcessor, StreamData,
 };
 pub use shared_stream::{
@@ -159,7 +159,8 @@ impl Collection for RecordBatch {
         for (row, result) in self.rows().iter().zip(filter_results.iter()) {
             match result {
                 Value::Bool(true) => selected_rows.push(row.clone()),
-                Value::Bool(false) => {}
+                // An unknown (NULL) predicate does not select the row.
+                Value::Bool(false) | Value::Null => {}
                 _ => {
                     return Err(CollectionError::FilterError {
                         message: format!(
@@ -90,9 +90,11 @@ impl AffiliateRow {
         }
     }
 
+    /// Derived columns in insertion order.
     pub fn entries(&self) -> impl Iterator<Item = (&Arc<String>, &Value)> {
-        self.index
-            .iter()
+        let mut keys: Vec<_> = self.index.iter().collect();
+        keys.sort_unstable_by_key(|(_, idx)| **idx);
+        keys.into_iter()
             .map(move |(key, idx)| (key, &self.values[*idx]))
     }
 
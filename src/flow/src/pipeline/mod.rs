@@ -1,16 +1,20 @@
 use crate::catalog::{Catalog, StreamDefinition, StreamProps};
 use crate::connector::{
-    register_mock_source_handle, MockSourceConnector, MqttClientManager, MqttSinkConfig,
-    MqttSourceConfig, MqttSourceConnector,
+    register_mock_source_handle, FileSinkConfig, FileSourceConfig, FileSourceConnector,
+    FileSyncPolicy, HttpSinkConfig, HttpSourceConfig, HttpSourceConnector, KafkaSinkConfig,
+    KafkaSourceConfig, KafkaSourceConnector, MockSourceConnector, MqttClientManager,
+    MqttSinkConfig, MqttSourceConfig, MqttSourceConnector,
 };
 use crate::expr::sql_conversion::{SchemaBinding, SchemaBindingEntry, SourceBindingKind};
 use crate::planner::logical::create_logical_plan;
 use crate::planner::plan_cache::{logical_plan_from_ir, sources_from_logical_ir, LogicalPlanIR};
 use crate::planner::sink::{CommonSinkProps, SinkEncoderConfig};
 use crate::processor::processor_builder::{PlanProcessor, ProcessorPipeline};
-use crate::processor::EventtimePipelineContext;
 use crate::processor::Processor;
 use crate::processor::{create_processor_pipeline, ProcessorPipelineDependencies};
+use crate::processor::{
+    ErrorPolicy, EventtimePipelineContext, PipelineCheckpoint, PipelineMetrics,
+};
 use crate::shared_stream::SharedStreamRegistry;
 use crate::{
     explain_pipeline_with_options, optimize_physical_plan, PipelineExplain, PipelineRegistries,
@@ -73,6 +77,12 @@ pub enum PipelineError {
 pub enum SinkType {
     /// MQTT sink.
     Mqtt,
+    /// Local file sink.
+    File,
+    /// HTTP webhook sink.
+    Http,
+    /// Kafka topic sink.
+    Kafka,
 }
 
 /// Sink configuration payload.
@@ -80,6 +90,12 @@ pub enum SinkType {
 pub enum SinkProps {
     /// MQTT sink configuration.
     Mqtt(MqttSinkProps),
+    /// File sink configuration.
+    File(FileSinkProps),
+    /// HTTP sink configuration.
+    Http(HttpSinkProps),
+    /// Kafka sink configuration.
+    Kafka(KafkaSinkProps),
 }
 
 /// Runtime state for pipeline execution.
@@ -128,8 +144,139 @@ impl MqttSinkProps {
     }
 }
 
+/// Concrete file sink configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSinkProps {
+    pub path: String,
+    /// Terminate payloads with a newline; defaults to whether the sink encodes JSON.
+    pub newline_delimited: Option<bool>,
+    pub max_file_bytes: Option<u64>,
+    pub rotate_interval: Option<Duration>,
+    pub compress: bool,
+    pub sync: FileSyncPolicy,
+}
+
+impl FileSinkProps {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            newline_delimited: None,
+            max_file_bytes: None,
+            rotate_interval: None,
+            compress: false,
+            sync: FileSyncPolicy::Never,
+        }
+    }
+
+    pub fn with_newline_delimited(mut self, newline_delimited: bool) -> Self {
+        self.newline_delimited = Some(newline_delimited);
+        self
+    }
+
+    pub fn with_max_file_bytes(mut self, max_file_bytes: u64) -> Self {
+        self.max_file_bytes = Some(max_file_bytes);
+        self
+    }
+
+    pub fn with_rotate_interval(mut self, rotate_interval: Duration) -> Self {
+        self.rotate_interval = Some(rotate_interval);
+        self
+    }
+
+    pub fn with_compress(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    pub fn with_sync(mut self, sync: FileSyncPolicy) -> Self {
+        self.sync = sync;
+        self
+    }
+}
+
+/// Concrete HTTP webhook sink configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpSinkProps {
+    pub url: String,
+    /// Request headers; `Content-Type` defaults to the sink encoder's media type.
+    pub headers: Vec<(String, String)>,
+    pub timeout: Option<Duration>,
+}
+
+impl HttpSinkProps {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: Vec::new(),
+            timeout: None,
+        }
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/// Concrete Kafka sink configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KafkaSinkProps {
+    pub brokers: String,
+    pub topic: String,
+    pub partition: Option<i32>,
+    pub key_column: Option<String>,
+    pub headers: Vec<(String, String)>,
+    pub delivery_timeout: Option<Duration>,
+    /// Extra librdkafka producer properties.
+    pub properties: Vec<(String, String)>,
+}
+
+impl KafkaSinkProps {
+    pub fn new(brokers: impl Into<String>, topic: impl Into<String>) -> Self {
+        Self {
+            brokers: brokers.into(),
+            topic: topic.into(),
+            partition: None,
+            key_column: None,
+            headers: Vec::new(),
+            delivery_timeout: None,
+            properties: Vec::new(),
+        }
+    }
+
+    pub fn with_partition(mut self, partition: i32) -> Self {
+        self.partition = Some(partition);
+        self
+    }
+
+    pub fn with_key_column(mut self, column: impl Into<String>) -> Self {
+        self.key_column = Some(column.into());
+        self
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn with_delivery_timeout(mut self, timeout: Duration) -> Self {
+        self.delivery_timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.push((key.into(), value.into()));
+        self
+    }
+}
+
 /// Sink definition for a pipeline.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SinkDefinition {
     pub sink_id: String,
     pub sink_type: SinkType,
@@ -206,6 +353,7 @@ impl PipelineDefinition {
 pub struct PipelineOptions {
     pub plan_cache: PlanCacheOptions,
     pub eventtime: EventtimeOptions,
+    pub errors: ErrorOptions,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -223,6 +371,14 @@ impl Default for EventtimeOptions {
     }
 }
 
+/// How records that fail to decode or evaluate are handled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorOptions {
+    pub policy: ErrorPolicy,
+    /// Sink receiving failed records; required by [`ErrorPolicy::DeadLetter`].
+    pub dead_letter: Option<SinkDefinition>,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct PlanCacheOptions {
     pub enabled: bool,
@@ -253,6 +409,13 @@ pub struct PipelineSnapshot {
     pub status: PipelineStatus,
 }
 
+/// Status of a pipeline together with the counters of its current runtime.
+#[derive(Clone)]
+pub struct PipelineRuntimeStatus {
+    pub snapshot: PipelineSnapshot,
+    pub metrics: PipelineMetrics,
+}
+
 /// Stores all registered pipelines and manages their lifecycle.
 pub struct PipelineManager {
     pipelines: RwLock<HashMap<String, ManagedPipeline>>,
@@ -422,6 +585,24 @@ impl PipelineManager {
         guard.values().map(|entry| entry.snapshot()).collect()
     }
 
+    /// Status and per-processor counters of a pipeline.
+    ///
+    /// Counters belong to the current runtime, so they restart from zero after a stop, restart
+    /// or update.
+    pub fn pipeline_status(
+        &self,
+        pipeline_id: &str,
+    ) -> Result<PipelineRuntimeStatus, PipelineError> {
+        let guard = self.pipelines.read().expect("pipeline manager poisoned");
+        let entry = guard
+            .get(pipeline_id)
+            .ok_or_else(|| PipelineError::NotFound(pipeline_id.to_string()))?;
+        Ok(PipelineRuntimeStatus {
+            snapshot: entry.snapshot(),
+            metrics: entry.pipeline.metrics(),
+        })
+    }
+
     /// Explain an existing pipeline by id (logical + physical plans).
     pub fn explain_pipeline(&self, pipeline_id: &str) -> Result<PipelineExplain, PipelineError> {
         let definition = {
@@ -431,14 +612,38 @@ impl PipelineManager {
                 .ok_or_else(|| PipelineError::NotFound(pipeline_id.to_string()))?;
             Arc::clone(&entry.definition)
         };
+        self.explain_definition(&definition, &[])
+    }
+
+    /// Explain a definition without registering or building a pipeline for it.
+    ///
+    /// When `streams` is not empty the SQL only sees those streams, so a plan can be checked
+    /// against the inputs it is meant to read.
+    pub fn explain_definition(
+        &self,
+        definition: &PipelineDefinition,
+        streams: &[String],
+    ) -> Result<PipelineExplain, PipelineError> {
+        let restricted;
+        let catalog = if streams.is_empty() {
+            self.catalog.as_ref()
+        } else {
+            restricted = Catalog::new();
+            for stream in streams {
+                let stream_definition = self.catalog.get(stream).ok_or_else(|| {
+                    PipelineError::BuildFailure(format!("stream '{stream}' not found in catalog"))
+                })?;
+                restricted.upsert(stream_definition.as_ref().clone());
+            }
+            &restricted
+        };
 
-        let sinks =
-            build_sinks_from_definition(&definition).map_err(PipelineError::BuildFailure)?;
+        let sinks = build_sinks_from_definition(definition).map_err(PipelineError::BuildFailure)?;
 
         explain_pipeline_with_options(
             definition.sql(),
             sinks,
-            &self.catalog,
+            catalog,
             self.shared_stream_registry,
             &self.registries,
             definition.options(),
@@ -460,6 +665,140 @@ impl PipelineManager {
         Ok(())
     }
 
+    /// Gracefully close a running pipeline and keep it registered, not started, so it can be
+    /// started again. Stopping a pipeline that is not running is a no-op.
+    pub async fn stop_pipeline(&self, pipeline_id: &str) -> Result<(), PipelineError> {
+        let definition = {
+            let guard = self.pipelines.read().expect("pipeline manager poisoned");
+            let entry = guard
+                .get(pipeline_id)
+                .ok_or_else(|| PipelineError::NotFound(pipeline_id.to_string()))?;
+            if !matches!(entry.status, PipelineStatus::Running) {
+                return Ok(());
+            }
+            Arc::clone(&entry.definition)
+        };
+        // A closed runtime cannot be started again, so build its replacement up front; a build
+        // failure then leaves the running pipeline untouched.
+        self.replace_runtime(definition).await.map(|_| ())
+    }
+
+    /// Stop the pipeline if it is running, then start it on a fresh runtime.
+    pub async fn restart_pipeline(&self, pipeline_id: &str) -> Result<(), PipelineError> {
+        self.stop_pipeline(pipeline_id).await?;
+        self.start_pipeline(pipeline_id)
+    }
+
+    /// Replace the definition of an existing pipeline, e.g. to change its SQL.
+    ///
+    /// The new runtime is built before the old one is closed, so an invalid definition keeps the
+    /// current pipeline as it is. A pipeline that was running is started again.
+    pub async fn update_pipeline(
+        &self,
+        definition: PipelineDefinition,
+    ) -> Result<PipelineSnapshot, PipelineError> {
+        self.update_pipeline_with_logical_ir(definition)
+            .await
+            .map(|(snapshot, _)| snapshot)
+    }
+
+    /// [`Self::update_pipeline`], also returning the logical plan IR of the new runtime.
+    pub async fn update_pipeline_with_logical_ir(
+        &self,
+        definition: PipelineDefinition,
+    ) -> Result<(PipelineSnapshot, Vec<u8>), PipelineError> {
+        let pipeline_id = definition.id().to_string();
+        if self.get(&pipeline_id).is_none() {
+            return Err(PipelineError::NotFound(pipeline_id));
+        }
+        let (was_running, logical_ir) = self.replace_runtime(Arc::new(definition)).await?;
+        if was_running {
+            self.start_pipeline(&pipeline_id)?;
+        }
+        let snapshot = self
+            .get(&pipeline_id)
+            .ok_or(PipelineError::NotFound(pipeline_id))?;
+        Ok((snapshot, logical_ir))
+    }
+
+    /// Swap in a fresh runtime for `definition` and close the old one if it was running.
+    ///
+    /// Returns whether the old runtime was running, and the logical plan IR of the new one.
+    async fn replace_runtime(
+        &self,
+        definition: Arc<PipelineDefinition>,
+    ) -> Result<(bool, Vec<u8>), PipelineError> {
+        let pipeline_id = definition.id().to_string();
+        let (pipeline, streams, logical_ir) = build_pipeline_runtime_with_logical_ir(
+            &definition,
+            &self.catalog,
+            self.shared_stream_registry,
+            &self.mqtt_client_manager,
+            &self.registries,
+        )
+        .map_err(PipelineError::BuildFailure)?;
+        let (old, was_running) = {
+            let mut guard = self.pipelines.write().expect("pipeline manager poisoned");
+            let entry = guard
+                .get_mut(&pipeline_id)
+                .ok_or_else(|| PipelineError::NotFound(pipeline_id.clone()))?;
+            let was_running = matches!(entry.status, PipelineStatus::Running);
+            entry.definition = definition;
+            entry.streams = streams;
+            entry.status = PipelineStatus::Created;
+            (
+                std::mem::replace(&mut entry.pipeline, pipeline),
+                was_running,
+            )
+        };
+        // Processors may have been dropped or renamed; the new runtime registers its own series
+        // when it starts.
+        old.remove_metrics();
+        if was_running {
+            close_pipeline(old).await?;
+        }
+        Ok((was_running, logical_ir))
+    }
+
+    /// Capture the processor state of a running pipeline.
+    pub async fn checkpoint_pipeline(
+        &self,
+        pipeline_id: &str,
+    ) -> Result<PipelineCheckpoint, PipelineError> {
+        let trigger = {
+            let guard = self.pipelines.read().expect("pipeline manager poisoned");
+            let entry = guard
+                .get(pipeline_id)
+                .ok_or_else(|| PipelineError::NotFound(pipeline_id.to_string()))?;
+            if !matches!(entry.status, PipelineStatus::Running) {
+                return Err(PipelineError::Runtime(format!(
+                    "pipeline {pipeline_id} is not running"
+                )));
+            }
+            entry.pipeline.checkpoint_trigger()
+        };
+        trigger
+            .trigger()
+            .await
+            .map_err(|err| PipelineError::Runtime(format!("checkpoint failed: {err}")))
+    }
+
+    /// Resume a pipeline from a previous checkpoint; only allowed before it is started.
+    pub fn restore_pipeline_checkpoint(
+        &self,
+        pipeline_id: &str,
+        checkpoint: PipelineCheckpoint,
+    ) -> Result<(), PipelineError> {
+        let mut guard = self.pipelines.write().expect("pipeline manager poisoned");
+        let entry = guard
+            .get_mut(pipeline_id)
+            .ok_or_else(|| PipelineError::NotFound(pipeline_id.to_string()))?;
+        entry
+            .pipeline
+            .restore_checkpoint(checkpoint)
+            .map_err(|err| PipelineError::Runtime(err.to_string()))
+    }
+
     /// Remove a pipeline runtime and close it if running.
     pub async fn delete_pipeline(&self, pipeline_id: &str) -> Result<(), PipelineError> {
         let maybe_entry = {
@@ -467,6 +806,7 @@ impl PipelineManager {
             guard.remove(pipeline_id)
         };
         let entry = maybe_entry.ok_or_else(|| PipelineError::NotFound(pipeline_id.to_string()))?;
+        entry.pipeline.remove_metrics();
         if matches!(entry.status, PipelineStatus::Running) {
             let pipeline_id = entry.definition.id().to_string();
             tokio::spawn(async move {
@@ -608,6 +948,7 @@ fn build_pipeline_runtime_with_logical_ir(
         None
     };
 
+    let dead_letter = build_dead_letter_sink(definition)?;
     let mut pipeline = create_processor_pipeline(
         optimized_plan,
         ProcessorPipelineDependencies::new(
@@ -618,7 +959,8 @@ fn build_pipeline_runtime_with_logical_ir(
             registries.aggregate_registry(),
             registries.stateful_registry(),
             eventtime,
-        ),
+        )
+        .with_error_policy(definition.options().errors.policy, dead_letter),
     )
     .map_err(|err| err.to_string())?;
     pipeline.set_pipeline_id(definition.id().to_string());
@@ -714,6 +1056,7 @@ fn build_pipeline_runtime_from_logical_ir(
         None
     };
 
+    let dead_letter = build_dead_letter_sink(definition)?;
     let mut pipeline = create_processor_pipeline(
         optimized_plan,
         ProcessorPipelineDependencies::new(
@@ -724,7 +1067,8 @@ fn build_pipeline_runtime_from_logical_ir(
             registries.aggregate_registry(),
             registries.stateful_registry(),
             eventtime,
-        ),
+        )
+        .with_error_policy(definition.options().errors.policy, dead_letter),
     )
     .map_err(|err| err.to_string())?;
 
@@ -737,42 +1081,136 @@ fn build_pipeline_runtime_from_logical_ir(
 fn build_sinks_from_definition(
     definition: &PipelineDefinition,
 ) -> Result<Vec<PipelineSink>, String> {
-    let mut sinks = Vec::with_capacity(definition.sinks().len());
-    for sink in definition.sinks() {
-        match sink.sink_type {
-            SinkType::Mqtt => {
-                let SinkProps::Mqtt(props) = &sink.props;
-                let mut config = MqttSinkConfig::new(
-                    sink.sink_id.clone(),
-                    props.broker_url.clone(),
-                    if props.topic.is_empty() {
-                        DEFAULT_SINK_TOPIC.to_string()
-                    } else {
-                        props.topic.clone()
-                    },
-                    props.qos,
-                );
-                config = config.with_retain(props.retain);
-                let client_id = props
-                    .client_id
-                    .clone()
-                    .unwrap_or_else(|| format!("{}-{}", definition.id(), sink.sink_id));
-                config = config.with_client_id(client_id);
-                if let Some(conn_key) = &props.connector_key {
-                    config = config.with_connector_key(conn_key.clone());
-                }
-                let connector = PipelineSinkConnector::new(
-                    sink.sink_id.clone(),
-                    SinkConnectorConfig::Mqtt(config),
-                    sink.encoder.clone(),
-                );
-                let pipeline_sink = PipelineSink::new(sink.sink_id.clone(), connector)
-                    .with_common_props(sink.common.clone());
-                sinks.push(pipeline_sink);
+    definition
+        .sinks()
+        .iter()
+        .map(|sink| build_pipeline_sink(definition, sink))
+        .collect()
+}
+
+fn build_dead_letter_sink(definition: &PipelineDefinition) -> Result<Option<PipelineSink>, String> {
+    definition
+        .options()
+        .errors
+        .dead_letter
+        .as_ref()
+        .map(|sink| build_pipeline_sink(definition, sink))
+        .transpose()
+}
+
+fn build_pipeline_sink(
+    definition: &PipelineDefinition,
+    sink: &SinkDefinition,
+) -> Result<PipelineSink, String> {
+    match (&sink.sink_type, &sink.props) {
+        (SinkType::Mqtt, SinkProps::Mqtt(props)) => {
+            let mut config = MqttSinkConfig::new(
+                sink.sink_id.clone(),
+                props.broker_url.clone(),
+                if props.topic.is_empty() {
+                    DEFAULT_SINK_TOPIC.to_string()
+                } else {
+                    props.topic.clone()
+                },
+                props.qos,
+            );
+            config = config.with_retain(props.retain);
+            let client_id = props
+                .client_id
+                .clone()
+                .unwrap_or_else(|| format!("{}-{}", definition.id(), sink.sink_id));
+            config = config.with_client_id(client_id);
+            if let Some(conn_key) = &props.connector_key {
+                config = config.with_connector_key(conn_key.clone());
+            }
+            let connector = PipelineSinkConnector::new(
+                sink.sink_id.clone(),
+                SinkConnectorConfig::Mqtt(config),
+                sink.encoder.clone(),
+            );
+            Ok(PipelineSink::new(sink.sink_id.clone(), connector)
+                .with_common_props(sink.common.clone()))
+        }
+        (SinkType::File, SinkProps::File(props)) => {
+            let newline_delimited = props
+                .newline_delimited
+                .unwrap_or_else(|| sink.encoder.kind() == "json");
+            let mut config = FileSinkConfig::new(sink.sink_id.clone(), props.path.clone())
+                .with_newline_delimited(newline_delimited)
+                .with_compress(props.compress)
+                .with_sync(props.sync);
+            if let Some(max_file_bytes) = props.max_file_bytes {
+                config = config.with_max_file_bytes(max_file_bytes);
+            }
+            if let Some(rotate_interval) = props.rotate_interval {
+                config = config.with_rotate_interval(rotate_interval);
+            }
+            let connector = PipelineSinkConnector::new(
+                sink.sink_id.clone(),
+                SinkConnectorConfig::File(config),
+                sink.encoder.clone(),
+            );
+            Ok(PipelineSink::new(sink.sink_id.clone(), connector)
+                .with_common_props(sink.common.clone()))
+        }
+        (SinkType::Http, SinkProps::Http(props)) => {
+            let mut config = HttpSinkConfig::new(sink.sink_id.clone(), props.url.clone());
+            for (name, value) in &props.headers {
+                config = config.with_header(name.clone(), value.clone());
+            }
+            if !config.has_header("content-type") {
+                let content_type = match sink.encoder.kind() {
+                    "json" => "application/json",
+                    "csv" => "text/csv",
+                    _ => "application/octet-stream",
+                };
+                config = config.with_header("content-type", content_type);
+            }
+            if let Some(timeout) = props.timeout {
+                config = config.with_timeout(timeout);
+            }
+            let connector = PipelineSinkConnector::new(
+                sink.sink_id.clone(),
+                SinkConnectorConfig::Http(config),
+                sink.encoder.clone(),
+            );
+            Ok(PipelineSink::new(sink.sink_id.clone(), connector)
+                .with_common_props(sink.common.clone()))
+        }
+        (SinkType::Kafka, SinkProps::Kafka(props)) => {
+            let mut config = KafkaSinkConfig::new(
+                sink.sink_id.clone(),
+                props.brokers.clone(),
+                props.topic.clone(),
+            );
+            if let Some(partition) = props.partition {
+                config = config.with_partition(partition);
+            }
+            if let Some(key_column) = &props.key_column {
+                config = config.with_key_column(key_column.clone());
+            }
+            for (name, value) in &props.headers {
+                config = config.with_header(name.clone(), value.clone());
+            }
+            if let Some(timeout) = props.delivery_timeout {
+                config = config.with_delivery_timeout(timeout);
             }
+            for (key, value) in &props.properties {
+                config = config.with_property(key.clone(), value.clone());
+            }
+            let connector = PipelineSinkConnector::new(
+                sink.sink_id.clone(),
+                SinkConnectorConfig::Kafka(config),
+                sink.encoder.clone(),
+            );
+            Ok(PipelineSink::new(sink.sink_id.clone(), connector)
+                .with_common_props(sink.common.clone()))
         }
+        (sink_type, _) => Err(format!(
+            "sink {} has props that do not match its type {sink_type:?}",
+            sink.sink_id
+        )),
     }
-    Ok(sinks)
 }
 
 fn attach_sources_from_catalog(
@@ -812,6 +1250,47 @@ fn attach_sources_from_catalog(
                     );
                     ds.add_connector(Box::new(connector));
                 }
+                StreamProps::File(file_props) => {
+                    let config = FileSourceConfig::new(processor_id.clone(), &file_props.path)
+                        .with_framing(file_props.framing)
+                        .with_mode(file_props.mode);
+                    let connector = FileSourceConnector::new(
+                        format!("{processor_id}_file_source_connector"),
+                        config,
+                    );
+                    ds.add_connector(Box::new(connector));
+                }
+                StreamProps::Http(http_props) => {
+                    let config = HttpSourceConfig::new(processor_id.clone(), &http_props.path);
+                    let connector = HttpSourceConnector::new(
+                        format!("{processor_id}_http_source_connector"),
+                        config,
+                    );
+                    ds.add_connector(Box::new(connector));
+                }
+                StreamProps::Kafka(kafka_props) => {
+                    // A stable default group keeps committed offsets across restarts.
+                    let group_id = kafka_props
+                        .group_id
+                        .clone()
+                        .unwrap_or_else(|| format!("{pipeline_id}-{stream_name}"));
+                    let mut config = KafkaSourceConfig::new(
+                        processor_id.clone(),
+                        kafka_props.brokers.clone(),
+                        kafka_props.topic.clone(),
+                        group_id,
+                    )
+                    .with_partitions(kafka_props.partitions.clone())
+                    .with_start_offset(kafka_props.start_offset);
+                    for (key, value) in &kafka_props.properties {
+                        config = config.with_property(key.clone(), value.clone());
+                    }
+                    let connector = KafkaSourceConnector::new(
+                        format!("{processor_id}_kafka_source_connector"),
+                        config,
+                    );
+                    ds.add_connector(Box::new(connector));
+                }
                 StreamProps::Mock(_) => {
                     let (connector, handle) =
                         MockSourceConnector::new(format!("{processor_id}_mock_source_connector"));
@@ -819,10 +1298,27 @@ fn attach_sources_from_catalog(
                     register_mock_source_handle(key, handle);
                     ds.add_connector(Box::new(connector));
                 }
+                StreamProps::Table(_) => {
+                    return Err(format!(
+                        "table {stream_name} can only be used as the lookup side of a JOIN"
+                    ));
+                }
             }
             continue;
         }
 
+        if let PlanProcessor::LookupJoin(lookup) = processor {
+            let table_name = lookup.table_name().to_string();
+            let table = stream_defs
+                .get(&table_name)
+                .and_then(|definition| definition.table_props())
+                .ok_or_else(|| {
+                    format!("table {table_name} missing definition when attaching sources")
+                })?;
+            lookup.set_table(table.data().clone());
+            continue;
+        }
+
         if matches!(processor, PlanProcessor::SharedSource(_)) {
             has_source_processor = true;
         }
@@ -835,7 +1331,8 @@ fn attach_sources_from_catalog(
     }
 }
 
-/// Attach source connectors for every `DataSourceProcessor` in the pipeline using the catalog.
+/// Attach source connectors for every `DataSourceProcessor` in the pipeline using the catalog,
+/// and the table rows for every `LookupJoinProcessor`.
 ///
 /// For mock streams this will create a `MockSourceConnector` and register a corresponding
 /// `MockSourceHandle` under key `"{pipeline_id}:{stream_name}:{processor_id}"`.
@@ -853,6 +1350,13 @@ pub fn attach_sources_for_pipeline(
                 .ok_or_else(|| format!("stream {stream_name} not found in catalog"))?;
             stream_definitions.insert(stream_name, definition);
         }
+        if let PlanProcessor::LookupJoin(lookup) = processor {
+            let table_name = lookup.table_name().to_string();
+            let definition = catalog
+                .get(&table_name)
+                .ok_or_else(|| format!("table {table_name} not found in catalog"))?;
+            stream_definitions.insert(table_name, definition);
+        }
     }
     attach_sources_from_catalog(pipeline, &stream_definitions, mqtt_client_manager)
 }
@@ -861,7 +1365,8 @@ pub fn attach_sources_for_pipeline(
 mod tests {
     use super::*;
     use crate::catalog::{
-        Catalog, MqttStreamProps, StreamDecoderConfig, StreamDefinition, StreamProps,
+        Catalog, MockStreamProps, MqttStreamProps, StreamDecoderConfig, StreamDefinition,
+        StreamProps,
     };
     use crate::codec::JsonDecoder;
     use crate::connector::{MockSourceConnector, MqttClientManager};
@@ -869,6 +1374,7 @@ mod tests {
     use crate::shared_stream_registry;
     use datatypes::{ColumnSchema, ConcreteDatatype, Int64Type, Schema};
     use serde_json::Map as JsonMap;
+    use std::collections::BTreeSet;
     use std::sync::Arc;
     use tokio::runtime::Runtime;
     use uuid::Uuid;
@@ -959,6 +1465,182 @@ mod tests {
             .ok();
     }
 
+    #[test]
+    fn update_pipeline_replaces_definition() {
+        let catalog = Arc::new(Catalog::new());
+        let registry = shared_stream_registry();
+        let mqtt_manager = MqttClientManager::new();
+        install_stream(&catalog, "update_stream");
+        let registries = PipelineRegistries::new_with_builtin();
+        let manager = PipelineManager::new(
+            Arc::clone(&catalog),
+            registry,
+            mqtt_manager.clone(),
+            registries,
+        );
+        manager
+            .create_pipeline(sample_pipeline("update_pipe", "update_stream"))
+            .expect("create pipeline");
+
+        let runtime = Runtime::new().unwrap();
+        runtime
+            .block_on(manager.stop_pipeline("update_pipe"))
+            .expect("stopping a created pipeline is a no-op");
+
+        let mut updated = sample_pipeline("update_pipe", "update_stream");
+        updated.sql = "SELECT value FROM update_stream WHERE value > 1".to_string();
+        let (snapshot, logical_ir) = runtime
+            .block_on(manager.update_pipeline_with_logical_ir(updated))
+            .expect("update pipeline");
+        assert_eq!(snapshot.status, PipelineStatus::Created);
+        assert!(LogicalPlanIR::decode(&logical_ir).is_ok());
+        assert_eq!(
+            snapshot.definition.sql(),
+            "SELECT value FROM update_stream WHERE value > 1"
+        );
+
+        let mut invalid = sample_pipeline("update_pipe", "update_stream");
+        invalid.sql = "SELECT value FROM missing_stream".to_string();
+        let result = runtime.block_on(manager.update_pipeline(invalid));
+        assert!(matches!(result, Err(PipelineError::BuildFailure(_))));
+        assert_eq!(
+            manager.get("update_pipe").unwrap().definition.sql(),
+            "SELECT value FROM update_stream WHERE value > 1"
+        );
+
+        let missing = runtime
+            .block_on(manager.update_pipeline(sample_pipeline("missing_pipe", "update_stream")));
+        assert!(matches!(missing, Err(PipelineError::NotFound(_))));
+        runtime
+            .block_on(manager.delete_pipeline("update_pipe"))
+            .expect("delete pipeline");
+    }
+
+    /// Processor labels exported to Prometheus under `pipeline_id`.
+    fn exported_processors(pipeline_id: &str) -> BTreeSet<String> {
+        let mut processors = BTreeSet::new();
+        for family in prometheus::gather() {
+            if !family.get_name().starts_with("processor_") {
+                continue;
+            }
+            for metric in family.get_metric() {
+                let label = |name: &str| {
+                    metric
+                        .get_label()
+                        .iter()
+                        .find(|label| label.get_name() == name)
+                        .map(|label| label.get_value().to_string())
+                };
+                if label("pipeline").as_deref() == Some(pipeline_id) {
+                    processors.extend(label("processor"));
+                }
+            }
+        }
+        processors
+    }
+
+    fn runtime_processors(manager: &PipelineManager, pipeline_id: &str) -> BTreeSet<String> {
+        manager
+            .pipeline_status(pipeline_id)
+            .expect("pipeline status")
+            .metrics
+            .processors
+            .into_iter()
+            .map(|processor| processor.processor_id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn processor_series_follow_runtime_replacement_and_deletion() {
+        let catalog = Arc::new(Catalog::new());
+        let schema = Schema::new(vec![ColumnSchema::new(
+            "metrics_stream".to_string(),
+            "value".to_string(),
+            ConcreteDatatype::Int64(Int64Type),
+        )]);
+        catalog.upsert(StreamDefinition::new(
+            "metrics_stream".to_string(),
+            Arc::new(schema),
+            StreamProps::Mock(MockStreamProps::default()),
+            StreamDecoderConfig::json(),
+        ));
+        let manager = PipelineManager::new(
+            Arc::clone(&catalog),
+            shared_stream_registry(),
+            MqttClientManager::new(),
+            PipelineRegistries::new_with_builtin(),
+        );
+        let dir = std::env::temp_dir().join(format!("flow-metrics-{}", Uuid::new_v4()));
+        let definition = |sql: &str| {
+            let sink = SinkDefinition::new(
+                "metrics_sink".to_string(),
+                SinkType::File,
+                SinkProps::File(FileSinkProps::new(
+                    dir.join("out.json").display().to_string(),
+                )),
+            );
+            PipelineDefinition::new("metrics_pipe".to_string(), sql.to_string(), vec![sink])
+        };
+
+        manager
+            .create_pipeline(definition(
+                "SELECT value FROM metrics_stream WHERE value > 1",
+            ))
+            .expect("create pipeline");
+        manager
+            .start_pipeline("metrics_pipe")
+            .expect("start pipeline");
+        let before = runtime_processors(&manager, "metrics_pipe");
+        assert_eq!(exported_processors("metrics_pipe"), before);
+
+        // Dropping the filter removes its processor from the new runtime.
+        manager
+            .update_pipeline(definition("SELECT value FROM metrics_stream"))
+            .await
+            .expect("update pipeline");
+        let after = runtime_processors(&manager, "metrics_pipe");
+        assert!(!before.is_subset(&after));
+        assert_eq!(exported_processors("metrics_pipe"), after);
+
+        manager
+            .delete_pipeline("metrics_pipe")
+            .await
+            .expect("delete pipeline");
+        assert!(exported_processors("metrics_pipe").is_empty());
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn explain_definition_limits_visible_streams() {
+        let catalog = Arc::new(Catalog::new());
+        let registry = shared_stream_registry();
+        let mqtt_manager = MqttClientManager::new();
+        install_stream(&catalog, "explain_a");
+        install_stream(&catalog, "explain_b");
+        let registries = PipelineRegistries::new_with_builtin();
+        let manager = PipelineManager::new(
+            Arc::clone(&catalog),
+            registry,
+            mqtt_manager.clone(),
+            registries,
+        );
+
+        let definition = sample_pipeline("explain_pipe", "explain_a");
+        let explain = manager
+            .explain_definition(&definition, &[])
+            .expect("explain against the full catalog");
+        assert!(explain.physical.table_string().contains("explain_a"));
+        assert!(manager.list().is_empty());
+
+        manager
+            .explain_definition(&definition, &["explain_a".to_string()])
+            .expect("explain with the referenced stream");
+        let hidden = manager.explain_definition(&definition, &["explain_b".to_string()]);
+        assert!(matches!(hidden, Err(PipelineError::BuildFailure(_))));
+        let unknown = manager.explain_definition(&definition, &["missing".to_string()]);
+        assert!(matches!(unknown, Err(PipelineError::BuildFailure(_))));
+    }
+
     #[test]
     fn attach_sources_accepts_shared_stream_only_pipeline() {
         let runtime = Runtime::new().expect("runtime");
@@ -269,12 +269,39 @@ fn build_logical_node(plan: &Arc<LogicalPlan>) -> ExplainNode {
         LogicalPlan::Tail(tail) => {
             info.push(format!("sink_count={}", tail.base.children.len()));
         }
+        LogicalPlan::Join(join) => {
+            info.push("kind=inner".to_string());
+            info.push(format!("left={}", join.left_source));
+            info.push(format!("right={}", join.right_source));
+            info.push(format!("on={}", join.condition));
+            info.push(format!("within_ms={}", join.within.as_millis()));
+        }
+        LogicalPlan::LookupJoin(join) => {
+            info.push("kind=inner".to_string());
+            info.push(format!("stream={}", join.stream_source));
+            info.push(format!("table={}", join.table_name));
+            if let Some(alias) = &join.table_alias {
+                info.push(format!("alias={}", alias));
+            }
+            info.push(format!("on={}", join.condition));
+            info.push(format_schema(join.table_schema.as_ref()));
+        }
         LogicalPlan::Window(window) => match &window.spec {
             LogicalWindowSpec::Tumbling { time_unit, length } => {
                 info.push("kind=tumbling".to_string());
                 info.push(format!("unit={:?}", time_unit));
                 info.push(format!("length={}", length));
             }
+            LogicalWindowSpec::Hopping {
+                time_unit,
+                size,
+                hop,
+            } => {
+                info.push("kind=hopping".to_string());
+                info.push(format!("unit={:?}", time_unit));
+                info.push(format!("size={}", size));
+                info.push(format!("hop={}", hop));
+            }
             LogicalWindowSpec::Count { count } => {
                 info.push("kind=count".to_string());
                 info.push(format!("count={}", count));
@@ -292,6 +319,29 @@ fn build_logical_node(plan: &Arc<LogicalPlan>) -> ExplainNode {
                     None => info.push("lookahead=none".to_string()),
                 }
             }
+            LogicalWindowSpec::Session {
+                time_unit,
+                gap,
+                max_duration,
+                partition_by,
+            } => {
+                info.push("kind=session".to_string());
+                info.push(format!("unit={:?}", time_unit));
+                info.push(format!("gap={}", gap));
+                if let Some(max_duration) = max_duration {
+                    info.push(format!("max_duration={}", max_duration));
+                }
+                if !partition_by.is_empty() {
+                    info.push(format!(
+                        "partition_by={}",
+                        partition_by
+                            .iter()
+                            .map(|e| e.to_string())
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    ));
+                }
+            }
             LogicalWindowSpec::State {
                 open,
                 emit,
@@ -652,6 +702,16 @@ fn build_physical_node_with_prefix(
                     info.push(format!("unit={:?}", time_unit));
                     info.push(format!("length={}", length));
                 }
+                crate::planner::physical::StreamingWindowSpec::Hopping {
+                    time_unit,
+                    size,
+                    hop,
+                } => {
+                    info.push("window=hopping".to_string());
+                    info.push(format!("unit={:?}", time_unit));
+                    info.push(format!("size={}", size));
+                    info.push(format!("hop={}", hop));
+                }
                 crate::planner::physical::StreamingWindowSpec::Count { count } => {
                     info.push("window=count".to_string());
                     info.push(format!("count={}", count));
@@ -669,6 +729,30 @@ fn build_physical_node_with_prefix(
                         None => info.push("lookahead=none".to_string()),
                     }
                 }
+                crate::planner::physical::StreamingWindowSpec::Session {
+                    time_unit,
+                    gap,
+                    max_duration,
+                    partition_by_exprs,
+                    ..
+                } => {
+                    info.push("window=session".to_string());
+                    info.push(format!("unit={:?}", time_unit));
+                    info.push(format!("gap={}", gap));
+                    if let Some(max_duration) = max_duration {
+                        info.push(format!("max_duration={}", max_duration));
+                    }
+                    if !partition_by_exprs.is_empty() {
+                        info.push(format!(
+                            "partition_by={}",
+                            partition_by_exprs
+                                .iter()
+                                .map(|e| e.to_string())
+                                .collect::<Vec<_>>()
+                                .join(",")
+                        ));
+                    }
+                }
                 crate::planner::physical::StreamingWindowSpec::State {
                     open_expr,
                     emit_expr,
@@ -738,6 +822,27 @@ fn build_physical_node_with_prefix(
                     }
                 }
             }
+            WatermarkConfig::Hopping {
+                time_unit,
+                size,
+                hop,
+                strategy,
+            } => {
+                info.push("window=hopping".to_string());
+                info.push(format!("unit={:?}", time_unit));
+                info.push(format!("size={}", size));
+                info.push(format!("hop={}", hop));
+                match strategy {
+                    WatermarkStrategy::ProcessingTime { interval, .. } => {
+                        info.push("mode=processing_time".to_string());
+                        info.push(format!("interval={}", interval));
+                    }
+                    WatermarkStrategy::EventTime { late_tolerance } => {
+                        info.push("mode=event_time".to_string());
+                        info.push(format!("lateToleranceMs={}", late_tolerance.as_millis()));
+                    }
+                }
+            }
             WatermarkConfig::Sliding {
                 time_unit,
                 lookback,
@@ -762,6 +867,29 @@ fn build_physical_node_with_prefix(
                     }
                 }
             }
+            WatermarkConfig::Session {
+                time_unit,
+                gap,
+                max_duration,
+                strategy,
+            } => {
+                info.push("window=session".to_string());
+                info.push(format!("unit={:?}", time_unit));
+                info.push(format!("gap={}", gap));
+                if let Some(max_duration) = max_duration {
+                    info.push(format!("max_duration={}", max_duration));
+                }
+                match strategy {
+                    WatermarkStrategy::ProcessingTime { interval, .. } => {
+                        info.push("mode=processing_time".to_string());
+                        info.push(format!("interval={}", interval));
+                    }
+                    WatermarkStrategy::EventTime { late_tolerance } => {
+                        info.push("mode=event_time".to_string());
+                        info.push(format!("lateToleranceMs={}", late_tolerance.as_millis()));
+                    }
+                }
+            }
         },
         PhysicalPlan::EventtimeWatermark(watermark) => match &watermark.config {
             WatermarkConfig::Tumbling {
@@ -783,6 +911,27 @@ fn build_physical_node_with_prefix(
                     }
                 }
             }
+            WatermarkConfig::Hopping {
+                time_unit,
+                size,
+                hop,
+                strategy,
+            } => {
+                info.push("window=hopping".to_string());
+                info.push(format!("unit={:?}", time_unit));
+                info.push(format!("size={}", size));
+                info.push(format!("hop={}", hop));
+                match strategy {
+                    WatermarkStrategy::ProcessingTime { interval, .. } => {
+                        info.push("mode=processing_time".to_string());
+                        info.push(format!("interval={}", interval));
+                    }
+                    WatermarkStrategy::EventTime { late_tolerance } => {
+                        info.push("mode=event_time".to_string());
+                        info.push(format!("lateToleranceMs={}", late_tolerance.as_millis()));
+                    }
+                }
+            }
             WatermarkConfig::Sliding {
                 time_unit,
                 lookback,
@@ -807,6 +956,29 @@ fn build_physical_node_with_prefix(
                     }
                 }
             }
+            WatermarkConfig::Session {
+                time_unit,
+                gap,
+                max_duration,
+                strategy,
+            } => {
+                info.push("window=session".to_string());
+                info.push(format!("unit={:?}", time_unit));
+                info.push(format!("gap={}", gap));
+                if let Some(max_duration) = max_duration {
+                    info.push(format!("max_duration={}", max_duration));
+                }
+                match strategy {
+                    WatermarkStrategy::ProcessingTime { interval, .. } => {
+                        info.push("mode=processing_time".to_string());
+                        info.push(format!("interval={}", interval));
+                    }
+                    WatermarkStrategy::EventTime { late_tolerance } => {
+                        info.push("mode=event_time".to_string());
+                        info.push(format!("lateToleranceMs={}", late_tolerance.as_millis()));
+                    }
+                }
+            }
         },
         PhysicalPlan::Watermark(watermark) => match &watermark.config {
             WatermarkConfig::Tumbling {
@@ -828,6 +1000,27 @@ fn build_physical_node_with_prefix(
                     }
                 }
             }
+            WatermarkConfig::Hopping {
+                time_unit,
+                size,
+                hop,
+                strategy,
+            } => {
+                info.push("window=hopping".to_string());
+                info.push(format!("unit={:?}", time_unit));
+                info.push(format!("size={}", size));
+                info.push(format!("hop={}", hop));
+                match strategy {
+                    WatermarkStrategy::ProcessingTime { interval, .. } => {
+                        info.push("mode=processing_time".to_string());
+                        info.push(format!("interval={}", interval));
+                    }
+                    WatermarkStrategy::EventTime { late_tolerance } => {
+                        info.push("mode=event_time".to_string());
+                        info.push(format!("lateToleranceMs={}", late_tolerance.as_millis()));
+                    }
+                }
+            }
             WatermarkConfig::Sliding {
                 time_unit,
                 lookback,
@@ -852,12 +1045,41 @@ fn build_physical_node_with_prefix(
                     }
                 }
             }
+            WatermarkConfig::Session {
+                time_unit,
+                gap,
+                max_duration,
+                strategy,
+            } => {
+                info.push("window=session".to_string());
+                info.push(format!("unit={:?}", time_unit));
+                info.push(format!("gap={}", gap));
+                if let Some(max_duration) = max_duration {
+                    info.push(format!("max_duration={}", max_duration));
+                }
+                match strategy {
+                    WatermarkStrategy::ProcessingTime { interval, .. } => {
+                        info.push("mode=processing_time".to_string());
+                        info.push(format!("interval={}", interval));
+                    }
+                    WatermarkStrategy::EventTime { late_tolerance } => {
+                        info.push("mode=event_time".to_string());
+                        info.push(format!("lateToleranceMs={}", late_tolerance.as_millis()));
+                    }
+                }
+            }
         },
         PhysicalPlan::TumblingWindow(window) => {
             info.push("kind=tumbling".to_string());
             info.push(format!("unit={:?}", window.time_unit));
             info.push(format!("length={}", window.length));
         }
+        PhysicalPlan::HoppingWindow(window) => {
+            info.push("kind=hopping".to_string());
+            info.push(format!("unit={:?}", window.time_unit));
+            info.push(format!("size={}", window.size));
+            info.push(format!("hop={}", window.hop));
+        }
         PhysicalPlan::CountWindow(window) => {
             info.push("kind=count".to_string());
             info.push(format!("count={}", window.count));
@@ -871,6 +1093,25 @@ fn build_physical_node_with_prefix(
                 None => info.push("lookahead=none".to_string()),
             }
         }
+        PhysicalPlan::SessionWindow(window) => {
+            info.push("kind=session".to_string());
+            info.push(format!("unit={:?}", window.time_unit));
+            info.push(format!("gap={}", window.gap));
+            if let Some(max_duration) = window.max_duration {
+                info.push(format!("max_duration={}", max_duration));
+            }
+            if !window.partition_by_exprs.is_empty() {
+                info.push(format!(
+                    "partition_by={}",
+                    window
+                        .partition_by_exprs
+                        .iter()
+                        .map(|e| e.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ));
+            }
+        }
         PhysicalPlan::StateWindow(window) => {
             info.push("kind=state".to_string());
             info.push(format!("open={}", window.open_expr));
@@ -887,6 +1128,36 @@ fn build_physical_node_with_prefix(
                 ));
             }
         }
+        PhysicalPlan::StreamJoin(join) => {
+            info.push("kind=inner".to_string());
+            info.push(format!("left={}", join.left_source));
+            info.push(format!("right={}", join.right_source));
+            let keys = join
+                .key_predicates
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>();
+            info.push(format!("keys=[{}]", keys.join(", ")));
+            if !join.residual_predicates.is_empty() {
+                info.push(format!("on={}", join.condition));
+            }
+            info.push(format!("within_ms={}", join.within.as_millis()));
+        }
+        PhysicalPlan::LookupJoin(join) => {
+            info.push("kind=inner".to_string());
+            info.push(format!("stream={}", join.stream_source));
+            info.push(format!("table={}", join.table_name));
+            let keys = join
+                .key_predicates
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>();
+            info.push(format!("keys=[{}]", keys.join(", ")));
+            if !join.residual_predicates.is_empty() {
+                info.push(format!("on={}", join.condition));
+            }
+            info.push(format_schema(join.table_schema.as_ref()));
+        }
     }
 
     let mut children: Vec<ExplainNode> = plan
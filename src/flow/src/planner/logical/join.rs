@@ -0,0 +1,36 @@
+use crate::planner::logical::{BaseLogicalPlan, LogicalPlan};
+use sqlparser::ast::Expr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Logical plan node for an interval-bounded inner join between two streams.
+///
+/// Children are the left and right DataSource plans, in that order.
+#[derive(Debug, Clone)]
+pub struct LogicalJoin {
+    pub base: BaseLogicalPlan,
+    pub left_source: String,
+    pub right_source: String,
+    pub condition: Expr,
+    pub within: Duration,
+}
+
+impl LogicalJoin {
+    pub fn new(
+        left_source: String,
+        right_source: String,
+        condition: Expr,
+        within: Duration,
+        children: Vec<Arc<LogicalPlan>>,
+        index: i64,
+    ) -> Self {
+        let base = BaseLogicalPlan::new(children, index);
+        Self {
+            base,
+            left_source,
+            right_source,
+            condition,
+            within,
+        }
+    }
+}
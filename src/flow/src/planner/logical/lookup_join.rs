@@ -0,0 +1,41 @@
+use crate::planner::logical::{BaseLogicalPlan, LogicalPlan};
+use datatypes::Schema;
+use sqlparser::ast::Expr;
+use std::sync::Arc;
+
+/// Logical plan node for an inner join between a stream and a lookup table.
+///
+/// The only child is the stream side. The table is not a DataSource: its rows are looked up
+/// from the catalog at runtime, and `table_schema` holds the (possibly pruned) columns the
+/// pipeline reads from it.
+#[derive(Debug, Clone)]
+pub struct LogicalLookupJoin {
+    pub base: BaseLogicalPlan,
+    pub stream_source: String,
+    pub table_name: String,
+    pub table_alias: Option<String>,
+    pub table_schema: Arc<Schema>,
+    pub condition: Expr,
+}
+
+impl LogicalLookupJoin {
+    pub fn new(
+        stream_source: String,
+        table_name: String,
+        table_alias: Option<String>,
+        table_schema: Arc<Schema>,
+        condition: Expr,
+        children: Vec<Arc<LogicalPlan>>,
+        index: i64,
+    ) -> Self {
+        let base = BaseLogicalPlan::new(children, index);
+        Self {
+            base,
+            stream_source,
+            table_name,
+            table_alias,
+            table_schema,
+            condition,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::catalog::StreamDefinition;
+use crate::catalog::{StreamDefinition, StreamType};
 use parser::window as parser_window;
 use parser::SelectStmt;
 use std::collections::HashMap;
@@ -9,6 +9,8 @@ use datatypes::{ConcreteDatatype, Schema};
 pub mod aggregation;
 pub mod datasource;
 pub mod filter;
+pub mod join;
+pub mod lookup_join;
 pub mod project;
 pub mod sink;
 pub mod stateful_function;
@@ -19,6 +21,8 @@ use crate::planner::sink::PipelineSink;
 pub use aggregation::Aggregation;
 pub use datasource::DataSource;
 pub use filter::Filter;
+pub use join::LogicalJoin;
+pub use lookup_join::LogicalLookupJoin;
 pub use project::Project;
 pub use sink::DataSinkPlan;
 pub use stateful_function::StatefulFunctionPlan;
@@ -55,6 +59,8 @@ pub enum LogicalPlan {
     DataSink(DataSinkPlan),
     Tail(TailPlan),
     Window(LogicalWindow),
+    Join(LogicalJoin),
+    LookupJoin(LogicalLookupJoin),
 }
 
 impl LogicalPlan {
@@ -68,6 +74,8 @@ impl LogicalPlan {
             LogicalPlan::DataSink(plan) => plan.base.children(),
             LogicalPlan::Tail(plan) => plan.base.children(),
             LogicalPlan::Window(plan) => plan.base.children(),
+            LogicalPlan::Join(plan) => plan.base.children(),
+            LogicalPlan::LookupJoin(plan) => plan.base.children(),
         }
     }
 
@@ -81,6 +89,8 @@ impl LogicalPlan {
             LogicalPlan::DataSink(_) => "DataSink",
             LogicalPlan::Tail(_) => "Tail",
             LogicalPlan::Window(_) => "Window",
+            LogicalPlan::Join(_) => "Join",
+            LogicalPlan::LookupJoin(_) => "LookupJoin",
         }
     }
 
@@ -94,6 +104,8 @@ impl LogicalPlan {
             LogicalPlan::DataSink(plan) => plan.base.index(),
             LogicalPlan::Tail(plan) => plan.base.index(),
             LogicalPlan::Window(plan) => plan.base.index(),
+            LogicalPlan::Join(plan) => plan.base.index(),
+            LogicalPlan::LookupJoin(plan) => plan.base.index(),
         }
     }
 
@@ -122,6 +134,8 @@ impl LogicalPlan {
 ///
 /// The plan structure will be:
 /// - DataSource(s) (from SelectStmt::source_infos, one per source)
+/// - Join (from SelectStmt::join, if present) - takes the left and right DataSources as children
+/// - LookupJoin (from SelectStmt::join, if one side is a table) - takes the stream DataSource as child
 /// - StatefulFunction (from SelectStmt::stateful_mappings, if present) - takes DataSources as children
 /// - Window (from SelectStmt::window, if present) - takes DataSources as children
 /// - Aggregation (from SelectStmt::aggregate_mappings, if present) - takes Window or DataSources as children
@@ -153,6 +167,7 @@ pub fn create_logical_plan(
     }
 
     let mut current_plans: Vec<Arc<LogicalPlan>> = Vec::new();
+    let mut table_sources = Vec::new();
     for source_info in &select_stmt.source_infos {
         let definition = stream_defs.get(&source_info.name).ok_or_else(|| {
             format!(
@@ -160,6 +175,11 @@ pub fn create_logical_plan(
                 source_info.name
             )
         })?;
+        // Tables are looked up by a LookupJoin rather than read by a DataSource.
+        if definition.stream_type() == StreamType::Table {
+            table_sources.push((source_info, Arc::clone(definition)));
+            continue;
+        }
         let schema = definition.schema();
         let datasource = DataSource::new(
             source_info.name.clone(),
@@ -173,6 +193,74 @@ pub fn create_logical_plan(
         current_index += 1;
     }
 
+    // 1.5 Join the sources if the FROM clause declares a join
+    match (&select_stmt.join, table_sources.as_slice()) {
+        (None, []) => {}
+        (None, [(table, _), ..]) => {
+            return Err(format!(
+                "table {} can only be used as the lookup side of a JOIN",
+                table.name
+            ));
+        }
+        (Some(join), []) => {
+            let within = join.within.ok_or_else(|| {
+                format!(
+                    "JOIN between {} and {} requires a WITHIN INTERVAL bound",
+                    join.left.name, join.right.name
+                )
+            })?;
+            if current_plans.len() != 2 {
+                return Err("stream JOIN expects exactly two data sources".to_string());
+            }
+            let join_plan = LogicalJoin::new(
+                join.left.name.clone(),
+                join.right.name.clone(),
+                join.condition.clone(),
+                within,
+                current_plans,
+                current_index,
+            );
+            current_plans = vec![Arc::new(LogicalPlan::Join(join_plan))];
+            current_index += 1;
+        }
+        (Some(join), [(table, definition)]) => {
+            if join.within.is_some() {
+                return Err(format!(
+                    "JOIN against table {} does not accept a WITHIN bound",
+                    table.name
+                ));
+            }
+            if current_plans.len() != 1 {
+                return Err(format!(
+                    "JOIN against table {} expects exactly one stream",
+                    table.name
+                ));
+            }
+            let stream_source = if join.left.name == table.name {
+                join.right.name.clone()
+            } else {
+                join.left.name.clone()
+            };
+            let join_plan = LogicalLookupJoin::new(
+                stream_source,
+                table.name.clone(),
+                table.alias.clone(),
+                definition.schema(),
+                join.condition.clone(),
+                current_plans,
+                current_index,
+            );
+            current_plans = vec![Arc::new(LogicalPlan::LookupJoin(join_plan))];
+            current_index += 1;
+        }
+        (Some(join), _) => {
+            return Err(format!(
+                "JOIN between tables {} and {} is not supported; one side must be a stream",
+                join.left.name, join.right.name
+            ));
+        }
+    }
+
     // 2. Create StatefulFunctionPlan if stateful mappings exist
     if !select_stmt.stateful_mappings.is_empty() {
         let stateful = stateful_function::StatefulFunctionPlan::new(
@@ -287,6 +375,9 @@ fn validate_expression_types(
     for expr in &select_stmt.group_by_exprs {
         validate_expr_against_sources(expr, &sources)?;
     }
+    if let Some(join) = &select_stmt.join {
+        validate_expr_against_sources(&join.condition, &sources)?;
+    }
 
     Ok(())
 }
@@ -419,7 +510,12 @@ fn validate_expr_against_sources(
             }
             Ok(())
         }
-        _ => Ok(()),
+        _ => {
+            for operand in expr_operands(expr) {
+                validate_expr_against_sources(operand, sources)?;
+            }
+            Ok(())
+        }
     }
 }
 
@@ -585,10 +681,8 @@ fn collect_non_placeholder_column_refs(expr: &sqlparser::ast::Expr) -> Vec<Strin
 
     fn collect(expr: &Expr, out: &mut std::collections::HashSet<String>) {
         match expr {
-            Expr::Identifier(ident) => {
-                if !is_placeholder(ident) {
-                    out.insert(ident.value.clone());
-                }
+            Expr::Identifier(ident) if !is_placeholder(ident) => {
+                out.insert(ident.value.clone());
             }
             Expr::CompoundIdentifier(idents) => {
                 out.insert(Expr::CompoundIdentifier(idents.clone()).to_string());
@@ -656,7 +750,11 @@ fn collect_non_placeholder_column_refs(expr: &sqlparser::ast::Expr) -> Vec<Strin
                     collect(expr, out);
                 }
             }
-            _ => {}
+            _ => {
+                for operand in expr_operands(expr) {
+                    collect(operand, out);
+                }
+            }
         }
     }
 
@@ -732,36 +830,122 @@ fn expr_contains_aggregate_placeholder(expr: &sqlparser::ast::Expr) -> bool {
             expr_contains_aggregate_placeholder(column)
                 || keys.iter().any(expr_contains_aggregate_placeholder)
         }
-        _ => false,
+        _ => expr_operands(expr)
+            .into_iter()
+            .any(expr_contains_aggregate_placeholder),
+    }
+}
+
+/// Direct operands of the SQL expressions that have dedicated syntax (BETWEEN, IN, CASE, LIKE,
+/// SIMILAR TO, SUBSTRING, TRIM, EXTRACT, CEIL/FLOOR, IS ...), for walkers that otherwise only
+/// descend into operators, function arguments and field access.
+pub(crate) fn expr_operands(expr: &sqlparser::ast::Expr) -> Vec<&sqlparser::ast::Expr> {
+    use sqlparser::ast::Expr;
+
+    match expr {
+        Expr::Between {
+            expr, low, high, ..
+        } => vec![expr, low, high],
+        Expr::InList { expr, list, .. } => std::iter::once(expr.as_ref()).chain(list).collect(),
+        Expr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => operand
+            .as_deref()
+            .into_iter()
+            .chain(conditions)
+            .chain(results)
+            .chain(else_result.as_deref())
+            .collect(),
+        Expr::Like { expr, pattern, .. }
+        | Expr::ILike { expr, pattern, .. }
+        | Expr::SimilarTo { expr, pattern, .. } => vec![expr, pattern],
+        Expr::Substring {
+            expr,
+            substring_from,
+            substring_for,
+            ..
+        } => std::iter::once(expr.as_ref())
+            .chain(substring_from.as_deref())
+            .chain(substring_for.as_deref())
+            .collect(),
+        Expr::Trim {
+            expr,
+            trim_what,
+            trim_characters,
+            ..
+        } => std::iter::once(expr.as_ref())
+            .chain(trim_what.as_deref())
+            .chain(trim_characters.iter().flatten())
+            .collect(),
+        Expr::Extract { expr, .. }
+        | Expr::Ceil { expr, .. }
+        | Expr::Floor { expr, .. }
+        | Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::IsTrue(expr)
+        | Expr::IsNotTrue(expr)
+        | Expr::IsFalse(expr)
+        | Expr::IsNotFalse(expr)
+        | Expr::IsUnknown(expr)
+        | Expr::IsNotUnknown(expr) => vec![expr],
+        Expr::IsDistinctFrom(left, right) | Expr::IsNotDistinctFrom(left, right) => {
+            vec![left, right]
+        }
+        _ => Vec::new(),
     }
 }
 
 fn convert_window_spec(window: parser_window::Window) -> Result<LogicalWindowSpec, String> {
     match window {
         parser_window::Window::Tumbling { time_unit, length } => {
-            let unit = match time_unit {
-                parser_window::TimeUnit::Seconds => TimeUnit::Seconds,
-            };
+            let unit = convert_time_unit(time_unit);
             Ok(LogicalWindowSpec::Tumbling {
                 time_unit: unit,
                 length,
             })
         }
+        parser_window::Window::Hopping {
+            time_unit,
+            size,
+            hop,
+        } => {
+            let unit = convert_time_unit(time_unit);
+            Ok(LogicalWindowSpec::Hopping {
+                time_unit: unit,
+                size,
+                hop,
+            })
+        }
         parser_window::Window::Count { count } => Ok(LogicalWindowSpec::Count { count }),
         parser_window::Window::Sliding {
             time_unit,
             lookback,
             lookahead,
         } => {
-            let unit = match time_unit {
-                parser_window::TimeUnit::Seconds => TimeUnit::Seconds,
-            };
+            let unit = convert_time_unit(time_unit);
             Ok(LogicalWindowSpec::Sliding {
                 time_unit: unit,
                 lookback,
                 lookahead,
             })
         }
+        parser_window::Window::Session {
+            time_unit,
+            gap,
+            max_duration,
+            partition_by,
+        } => {
+            let unit = convert_time_unit(time_unit);
+            Ok(LogicalWindowSpec::Session {
+                time_unit: unit,
+                gap,
+                max_duration,
+                partition_by,
+            })
+        }
         parser_window::Window::State {
             open,
             emit,
@@ -774,6 +958,16 @@ fn convert_window_spec(window: parser_window::Window) -> Result<LogicalWindowSpe
     }
 }
 
+fn convert_time_unit(unit: parser_window::TimeUnit) -> TimeUnit {
+    match unit {
+        parser_window::TimeUnit::Milliseconds => TimeUnit::Milliseconds,
+        parser_window::TimeUnit::Seconds => TimeUnit::Seconds,
+        parser_window::TimeUnit::Minutes => TimeUnit::Minutes,
+        parser_window::TimeUnit::Hours => TimeUnit::Hours,
+        parser_window::TimeUnit::Days => TimeUnit::Days,
+    }
+}
+
 /// Helper function to print logical plan structure for debugging
 pub fn print_logical_plan(plan: &Arc<LogicalPlan>, indent: usize) {
     plan.print_topology(indent);
@@ -1,11 +1,29 @@
 use crate::planner::logical::BaseLogicalPlan;
 use sqlparser::ast::Expr;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Supported time units for window definitions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TimeUnit {
+    Milliseconds,
     Seconds,
+    Minutes,
+    Hours,
+    Days,
+}
+
+impl TimeUnit {
+    /// Length of `value` units.
+    pub fn duration(&self, value: u64) -> Duration {
+        match self {
+            TimeUnit::Milliseconds => Duration::from_millis(value),
+            TimeUnit::Seconds => Duration::from_secs(value),
+            TimeUnit::Minutes => Duration::from_secs(value.saturating_mul(60)),
+            TimeUnit::Hours => Duration::from_secs(value.saturating_mul(60 * 60)),
+            TimeUnit::Days => Duration::from_secs(value.saturating_mul(24 * 60 * 60)),
+        }
+    }
 }
 
 /// Logical window specification.
@@ -15,6 +33,11 @@ pub enum LogicalWindowSpec {
         time_unit: TimeUnit,
         length: u64,
     },
+    Hopping {
+        time_unit: TimeUnit,
+        size: u64,
+        hop: u64,
+    },
     Count {
         count: u64,
     },
@@ -23,6 +46,13 @@ pub enum LogicalWindowSpec {
         lookback: u64,
         lookahead: Option<u64>,
     },
+    Session {
+        time_unit: TimeUnit,
+        gap: u64,
+        max_duration: Option<u64>,
+        /// Optional partition keys extracted from `OVER (PARTITION BY ...)`.
+        partition_by: Vec<Expr>,
+    },
     State {
         open: Box<Expr>,
         emit: Box<Expr>,
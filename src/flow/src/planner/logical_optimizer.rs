@@ -1,6 +1,6 @@
 use crate::expr::sql_conversion::{SchemaBinding, SchemaBindingEntry};
 use crate::planner::decode_projection::{DecodeProjection, FieldPath, FieldPathSegment, ListIndex};
-use crate::planner::logical::{LogicalPlan, TailPlan};
+use crate::planner::logical::{expr_operands, LogicalPlan, TailPlan};
 use datatypes::Schema;
 use sqlparser::ast::{Expr as SqlExpr, FunctionArg, FunctionArgExpr, Ident, ObjectName};
 use std::collections::{HashMap, HashSet};
@@ -212,6 +212,8 @@ impl<'a> TopLevelColumnUsageCollector<'a> {
                 }
             }
             LogicalPlan::Filter(filter) => self.collect_expr_ast(&filter.predicate),
+            LogicalPlan::Join(join) => self.collect_expr_ast(&join.condition),
+            LogicalPlan::LookupJoin(join) => self.collect_expr_ast(&join.condition),
             LogicalPlan::Aggregation(agg) => {
                 for expr in agg.aggregate_mappings.values() {
                     self.collect_expr_ast(expr);
@@ -220,20 +222,25 @@ impl<'a> TopLevelColumnUsageCollector<'a> {
                     self.collect_expr_ast(expr);
                 }
             }
-            LogicalPlan::Window(window) => {
-                if let crate::planner::logical::LogicalWindowSpec::State {
+            LogicalPlan::Window(window) => match &window.spec {
+                crate::planner::logical::LogicalWindowSpec::State {
                     open,
                     emit,
                     partition_by,
-                } = &window.spec
-                {
+                } => {
                     self.collect_expr_ast(open.as_ref());
                     self.collect_expr_ast(emit.as_ref());
                     for expr in partition_by {
                         self.collect_expr_ast(expr);
                     }
                 }
-            }
+                crate::planner::logical::LogicalWindowSpec::Session { partition_by, .. } => {
+                    for expr in partition_by {
+                        self.collect_expr_ast(expr);
+                    }
+                }
+                _ => {}
+            },
             LogicalPlan::DataSource(ds) => {
                 if self.eventtime_enabled {
                     if let Some(eventtime) = ds.eventtime() {
@@ -258,6 +265,9 @@ impl<'a> TopLevelColumnUsageCollector<'a> {
                 for arg in &func.args {
                     self.collect_function_arg(arg);
                 }
+                if let Some(filter) = &func.filter {
+                    self.collect_expr_ast(filter);
+                }
             }
             SqlExpr::BinaryOp { left, right, .. } => {
                 self.collect_expr_ast(left);
@@ -276,7 +286,11 @@ impl<'a> TopLevelColumnUsageCollector<'a> {
                     self.collect_expr_ast(key);
                 }
             }
-            _ => {}
+            _ => {
+                for operand in expr_operands(expr) {
+                    self.collect_expr_ast(operand);
+                }
+            }
         }
     }
 
@@ -582,6 +596,8 @@ impl<'a> StructFieldUsageCollector<'a> {
                 }
             }
             LogicalPlan::Filter(filter) => self.collect_expr_ast(&filter.predicate),
+            LogicalPlan::Join(join) => self.collect_expr_ast(&join.condition),
+            LogicalPlan::LookupJoin(join) => self.collect_expr_ast(&join.condition),
             LogicalPlan::Aggregation(agg) => {
                 for expr in agg.aggregate_mappings.values() {
                     self.collect_expr_ast(expr);
@@ -590,20 +606,25 @@ impl<'a> StructFieldUsageCollector<'a> {
                     self.collect_expr_ast(expr);
                 }
             }
-            LogicalPlan::Window(window) => {
-                if let crate::planner::logical::LogicalWindowSpec::State {
+            LogicalPlan::Window(window) => match &window.spec {
+                crate::planner::logical::LogicalWindowSpec::State {
                     open,
                     emit,
                     partition_by,
-                } = &window.spec
-                {
+                } => {
                     self.collect_expr_ast(open.as_ref());
                     self.collect_expr_ast(emit.as_ref());
                     for expr in partition_by {
                         self.collect_expr_ast(expr);
                     }
                 }
-            }
+                crate::planner::logical::LogicalWindowSpec::Session { partition_by, .. } => {
+                    for expr in partition_by {
+                        self.collect_expr_ast(expr);
+                    }
+                }
+                _ => {}
+            },
             LogicalPlan::DataSource(_) => {}
             LogicalPlan::DataSink(_) => {}
             LogicalPlan::Tail(TailPlan { .. }) => {}
@@ -620,6 +641,9 @@ impl<'a> StructFieldUsageCollector<'a> {
                 for arg in &func.args {
                     self.collect_function_arg(arg);
                 }
+                if let Some(filter) = &func.filter {
+                    self.collect_expr_ast(filter);
+                }
             }
             SqlExpr::BinaryOp { left, right, .. } => {
                 self.collect_expr_ast(left);
@@ -656,7 +680,11 @@ impl<'a> StructFieldUsageCollector<'a> {
                     self.collect_expr_ast(key);
                 }
             }
-            _ => {}
+            _ => {
+                for operand in expr_operands(expr) {
+                    self.collect_expr_ast(operand);
+                }
+            }
         }
     }
 
@@ -846,6 +874,8 @@ impl<'a> ListElementUsageCollector<'a> {
                 }
             }
             LogicalPlan::Filter(filter) => self.collect_expr_ast(&filter.predicate),
+            LogicalPlan::Join(join) => self.collect_expr_ast(&join.condition),
+            LogicalPlan::LookupJoin(join) => self.collect_expr_ast(&join.condition),
             LogicalPlan::Aggregation(agg) => {
                 for expr in agg.aggregate_mappings.values() {
                     self.collect_expr_ast(expr);
@@ -854,20 +884,25 @@ impl<'a> ListElementUsageCollector<'a> {
                     self.collect_expr_ast(expr);
                 }
             }
-            LogicalPlan::Window(window) => {
-                if let crate::planner::logical::LogicalWindowSpec::State {
+            LogicalPlan::Window(window) => match &window.spec {
+                crate::planner::logical::LogicalWindowSpec::State {
                     open,
                     emit,
                     partition_by,
-                } = &window.spec
-                {
+                } => {
                     self.collect_expr_ast(open.as_ref());
                     self.collect_expr_ast(emit.as_ref());
                     for expr in partition_by {
                         self.collect_expr_ast(expr);
                     }
                 }
-            }
+                crate::planner::logical::LogicalWindowSpec::Session { partition_by, .. } => {
+                    for expr in partition_by {
+                        self.collect_expr_ast(expr);
+                    }
+                }
+                _ => {}
+            },
             LogicalPlan::DataSource(_) => {}
             LogicalPlan::DataSink(_) => {}
             LogicalPlan::Tail(TailPlan { .. }) => {}
@@ -884,6 +919,9 @@ impl<'a> ListElementUsageCollector<'a> {
                 for arg in &func.args {
                     self.collect_function_arg(arg);
                 }
+                if let Some(filter) = &func.filter {
+                    self.collect_expr_ast(filter);
+                }
             }
             SqlExpr::BinaryOp { left, right, .. } => {
                 self.collect_expr_ast(left);
@@ -927,7 +965,11 @@ impl<'a> ListElementUsageCollector<'a> {
                 self.collect_expr_ast(left);
                 self.collect_expr_ast(right);
             }
-            _ => {}
+            _ => {
+                for operand in expr_operands(expr) {
+                    self.collect_expr_ast(operand);
+                }
+            }
         }
     }
 
@@ -1282,6 +1324,31 @@ fn apply_pruned_with_cache(
                 Arc::new(LogicalPlan::DataSource(new_ds))
             }
         }
+        LogicalPlan::LookupJoin(join) => {
+            let child = apply_pruned_with_cache(
+                Arc::clone(&join.base.children[0]),
+                bindings,
+                decode_projections,
+                shared_required_schemas,
+                cache,
+            );
+            let table_schema = bindings
+                .entries()
+                .iter()
+                .find(|entry| entry.source_name == join.table_name)
+                .map(|entry| Arc::clone(&entry.schema))
+                .unwrap_or_else(|| Arc::clone(&join.table_schema));
+            if Arc::ptr_eq(&child, &join.base.children[0])
+                && Arc::ptr_eq(&table_schema, &join.table_schema)
+            {
+                plan
+            } else {
+                let mut new_join = join.clone();
+                new_join.base.children = vec![child];
+                new_join.table_schema = table_schema;
+                Arc::new(LogicalPlan::LookupJoin(new_join))
+            }
+        }
         _ => {
             let new_children: Vec<Arc<LogicalPlan>> = plan
                 .children()
@@ -1352,6 +1419,16 @@ fn clone_with_children(plan: &LogicalPlan, children: Vec<Arc<LogicalPlan>>) -> A
             new.base.children = children;
             Arc::new(LogicalPlan::Window(new))
         }
+        LogicalPlan::Join(join) => {
+            let mut new = join.clone();
+            new.base.children = children;
+            Arc::new(LogicalPlan::Join(new))
+        }
+        LogicalPlan::LookupJoin(join) => {
+            let mut new = join.clone();
+            new.base.children = children;
+            Arc::new(LogicalPlan::LookupJoin(new))
+        }
     }
 }
 
@@ -115,6 +115,21 @@ impl StreamingAggregationRewrite {
                 let upstream = window.base.children.first()?.clone();
                 (spec, upstream)
             }
+            // Panes are shared across overlapping windows, so every call has to merge.
+            PhysicalPlan::HoppingWindow(window)
+                if PhysicalStreamingAggregation::all_calls_mergeable(
+                    &agg.aggregate_calls,
+                    &self.aggregate_registry,
+                ) =>
+            {
+                let spec = StreamingWindowSpec::Hopping {
+                    time_unit: window.time_unit,
+                    size: window.size,
+                    hop: window.hop,
+                };
+                let upstream = window.base.children.first()?.clone();
+                (spec, upstream)
+            }
             PhysicalPlan::CountWindow(window) => {
                 let spec = StreamingWindowSpec::Count {
                     count: window.count,
@@ -131,6 +146,17 @@ impl StreamingAggregationRewrite {
                 let upstream = window.base.children.first()?.clone();
                 (spec, upstream)
             }
+            PhysicalPlan::SessionWindow(window) => {
+                let spec = StreamingWindowSpec::Session {
+                    time_unit: window.time_unit,
+                    gap: window.gap,
+                    max_duration: window.max_duration,
+                    partition_by_exprs: window.partition_by_exprs.clone(),
+                    partition_by_scalars: window.partition_by_scalars.clone(),
+                };
+                let upstream = window.base.children.first()?.clone();
+                (spec, upstream)
+            }
             PhysicalPlan::StateWindow(window) => {
                 let spec = StreamingWindowSpec::State {
                     open_expr: window.open_expr.clone(),
@@ -334,6 +360,11 @@ fn rebuild_with_children(
             new.base.children = children;
             Arc::new(PhysicalPlan::TumblingWindow(new))
         }
+        PhysicalPlan::HoppingWindow(window) => {
+            let mut new = window.clone();
+            new.base.children = children;
+            Arc::new(PhysicalPlan::HoppingWindow(new))
+        }
         PhysicalPlan::ProcessTimeWatermark(watermark) => {
             let mut new = watermark.clone();
             new.base.children = children;
@@ -359,11 +390,26 @@ fn rebuild_with_children(
             new.base.children = children;
             Arc::new(PhysicalPlan::SlidingWindow(new))
         }
+        PhysicalPlan::SessionWindow(window) => {
+            let mut new = window.as_ref().clone();
+            new.base.children = children;
+            Arc::new(PhysicalPlan::SessionWindow(Box::new(new)))
+        }
         PhysicalPlan::StateWindow(window) => {
             let mut new = window.as_ref().clone();
             new.base.children = children;
             Arc::new(PhysicalPlan::StateWindow(Box::new(new)))
         }
+        PhysicalPlan::StreamJoin(join) => {
+            let mut new = join.clone();
+            new.base.children = children;
+            Arc::new(PhysicalPlan::StreamJoin(new))
+        }
+        PhysicalPlan::LookupJoin(join) => {
+            let mut new = join.clone();
+            new.base.children = children;
+            Arc::new(PhysicalPlan::LookupJoin(new))
+        }
         PhysicalPlan::DataSink(sink) => {
             let mut new = sink.clone();
             new.base.children = children;
@@ -9,11 +9,13 @@ pub mod physical_decoder;
 pub mod physical_encoder;
 pub mod physical_eventtime_watermark;
 pub mod physical_filter;
+pub mod physical_lookup_join;
 pub mod physical_process_time_watermark;
 pub mod physical_project;
 pub mod physical_result_collect;
 pub mod physical_shared_stream;
 pub mod physical_stateful_function;
+pub mod physical_stream_join;
 pub mod physical_streaming_aggregation;
 pub mod physical_streaming_encoder;
 pub mod physical_watermark;
@@ -29,16 +31,19 @@ pub use physical_decoder::PhysicalDecoderEventtimeSpec;
 pub use physical_encoder::PhysicalEncoder;
 pub use physical_eventtime_watermark::PhysicalEventtimeWatermark;
 pub use physical_filter::PhysicalFilter;
+pub use physical_lookup_join::PhysicalLookupJoin;
 pub use physical_process_time_watermark::PhysicalProcessTimeWatermark;
 pub use physical_project::{PhysicalProject, PhysicalProjectField};
 pub use physical_result_collect::PhysicalResultCollect;
 pub use physical_shared_stream::PhysicalSharedStream;
 pub use physical_stateful_function::{PhysicalStatefulFunction, StatefulCall};
+pub use physical_stream_join::PhysicalStreamJoin;
 pub use physical_streaming_aggregation::{PhysicalStreamingAggregation, StreamingWindowSpec};
 pub use physical_streaming_encoder::PhysicalStreamingEncoder;
 pub use physical_watermark::{PhysicalWatermark, WatermarkConfig, WatermarkStrategy};
 pub use physical_window::{
-    PhysicalCountWindow, PhysicalSlidingWindow, PhysicalStateWindow, PhysicalTumblingWindow,
+    PhysicalCountWindow, PhysicalHoppingWindow, PhysicalSessionWindow, PhysicalSlidingWindow,
+    PhysicalStateWindow, PhysicalTumblingWindow,
 };
 
 /// Enum describing all supported physical execution nodes
@@ -59,9 +64,13 @@ pub enum PhysicalPlan {
     StreamingEncoder(PhysicalStreamingEncoder),
     ResultCollect(PhysicalResultCollect),
     TumblingWindow(PhysicalTumblingWindow),
+    HoppingWindow(PhysicalHoppingWindow),
     CountWindow(PhysicalCountWindow),
     SlidingWindow(PhysicalSlidingWindow),
+    SessionWindow(Box<PhysicalSessionWindow>),
     StateWindow(Box<PhysicalStateWindow>),
+    StreamJoin(PhysicalStreamJoin),
+    LookupJoin(PhysicalLookupJoin),
     /// Processing-time watermark physical node (ticker-based).
     ProcessTimeWatermark(PhysicalProcessTimeWatermark),
     /// Event-time watermark physical node (data-driven).
@@ -88,9 +97,13 @@ impl PhysicalPlan {
             PhysicalPlan::StreamingEncoder(plan) => plan.base.children(),
             PhysicalPlan::ResultCollect(plan) => plan.base.children(),
             PhysicalPlan::TumblingWindow(plan) => plan.base.children(),
+            PhysicalPlan::HoppingWindow(plan) => plan.base.children(),
             PhysicalPlan::CountWindow(plan) => plan.base.children(),
             PhysicalPlan::SlidingWindow(plan) => plan.base.children(),
+            PhysicalPlan::SessionWindow(plan) => plan.base.children(),
             PhysicalPlan::StateWindow(plan) => plan.base.children(),
+            PhysicalPlan::StreamJoin(plan) => plan.base.children(),
+            PhysicalPlan::LookupJoin(plan) => plan.base.children(),
             PhysicalPlan::ProcessTimeWatermark(plan) => plan.base.children(),
             PhysicalPlan::EventtimeWatermark(plan) => plan.base.children(),
             PhysicalPlan::Watermark(plan) => plan.base.children(),
@@ -114,9 +127,13 @@ impl PhysicalPlan {
             PhysicalPlan::StreamingEncoder(_) => "PhysicalStreamingEncoder",
             PhysicalPlan::ResultCollect(_) => "PhysicalResultCollect",
             PhysicalPlan::TumblingWindow(_) => "PhysicalTumblingWindow",
+            PhysicalPlan::HoppingWindow(_) => "PhysicalHoppingWindow",
             PhysicalPlan::CountWindow(_) => "PhysicalCountWindow",
             PhysicalPlan::SlidingWindow(_) => "PhysicalSlidingWindow",
+            PhysicalPlan::SessionWindow(_) => "PhysicalSessionWindow",
             PhysicalPlan::StateWindow(_) => "PhysicalStateWindow",
+            PhysicalPlan::StreamJoin(_) => "PhysicalStreamJoin",
+            PhysicalPlan::LookupJoin(_) => "PhysicalLookupJoin",
             PhysicalPlan::ProcessTimeWatermark(_) => "PhysicalProcessTimeWatermark",
             PhysicalPlan::EventtimeWatermark(_) => "PhysicalEventtimeWatermark",
             PhysicalPlan::Watermark(_) => "PhysicalWatermark",
@@ -140,9 +157,13 @@ impl PhysicalPlan {
             PhysicalPlan::StreamingEncoder(plan) => plan.base.index(),
             PhysicalPlan::ResultCollect(plan) => plan.base.index(),
             PhysicalPlan::TumblingWindow(plan) => plan.base.index(),
+            PhysicalPlan::HoppingWindow(plan) => plan.base.index(),
             PhysicalPlan::CountWindow(plan) => plan.base.index(),
             PhysicalPlan::SlidingWindow(plan) => plan.base.index(),
+            PhysicalPlan::SessionWindow(plan) => plan.base.index(),
             PhysicalPlan::StateWindow(plan) => plan.base.index(),
+            PhysicalPlan::StreamJoin(plan) => plan.base.index(),
+            PhysicalPlan::LookupJoin(plan) => plan.base.index(),
             PhysicalPlan::ProcessTimeWatermark(plan) => plan.base.index(),
             PhysicalPlan::EventtimeWatermark(plan) => plan.base.index(),
             PhysicalPlan::Watermark(plan) => plan.base.index(),
@@ -185,9 +206,13 @@ impl PhysicalPlan {
             PhysicalPlan::StreamingEncoder(plan) => &mut plan.base.children,
             PhysicalPlan::ResultCollect(plan) => &mut plan.base.children,
             PhysicalPlan::TumblingWindow(plan) => &mut plan.base.children,
+            PhysicalPlan::HoppingWindow(plan) => &mut plan.base.children,
             PhysicalPlan::CountWindow(plan) => &mut plan.base.children,
             PhysicalPlan::SlidingWindow(plan) => &mut plan.base.children,
+            PhysicalPlan::SessionWindow(plan) => &mut plan.base.children,
             PhysicalPlan::StateWindow(plan) => &mut plan.base.children,
+            PhysicalPlan::StreamJoin(plan) => &mut plan.base.children,
+            PhysicalPlan::LookupJoin(plan) => &mut plan.base.children,
             PhysicalPlan::ProcessTimeWatermark(plan) => &mut plan.base.children,
             PhysicalPlan::EventtimeWatermark(plan) => &mut plan.base.children,
             PhysicalPlan::Watermark(plan) => &mut plan.base.children,
@@ -204,15 +229,21 @@ pub fn rewrite_watermark_strategy(plan: &mut Arc<PhysicalPlan>, strategy: Waterm
     match plan_mut {
         PhysicalPlan::ProcessTimeWatermark(watermark) => match &mut watermark.config {
             WatermarkConfig::Tumbling { strategy: s, .. } => *s = strategy,
+            WatermarkConfig::Hopping { strategy: s, .. } => *s = strategy,
             WatermarkConfig::Sliding { strategy: s, .. } => *s = strategy,
+            WatermarkConfig::Session { strategy: s, .. } => *s = strategy,
         },
         PhysicalPlan::EventtimeWatermark(watermark) => match &mut watermark.config {
             WatermarkConfig::Tumbling { strategy: s, .. } => *s = strategy,
+            WatermarkConfig::Hopping { strategy: s, .. } => *s = strategy,
             WatermarkConfig::Sliding { strategy: s, .. } => *s = strategy,
+            WatermarkConfig::Session { strategy: s, .. } => *s = strategy,
         },
         PhysicalPlan::Watermark(watermark) => match &mut watermark.config {
             WatermarkConfig::Tumbling { strategy: s, .. } => *s = strategy,
+            WatermarkConfig::Hopping { strategy: s, .. } => *s = strategy,
             WatermarkConfig::Sliding { strategy: s, .. } => *s = strategy,
+            WatermarkConfig::Session { strategy: s, .. } => *s = strategy,
         },
         _ => {}
     }
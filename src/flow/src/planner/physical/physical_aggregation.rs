@@ -1,10 +1,12 @@
-use crate::aggregation::AggregateFunctionRegistry;
+use crate::aggregation::{AggregateAccumulator, AggregateFunctionRegistry, DistinctAccumulator};
 use crate::expr::custom_func::CustomFuncRegistry;
 use crate::expr::sql_conversion::{
     convert_expr_to_scalar_with_bindings_and_custom_registry, SchemaBinding,
 };
 use crate::expr::ScalarExpr;
+use crate::model::Tuple;
 use crate::planner::physical::BasePhysicalPlan;
+use datatypes::Value;
 use sqlparser::ast::Expr;
 use sqlparser::ast::{FunctionArg, FunctionArgExpr};
 use std::collections::HashMap;
@@ -18,6 +20,52 @@ pub struct AggregateCall {
     pub func_name: String,
     pub args: Vec<ScalarExpr>,
     pub distinct: bool,
+    /// `FILTER (WHERE ...)` predicate; rows for which it is not true skip this call.
+    pub filter: Option<ScalarExpr>,
+}
+
+impl AggregateCall {
+    /// Create an accumulator for this call, deduplicating its inputs when it is `DISTINCT`.
+    pub fn create_accumulator(
+        &self,
+        registry: &AggregateFunctionRegistry,
+    ) -> Result<Box<dyn AggregateAccumulator>, String> {
+        let function = registry
+            .get(&self.func_name)
+            .ok_or_else(|| format!("Aggregate function '{}' not found", self.func_name))?;
+        let accumulator = function.create_accumulator();
+        if self.distinct {
+            Ok(Box::new(DistinctAccumulator::new(accumulator)))
+        } else {
+            Ok(accumulator)
+        }
+    }
+
+    /// Evaluate the arguments for `tuple`, or `None` when the `FILTER` clause rejects it.
+    pub fn eval_args(&self, tuple: &Tuple) -> Result<Option<Vec<Value>>, String> {
+        if let Some(filter) = &self.filter {
+            let keep = filter
+                .eval_with_tuple(tuple)
+                .map_err(|e| format!("Failed to evaluate aggregate filter: {}", e))?;
+            if keep != Value::Bool(true) {
+                return Ok(None);
+            }
+        }
+        let mut args = Vec::with_capacity(self.args.len());
+        for arg_expr in &self.args {
+            args.push(
+                arg_expr
+                    .eval_with_tuple(tuple)
+                    .map_err(|e| format!("Failed to evaluate aggregate argument: {}", e))?,
+            );
+        }
+        Ok(Some(args))
+    }
+
+    /// Whether rows fed to this call can be retracted again; `DISTINCT` calls never can.
+    pub fn supports_retract(&self, registry: &AggregateFunctionRegistry) -> bool {
+        !self.distinct && registry.supports_retract(&self.func_name)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -105,11 +153,30 @@ fn build_aggregate_call(
                     )
                 })?;
 
+            let filter = func
+                .filter
+                .as_deref()
+                .map(|predicate| {
+                    convert_expr_to_scalar_with_bindings_and_custom_registry(
+                        predicate,
+                        bindings,
+                        custom_func_registry,
+                    )
+                    .map_err(|err| {
+                        format!(
+                            "Failed to compile aggregate filter for {}: {}",
+                            output_column, err
+                        )
+                    })
+                })
+                .transpose()?;
+
             Ok(AggregateCall {
                 output_column: output_column.to_string(),
                 func_name,
                 args,
                 distinct: func.distinct,
+                filter,
             })
         }
         other => Err(format!(
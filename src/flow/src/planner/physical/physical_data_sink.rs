@@ -1,5 +1,5 @@
 use crate::planner::physical::BasePhysicalPlan;
-use crate::planner::sink::SinkConnectorConfig;
+use crate::planner::sink::{SinkConnectorConfig, SinkRetryProps};
 use std::fmt;
 use std::sync::Arc;
 
@@ -40,6 +40,7 @@ pub struct PhysicalSinkConnector {
     pub forward_to_result: bool,
     pub connector: SinkConnectorConfig,
     pub encoder_plan_index: i64,
+    pub retry: SinkRetryProps,
 }
 
 impl PhysicalSinkConnector {
@@ -54,6 +55,12 @@ impl PhysicalSinkConnector {
             forward_to_result,
             connector,
             encoder_plan_index,
+            retry: SinkRetryProps::default(),
         }
     }
+
+    pub fn with_retry(mut self, retry: SinkRetryProps) -> Self {
+        self.retry = retry;
+        self
+    }
 }
@@ -0,0 +1,56 @@
+use crate::expr::ScalarExpr;
+use crate::planner::physical::{BasePhysicalPlan, PhysicalPlan};
+use datatypes::Schema;
+use sqlparser::ast::Expr;
+use std::sync::Arc;
+
+/// Physical operator for an inner join between a stream and a lookup table.
+///
+/// Each stream row is matched against the table rows whose `table_keys` equal its
+/// `stream_keys`, then filtered by the remaining ON conjuncts. The table rows are not part of
+/// the plan; the processor reads them from the catalog's table handle at runtime.
+#[derive(Debug, Clone)]
+pub struct PhysicalLookupJoin {
+    pub base: BasePhysicalPlan,
+    pub stream_source: String,
+    pub table_name: String,
+    /// Table columns read by the pipeline; `table_keys` and residuals index into this schema
+    pub table_schema: Arc<Schema>,
+    /// Original ON condition, kept for explain output
+    pub condition: Expr,
+    /// Equality conjuncts used as join keys, in original form
+    pub key_predicates: Vec<Expr>,
+    pub stream_keys: Vec<ScalarExpr>,
+    pub table_keys: Vec<ScalarExpr>,
+    /// Non-equi conjuncts evaluated against the joined row
+    pub residual_predicates: Vec<ScalarExpr>,
+}
+
+impl PhysicalLookupJoin {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stream_source: String,
+        table_name: String,
+        table_schema: Arc<Schema>,
+        condition: Expr,
+        key_predicates: Vec<Expr>,
+        stream_keys: Vec<ScalarExpr>,
+        table_keys: Vec<ScalarExpr>,
+        residual_predicates: Vec<ScalarExpr>,
+        children: Vec<Arc<PhysicalPlan>>,
+        index: i64,
+    ) -> Self {
+        let base = BasePhysicalPlan::new(children, index);
+        Self {
+            base,
+            stream_source,
+            table_name,
+            table_schema,
+            condition,
+            key_predicates,
+            stream_keys,
+            table_keys,
+            residual_predicates,
+        }
+    }
+}
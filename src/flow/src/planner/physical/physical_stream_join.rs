@@ -0,0 +1,103 @@
+use crate::expr::scalar::ColumnRef;
+use crate::expr::ScalarExpr;
+use crate::planner::physical::{BasePhysicalPlan, PhysicalPlan};
+use sqlparser::ast::Expr;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Physical operator for an interval-bounded inner join between two streams.
+///
+/// Rows are matched on `left_keys[i] = right_keys[i]` (evaluated against the row's own side),
+/// then filtered by the remaining ON conjuncts once both sides are combined.
+#[derive(Debug, Clone)]
+pub struct PhysicalStreamJoin {
+    pub base: BasePhysicalPlan,
+    pub left_source: String,
+    pub right_source: String,
+    /// Original ON condition, kept for explain output
+    pub condition: Expr,
+    /// Equality conjuncts used as join keys, in original form
+    pub key_predicates: Vec<Expr>,
+    pub left_keys: Vec<ScalarExpr>,
+    pub right_keys: Vec<ScalarExpr>,
+    /// Non-equi conjuncts evaluated against the joined row
+    pub residual_predicates: Vec<ScalarExpr>,
+    pub within: Duration,
+}
+
+impl PhysicalStreamJoin {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        left_source: String,
+        right_source: String,
+        condition: Expr,
+        key_predicates: Vec<Expr>,
+        left_keys: Vec<ScalarExpr>,
+        right_keys: Vec<ScalarExpr>,
+        residual_predicates: Vec<ScalarExpr>,
+        within: Duration,
+        children: Vec<Arc<PhysicalPlan>>,
+        index: i64,
+    ) -> Self {
+        let base = BasePhysicalPlan::new(children, index);
+        Self {
+            base,
+            left_source,
+            right_source,
+            condition,
+            key_predicates,
+            left_keys,
+            right_keys,
+            residual_predicates,
+            within,
+        }
+    }
+}
+
+/// Collect the source names referenced by column refs inside `expr`.
+pub(crate) fn referenced_sources(expr: &ScalarExpr, out: &mut BTreeSet<String>) {
+    match expr {
+        ScalarExpr::Column(ColumnRef::ByIndex { source_name, .. }) => {
+            out.insert(source_name.clone());
+        }
+        ScalarExpr::Column(ColumnRef::ByName { .. }) | ScalarExpr::Literal(..) => {}
+        ScalarExpr::Wildcard { source_name } => {
+            if let Some(source_name) = source_name {
+                out.insert(source_name.clone());
+            }
+        }
+        ScalarExpr::CallUnary { expr, .. } | ScalarExpr::FieldAccess { expr, .. } => {
+            referenced_sources(expr, out)
+        }
+        ScalarExpr::CallBinary { expr1, expr2, .. } => {
+            referenced_sources(expr1, out);
+            referenced_sources(expr2, out);
+        }
+        ScalarExpr::ListIndex { expr, index_expr } => {
+            referenced_sources(expr, out);
+            referenced_sources(index_expr, out);
+        }
+        ScalarExpr::CallFunc { args, .. } | ScalarExpr::Coalesce(args) => {
+            for arg in args {
+                referenced_sources(arg, out);
+            }
+        }
+        ScalarExpr::NullIf { expr, other } => {
+            referenced_sources(expr, out);
+            referenced_sources(other, out);
+        }
+        ScalarExpr::Case {
+            branches,
+            else_expr,
+        } => {
+            for (condition, result) in branches {
+                referenced_sources(condition, out);
+                referenced_sources(result, out);
+            }
+            if let Some(else_expr) = else_expr {
+                referenced_sources(else_expr, out);
+            }
+        }
+    }
+}
@@ -14,6 +14,11 @@ pub enum StreamingWindowSpec {
         time_unit: TimeUnit,
         length: u64,
     },
+    Hopping {
+        time_unit: TimeUnit,
+        size: u64,
+        hop: u64,
+    },
     Count {
         count: u64,
     },
@@ -22,6 +27,13 @@ pub enum StreamingWindowSpec {
         lookback: u64,
         lookahead: Option<u64>,
     },
+    Session {
+        time_unit: TimeUnit,
+        gap: u64,
+        max_duration: Option<u64>,
+        partition_by_exprs: Vec<Expr>,
+        partition_by_scalars: Vec<ScalarExpr>,
+    },
     State {
         open_expr: Expr,
         emit_expr: Expr,
@@ -71,4 +83,14 @@ impl PhysicalStreamingAggregation {
             .iter()
             .all(|call| registry.supports_incremental(&call.func_name))
     }
+
+    /// Whether every call's partial state can be merged, which pane sharing relies on.
+    pub fn all_calls_mergeable(
+        aggregate_calls: &[AggregateCall],
+        registry: &AggregateFunctionRegistry,
+    ) -> bool {
+        aggregate_calls
+            .iter()
+            .all(|call| registry.supports_merge(&call.func_name))
+    }
 }
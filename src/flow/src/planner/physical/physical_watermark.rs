@@ -18,9 +18,7 @@ impl WatermarkStrategy {
             WatermarkStrategy::ProcessingTime {
                 time_unit,
                 interval,
-            } => match time_unit {
-                TimeUnit::Seconds => Some(Duration::from_secs(*interval)),
-            },
+            } => Some(time_unit.duration(*interval)),
             WatermarkStrategy::EventTime { .. } => None,
         }
     }
@@ -34,19 +32,33 @@ pub enum WatermarkConfig {
         length: u64,
         strategy: WatermarkStrategy,
     },
+    Hopping {
+        time_unit: TimeUnit,
+        size: u64,
+        hop: u64,
+        strategy: WatermarkStrategy,
+    },
     Sliding {
         time_unit: TimeUnit,
         lookback: u64,
         lookahead: Option<u64>,
         strategy: WatermarkStrategy,
     },
+    Session {
+        time_unit: TimeUnit,
+        gap: u64,
+        max_duration: Option<u64>,
+        strategy: WatermarkStrategy,
+    },
 }
 
 impl WatermarkConfig {
     pub fn strategy(&self) -> &WatermarkStrategy {
         match self {
             WatermarkConfig::Tumbling { strategy, .. } => strategy,
+            WatermarkConfig::Hopping { strategy, .. } => strategy,
             WatermarkConfig::Sliding { strategy, .. } => strategy,
+            WatermarkConfig::Session { strategy, .. } => strategy,
         }
     }
 }
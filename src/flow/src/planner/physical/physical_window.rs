@@ -27,6 +27,33 @@ impl PhysicalTumblingWindow {
     }
 }
 
+/// Fixed-size window of `size` that opens every `hop`.
+#[derive(Debug, Clone)]
+pub struct PhysicalHoppingWindow {
+    pub base: BasePhysicalPlan,
+    pub time_unit: TimeUnit,
+    pub size: u64,
+    pub hop: u64,
+}
+
+impl PhysicalHoppingWindow {
+    pub fn new(
+        time_unit: TimeUnit,
+        size: u64,
+        hop: u64,
+        children: Vec<Arc<PhysicalPlan>>,
+        index: i64,
+    ) -> Self {
+        let base = BasePhysicalPlan::new(children, index);
+        Self {
+            base,
+            time_unit,
+            size,
+            hop,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PhysicalCountWindow {
     pub base: BasePhysicalPlan,
@@ -66,6 +93,40 @@ impl PhysicalSlidingWindow {
     }
 }
 
+/// Session window that closes after `gap` of inactivity, optionally capped at `max_duration`.
+#[derive(Debug, Clone)]
+pub struct PhysicalSessionWindow {
+    pub base: BasePhysicalPlan,
+    pub time_unit: TimeUnit,
+    pub gap: u64,
+    pub max_duration: Option<u64>,
+    pub partition_by_exprs: Vec<Expr>,
+    pub partition_by_scalars: Vec<ScalarExpr>,
+}
+
+impl PhysicalSessionWindow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        time_unit: TimeUnit,
+        gap: u64,
+        max_duration: Option<u64>,
+        partition_by_exprs: Vec<Expr>,
+        partition_by_scalars: Vec<ScalarExpr>,
+        children: Vec<Arc<PhysicalPlan>>,
+        index: i64,
+    ) -> Self {
+        let base = BasePhysicalPlan::new(children, index);
+        Self {
+            base,
+            time_unit,
+            gap,
+            max_duration,
+            partition_by_exprs,
+            partition_by_scalars,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PhysicalStateWindow {
     pub base: BasePhysicalPlan,
@@ -3,18 +3,20 @@ use crate::expr::sql_conversion::{
     convert_expr_to_scalar_with_bindings_and_custom_registry, SchemaBinding, SchemaBindingEntry,
     SourceBindingKind,
 };
+use crate::expr::ScalarExpr;
 use crate::planner::logical::{
     aggregation::Aggregation as LogicalAggregation, DataSinkPlan, DataSource as LogicalDataSource,
-    Filter as LogicalFilter, LogicalPlan, LogicalWindow, LogicalWindowSpec,
-    Project as LogicalProject, StatefulFunctionPlan as LogicalStatefulFunction,
+    Filter as LogicalFilter, LogicalJoin, LogicalLookupJoin, LogicalPlan, LogicalWindow,
+    LogicalWindowSpec, Project as LogicalProject, StatefulFunctionPlan as LogicalStatefulFunction,
+    TimeUnit,
 };
 use crate::planner::physical::physical_project::PhysicalProjectField;
 use crate::planner::physical::{
     PhysicalAggregation, PhysicalBatch, PhysicalDataSink, PhysicalDataSource, PhysicalDecoder,
     PhysicalDecoderEventtimeSpec, PhysicalEncoder, PhysicalEventtimeWatermark, PhysicalFilter,
-    PhysicalPlan, PhysicalProcessTimeWatermark, PhysicalProject, PhysicalResultCollect,
-    PhysicalSharedStream, PhysicalSinkConnector, PhysicalStatefulFunction, StatefulCall,
-    WatermarkConfig, WatermarkStrategy,
+    PhysicalLookupJoin, PhysicalPlan, PhysicalProcessTimeWatermark, PhysicalProject,
+    PhysicalResultCollect, PhysicalSharedStream, PhysicalSinkConnector, PhysicalStatefulFunction,
+    PhysicalStreamJoin, StatefulCall, WatermarkConfig, WatermarkStrategy,
 };
 use crate::planner::sink::{PipelineSink, PipelineSinkConnector};
 use crate::PipelineRegistries;
@@ -225,6 +227,22 @@ fn create_physical_plan_with_builder_cached_with_options(
             options,
             builder,
         )?,
+        LogicalPlan::Join(logical_join) => create_physical_stream_join_with_builder(
+            logical_join,
+            &logical_plan,
+            bindings,
+            registries,
+            options,
+            builder,
+        )?,
+        LogicalPlan::LookupJoin(logical_join) => create_physical_lookup_join_with_builder(
+            logical_join,
+            &logical_plan,
+            bindings,
+            registries,
+            options,
+            builder,
+        )?,
     };
 
     // Cache the result for future reuse using builder's cache
@@ -253,7 +271,7 @@ fn create_physical_stateful_function_with_builder(
     }
 
     let mut entries: Vec<_> = logical_stateful.stateful_mappings.iter().collect();
-    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    entries.sort_by_key(|(a, _)| *a);
 
     let mut calls = Vec::with_capacity(entries.len());
     for (output_column, expr) in entries {
@@ -404,6 +422,52 @@ fn create_physical_window_with_builder(
             );
             PhysicalPlan::TumblingWindow(tumbling)
         }
+        LogicalWindowSpec::Hopping {
+            time_unit,
+            size,
+            hop,
+        } => {
+            let watermark_index = builder.allocate_index();
+            let strategy = if options.eventtime_enabled {
+                WatermarkStrategy::EventTime {
+                    late_tolerance: options.eventtime_late_tolerance,
+                }
+            } else {
+                // A window closes every `hop`, so that is how often time has to advance.
+                WatermarkStrategy::ProcessingTime {
+                    time_unit: *time_unit,
+                    interval: *hop,
+                }
+            };
+            let watermark_config = WatermarkConfig::Hopping {
+                time_unit: *time_unit,
+                size: *size,
+                hop: *hop,
+                strategy,
+            };
+            let watermark_plan = if options.eventtime_enabled {
+                PhysicalPlan::EventtimeWatermark(PhysicalEventtimeWatermark::new(
+                    watermark_config,
+                    physical_children,
+                    watermark_index,
+                ))
+            } else {
+                PhysicalPlan::ProcessTimeWatermark(PhysicalProcessTimeWatermark::new(
+                    watermark_config,
+                    physical_children,
+                    watermark_index,
+                ))
+            };
+            let index = builder.allocate_index();
+            let hopping = crate::planner::physical::PhysicalHoppingWindow::new(
+                *time_unit,
+                *size,
+                *hop,
+                vec![Arc::new(watermark_plan)],
+                index,
+            );
+            PhysicalPlan::HoppingWindow(hopping)
+        }
         LogicalWindowSpec::Count { count } => {
             let index = builder.allocate_index();
             let count_window = crate::planner::physical::PhysicalCountWindow::new(
@@ -424,10 +488,7 @@ fn create_physical_window_with_builder(
                     late_tolerance: options.eventtime_late_tolerance,
                 }
             } else {
-                WatermarkStrategy::ProcessingTime {
-                    time_unit: *time_unit,
-                    interval: 1,
-                }
+                gc_tick_strategy(*time_unit)
             };
             let watermark_config = WatermarkConfig::Sliding {
                 time_unit: *time_unit,
@@ -460,6 +521,64 @@ fn create_physical_window_with_builder(
             );
             PhysicalPlan::SlidingWindow(sliding)
         }
+        LogicalWindowSpec::Session {
+            time_unit,
+            gap,
+            max_duration,
+            partition_by,
+        } => {
+            let mut partition_by_scalars = Vec::with_capacity(partition_by.len());
+            for expr in partition_by {
+                partition_by_scalars.push(
+                    convert_expr_to_scalar_with_bindings_and_custom_registry(
+                        expr,
+                        bindings,
+                        registries.custom_func_registry().as_ref(),
+                    )
+                    .map_err(|err| err.to_string())?,
+                );
+            }
+
+            let watermark_index = builder.allocate_index();
+            let strategy = if options.eventtime_enabled {
+                WatermarkStrategy::EventTime {
+                    late_tolerance: options.eventtime_late_tolerance,
+                }
+            } else {
+                // Sessions can expire at any moment, not on a fixed grid.
+                gc_tick_strategy(*time_unit)
+            };
+            let watermark_config = WatermarkConfig::Session {
+                time_unit: *time_unit,
+                gap: *gap,
+                max_duration: *max_duration,
+                strategy,
+            };
+            let watermark_plan = if options.eventtime_enabled {
+                PhysicalPlan::EventtimeWatermark(PhysicalEventtimeWatermark::new(
+                    watermark_config,
+                    physical_children,
+                    watermark_index,
+                ))
+            } else {
+                PhysicalPlan::ProcessTimeWatermark(PhysicalProcessTimeWatermark::new(
+                    watermark_config,
+                    physical_children,
+                    watermark_index,
+                ))
+            };
+            let index = builder.allocate_index();
+            let session = crate::planner::physical::PhysicalSessionWindow::new(
+                *time_unit,
+                *gap,
+                *max_duration,
+                partition_by.clone(),
+                partition_by_scalars,
+                vec![Arc::new(watermark_plan)],
+                index,
+            );
+            PhysicalPlan::SessionWindow(Box::new(session))
+        }
         LogicalWindowSpec::State {
             open,
             emit,
@@ -508,6 +627,21 @@ fn create_physical_window_with_builder(
     Ok(Arc::new(physical))
 }
 
+/// Processing-time ticker for windows that close per row rather than on a fixed grid: every
+/// second, or every 100ms for millisecond windows.
+fn gc_tick_strategy(time_unit: TimeUnit) -> WatermarkStrategy {
+    match time_unit {
+        TimeUnit::Milliseconds => WatermarkStrategy::ProcessingTime {
+            time_unit,
+            interval: 100,
+        },
+        _ => WatermarkStrategy::ProcessingTime {
+            time_unit: TimeUnit::Seconds,
+            interval: 1,
+        },
+    }
+}
+
 fn create_physical_aggregation_with_builder(
     logical_agg: &LogicalAggregation,
     logical_plan: &Arc<LogicalPlan>,
@@ -685,6 +819,202 @@ fn create_physical_filter_with_builder_cached(
     Ok(Arc::new(PhysicalPlan::Filter(physical_filter)))
 }
 
+/// Create a PhysicalStreamJoin from a LogicalJoin.
+///
+/// ON conjuncts of the form `<left expr> = <right expr>` become join keys; every other conjunct
+/// is kept as a residual predicate evaluated on the joined row.
+/// ON condition split into cross-side equality keys and residual predicates.
+struct SplitJoinCondition {
+    key_predicates: Vec<sqlparser::ast::Expr>,
+    left_keys: Vec<ScalarExpr>,
+    right_keys: Vec<ScalarExpr>,
+    residual_predicates: Vec<ScalarExpr>,
+}
+
+/// Split an ON condition into `left = right` equi-keys (each side referencing exactly one of
+/// the two sources) and residual conjuncts evaluated on the joined row.
+fn split_join_condition(
+    condition: &sqlparser::ast::Expr,
+    left_source: &str,
+    right_source: &str,
+    bindings: &SchemaBinding,
+    registries: &PipelineRegistries,
+) -> Result<SplitJoinCondition, String> {
+    use crate::planner::physical::physical_stream_join::referenced_sources;
+    use sqlparser::ast::{BinaryOperator, Expr};
+    use std::collections::BTreeSet;
+
+    fn split_conjunction(expr: &Expr, out: &mut Vec<Expr>) {
+        match expr {
+            Expr::BinaryOp {
+                left,
+                op: BinaryOperator::And,
+                right,
+            } => {
+                split_conjunction(left, out);
+                split_conjunction(right, out);
+            }
+            Expr::Nested(inner) if matches!(inner.as_ref(), Expr::BinaryOp { op, .. } if *op == BinaryOperator::And) => {
+                split_conjunction(inner, out)
+            }
+            other => out.push(other.clone()),
+        }
+    }
+
+    let convert = |expr: &Expr| {
+        convert_expr_to_scalar_with_bindings_and_custom_registry(
+            expr,
+            bindings,
+            registries.custom_func_registry().as_ref(),
+        )
+        .map_err(|e| format!("Failed to convert join condition {}: {}", expr, e))
+    };
+    let only_source =
+        |sources: &BTreeSet<String>, name: &str| sources.len() == 1 && sources.contains(name);
+
+    let mut conjuncts = Vec::new();
+    split_conjunction(condition, &mut conjuncts);
+
+    let mut split = SplitJoinCondition {
+        key_predicates: Vec::new(),
+        left_keys: Vec::new(),
+        right_keys: Vec::new(),
+        residual_predicates: Vec::new(),
+    };
+    for conjunct in conjuncts {
+        if let Expr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } = &conjunct
+        {
+            let lhs = convert(left)?;
+            let rhs = convert(right)?;
+            let mut lhs_sources = BTreeSet::new();
+            let mut rhs_sources = BTreeSet::new();
+            referenced_sources(&lhs, &mut lhs_sources);
+            referenced_sources(&rhs, &mut rhs_sources);
+
+            if only_source(&lhs_sources, left_source) && only_source(&rhs_sources, right_source) {
+                split.left_keys.push(lhs);
+                split.right_keys.push(rhs);
+                split.key_predicates.push(conjunct);
+                continue;
+            }
+            if only_source(&lhs_sources, right_source) && only_source(&rhs_sources, left_source) {
+                split.left_keys.push(rhs);
+                split.right_keys.push(lhs);
+                split.key_predicates.push(conjunct);
+                continue;
+            }
+        }
+        split.residual_predicates.push(convert(&conjunct)?);
+    }
+    Ok(split)
+}
+
+/// Create a PhysicalStreamJoin from a LogicalJoin
+fn create_physical_stream_join_with_builder(
+    logical_join: &LogicalJoin,
+    logical_plan: &Arc<LogicalPlan>,
+    bindings: &SchemaBinding,
+    registries: &PipelineRegistries,
+    options: &PhysicalPlanBuildOptions,
+    builder: &mut PhysicalPlanBuilder,
+) -> Result<Arc<PhysicalPlan>, String> {
+    let mut physical_children = Vec::new();
+    for child in logical_plan.children() {
+        let physical_child = create_physical_plan_with_builder_cached_with_options(
+            child.clone(),
+            bindings,
+            registries,
+            options,
+            builder,
+        )?;
+        physical_children.push(physical_child);
+    }
+
+    let split = split_join_condition(
+        &logical_join.condition,
+        &logical_join.left_source,
+        &logical_join.right_source,
+        bindings,
+        registries,
+    )?;
+    if split.key_predicates.is_empty() {
+        return Err(format!(
+            "JOIN between {} and {} requires at least one equality predicate across both streams",
+            logical_join.left_source, logical_join.right_source
+        ));
+    }
+
+    let index = builder.allocate_index();
+    let physical_join = PhysicalStreamJoin::new(
+        logical_join.left_source.clone(),
+        logical_join.right_source.clone(),
+        logical_join.condition.clone(),
+        split.key_predicates,
+        split.left_keys,
+        split.right_keys,
+        split.residual_predicates,
+        logical_join.within,
+        physical_children,
+        index,
+    );
+    Ok(Arc::new(PhysicalPlan::StreamJoin(physical_join)))
+}
+
+/// Create a PhysicalLookupJoin from a LogicalLookupJoin
+fn create_physical_lookup_join_with_builder(
+    logical_join: &LogicalLookupJoin,
+    logical_plan: &Arc<LogicalPlan>,
+    bindings: &SchemaBinding,
+    registries: &PipelineRegistries,
+    options: &PhysicalPlanBuildOptions,
+    builder: &mut PhysicalPlanBuilder,
+) -> Result<Arc<PhysicalPlan>, String> {
+    let mut physical_children = Vec::new();
+    for child in logical_plan.children() {
+        let physical_child = create_physical_plan_with_builder_cached_with_options(
+            child.clone(),
+            bindings,
+            registries,
+            options,
+            builder,
+        )?;
+        physical_children.push(physical_child);
+    }
+
+    let split = split_join_condition(
+        &logical_join.condition,
+        &logical_join.stream_source,
+        &logical_join.table_name,
+        bindings,
+        registries,
+    )?;
+    if split.key_predicates.is_empty() {
+        return Err(format!(
+            "JOIN between {} and table {} requires at least one equality predicate across both sides",
+            logical_join.stream_source, logical_join.table_name
+        ));
+    }
+
+    let index = builder.allocate_index();
+    let physical_join = PhysicalLookupJoin::new(
+        logical_join.stream_source.clone(),
+        logical_join.table_name.clone(),
+        Arc::clone(&logical_join.table_schema),
+        logical_join.condition.clone(),
+        split.key_predicates,
+        split.left_keys,
+        split.right_keys,
+        split.residual_predicates,
+        physical_children,
+        index,
+    );
+    Ok(Arc::new(PhysicalPlan::LookupJoin(physical_join)))
+}
+
 /// Create a PhysicalProject from a LogicalProject using centralized index management with caching
 fn create_physical_project_with_builder_cached(
     logical_project: &LogicalProject,
@@ -832,12 +1162,15 @@ fn add_regular_encoder_with_builder(
         connector.encoder.clone(),
     );
     encoder_children.push(Arc::new(PhysicalPlan::Encoder(encoder)));
-    connectors.push(PhysicalSinkConnector::new(
-        sink.sink_id.clone(),
-        sink.forward_to_result, // Always forward if sink is configured to do so (single connector)
-        connector.connector.clone(),
-        encoder_index,
-    ));
+    connectors.push(
+        PhysicalSinkConnector::new(
+            sink.sink_id.clone(),
+            sink.forward_to_result, // Always forward if sink is configured to do so (single connector)
+            connector.connector.clone(),
+            encoder_index,
+        )
+        .with_retry(sink.common.retry.clone()),
+    );
 }
 
 fn find_binding_entry<'a>(
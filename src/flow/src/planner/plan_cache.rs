@@ -8,12 +8,15 @@ use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
 
+use crate::connector::sink::file::{FileSinkConfig, FileSyncPolicy};
+use crate::connector::sink::http::HttpSinkConfig;
+use crate::connector::sink::kafka::KafkaSinkConfig;
 use crate::connector::sink::mqtt::MqttSinkConfig;
 use crate::planner::logical::LogicalPlan;
 use crate::planner::physical::PhysicalPlan;
 use crate::planner::sink::{
     CommonSinkProps, CustomSinkConnectorConfig, PipelineSink, PipelineSinkConnector,
-    SinkConnectorConfig, SinkEncoderConfig,
+    SinkConnectorConfig, SinkEncoderConfig, SinkRetryProps,
 };
 
 #[derive(Debug, Error)]
@@ -84,6 +87,17 @@ pub fn snapshot_matches_inputs(inputs: &PlanCacheInputs) -> bool {
 pub struct CommonSinkPropsIR {
     pub batch_count: Option<usize>,
     pub batch_duration_ms: Option<u64>,
+    /// Absent in snapshots taken before sink retries existed.
+    #[serde(default)]
+    pub retry: Option<SinkRetryPropsIR>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SinkRetryPropsIR {
+    pub max_retries: u32,
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub buffer_capacity: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -111,7 +125,11 @@ pub struct AggregateExprIR {
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum TimeUnitIR {
+    Milliseconds,
     Seconds,
+    Minutes,
+    Hours,
+    Days,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -120,6 +138,11 @@ pub enum WindowIR {
         time_unit: TimeUnitIR,
         length: u64,
     },
+    Hopping {
+        time_unit: TimeUnitIR,
+        size: u64,
+        hop: u64,
+    },
     Count {
         count: u64,
     },
@@ -128,6 +151,12 @@ pub enum WindowIR {
         lookback: u64,
         lookahead: Option<u64>,
     },
+    Session {
+        time_unit: TimeUnitIR,
+        gap: u64,
+        max_duration: Option<u64>,
+        partition_by: Vec<Expr>,
+    },
     State {
         open: Box<Expr>,
         emit: Box<Expr>,
@@ -174,6 +203,18 @@ pub enum LogicalPlanNodeKindIR {
     DataSink {
         sinks: Vec<SinkIR>,
     },
+    Join {
+        left: String,
+        right: String,
+        condition: Expr,
+        within_ms: u64,
+    },
+    LookupJoin {
+        stream: String,
+        table: String,
+        alias: Option<String>,
+        condition: Expr,
+    },
     Opaque {
         plan_type: String,
     },
@@ -273,8 +314,14 @@ pub fn logical_plan_from_ir(
 pub fn sources_from_logical_ir(ir: &LogicalPlanIR) -> Vec<(String, Option<String>)> {
     let mut sources = Vec::new();
     for node in &ir.nodes {
-        if let LogicalPlanNodeKindIR::DataSource { stream, alias } = &node.kind {
-            sources.push((stream.clone(), alias.clone()));
+        match &node.kind {
+            LogicalPlanNodeKindIR::DataSource { stream, alias } => {
+                sources.push((stream.clone(), alias.clone()));
+            }
+            LogicalPlanNodeKindIR::LookupJoin { table, alias, .. } => {
+                sources.push((table.clone(), alias.clone()));
+            }
+            _ => {}
         }
     }
     sources
@@ -384,6 +431,43 @@ fn build_logical_plan_node(
             let plan = crate::planner::logical::DataSinkPlan::new(child, node.index, sink);
             Arc::new(LogicalPlan::DataSink(plan))
         }
+        LogicalPlanNodeKindIR::Join {
+            left,
+            right,
+            condition,
+            within_ms,
+        } => {
+            let plan = crate::planner::logical::LogicalJoin::new(
+                left.clone(),
+                right.clone(),
+                condition.clone(),
+                Duration::from_millis(*within_ms),
+                children,
+                node.index,
+            );
+            Arc::new(LogicalPlan::Join(plan))
+        }
+        LogicalPlanNodeKindIR::LookupJoin {
+            stream,
+            table,
+            alias,
+            condition,
+        } => {
+            let (_, schema) = streams
+                .get(table)
+                .ok_or_else(|| format!("missing table definition for {table}"))?
+                .clone();
+            let plan = crate::planner::logical::LogicalLookupJoin::new(
+                stream.clone(),
+                table.clone(),
+                alias.clone(),
+                schema,
+                condition.clone(),
+                children,
+                node.index,
+            );
+            Arc::new(LogicalPlan::LookupJoin(plan))
+        }
         LogicalPlanNodeKindIR::Opaque { plan_type } => {
             return Err(format!(
                 "unsupported logical plan IR node kind: {plan_type}"
@@ -408,6 +492,11 @@ fn sink_ir_to_pipeline_sink(sink: &SinkIR) -> Result<PipelineSink, String> {
 
     let connector = match sink.connector_kind.as_str() {
         "mqtt" => SinkConnectorConfig::Mqtt(mqtt_sink_from_ir_settings(&sink.connector_settings)?),
+        "file" => SinkConnectorConfig::File(file_sink_from_ir_settings(&sink.connector_settings)?),
+        "http" => SinkConnectorConfig::Http(http_sink_from_ir_settings(&sink.connector_settings)?),
+        "kafka" => {
+            SinkConnectorConfig::Kafka(kafka_sink_from_ir_settings(&sink.connector_settings)?)
+        }
         "nop" => SinkConnectorConfig::Nop(crate::planner::sink::NopSinkConfig),
         other => SinkConnectorConfig::Custom(CustomSinkConnectorConfig {
             kind: other.to_string(),
@@ -469,10 +558,138 @@ fn mqtt_sink_from_ir_settings(settings: &JsonValue) -> Result<MqttSinkConfig, St
     Ok(config)
 }
 
+fn file_sink_from_ir_settings(settings: &JsonValue) -> Result<FileSinkConfig, String> {
+    let obj = settings
+        .as_object()
+        .ok_or_else(|| "file sink settings must be an object".to_string())?;
+
+    let sink_name = obj
+        .get("sink_name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "file sink settings missing sink_name".to_string())?;
+    let path = obj
+        .get("path")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "file sink settings missing path".to_string())?;
+    let sync = match obj.get("sync").and_then(|v| v.as_str()).unwrap_or("never") {
+        "never" => FileSyncPolicy::Never,
+        "always" => FileSyncPolicy::Always,
+        "interval" => FileSyncPolicy::Interval(Duration::from_millis(
+            obj.get("sync_interval_ms")
+                .and_then(|v| v.as_u64())
+                .ok_or_else(|| "file sink settings missing sync_interval_ms".to_string())?,
+        )),
+        other => return Err(format!("unsupported file sink sync policy: {other}")),
+    };
+
+    let mut config = FileSinkConfig::new(sink_name, path)
+        .with_newline_delimited(
+            obj.get("newline_delimited")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true),
+        )
+        .with_compress(
+            obj.get("compress")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        )
+        .with_sync(sync);
+    if let Some(max_file_bytes) = obj.get("max_file_bytes").and_then(|v| v.as_u64()) {
+        config = config.with_max_file_bytes(max_file_bytes);
+    }
+    if let Some(interval_ms) = obj.get("rotate_interval_ms").and_then(|v| v.as_u64()) {
+        config = config.with_rotate_interval(Duration::from_millis(interval_ms));
+    }
+    Ok(config)
+}
+
+fn http_sink_from_ir_settings(settings: &JsonValue) -> Result<HttpSinkConfig, String> {
+    let obj = settings
+        .as_object()
+        .ok_or_else(|| "http sink settings must be an object".to_string())?;
+
+    let sink_name = obj
+        .get("sink_name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "http sink settings missing sink_name".to_string())?;
+    let url = obj
+        .get("url")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "http sink settings missing url".to_string())?;
+
+    let mut config = HttpSinkConfig::new(sink_name, url);
+    if let Some(timeout_ms) = obj.get("timeout_ms").and_then(|v| v.as_u64()) {
+        config = config.with_timeout(Duration::from_millis(timeout_ms));
+    }
+    for (name, value) in string_pairs_from_ir(obj.get("headers"), "http sink headers")? {
+        config = config.with_header(name, value);
+    }
+    Ok(config)
+}
+
+fn kafka_sink_from_ir_settings(settings: &JsonValue) -> Result<KafkaSinkConfig, String> {
+    let obj = settings
+        .as_object()
+        .ok_or_else(|| "kafka sink settings must be an object".to_string())?;
+
+    let field = |name: &str| {
+        obj.get(name)
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| format!("kafka sink settings missing {name}"))
+    };
+    let mut config = KafkaSinkConfig::new(field("sink_name")?, field("brokers")?, field("topic")?);
+    if let Some(partition) = obj.get("partition").and_then(|v| v.as_i64()) {
+        config = config.with_partition(partition as i32);
+    }
+    if let Some(key_column) = obj.get("key_column").and_then(|v| v.as_str()) {
+        config = config.with_key_column(key_column);
+    }
+    if let Some(timeout_ms) = obj.get("delivery_timeout_ms").and_then(|v| v.as_u64()) {
+        config = config.with_delivery_timeout(Duration::from_millis(timeout_ms));
+    }
+    for (name, value) in string_pairs_from_ir(obj.get("headers"), "kafka sink headers")? {
+        config = config.with_header(name, value);
+    }
+    for (key, value) in string_pairs_from_ir(obj.get("properties"), "kafka sink properties")? {
+        config = config.with_property(key, value);
+    }
+    Ok(config)
+}
+
+/// Read an optional list of `[name, value]` string pairs.
+fn string_pairs_from_ir(
+    value: Option<&JsonValue>,
+    what: &str,
+) -> Result<Vec<(String, String)>, String> {
+    let items = value
+        .and_then(|v| v.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    items
+        .iter()
+        .map(|item| match item.as_array().map(Vec::as_slice) {
+            Some([JsonValue::String(name), JsonValue::String(value)]) => {
+                Ok((name.clone(), value.clone()))
+            }
+            _ => Err(format!("{what} must be [name, value] pairs")),
+        })
+        .collect()
+}
+
 fn common_sink_props_from_ir(common: &CommonSinkPropsIR) -> CommonSinkProps {
     CommonSinkProps {
         batch_count: common.batch_count,
         batch_duration: common.batch_duration_ms.map(Duration::from_millis),
+        retry: common
+            .retry
+            .as_ref()
+            .map(|retry| SinkRetryProps {
+                max_retries: retry.max_retries,
+                initial_backoff: Duration::from_millis(retry.initial_backoff_ms),
+                max_backoff: Duration::from_millis(retry.max_backoff_ms),
+                buffer_capacity: retry.buffer_capacity,
+            })
+            .unwrap_or_default(),
     }
 }
 
@@ -486,6 +703,15 @@ fn window_ir_to_spec(
                 length: *length,
             }
         }
+        WindowIR::Hopping {
+            time_unit,
+            size,
+            hop,
+        } => crate::planner::logical::LogicalWindowSpec::Hopping {
+            time_unit: time_unit_ir_to_time_unit(*time_unit),
+            size: *size,
+            hop: *hop,
+        },
         WindowIR::Count { count } => {
             crate::planner::logical::LogicalWindowSpec::Count { count: *count }
         }
@@ -498,6 +724,17 @@ fn window_ir_to_spec(
             lookback: *lookback,
             lookahead: *lookahead,
         },
+        WindowIR::Session {
+            time_unit,
+            gap,
+            max_duration,
+            partition_by,
+        } => crate::planner::logical::LogicalWindowSpec::Session {
+            time_unit: time_unit_ir_to_time_unit(*time_unit),
+            gap: *gap,
+            max_duration: *max_duration,
+            partition_by: partition_by.clone(),
+        },
         WindowIR::State {
             open,
             emit,
@@ -512,7 +749,21 @@ fn window_ir_to_spec(
 
 fn time_unit_ir_to_time_unit(unit: TimeUnitIR) -> crate::planner::logical::TimeUnit {
     match unit {
+        TimeUnitIR::Milliseconds => crate::planner::logical::TimeUnit::Milliseconds,
         TimeUnitIR::Seconds => crate::planner::logical::TimeUnit::Seconds,
+        TimeUnitIR::Minutes => crate::planner::logical::TimeUnit::Minutes,
+        TimeUnitIR::Hours => crate::planner::logical::TimeUnit::Hours,
+        TimeUnitIR::Days => crate::planner::logical::TimeUnit::Days,
+    }
+}
+
+fn time_unit_to_ir(unit: crate::planner::logical::TimeUnit) -> TimeUnitIR {
+    match unit {
+        crate::planner::logical::TimeUnit::Milliseconds => TimeUnitIR::Milliseconds,
+        crate::planner::logical::TimeUnit::Seconds => TimeUnitIR::Seconds,
+        crate::planner::logical::TimeUnit::Minutes => TimeUnitIR::Minutes,
+        crate::planner::logical::TimeUnit::Hours => TimeUnitIR::Hours,
+        crate::planner::logical::TimeUnit::Days => TimeUnitIR::Days,
     }
 }
 
@@ -621,6 +872,18 @@ fn build_logical_ir(
         LogicalPlan::DataSink(plan) => LogicalPlanNodeKindIR::DataSink {
             sinks: vec![sink_to_ir(&plan.sink)],
         },
+        LogicalPlan::Join(plan) => LogicalPlanNodeKindIR::Join {
+            left: plan.left_source.clone(),
+            right: plan.right_source.clone(),
+            condition: plan.condition.clone(),
+            within_ms: plan.within.as_millis() as u64,
+        },
+        LogicalPlan::LookupJoin(plan) => LogicalPlanNodeKindIR::LookupJoin {
+            stream: plan.stream_source.clone(),
+            table: plan.table_name.clone(),
+            alias: plan.table_alias.clone(),
+            condition: plan.condition.clone(),
+        },
     };
 
     out.push(LogicalPlanNodeIR {
@@ -718,6 +981,12 @@ fn common_sink_props_to_ir(common: &CommonSinkProps) -> CommonSinkPropsIR {
     CommonSinkPropsIR {
         batch_count: common.batch_count,
         batch_duration_ms: common.batch_duration.map(|d| d.as_millis() as u64),
+        retry: Some(SinkRetryPropsIR {
+            max_retries: common.retry.max_retries,
+            initial_backoff_ms: common.retry.initial_backoff.as_millis() as u64,
+            max_backoff_ms: common.retry.max_backoff.as_millis() as u64,
+            buffer_capacity: common.retry.buffer_capacity,
+        }),
     }
 }
 
@@ -735,6 +1004,50 @@ fn connector_to_ir(connector: &SinkConnectorConfig) -> (String, JsonValue) {
                 "connector_key": cfg.connector_key,
             }),
         ),
+        SinkConnectorConfig::File(cfg) => {
+            let (sync, sync_interval_ms) = match cfg.sync {
+                FileSyncPolicy::Never => ("never", None),
+                FileSyncPolicy::Always => ("always", None),
+                FileSyncPolicy::Interval(interval) => {
+                    ("interval", Some(interval.as_millis() as u64))
+                }
+            };
+            (
+                "file".to_string(),
+                serde_json::json!({
+                    "sink_name": cfg.sink_name,
+                    "path": cfg.path.to_string_lossy(),
+                    "newline_delimited": cfg.newline_delimited,
+                    "max_file_bytes": cfg.max_file_bytes,
+                    "rotate_interval_ms": cfg.rotate_interval.map(|d| d.as_millis() as u64),
+                    "compress": cfg.compress,
+                    "sync": sync,
+                    "sync_interval_ms": sync_interval_ms,
+                }),
+            )
+        }
+        SinkConnectorConfig::Http(cfg) => (
+            "http".to_string(),
+            serde_json::json!({
+                "sink_name": cfg.sink_name,
+                "url": cfg.url,
+                "headers": cfg.headers,
+                "timeout_ms": cfg.timeout.as_millis() as u64,
+            }),
+        ),
+        SinkConnectorConfig::Kafka(cfg) => (
+            "kafka".to_string(),
+            serde_json::json!({
+                "sink_name": cfg.sink_name,
+                "brokers": cfg.brokers,
+                "topic": cfg.topic,
+                "partition": cfg.partition,
+                "key_column": cfg.key_column,
+                "headers": cfg.headers,
+                "delivery_timeout_ms": cfg.delivery_timeout.as_millis() as u64,
+                "properties": cfg.properties,
+            }),
+        ),
         SinkConnectorConfig::Nop(_) => ("nop".to_string(), JsonValue::Object(JsonMap::new())),
         SinkConnectorConfig::Custom(custom) => (custom.kind.clone(), custom.settings.clone()),
     }
@@ -744,12 +1057,19 @@ fn window_spec_to_ir(spec: &crate::planner::logical::LogicalWindowSpec) -> Windo
     match spec {
         crate::planner::logical::LogicalWindowSpec::Tumbling { time_unit, length } => {
             WindowIR::Tumbling {
-                time_unit: match time_unit {
-                    crate::planner::logical::TimeUnit::Seconds => TimeUnitIR::Seconds,
-                },
+                time_unit: time_unit_to_ir(*time_unit),
                 length: *length,
             }
         }
+        crate::planner::logical::LogicalWindowSpec::Hopping {
+            time_unit,
+            size,
+            hop,
+        } => WindowIR::Hopping {
+            time_unit: time_unit_to_ir(*time_unit),
+            size: *size,
+            hop: *hop,
+        },
         crate::planner::logical::LogicalWindowSpec::Count { count } => {
             WindowIR::Count { count: *count }
         }
@@ -758,12 +1078,21 @@ fn window_spec_to_ir(spec: &crate::planner::logical::LogicalWindowSpec) -> Windo
             lookback,
             lookahead,
         } => WindowIR::Sliding {
-            time_unit: match time_unit {
-                crate::planner::logical::TimeUnit::Seconds => TimeUnitIR::Seconds,
-            },
+            time_unit: time_unit_to_ir(*time_unit),
             lookback: *lookback,
             lookahead: *lookahead,
         },
+        crate::planner::logical::LogicalWindowSpec::Session {
+            time_unit,
+            gap,
+            max_duration,
+            partition_by,
+        } => WindowIR::Session {
+            time_unit: time_unit_to_ir(*time_unit),
+            gap: *gap,
+            max_duration: *max_duration,
+            partition_by: partition_by.clone(),
+        },
         crate::planner::logical::LogicalWindowSpec::State {
             open,
             emit,
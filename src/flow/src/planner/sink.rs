@@ -1,3 +1,6 @@
+use crate::connector::sink::file::FileSinkConfig;
+use crate::connector::sink::http::HttpSinkConfig;
+use crate::connector::sink::kafka::KafkaSinkConfig;
 use crate::connector::sink::mqtt::MqttSinkConfig;
 use serde_json::{Map as JsonMap, Value as JsonValue};
 use std::fmt;
@@ -82,6 +85,9 @@ impl fmt::Debug for PipelineSinkConnector {
 #[derive(Clone, Debug)]
 pub enum SinkConnectorConfig {
     Mqtt(MqttSinkConfig),
+    File(FileSinkConfig),
+    Http(HttpSinkConfig),
+    Kafka(KafkaSinkConfig),
     Nop(NopSinkConfig),
     Custom(CustomSinkConnectorConfig),
 }
@@ -90,6 +96,9 @@ impl SinkConnectorConfig {
     pub fn kind(&self) -> &str {
         match self {
             SinkConnectorConfig::Mqtt(_) => "mqtt",
+            SinkConnectorConfig::File(_) => "file",
+            SinkConnectorConfig::Http(_) => "http",
+            SinkConnectorConfig::Kafka(_) => "kafka",
             SinkConnectorConfig::Nop(_) => "nop",
             SinkConnectorConfig::Custom(custom) => custom.kind.as_str(),
         }
@@ -115,7 +124,7 @@ pub struct CustomSinkConnectorConfig {
 pub struct NopSinkConfig;
 
 /// Configuration for supported sink encoders.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SinkEncoderConfig {
     kind: String,
     props: JsonMap<String, JsonValue>,
@@ -142,11 +151,45 @@ impl SinkEncoderConfig {
     }
 }
 
-/// Common sink-level properties (batching, etc.).
+/// Common sink-level properties (batching, delivery retries, etc.).
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct CommonSinkProps {
     pub batch_count: Option<usize>,
     pub batch_duration: Option<Duration>,
+    pub retry: SinkRetryProps,
+}
+
+/// How a sink reacts when its connector fails to deliver a payload.
+///
+/// A failed send is retried `max_retries` times, waiting `initial_backoff` before the first
+/// retry and doubling the wait up to `max_backoff`. If the connector is still unavailable
+/// afterwards, the payload is kept in a buffer of `buffer_capacity` payloads (oldest dropped
+/// first) and redelivered once the connector recovers. The defaults disable both retries and
+/// buffering, so failed payloads are reported as errors and dropped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SinkRetryProps {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub buffer_capacity: usize,
+}
+
+impl SinkRetryProps {
+    /// Wait before the retry following one that waited `backoff`.
+    pub fn next_backoff(&self, backoff: Duration) -> Duration {
+        backoff.saturating_mul(2).min(self.max_backoff)
+    }
+}
+
+impl Default for SinkRetryProps {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            buffer_capacity: 0,
+        }
+    }
 }
 
 impl CommonSinkProps {
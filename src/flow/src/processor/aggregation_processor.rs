@@ -36,6 +36,8 @@ struct GroupByMeta {
 struct AggregateCallArgs {
     call_idx: usize,
     arg_values: Vec<Vec<Value>>,
+    /// Per-row `FILTER (WHERE ...)` outcome; `None` when the call has no filter.
+    selected: Option<Vec<bool>>,
 }
 
 /// Pre-evaluated group-by expressions for the batch.
@@ -121,6 +123,13 @@ impl AggregationProcessor {
 
             // Update aggregates for this group.
             for call_args in &aggregate_args {
+                if call_args
+                    .selected
+                    .as_ref()
+                    .is_some_and(|selected| !selected[row_idx])
+                {
+                    continue;
+                }
                 let call_idx = call_args.call_idx;
                 let mut row_args = Vec::new();
                 for arg_values in &call_args.arg_values {
@@ -261,6 +270,10 @@ impl Processor for AggregationProcessor {
                                     }
                                 }
                             }
+                            Some(Ok(StreamData::Control(control_signal @ ControlSignal::Checkpoint { .. }))) => {
+                                // Barriers stay on the data path so downstream processors see them in order.
+                                send_with_backpressure(&output, StreamData::control(control_signal)).await?;
+                            }
                             Some(Ok(StreamData::Control(control_signal))) => {
                                 let is_terminal = control_signal.is_terminal();
                                 send_control_with_backpressure(&control_output, control_signal).await?;
@@ -306,6 +319,10 @@ impl Processor for AggregationProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -325,25 +342,11 @@ impl AggregationProcessor {
         physical_aggregation: &PhysicalAggregation,
         aggregate_registry: &Arc<AggregateFunctionRegistry>,
     ) -> Result<Vec<Box<dyn AggregateAccumulator>>, String> {
-        let mut accumulators = Vec::new();
-
-        for call in &physical_aggregation.aggregate_calls {
-            if call.distinct {
-                return Err("DISTINCT aggregates are not supported yet".to_string());
-            }
-
-            let function = aggregate_registry.get(&call.func_name).ok_or_else(|| {
-                format!(
-                    "Aggregate function '{}' not found in registry",
-                    call.func_name
-                )
-            })?;
-
-            let accumulator = function.create_accumulator();
-            accumulators.push(accumulator);
-        }
-
-        Ok(accumulators)
+        physical_aggregation
+            .aggregate_calls
+            .iter()
+            .map(|call| call.create_accumulator(aggregate_registry))
+            .collect()
     }
 
     /// Static version of evaluate_arguments for use in async context
@@ -437,9 +440,25 @@ impl AggregationProcessor {
         let mut all_call_args = Vec::new();
         for (idx, call) in physical_aggregation.aggregate_calls.iter().enumerate() {
             let args = Self::evaluate_arguments_static(call, collection)?;
+            let selected = call
+                .filter
+                .as_ref()
+                .map(|filter| {
+                    filter
+                        .eval_with_collection(collection)
+                        .map(|values| {
+                            values
+                                .into_iter()
+                                .map(|value| value == Value::Bool(true))
+                                .collect()
+                        })
+                        .map_err(|e| format!("Failed to evaluate aggregate filter: {}", e))
+                })
+                .transpose()?;
             all_call_args.push(AggregateCallArgs {
                 call_idx: idx,
                 arg_values: args,
+                selected,
             });
         }
         Ok(all_call_args)
@@ -93,6 +93,9 @@ pub trait Processor: Send + Sync {
     /// Get output channel senders (for connecting downstream processors)
     fn subscribe_output(&self) -> Option<broadcast::Receiver<StreamData>>;
 
+    /// Number of messages still buffered on the output channel for the slowest consumer
+    fn output_queue_len(&self) -> Option<usize>;
+
     /// Subscribe to the processor's control signal output (high priority path)
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>>;
 
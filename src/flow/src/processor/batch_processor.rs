@@ -224,6 +224,10 @@ impl Processor for BatchProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
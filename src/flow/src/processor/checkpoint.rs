@@ -0,0 +1,371 @@
+//! Checkpointing of processor state.
+//!
+//! A checkpoint starts when [`ProcessorPipeline::checkpoint`](super::ProcessorPipeline::checkpoint)
+//! injects a [`ControlSignal::Checkpoint`](super::ControlSignal::Checkpoint) barrier into the
+//! pipeline input. The barrier travels on the data channel, so when it reaches a stateful
+//! processor all rows sent before it have been applied. The processor then reports its state to
+//! the pipeline's [`CheckpointCoordinator`] and forwards the barrier; the checkpoint completes once
+//! every registered processor has reported.
+//!
+//! Barriers are not aligned across multiple inputs: a processor fed by several inputs snapshots
+//! when the first copy of the barrier arrives.
+
+use crate::model::{AffiliateRow, Message, Tuple};
+use crate::processor::base::forward_error;
+use crate::processor::{ControlSignal, ProcessorError, StreamData};
+use datatypes::Value;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tokio::sync::{broadcast, mpsc, oneshot};
+
+/// How long a checkpoint waits for every stateful processor to report.
+pub const CHECKPOINT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Errors raised while encoding or decoding checkpoint state.
+#[derive(Debug, thiserror::Error)]
+pub enum CheckpointError {
+    #[error("failed to encode checkpoint state: {0}")]
+    Encode(String),
+    #[error("failed to decode checkpoint state: {0}")]
+    Decode(String),
+}
+
+/// State of every stateful processor of a pipeline, captured at one barrier.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PipelineCheckpoint {
+    pub checkpoint_id: u64,
+    /// Processor state keyed by processor id.
+    pub processors: BTreeMap<String, JsonValue>,
+}
+
+impl PipelineCheckpoint {
+    pub fn encode(&self) -> Result<Vec<u8>, CheckpointError> {
+        serde_json::to_vec(self).map_err(|err| CheckpointError::Encode(err.to_string()))
+    }
+
+    pub fn decode(raw: &[u8]) -> Result<Self, CheckpointError> {
+        serde_json::from_slice(raw).map_err(|err| CheckpointError::Decode(err.to_string()))
+    }
+}
+
+/// Serializable form of a buffered [`Tuple`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TupleSnapshot {
+    messages: Vec<MessageSnapshot>,
+    affiliate: Option<Vec<(String, Value)>>,
+    timestamp: SystemTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MessageSnapshot {
+    source: String,
+    keys: Vec<String>,
+    values: Vec<Value>,
+}
+
+impl From<&Tuple> for TupleSnapshot {
+    fn from(tuple: &Tuple) -> Self {
+        let messages = tuple
+            .messages
+            .iter()
+            .map(|message| {
+                let (keys, values) = message
+                    .entries()
+                    .map(|(key, value)| (key.to_string(), value.clone()))
+                    .unzip();
+                MessageSnapshot {
+                    source: message.source().to_string(),
+                    keys,
+                    values,
+                }
+            })
+            .collect();
+        let affiliate = tuple.affiliate.as_ref().map(|affiliate| {
+            affiliate
+                .entries()
+                .map(|(key, value)| (key.as_ref().clone(), value.clone()))
+                .collect()
+        });
+        Self {
+            messages,
+            affiliate,
+            timestamp: tuple.timestamp,
+        }
+    }
+}
+
+impl TupleSnapshot {
+    pub fn into_tuple(self) -> Tuple {
+        let messages = self
+            .messages
+            .into_iter()
+            .map(|message| {
+                Arc::new(Message::new(
+                    message.source,
+                    message.keys.into_iter().map(Arc::from).collect(),
+                    message.values.into_iter().map(Arc::new).collect(),
+                ))
+            })
+            .collect();
+        let mut tuple = Tuple::with_timestamp(messages, self.timestamp);
+        tuple.affiliate = self.affiliate.map(|entries| {
+            AffiliateRow::new(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (Arc::new(key), value))
+                    .collect(),
+            )
+        });
+        tuple
+    }
+}
+
+/// Collects processor snapshots for the checkpoints of one pipeline and hands restored state
+/// back to processors when the pipeline starts.
+///
+/// Cloning is cheap; all clones share the same state.
+#[derive(Clone, Default)]
+pub struct CheckpointCoordinator {
+    inner: Arc<Mutex<CoordinatorState>>,
+}
+
+#[derive(Default)]
+struct CoordinatorState {
+    participants: BTreeSet<String>,
+    restored: HashMap<String, JsonValue>,
+    next_checkpoint_id: u64,
+    pending: HashMap<u64, PendingCheckpoint>,
+}
+
+struct PendingCheckpoint {
+    processors: BTreeMap<String, JsonValue>,
+    waiter: oneshot::Sender<PipelineCheckpoint>,
+}
+
+impl CheckpointCoordinator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a processor whose state must be part of every checkpoint.
+    pub fn register(&self, processor_id: &str) {
+        self.lock().participants.insert(processor_id.to_string());
+    }
+
+    pub fn has_participants(&self) -> bool {
+        !self.lock().participants.is_empty()
+    }
+
+    /// Stage the state of a previous checkpoint; processors pick it up when they start.
+    pub fn restore(&self, checkpoint: PipelineCheckpoint) {
+        let mut state = self.lock();
+        state.next_checkpoint_id = state
+            .next_checkpoint_id
+            .max(checkpoint.checkpoint_id.saturating_add(1));
+        state.restored = checkpoint.processors.into_iter().collect();
+    }
+
+    /// Take the restored state for a processor, if any was staged.
+    pub fn take_restored<T: DeserializeOwned>(
+        &self,
+        processor_id: &str,
+    ) -> Result<Option<T>, CheckpointError> {
+        let Some(raw) = self.lock().restored.remove(processor_id) else {
+            return Ok(None);
+        };
+        serde_json::from_value(raw)
+            .map(Some)
+            .map_err(|err| CheckpointError::Decode(format!("processor {processor_id}: {err}")))
+    }
+
+    /// Allocate a checkpoint id and a receiver that resolves once every participant reported.
+    pub(crate) fn begin(&self) -> (u64, oneshot::Receiver<PipelineCheckpoint>) {
+        let mut state = self.lock();
+        let checkpoint_id = state.next_checkpoint_id;
+        state.next_checkpoint_id += 1;
+        let (waiter, receiver) = oneshot::channel();
+        if state.participants.is_empty() {
+            let _ = waiter.send(PipelineCheckpoint {
+                checkpoint_id,
+                processors: BTreeMap::new(),
+            });
+        } else {
+            state.pending.insert(
+                checkpoint_id,
+                PendingCheckpoint {
+                    processors: BTreeMap::new(),
+                    waiter,
+                },
+            );
+        }
+        (checkpoint_id, receiver)
+    }
+
+    /// Drop a checkpoint that will not complete.
+    pub(crate) fn abort(&self, checkpoint_id: u64) {
+        self.lock().pending.remove(&checkpoint_id);
+    }
+
+    /// Record the state of one processor for a checkpoint.
+    ///
+    /// Reports for unknown or already completed checkpoints are ignored.
+    pub fn report<T: Serialize>(
+        &self,
+        checkpoint_id: u64,
+        processor_id: &str,
+        processor_state: &T,
+    ) -> Result<(), CheckpointError> {
+        let encoded = serde_json::to_value(processor_state)
+            .map_err(|err| CheckpointError::Encode(format!("processor {processor_id}: {err}")))?;
+        let mut state = self.lock();
+        let Some(pending) = state.pending.get_mut(&checkpoint_id) else {
+            return Ok(());
+        };
+        pending.processors.insert(processor_id.to_string(), encoded);
+        let complete = state.participants.iter().all(|id| {
+            state
+                .pending
+                .get(&checkpoint_id)
+                .is_some_and(|pending| pending.processors.contains_key(id))
+        });
+        if complete {
+            if let Some(pending) = state.pending.remove(&checkpoint_id) {
+                let _ = pending.waiter.send(PipelineCheckpoint {
+                    checkpoint_id,
+                    processors: pending.processors,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CoordinatorState> {
+        self.inner.lock().expect("checkpoint coordinator poisoned")
+    }
+}
+
+/// Handle that injects checkpoint barriers into a running pipeline.
+///
+/// It only holds the pipeline input sender and coordinator, so callers can keep it around
+/// without borrowing the pipeline while waiting for the checkpoint to complete.
+#[derive(Clone)]
+pub struct CheckpointTrigger {
+    input: mpsc::Sender<StreamData>,
+    coordinator: CheckpointCoordinator,
+}
+
+impl CheckpointTrigger {
+    pub(crate) fn new(input: mpsc::Sender<StreamData>, coordinator: CheckpointCoordinator) -> Self {
+        Self { input, coordinator }
+    }
+
+    /// Send a barrier and wait until every stateful processor reported its state.
+    pub async fn trigger(&self) -> Result<PipelineCheckpoint, ProcessorError> {
+        let (checkpoint_id, receiver) = self.coordinator.begin();
+        if self.coordinator.has_participants() {
+            let barrier = StreamData::control(ControlSignal::Checkpoint { checkpoint_id });
+            if self.input.send(barrier).await.is_err() {
+                self.coordinator.abort(checkpoint_id);
+                return Err(ProcessorError::ChannelClosed);
+            }
+        }
+        match tokio::time::timeout(CHECKPOINT_TIMEOUT, receiver).await {
+            Ok(Ok(checkpoint)) => Ok(checkpoint),
+            Ok(Err(_)) => Err(ProcessorError::ChannelClosed),
+            Err(_) => {
+                self.coordinator.abort(checkpoint_id);
+                Err(ProcessorError::Timeout)
+            }
+        }
+    }
+}
+
+/// Take the restored state of a processor when it starts.
+///
+/// State that cannot be decoded is reported downstream as an error and the processor starts
+/// from scratch.
+pub(crate) async fn take_restored_state<T: DeserializeOwned>(
+    coordinator: Option<&CheckpointCoordinator>,
+    processor_id: &str,
+    output: &broadcast::Sender<StreamData>,
+) -> Result<Option<T>, ProcessorError> {
+    let Some(coordinator) = coordinator else {
+        return Ok(None);
+    };
+    match coordinator.take_restored(processor_id) {
+        Ok(state) => Ok(state),
+        Err(err) => {
+            forward_error(output, processor_id, err.to_string()).await?;
+            Ok(None)
+        }
+    }
+}
+
+/// Report the state of a processor for the checkpoint barrier it just received.
+pub(crate) async fn report_state<T: Serialize>(
+    coordinator: &CheckpointCoordinator,
+    checkpoint_id: u64,
+    processor_id: &str,
+    state: &T,
+    output: &broadcast::Sender<StreamData>,
+) -> Result<(), ProcessorError> {
+    if let Err(err) = coordinator.report(checkpoint_id, processor_id, state) {
+        forward_error(output, processor_id, err.to_string()).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkpoint_completes_when_all_participants_report() {
+        let coordinator = CheckpointCoordinator::new();
+        coordinator.register("a");
+        coordinator.register("b");
+
+        let (checkpoint_id, mut receiver) = coordinator.begin();
+        coordinator.report(checkpoint_id, "a", &1u64).unwrap();
+        assert!(receiver.try_recv().is_err());
+        coordinator.report(checkpoint_id, "b", &2u64).unwrap();
+
+        let checkpoint = receiver.try_recv().expect("checkpoint complete");
+        assert_eq!(checkpoint.checkpoint_id, checkpoint_id);
+        assert_eq!(checkpoint.processors.len(), 2);
+
+        let restored = CheckpointCoordinator::new();
+        restored.restore(PipelineCheckpoint::decode(&checkpoint.encode().unwrap()).unwrap());
+        assert_eq!(restored.take_restored::<u64>("b").unwrap(), Some(2));
+        assert_eq!(restored.take_restored::<u64>("b").unwrap(), None);
+        assert_eq!(restored.begin().0, checkpoint_id + 1);
+    }
+
+    #[test]
+    fn tuple_snapshot_roundtrip() {
+        let mut tuple = Tuple::with_timestamp(
+            vec![Arc::new(Message::new(
+                "s",
+                vec![Arc::from("a")],
+                vec![Arc::new(Value::Int64(1))],
+            ))],
+            SystemTime::UNIX_EPOCH,
+        );
+        tuple.add_affiliate_column(Arc::new("x".to_string()), Value::Bool(true));
+
+        let encoded = serde_json::to_vec(&TupleSnapshot::from(&tuple)).unwrap();
+        let decoded = serde_json::from_slice::<TupleSnapshot>(&encoded)
+            .unwrap()
+            .into_tuple();
+        assert_eq!(decoded.value_by_name("s", "a"), Some(&Value::Int64(1)));
+        assert_eq!(
+            decoded.affiliate.as_ref().and_then(|aff| aff.value("x")),
+            Some(&Value::Bool(true))
+        );
+        assert_eq!(decoded.timestamp, SystemTime::UNIX_EPOCH);
+    }
+}
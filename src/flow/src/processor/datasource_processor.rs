@@ -339,6 +339,10 @@ impl Processor for DataSourceProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -0,0 +1,250 @@
+//! Record-level error policy and dead-letter delivery.
+//!
+//! Decoders fail on malformed payloads and filters/projections fail when an expression cannot be
+//! evaluated. The pipeline's [`ErrorPolicy`] decides what happens to such a record:
+//! - `Drop` reports the error downstream as [`StreamData::Error`] and moves on.
+//! - `Fail` reports the error, ends the stream downstream and stops the processor.
+//! - `DeadLetter` behaves like `Drop`, and a [`DeadLetterProcessor`] additionally delivers the
+//!   failed record to a dedicated sink connector.
+//!
+//! Failed records reach the dead-letter processor through a bounded queue of their own rather
+//! than the broadcast data path, so a slow dead-letter sink holds back the reporting processor
+//! instead of losing records.
+
+use crate::codec::{CollectionEncoder, JsonEncoder};
+use crate::connector::SinkConnector;
+use crate::model::{batch_from_columns_simple, Collection};
+use crate::processor::base::{
+    fan_in_control_streams, send_with_backpressure, ControlInputStream, DEFAULT_CHANNEL_CAPACITY,
+};
+use crate::processor::{ControlSignal, ProcessorError, StreamData, StreamError};
+use datatypes::{Timestamp, TimestampPrecision, Value};
+use futures::stream::StreamExt;
+use serde_json::Map as JsonMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
+
+/// Sending half of the dead-letter queue, handed to every processor that rejects records.
+pub type DeadLetterSender = mpsc::Sender<StreamError>;
+
+/// What a pipeline does with records that fail to decode or evaluate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Report the error and skip the record.
+    #[default]
+    Drop,
+    /// Report the error and stop the pipeline.
+    Fail,
+    /// Report the error and deliver the record to the dead-letter sink.
+    DeadLetter,
+}
+
+impl ErrorPolicy {
+    /// Parse a policy name as used in pipeline options (`drop`, `fail`, `dead_letter`).
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "drop" => Some(ErrorPolicy::Drop),
+            "fail" => Some(ErrorPolicy::Fail),
+            "dead_letter" => Some(ErrorPolicy::DeadLetter),
+            _ => None,
+        }
+    }
+
+    /// Whether failed records must be kept on the error for dead-letter delivery.
+    pub fn keeps_payload(&self) -> bool {
+        matches!(self, ErrorPolicy::DeadLetter)
+    }
+}
+
+/// Report a failed record on `output` according to `policy`.
+///
+/// Under [`ErrorPolicy::DeadLetter`] the record is also queued on `dead_letter`, waiting for room
+/// when the queue is full. Under [`ErrorPolicy::Fail`] the stream is ended downstream and an error
+/// is returned so the calling processor stops.
+pub(crate) async fn report_record_error(
+    output: &broadcast::Sender<StreamData>,
+    policy: ErrorPolicy,
+    dead_letter: Option<&DeadLetterSender>,
+    error: StreamError,
+) -> Result<(), ProcessorError> {
+    let message = error.message.clone();
+    if let (ErrorPolicy::DeadLetter, Some(dead_letter)) = (policy, dead_letter) {
+        if dead_letter.send(error.clone()).await.is_err() {
+            tracing::warn!(
+                processor_id = error.source.as_deref().unwrap_or_default(),
+                "dead-letter processor stopped, failed record not delivered"
+            );
+        }
+    }
+    send_with_backpressure(output, StreamData::error(error)).await?;
+    if policy == ErrorPolicy::Fail {
+        send_with_backpressure(output, StreamData::stream_end()).await?;
+        return Err(ProcessorError::ProcessingError(message));
+    }
+    Ok(())
+}
+
+/// Rows of `collection` encoded as JSON, used as the dead-letter payload of evaluation errors.
+pub(crate) fn collection_payload(collection: &dyn Collection) -> Option<Vec<u8>> {
+    JsonEncoder::new("dead_letter", JsonMap::new())
+        .encode(collection)
+        .ok()
+}
+
+/// Delivers records rejected by decoders, filters and projections to a sink connector.
+///
+/// Every dead-letter record has the columns `processor`, `message`, `timestamp`, `payload` and
+/// `payload_encoding`. The payload holds the original bytes for decode errors and the input rows
+/// as JSON for evaluation errors; it is stored as text when it is valid UTF-8 (`utf8`) and as
+/// lowercase hex otherwise (`hex`).
+pub struct DeadLetterProcessor {
+    id: String,
+    /// Kept until start so that [`Self::sender`] can hand out clones
+    sender: Option<DeadLetterSender>,
+    receiver: Option<mpsc::Receiver<StreamError>>,
+    control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
+    connector: Option<Box<dyn SinkConnector>>,
+    encoder: Arc<dyn CollectionEncoder>,
+}
+
+impl DeadLetterProcessor {
+    pub fn new(
+        id: impl Into<String>,
+        connector: Box<dyn SinkConnector>,
+        encoder: Arc<dyn CollectionEncoder>,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel(DEFAULT_CHANNEL_CAPACITY);
+        Self {
+            id: id.into(),
+            sender: Some(sender),
+            receiver: Some(receiver),
+            control_inputs: Vec::new(),
+            connector: Some(connector),
+            encoder,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Queue for the failed records of one processor; must be taken before [`Self::start`].
+    ///
+    /// The processor stops once every sender is dropped or a terminal control signal arrives.
+    pub fn sender(&self) -> Option<DeadLetterSender> {
+        self.sender.clone()
+    }
+
+    pub fn add_control_input(&mut self, receiver: broadcast::Receiver<ControlSignal>) {
+        self.control_inputs.push(receiver);
+    }
+
+    pub fn start(&mut self) -> JoinHandle<Result<(), ProcessorError>> {
+        let processor_id = self.id.clone();
+        let encoder = Arc::clone(&self.encoder);
+        // Only the reporting processors keep the queue open from here on.
+        self.sender = None;
+        let mut control_streams: ControlInputStream =
+            fan_in_control_streams(std::mem::take(&mut self.control_inputs));
+        let mut control_active = !control_streams.is_empty();
+        let (Some(mut connector), Some(mut receiver)) =
+            (self.connector.take(), self.receiver.take())
+        else {
+            return tokio::spawn(async {
+                Err(ProcessorError::InvalidConfiguration(
+                    "dead-letter processor already started".to_string(),
+                ))
+            });
+        };
+        tracing::info!(processor_id = %processor_id, "dead-letter processor starting");
+
+        tokio::spawn(async move {
+            connector
+                .ready()
+                .await
+                .map_err(|err| ProcessorError::ProcessingError(err.to_string()))?;
+            loop {
+                tokio::select! {
+                    biased;
+                    control_item = control_streams.next(), if control_active => {
+                        match control_item {
+                            // Deliver what is already queued, then stop.
+                            Some(Ok(signal)) if signal.is_terminal() => {
+                                receiver.close();
+                                control_active = false;
+                            }
+                            Some(_) => {}
+                            None => control_active = false,
+                        }
+                    }
+                    error = receiver.recv() => {
+                        let Some(error) = error else {
+                            break;
+                        };
+                        let Some(payload) = encode_dead_letter(encoder.as_ref(), &error) else {
+                            continue;
+                        };
+                        // A broken dead-letter sink must not take the pipeline down.
+                        if let Err(err) = connector.send(&payload).await {
+                            tracing::error!(
+                                processor_id = %processor_id,
+                                error = %err,
+                                "dead-letter delivery failed"
+                            );
+                        }
+                    }
+                }
+            }
+            connector
+                .close()
+                .await
+                .map_err(|err| ProcessorError::ProcessingError(err.to_string()))?;
+            tracing::info!(processor_id = %processor_id, "stopped");
+            Ok(())
+        })
+    }
+}
+
+fn encode_dead_letter(encoder: &dyn CollectionEncoder, error: &StreamError) -> Option<Vec<u8>> {
+    let (payload, encoding) = match &error.payload {
+        Some(bytes) => match std::str::from_utf8(bytes) {
+            Ok(text) => (Value::String(text.to_string()), "utf8"),
+            Err(_) => (Value::String(hex(bytes)), "hex"),
+        },
+        None => (Value::Null, "utf8"),
+    };
+    let at = error.timestamp.unwrap_or_else(SystemTime::now);
+    let timestamp = Timestamp::from_system_time(at, TimestampPrecision::Millisecond)
+        .map(Value::Timestamp)
+        .unwrap_or(Value::Null);
+    let column = |name: &str, value: Value| (String::new(), name.to_string(), vec![value]);
+    let batch = batch_from_columns_simple(vec![
+        column(
+            "processor",
+            Value::String(error.source.clone().unwrap_or_default()),
+        ),
+        column("message", Value::String(error.message.clone())),
+        column("timestamp", timestamp),
+        column("payload", payload),
+        column("payload_encoding", Value::String(encoding.to_string())),
+    ])
+    .ok()?;
+    match encoder.encode(&batch) {
+        Ok(payload) => Some(payload),
+        Err(err) => {
+            tracing::error!(error = %err, "failed to encode dead-letter record");
+            None
+        }
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(out, "{byte:02x}");
+    }
+    out
+}
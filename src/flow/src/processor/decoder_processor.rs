@@ -7,7 +7,8 @@ use crate::processor::base::{
     fan_in_control_streams, fan_in_streams, forward_error, log_received_data,
     send_control_with_backpressure, send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
 };
-use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData};
+use crate::processor::dead_letter::{report_record_error, DeadLetterSender, ErrorPolicy};
+use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData, StreamError};
 use futures::stream::StreamExt;
 use std::sync::Arc;
 use std::time::SystemTime;
@@ -33,6 +34,8 @@ pub struct DecoderProcessor {
     projection: Option<Arc<std::sync::RwLock<Vec<String>>>>,
     decode_projection: Option<DecodeProjection>,
     eventtime: Option<EventtimeDecodeConfig>,
+    error_policy: ErrorPolicy,
+    dead_letter: Option<DeadLetterSender>,
 }
 
 impl DecoderProcessor {
@@ -54,6 +57,8 @@ impl DecoderProcessor {
             projection: None,
             decode_projection: None,
             eventtime: None,
+            error_policy: ErrorPolicy::default(),
+            dead_letter: None,
         }
     }
 
@@ -71,6 +76,17 @@ impl DecoderProcessor {
         self.eventtime = Some(eventtime);
         self
     }
+
+    /// Decide what happens to payloads that fail to decode.
+    pub fn with_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.error_policy = policy;
+        self
+    }
+
+    /// Queue failed records for the pipeline's dead-letter processor.
+    pub fn set_dead_letter_sender(&mut self, sender: DeadLetterSender) {
+        self.dead_letter = Some(sender);
+    }
 }
 
 impl Processor for DecoderProcessor {
@@ -85,6 +101,8 @@ impl Processor for DecoderProcessor {
         let projection = self.projection.clone();
         let decode_projection = self.decode_projection.clone();
         let eventtime = self.eventtime.clone();
+        let error_policy = self.error_policy;
+        let dead_letter = self.dead_letter.take();
         let processor_id = self.id.clone();
         let base_inputs = std::mem::take(&mut self.inputs);
         let mut input_streams = fan_in_streams(base_inputs);
@@ -139,8 +157,13 @@ impl Processor for DecoderProcessor {
                                             }
                                         }
                                         Err(err) => {
-                                            let message = format!("decode error: {}", err);
-                                            forward_error(&output, &processor_id, message).await?;
+                                            let mut error = StreamError::new(format!("decode error: {}", err))
+                                                .with_source(processor_id.clone())
+                                                .with_timestamp(SystemTime::now());
+                                            if error_policy.keeps_payload() {
+                                                error = error.with_payload(payload.clone());
+                                            }
+                                            report_record_error(&output, error_policy, dead_letter.as_ref(), error).await?;
                                             continue;
                                         }
                                     }
@@ -175,6 +198,10 @@ impl Processor for DecoderProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -0,0 +1,426 @@
+//! HoppingWindowProcessor - buffers rows and emits overlapping fixed-size windows on watermarks.
+//!
+//! Windows have length `size` and start at every multiple of `hop`, so a row belongs to up to
+//! `ceil(size / hop)` windows. Each window is emitted as its own batch once the watermark passes
+//! its end; rows are kept until the last window containing them has been emitted.
+
+use crate::planner::physical::{PhysicalHoppingWindow, PhysicalPlan};
+use crate::processor::base::{
+    fan_in_control_streams, fan_in_streams, forward_error, send_control_with_backpressure,
+    send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+};
+use crate::processor::checkpoint::{report_state, take_restored_state, TupleSnapshot};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::StreamExt;
+
+pub struct HoppingWindowProcessor {
+    id: String,
+    size: Duration,
+    hop: Duration,
+    inputs: Vec<broadcast::Receiver<StreamData>>,
+    control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
+    output: broadcast::Sender<StreamData>,
+    control_output: broadcast::Sender<ControlSignal>,
+    checkpoint: Option<CheckpointCoordinator>,
+}
+
+impl HoppingWindowProcessor {
+    pub fn new(id: impl Into<String>, physical: Arc<PhysicalHoppingWindow>) -> Self {
+        let (output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let (control_output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let size = physical.time_unit.duration(physical.size);
+        let hop = physical.time_unit.duration(physical.hop);
+        Self {
+            id: id.into(),
+            size,
+            hop,
+            inputs: Vec::new(),
+            control_inputs: Vec::new(),
+            output,
+            control_output,
+            checkpoint: None,
+        }
+    }
+
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
+    pub fn from_physical_plan(id: impl Into<String>, plan: Arc<PhysicalPlan>) -> Option<Self> {
+        match plan.as_ref() {
+            PhysicalPlan::HoppingWindow(window) => Some(Self::new(id, Arc::new(window.clone()))),
+            _ => None,
+        }
+    }
+}
+
+impl Processor for HoppingWindowProcessor {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn start(&mut self) -> tokio::task::JoinHandle<Result<(), ProcessorError>> {
+        let id = self.id.clone();
+        let mut input_streams = fan_in_streams(std::mem::take(&mut self.inputs));
+        let control_receivers = std::mem::take(&mut self.control_inputs);
+        let mut control_streams = fan_in_control_streams(control_receivers);
+        let mut control_active = !control_streams.is_empty();
+        let output = self.output.clone();
+        let control_output = self.control_output.clone();
+
+        let size_ms = (self.size.as_millis() as u64).max(1);
+        let hop_ms = (self.hop.as_millis() as u64).clamp(1, size_ms);
+        let mut state = ProcessingState::new(size_ms, hop_ms, output.clone());
+        let checkpoint = self.checkpoint.clone();
+
+        tokio::spawn(async move {
+            if let Some(snapshot) =
+                take_restored_state::<HoppingWindowSnapshot>(checkpoint.as_ref(), &id, &output)
+                    .await?
+            {
+                state.restore(snapshot);
+            }
+            loop {
+                tokio::select! {
+                    biased;
+                    control_item = control_streams.next(), if control_active => {
+                        if let Some(Ok(control_signal)) = control_item {
+                            let is_terminal = control_signal.is_terminal();
+                            send_control_with_backpressure(&control_output, control_signal).await?;
+                            if is_terminal {
+                                tracing::info!(processor_id = %id, "stopped");
+                                return Ok(());
+                            }
+                            continue;
+                        } else {
+                            control_active = false;
+                        }
+                    }
+                    item = input_streams.next() => {
+                        match item {
+                            Some(Ok(StreamData::Collection(collection))) => {
+                                if let Err(e) = state.add_collection(collection) {
+                                    forward_error(&output, &id, e.to_string()).await?;
+                                }
+                            }
+                            Some(Ok(StreamData::Watermark(ts))) => {
+                                state.flush_up_to(Some(ts)).await?;
+                            }
+                            Some(Ok(StreamData::Control(signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&signal, &checkpoint) {
+                                    report_state(checkpoint, *checkpoint_id, &id, &state.snapshot(), &output).await?;
+                                }
+                                let is_terminal = signal.is_terminal();
+                                let is_graceful = matches!(signal, ControlSignal::StreamGracefulEnd);
+                                send_with_backpressure(&output, StreamData::control(signal)).await?;
+                                if is_terminal {
+                                    if is_graceful {
+                                        state.flush_up_to(None).await?;
+                                    }
+                                    tracing::info!(processor_id = %id, "stopped");
+                                    return Ok(());
+                                }
+                            }
+                            Some(Ok(other)) => {
+                                let is_terminal = other.is_terminal();
+                                send_with_backpressure(&output, other).await?;
+                                if is_terminal {
+                                    // Non-graceful end on data path: drop buffered rows.
+                                    tracing::info!(processor_id = %id, "stopped");
+                                    return Ok(());
+                                }
+                            }
+                            Some(Err(BroadcastStreamRecvError::Lagged(skipped))) => {
+                                let message = format!(
+                                    "HoppingWindowProcessor input lagged by {} messages",
+                                    skipped
+                                );
+                                forward_error(&output, &id, message).await?;
+                            }
+                            None => {
+                                // Upstream ended without control signal: drop buffered rows.
+                                tracing::info!(processor_id = %id, "stopped");
+                                return Ok(());
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    fn subscribe_output(&self) -> Option<broadcast::Receiver<StreamData>> {
+        Some(self.output.subscribe())
+    }
+
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
+    fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
+        Some(self.control_output.subscribe())
+    }
+
+    fn add_input(&mut self, receiver: broadcast::Receiver<StreamData>) {
+        self.inputs.push(receiver);
+    }
+
+    fn add_control_input(&mut self, receiver: broadcast::Receiver<ControlSignal>) {
+        self.control_inputs.push(receiver);
+    }
+}
+
+/// Checkpointed buffer of a hopping window.
+#[derive(Serialize, Deserialize)]
+struct HoppingWindowSnapshot {
+    rows: Vec<TupleSnapshot>,
+    next_start_ms: Option<u64>,
+}
+
+/// Processing-time hopping state: assumes timestamps are non-decreasing, buffers rows in order.
+struct ProcessingState {
+    rows: VecDeque<crate::model::Tuple>,
+    size_ms: u64,
+    hop_ms: u64,
+    /// Start of the next window to emit; earlier windows have been flushed already.
+    next_start: Option<u64>,
+    output: broadcast::Sender<StreamData>,
+}
+
+impl ProcessingState {
+    fn new(size_ms: u64, hop_ms: u64, output: broadcast::Sender<StreamData>) -> Self {
+        Self {
+            rows: VecDeque::new(),
+            size_ms,
+            hop_ms,
+            next_start: None,
+            output,
+        }
+    }
+
+    fn snapshot(&self) -> HoppingWindowSnapshot {
+        HoppingWindowSnapshot {
+            rows: self.rows.iter().map(TupleSnapshot::from).collect(),
+            next_start_ms: self.next_start,
+        }
+    }
+
+    fn restore(&mut self, snapshot: HoppingWindowSnapshot) {
+        self.rows = snapshot
+            .rows
+            .into_iter()
+            .map(TupleSnapshot::into_tuple)
+            .collect();
+        self.next_start = snapshot.next_start_ms;
+    }
+
+    fn add_collection(
+        &mut self,
+        collection: Box<dyn crate::model::Collection>,
+    ) -> Result<(), ProcessorError> {
+        let rows = collection
+            .into_rows()
+            .map_err(|e| ProcessorError::ProcessingError(format!("failed to extract rows: {e}")))?;
+        self.rows.extend(rows);
+        Ok(())
+    }
+
+    /// Emit every window ending at or before `watermark`, or all remaining windows when `None`.
+    async fn flush_up_to(&mut self, watermark: Option<SystemTime>) -> Result<(), ProcessorError> {
+        let watermark_ms = watermark.map(|ts| to_millis(ts, "watermark")).transpose()?;
+        while let Some(front) = self.rows.front() {
+            let first = first_hopping_window_start(
+                to_millis(front.timestamp, "timestamp")?,
+                self.size_ms,
+                self.hop_ms,
+            );
+            let start = self.next_start.map_or(first, |next| next.max(first));
+            let end = start.saturating_add(self.size_ms);
+            if watermark_ms.is_some_and(|watermark| end > watermark) {
+                break;
+            }
+
+            let mut window_rows = Vec::new();
+            for row in &self.rows {
+                if to_millis(row.timestamp, "timestamp")? >= end {
+                    break;
+                }
+                window_rows.push(row.clone());
+            }
+
+            let next_start = start.saturating_add(self.hop_ms);
+            self.next_start = Some(next_start);
+            while let Some(row) = self.rows.front() {
+                if to_millis(row.timestamp, "timestamp")? >= next_start {
+                    break;
+                }
+                self.rows.pop_front();
+            }
+
+            if window_rows.is_empty() {
+                continue;
+            }
+            let batch = crate::model::RecordBatch::new(window_rows)
+                .map_err(|e| ProcessorError::ProcessingError(e.to_string()))?;
+            send_with_backpressure(&self.output, StreamData::collection(Box::new(batch))).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Start of the earliest hopping window containing `ts`; all arguments share one unit.
+///
+/// Windows are `[k * hop, k * hop + size)`, so the earliest one starts right after `ts - size`.
+pub(crate) fn first_hopping_window_start(ts: u64, size: u64, hop: u64) -> u64 {
+    let hop = hop.max(1);
+    if ts < size {
+        0
+    } else {
+        (ts - size) / hop * hop + hop
+    }
+}
+
+fn to_millis(ts: SystemTime, label: &str) -> Result<u64, ProcessorError> {
+    ts.duration_since(UNIX_EPOCH)
+        .map_err(|e| ProcessorError::ProcessingError(format!("invalid {label}: {e}")))
+        .map(|d| d.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planner::logical::TimeUnit;
+
+    fn tuple_at(sec: u64) -> crate::model::Tuple {
+        crate::model::Tuple::with_timestamp(Vec::new(), UNIX_EPOCH + Duration::from_secs(sec))
+    }
+
+    #[test]
+    fn first_window_start_covers_the_row() {
+        // size=5, hop=2: windows [0,5), [2,7), [4,9), ...
+        assert_eq!(first_hopping_window_start(3, 5, 2), 0);
+        assert_eq!(first_hopping_window_start(6, 5, 2), 2);
+        assert_eq!(first_hopping_window_start(7, 5, 2), 4);
+        // hop == size degenerates to tumbling windows
+        assert_eq!(first_hopping_window_start(13, 10, 10), 10);
+    }
+
+    #[tokio::test]
+    async fn hopping_window_emits_overlapping_windows_on_watermark() {
+        let physical = PhysicalHoppingWindow::new(TimeUnit::Seconds, 4, 2, Vec::new(), 0);
+        let mut processor = HoppingWindowProcessor::new("hw", Arc::new(physical));
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        let batch = crate::model::RecordBatch::new(vec![tuple_at(1), tuple_at(3), tuple_at(6)])
+            .expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+        assert!(input
+            .send(StreamData::watermark(UNIX_EPOCH + Duration::from_secs(8)))
+            .is_ok());
+
+        let mut seen = Vec::new();
+        for _ in 0..3 {
+            match output_rx.recv().await.unwrap() {
+                StreamData::Collection(collection) => seen.push(collection.rows().len()),
+                _ => panic!("unexpected output"),
+            }
+        }
+
+        // [0,4) holds 1 and 3, [2,6) holds 3, [4,8) holds 6.
+        assert_eq!(seen, vec![2, 1, 1]);
+    }
+
+    #[tokio::test]
+    async fn hopping_window_honors_millisecond_unit() {
+        let physical = PhysicalHoppingWindow::new(TimeUnit::Milliseconds, 400, 200, Vec::new(), 0);
+        let mut processor = HoppingWindowProcessor::new("hw", Arc::new(physical));
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        let at = |ms| {
+            crate::model::Tuple::with_timestamp(Vec::new(), UNIX_EPOCH + Duration::from_millis(ms))
+        };
+        let batch = crate::model::RecordBatch::new(vec![at(100), at(300), at(500)]).expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+        assert!(input
+            .send(StreamData::watermark(
+                UNIX_EPOCH + Duration::from_millis(600)
+            ))
+            .is_ok());
+
+        let mut seen = Vec::new();
+        for _ in 0..2 {
+            match output_rx.recv().await.unwrap() {
+                StreamData::Collection(collection) => seen.push(collection.rows().len()),
+                _ => panic!("unexpected output"),
+            }
+        }
+
+        // [0,400ms) holds 100 and 300, [200,600ms) holds 300 and 500.
+        assert_eq!(seen, vec![2, 2]);
+    }
+
+    #[tokio::test]
+    async fn hopping_window_resumes_buffer_from_checkpoint() {
+        let physical = Arc::new(PhysicalHoppingWindow::new(
+            TimeUnit::Seconds,
+            4,
+            2,
+            Vec::new(),
+            0,
+        ));
+        let coordinator = CheckpointCoordinator::new();
+        let mut processor = HoppingWindowProcessor::new("hw", Arc::clone(&physical));
+        processor.set_checkpoint_coordinator(coordinator.clone());
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let _handle = processor.start();
+
+        let batch = crate::model::RecordBatch::new(vec![tuple_at(1), tuple_at(3)]).expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+        let (checkpoint_id, receiver) = coordinator.begin();
+        assert!(input
+            .send(StreamData::control(ControlSignal::Checkpoint {
+                checkpoint_id
+            }))
+            .is_ok());
+        let checkpoint = receiver.await.expect("checkpoint completes");
+
+        let restored = CheckpointCoordinator::new();
+        restored.restore(checkpoint);
+        let mut processor = HoppingWindowProcessor::new("hw", physical);
+        processor.set_checkpoint_coordinator(restored);
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        assert!(input
+            .send(StreamData::watermark(UNIX_EPOCH + Duration::from_secs(6)))
+            .is_ok());
+        let mut seen = Vec::new();
+        for _ in 0..2 {
+            match output_rx.recv().await.unwrap() {
+                StreamData::Collection(collection) => seen.push(collection.rows().len()),
+                _ => panic!("unexpected output"),
+            }
+        }
+
+        // [0,4) holds 1 and 3, [2,6) holds 3.
+        assert_eq!(seen, vec![2, 1]);
+    }
+}
@@ -0,0 +1,66 @@
+//! Join key normalization shared by the stream join and the lookup join.
+
+use crate::expr::ScalarExpr;
+use crate::model::Tuple;
+use crate::processor::ProcessorError;
+use datatypes::Value;
+
+/// One join key value in a form that hashes equal whenever `=` compares equal.
+///
+/// Integers of any width and integral floats share `Int`, so `Int32(1)`, `Int64(1)` and
+/// `Float64(1.0)` land in the same bucket. Timestamps compare by instant, whatever their
+/// precision.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum KeyPart {
+    Int(i128),
+    /// Bits of a non-integral float, with -0.0 folded into 0.0
+    Float(u64),
+    /// Nanoseconds since the unix epoch
+    Timestamp(i128),
+    Other(String),
+}
+
+impl KeyPart {
+    pub(crate) fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Int8(v) => Self::Int(*v as i128),
+            Value::Int16(v) => Self::Int(*v as i128),
+            Value::Int32(v) => Self::Int(*v as i128),
+            Value::Int64(v) => Self::Int(*v as i128),
+            Value::Uint8(v) => Self::Int(*v as i128),
+            Value::Uint16(v) => Self::Int(*v as i128),
+            Value::Uint32(v) => Self::Int(*v as i128),
+            Value::Uint64(v) => Self::Int(*v as i128),
+            Value::Float32(v) => Self::from_float(*v as f64),
+            Value::Float64(v) => Self::from_float(*v),
+            Value::Timestamp(ts) => Self::Timestamp(ts.to_nanos()),
+            other => Self::Other(format!("{:?}", other)),
+        }
+    }
+
+    fn from_float(v: f64) -> Self {
+        if v.fract() == 0.0 && v.abs() < i128::MAX as f64 {
+            Self::Int(v as i128)
+        } else {
+            Self::Float(v.to_bits())
+        }
+    }
+}
+
+/// Evaluate the join key `exprs` against `tuple`; rows with a NULL key never match.
+pub(crate) fn eval_join_key(
+    exprs: &[ScalarExpr],
+    tuple: &Tuple,
+) -> Result<Option<Vec<KeyPart>>, ProcessorError> {
+    let mut key_values = Vec::with_capacity(exprs.len());
+    for expr in exprs {
+        let value = expr.eval_with_tuple(tuple).map_err(|e| {
+            ProcessorError::ProcessingError(format!("failed to evaluate join key: {e}"))
+        })?;
+        if matches!(value, Value::Null) {
+            return Ok(None);
+        }
+        key_values.push(KeyPart::from_value(&value));
+    }
+    Ok(Some(key_values))
+}
@@ -0,0 +1,435 @@
+//! LookupJoinProcessor - inner join between a stream and an in-memory lookup table.
+//!
+//! Table rows are indexed by their join key. The index is rebuilt whenever the table handle
+//! exposes a new snapshot, so a table reload is picked up by the next incoming batch without
+//! restarting the pipeline.
+
+use crate::catalog::LookupTable;
+use crate::expr::ScalarExpr;
+use crate::model::{Message, RecordBatch, Tuple};
+use crate::planner::physical::{PhysicalLookupJoin, PhysicalPlan};
+use crate::processor::base::{
+    fan_in_control_streams, fan_in_streams, forward_error, log_received_data,
+    send_control_with_backpressure, send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+};
+use crate::processor::join_key::{eval_join_key, KeyPart};
+use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData};
+use datatypes::Value;
+use futures::stream::StreamExt;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+
+/// LookupJoinProcessor - enriches stream rows with matching rows of a lookup table
+pub struct LookupJoinProcessor {
+    /// Processor identifier
+    id: String,
+    /// Physical join configuration
+    physical_join: Arc<PhysicalLookupJoin>,
+    /// Shared table rows, attached from the catalog when the pipeline is built
+    table: LookupTable,
+    /// Input channels for receiving data
+    inputs: Vec<broadcast::Receiver<StreamData>>,
+    /// Control input channels
+    control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
+    /// Broadcast channel for downstream processors
+    output: broadcast::Sender<StreamData>,
+    /// Dedicated control output channel
+    control_output: broadcast::Sender<ControlSignal>,
+}
+
+impl LookupJoinProcessor {
+    /// Create a new LookupJoinProcessor from PhysicalLookupJoin
+    pub fn new(id: impl Into<String>, physical_join: Arc<PhysicalLookupJoin>) -> Self {
+        let (output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let (control_output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        Self {
+            id: id.into(),
+            physical_join,
+            table: LookupTable::default(),
+            inputs: Vec::new(),
+            control_inputs: Vec::new(),
+            output,
+            control_output,
+        }
+    }
+
+    /// Create a LookupJoinProcessor from a PhysicalPlan
+    /// Returns None if the plan is not a PhysicalLookupJoin
+    pub fn from_physical_plan(id: impl Into<String>, plan: Arc<PhysicalPlan>) -> Option<Self> {
+        match plan.as_ref() {
+            PhysicalPlan::LookupJoin(join) => Some(Self::new(id, Arc::new(join.clone()))),
+            _ => None,
+        }
+    }
+
+    /// Name of the catalog table this processor looks up
+    pub fn table_name(&self) -> &str {
+        &self.physical_join.table_name
+    }
+
+    /// Attach the shared table rows to read from
+    pub fn set_table(&mut self, table: LookupTable) {
+        self.table = table;
+    }
+}
+
+/// Table rows grouped by their normalized key values, built from one snapshot.
+struct LookupIndex {
+    physical: Arc<PhysicalLookupJoin>,
+    table: LookupTable,
+    snapshot: Option<Arc<Vec<Tuple>>>,
+    rows_by_key: HashMap<Vec<KeyPart>, Vec<Arc<Message>>>,
+}
+
+impl LookupIndex {
+    fn new(physical: Arc<PhysicalLookupJoin>, table: LookupTable) -> Self {
+        Self {
+            physical,
+            table,
+            snapshot: None,
+            rows_by_key: HashMap::new(),
+        }
+    }
+
+    /// Rebuild the index if the table was replaced since the last batch.
+    fn refresh(&mut self) -> Result<(), ProcessorError> {
+        let current = self.table.snapshot();
+        if self
+            .snapshot
+            .as_ref()
+            .is_some_and(|snapshot| Arc::ptr_eq(snapshot, &current))
+        {
+            return Ok(());
+        }
+
+        let mut rows_by_key: HashMap<Vec<KeyPart>, Vec<Arc<Message>>> = HashMap::new();
+        for row in current.iter() {
+            let message = Arc::new(self.project_table_row(row));
+            let tuple = Tuple::with_timestamp(vec![Arc::clone(&message)], row.timestamp);
+            if let Some(key) = eval_join_key(&self.physical.table_keys, &tuple)? {
+                rows_by_key.entry(key).or_default().push(message);
+            }
+        }
+        self.rows_by_key = rows_by_key;
+        self.snapshot = Some(current);
+        Ok(())
+    }
+
+    /// Restrict a stored table row to the columns the plan reads, in schema order.
+    fn project_table_row(&self, row: &Tuple) -> Message {
+        let table_name = self.physical.table_name.as_str();
+        let stored = row.message_by_source(table_name);
+        let columns = self.physical.table_schema.column_schemas();
+        let mut keys = Vec::with_capacity(columns.len());
+        let mut values = Vec::with_capacity(columns.len());
+        for column in columns {
+            let value = stored
+                .and_then(|message| message.value(&column.name))
+                .cloned()
+                .unwrap_or(Value::Null);
+            keys.push(Arc::from(column.name.as_str()));
+            values.push(Arc::new(value));
+        }
+        Message::new(table_name, keys, values)
+    }
+
+    fn join_row(&self, row: &Tuple) -> Result<Vec<Tuple>, ProcessorError> {
+        let Some(key) = eval_join_key(&self.physical.stream_keys, row)? else {
+            return Ok(Vec::new());
+        };
+        let Some(matches) = self.rows_by_key.get(&key) else {
+            return Ok(Vec::new());
+        };
+
+        let mut joined = Vec::with_capacity(matches.len());
+        for message in matches {
+            let mut combined = row.clone();
+            combined.messages.push(Arc::clone(message));
+            if residuals_hold(&self.physical.residual_predicates, &combined)? {
+                joined.push(combined);
+            }
+        }
+        Ok(joined)
+    }
+}
+
+fn residuals_hold(predicates: &[ScalarExpr], tuple: &Tuple) -> Result<bool, ProcessorError> {
+    for predicate in predicates {
+        let value = predicate.eval_with_tuple(tuple).map_err(|e| {
+            ProcessorError::ProcessingError(format!("failed to evaluate join condition: {e}"))
+        })?;
+        if !matches!(value, Value::Bool(true)) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+impl Processor for LookupJoinProcessor {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn start(&mut self) -> tokio::task::JoinHandle<Result<(), ProcessorError>> {
+        let id = self.id.clone();
+        let mut input_streams = fan_in_streams(std::mem::take(&mut self.inputs));
+        let control_receivers = std::mem::take(&mut self.control_inputs);
+        let mut control_streams = fan_in_control_streams(control_receivers);
+        let mut control_active = !control_streams.is_empty();
+        let output = self.output.clone();
+        let control_output = self.control_output.clone();
+        let mut index = LookupIndex::new(Arc::clone(&self.physical_join), self.table.clone());
+        tracing::info!(processor_id = %id, table = %self.physical_join.table_name, "lookup join processor starting");
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    biased;
+                    control_item = control_streams.next(), if control_active => {
+                        if let Some(Ok(control_signal)) = control_item {
+                            let is_terminal = control_signal.is_terminal();
+                            send_control_with_backpressure(&control_output, control_signal).await?;
+                            if is_terminal {
+                                tracing::info!(processor_id = %id, "received StreamEnd (control)");
+                                tracing::info!(processor_id = %id, "stopped");
+                                return Ok(());
+                            }
+                            continue;
+                        } else {
+                            control_active = false;
+                        }
+                    }
+                    item = input_streams.next() => {
+                        match item {
+                            Some(Ok(StreamData::Collection(collection))) => {
+                                log_received_data(&id, &StreamData::Collection(collection.clone()));
+                                if let Err(e) = index.refresh() {
+                                    forward_error(&output, &id, e.to_string()).await?;
+                                    continue;
+                                }
+                                let mut joined = Vec::new();
+                                for row in collection.rows() {
+                                    match index.join_row(row) {
+                                        Ok(rows) => joined.extend(rows),
+                                        Err(e) => forward_error(&output, &id, e.to_string()).await?,
+                                    }
+                                }
+                                if joined.is_empty() {
+                                    continue;
+                                }
+                                match RecordBatch::new(joined) {
+                                    Ok(batch) => {
+                                        send_with_backpressure(&output, StreamData::collection(Box::new(batch))).await?;
+                                    }
+                                    Err(e) => forward_error(&output, &id, e.to_string()).await?,
+                                }
+                            }
+                            Some(Ok(data)) => {
+                                log_received_data(&id, &data);
+                                let is_terminal = data.is_terminal();
+                                send_with_backpressure(&output, data).await?;
+                                if is_terminal {
+                                    tracing::info!(processor_id = %id, "received StreamEnd (data)");
+                                    tracing::info!(processor_id = %id, "stopped");
+                                    return Ok(());
+                                }
+                            }
+                            Some(Err(BroadcastStreamRecvError::Lagged(skipped))) => {
+                                let message = format!(
+                                    "LookupJoinProcessor input lagged by {} messages",
+                                    skipped
+                                );
+                                tracing::warn!(processor_id = %id, skipped = skipped, "input lagged");
+                                forward_error(&output, &id, message).await?;
+                            }
+                            None => {
+                                tracing::info!(processor_id = %id, "stopped");
+                                return Ok(());
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    fn subscribe_output(&self) -> Option<broadcast::Receiver<StreamData>> {
+        Some(self.output.subscribe())
+    }
+
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
+    fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
+        Some(self.control_output.subscribe())
+    }
+
+    fn add_input(&mut self, receiver: broadcast::Receiver<StreamData>) {
+        self.inputs.push(receiver);
+    }
+
+    fn add_control_input(&mut self, receiver: broadcast::Receiver<ControlSignal>) {
+        self.control_inputs.push(receiver);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::decode_table_json;
+    use datatypes::{ColumnSchema, ConcreteDatatype, Int64Type, Schema, StringType};
+    use sqlparser::ast::{Expr, Ident};
+
+    fn table_schema() -> Arc<Schema> {
+        Arc::new(Schema::new(vec![
+            ColumnSchema::new(
+                "devices".to_string(),
+                "id".to_string(),
+                ConcreteDatatype::Int64(Int64Type),
+            ),
+            ColumnSchema::new(
+                "devices".to_string(),
+                "name".to_string(),
+                ConcreteDatatype::String(StringType),
+            ),
+        ]))
+    }
+
+    fn stream_row(id: i64) -> Tuple {
+        stream_row_with_key(Value::Int64(id))
+    }
+
+    fn stream_row_with_key(device_id: Value) -> Tuple {
+        let message = Message::new(
+            "events",
+            vec![Arc::from("device_id")],
+            vec![Arc::new(device_id)],
+        );
+        Tuple::new(vec![Arc::new(message)])
+    }
+
+    fn lookup_processor(table: LookupTable) -> LookupJoinProcessor {
+        let key_expr = Expr::BinaryOp {
+            left: Box::new(Expr::CompoundIdentifier(vec![
+                Ident::new("events"),
+                Ident::new("device_id"),
+            ])),
+            op: sqlparser::ast::BinaryOperator::Eq,
+            right: Box::new(Expr::CompoundIdentifier(vec![
+                Ident::new("devices"),
+                Ident::new("id"),
+            ])),
+        };
+        let physical = PhysicalLookupJoin::new(
+            "events".to_string(),
+            "devices".to_string(),
+            table_schema(),
+            key_expr.clone(),
+            vec![key_expr],
+            vec![ScalarExpr::column_with_index("events", "device_id", Some(0)).unwrap()],
+            vec![ScalarExpr::column_with_index("devices", "id", Some(0)).unwrap()],
+            Vec::new(),
+            Vec::new(),
+            0,
+        );
+        let mut processor = LookupJoinProcessor::new("lookup", Arc::new(physical));
+        processor.set_table(table);
+        processor
+    }
+
+    async fn send_rows(input: &broadcast::Sender<StreamData>, rows: Vec<Tuple>) {
+        let batch = RecordBatch::new(rows).expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+    }
+
+    async fn recv_rows(output: &mut broadcast::Receiver<StreamData>) -> Vec<Tuple> {
+        match output.recv().await.unwrap() {
+            StreamData::Collection(collection) => collection.rows().to_vec(),
+            other => panic!("unexpected output: {}", other.description()),
+        }
+    }
+
+    #[tokio::test]
+    async fn lookup_join_enriches_rows_and_sees_reloads() {
+        let table = LookupTable::default();
+        table.replace(
+            decode_table_json(
+                "devices",
+                table_schema(),
+                br#"[{"id": 1, "name": "kitchen"}, {"id": 2, "name": "hall"}]"#,
+            )
+            .unwrap(),
+        );
+        let mut processor = lookup_processor(table.clone());
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        send_rows(&input, vec![stream_row(1), stream_row(3)]).await;
+        let rows = recv_rows(&mut output_rx).await;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].value_by_index("events", 0), Some(&Value::Int64(1)));
+        assert_eq!(
+            rows[0].value_by_index("devices", 1),
+            Some(&Value::String("kitchen".to_string()))
+        );
+
+        table.replace(
+            decode_table_json(
+                "devices",
+                table_schema(),
+                br#"[{"id": 3, "name": "garage"}]"#,
+            )
+            .unwrap(),
+        );
+        send_rows(&input, vec![stream_row(1), stream_row(3)]).await;
+        let rows = recv_rows(&mut output_rx).await;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(
+            rows[0].value_by_index("devices", 1),
+            Some(&Value::String("garage".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn lookup_join_matches_numeric_keys_by_value() {
+        let table = LookupTable::default();
+        table.replace(
+            decode_table_json(
+                "devices",
+                table_schema(),
+                br#"[{"id": 1, "name": "kitchen"}, {"id": 2, "name": "hall"}]"#,
+            )
+            .unwrap(),
+        );
+        let mut processor = lookup_processor(table);
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        send_rows(
+            &input,
+            vec![
+                stream_row_with_key(Value::Int32(1)),
+                stream_row_with_key(Value::Uint64(2)),
+                stream_row_with_key(Value::Float64(2.5)),
+            ],
+        )
+        .await;
+        let rows = recv_rows(&mut output_rx).await;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0].value_by_index("devices", 1),
+            Some(&Value::String("kitchen".to_string()))
+        );
+        assert_eq!(
+            rows[1].value_by_index("devices", 1),
+            Some(&Value::String("hall".to_string()))
+        );
+    }
+}
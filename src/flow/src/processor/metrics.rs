@@ -0,0 +1,291 @@
+//! Per-processor runtime metrics.
+//!
+//! When a [`ProcessorPipeline`](super::ProcessorPipeline) starts, every processor output is
+//! tapped by a small task that counts what the processor emits: rows, errors it raised and the
+//! latest watermark. Rows emitted on an output are also counted as rows received by each
+//! processor subscribed to it. Counters are exported to Prometheus with `pipeline` and
+//! `processor` labels and kept in memory so a pipeline can report its own status.
+
+use crate::processor::StreamData;
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_int_counter_vec, register_int_gauge_vec, IntCounter, IntCounterVec, IntGaugeVec,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+static PROCESSOR_RECORDS_IN: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "processor_records_in_total",
+        "Rows received by pipeline processors",
+        &["pipeline", "processor"]
+    )
+    .expect("create processor records_in counter vec")
+});
+
+static PROCESSOR_RECORDS_OUT: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "processor_records_out_total",
+        "Rows emitted by pipeline processors",
+        &["pipeline", "processor"]
+    )
+    .expect("create processor records_out counter vec")
+});
+
+static PROCESSOR_ERRORS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "processor_errors_total",
+        "Errors raised by pipeline processors",
+        &["pipeline", "processor"]
+    )
+    .expect("create processor errors counter vec")
+});
+
+static PROCESSOR_WATERMARK: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "processor_last_watermark_ms",
+        "Latest watermark emitted by pipeline processors, in unix milliseconds",
+        &["pipeline", "processor"]
+    )
+    .expect("create processor watermark gauge vec")
+});
+
+/// Error most recently raised by a processor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessorErrorRecord {
+    pub processor_id: String,
+    pub message: String,
+    pub at: SystemTime,
+}
+
+/// Live counters of one processor.
+#[derive(Debug, Default)]
+pub struct ProcessorStats {
+    records_in: AtomicU64,
+    records_out: AtomicU64,
+    errors: AtomicU64,
+    last_watermark: Mutex<Option<SystemTime>>,
+    last_error: Mutex<Option<ProcessorErrorRecord>>,
+}
+
+impl ProcessorStats {
+    fn snapshot(&self, processor_id: &str, queue_len: Option<usize>) -> ProcessorMetrics {
+        ProcessorMetrics {
+            processor_id: processor_id.to_string(),
+            records_in: self.records_in.load(Ordering::Relaxed),
+            records_out: self.records_out.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            last_watermark: *self
+                .last_watermark
+                .lock()
+                .expect("processor stats poisoned"),
+            queue_len,
+            last_error: self
+                .last_error
+                .lock()
+                .expect("processor stats poisoned")
+                .clone(),
+        }
+    }
+}
+
+/// Point-in-time view of one processor's counters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessorMetrics {
+    pub processor_id: String,
+    pub records_in: u64,
+    pub records_out: u64,
+    pub errors: u64,
+    pub last_watermark: Option<SystemTime>,
+    /// Messages buffered on the processor output that a consumer has not read yet.
+    pub queue_len: Option<usize>,
+    pub last_error: Option<ProcessorErrorRecord>,
+}
+
+/// Counters of every processor in a pipeline, keyed by processor id.
+#[derive(Debug, Default)]
+pub(crate) struct PipelineStats {
+    processors: Vec<(String, Arc<ProcessorStats>)>,
+}
+
+impl PipelineStats {
+    /// Register a processor; ids are kept sorted so snapshots have a stable order.
+    pub(crate) fn register(&mut self, processor_id: &str) {
+        if let Err(pos) = self
+            .processors
+            .binary_search_by(|(id, _)| id.as_str().cmp(processor_id))
+        {
+            self.processors.insert(
+                pos,
+                (
+                    processor_id.to_string(),
+                    Arc::new(ProcessorStats::default()),
+                ),
+            );
+        }
+    }
+
+    pub(crate) fn get(&self, processor_id: &str) -> Option<Arc<ProcessorStats>> {
+        self.processors
+            .binary_search_by(|(id, _)| id.as_str().cmp(processor_id))
+            .ok()
+            .map(|pos| Arc::clone(&self.processors[pos].1))
+    }
+
+    /// Snapshot every processor; `queue_len` looks up the current output backlog by id.
+    pub(crate) fn snapshot(
+        &self,
+        queue_len: impl Fn(&str) -> Option<usize>,
+    ) -> Vec<ProcessorMetrics> {
+        self.processors
+            .iter()
+            .map(|(id, stats)| stats.snapshot(id, queue_len(id)))
+            .collect()
+    }
+
+    /// Drop the Prometheus series of every registered processor under `pipeline_id`.
+    ///
+    /// Taps still running keep counting into detached series, so this is safe to call before the
+    /// pipeline has finished closing.
+    pub(crate) fn remove_series(&self, pipeline_id: &str) {
+        for (processor_id, _) in &self.processors {
+            let labels = [pipeline_id, processor_id.as_str()];
+            let _ = PROCESSOR_RECORDS_IN.remove_label_values(&labels);
+            let _ = PROCESSOR_RECORDS_OUT.remove_label_values(&labels);
+            let _ = PROCESSOR_ERRORS.remove_label_values(&labels);
+            let _ = PROCESSOR_WATERMARK.remove_label_values(&labels);
+        }
+    }
+}
+
+struct TapTarget {
+    stats: Arc<ProcessorStats>,
+    records_in: IntCounter,
+}
+
+/// Spawn the task counting everything `processor_id` emits on `output`.
+///
+/// `downstream` lists the processors reading that output; their `records_in` grow with every
+/// row seen here. The task ends once the output channel closes.
+pub(crate) fn spawn_output_tap(
+    pipeline_id: &str,
+    processor_id: &str,
+    stats: Arc<ProcessorStats>,
+    downstream: Vec<(String, Arc<ProcessorStats>)>,
+    mut output: broadcast::Receiver<StreamData>,
+) -> JoinHandle<()> {
+    let processor_id = processor_id.to_string();
+    let records_out = PROCESSOR_RECORDS_OUT.with_label_values(&[pipeline_id, &processor_id]);
+    let errors = PROCESSOR_ERRORS.with_label_values(&[pipeline_id, &processor_id]);
+    let watermark = PROCESSOR_WATERMARK.with_label_values(&[pipeline_id, &processor_id]);
+    let targets = downstream
+        .into_iter()
+        .map(|(id, stats)| TapTarget {
+            records_in: PROCESSOR_RECORDS_IN.with_label_values(&[pipeline_id, &id]),
+            stats,
+        })
+        .collect::<Vec<_>>();
+
+    tokio::spawn(async move {
+        loop {
+            let data = match output.recv().await {
+                Ok(data) => data,
+                // Producers using a plain `send` may outrun the tap; it then under-reports.
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return,
+            };
+            match &data {
+                StreamData::Collection(collection) => {
+                    record_rows(&stats, &records_out, &targets, collection.num_rows() as u64)
+                }
+                StreamData::Encoded { collection, .. } => {
+                    record_rows(&stats, &records_out, &targets, collection.num_rows() as u64)
+                }
+                StreamData::Bytes(_) => record_rows(&stats, &records_out, &targets, 1),
+                StreamData::Watermark(ts) => {
+                    *stats
+                        .last_watermark
+                        .lock()
+                        .expect("processor stats poisoned") = Some(*ts);
+                    watermark.set(unix_millis(*ts));
+                }
+                StreamData::Error(error) => {
+                    // Errors are forwarded downstream unchanged; only count them where raised.
+                    if error
+                        .source
+                        .as_deref()
+                        .is_some_and(|source| source != processor_id)
+                    {
+                        continue;
+                    }
+                    stats.errors.fetch_add(1, Ordering::Relaxed);
+                    errors.inc();
+                    *stats.last_error.lock().expect("processor stats poisoned") =
+                        Some(ProcessorErrorRecord {
+                            processor_id: processor_id.clone(),
+                            message: error.message.clone(),
+                            at: error.timestamp.unwrap_or_else(SystemTime::now),
+                        });
+                }
+                StreamData::Control(_) => {}
+            }
+        }
+    })
+}
+
+fn record_rows(stats: &ProcessorStats, records_out: &IntCounter, targets: &[TapTarget], rows: u64) {
+    stats.records_out.fetch_add(rows, Ordering::Relaxed);
+    records_out.inc_by(rows);
+    for target in targets {
+        target.stats.records_in.fetch_add(rows, Ordering::Relaxed);
+        target.records_in.inc_by(rows);
+    }
+}
+
+fn unix_millis(ts: SystemTime) -> i64 {
+    ts.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn has_series(pipeline_id: &str) -> bool {
+        prometheus::gather()
+            .iter()
+            .filter(|family| family.get_name().starts_with("processor_"))
+            .flat_map(|family| family.get_metric())
+            .any(|metric| {
+                metric
+                    .get_label()
+                    .iter()
+                    .any(|label| label.get_name() == "pipeline" && label.get_value() == pipeline_id)
+            })
+    }
+
+    #[tokio::test]
+    async fn remove_series_drops_pipeline_labels() {
+        let mut stats = PipelineStats::default();
+        stats.register("source");
+        stats.register("sink");
+        let (sender, receiver) = broadcast::channel(4);
+        let tap = spawn_output_tap(
+            "metrics_remove_pipe",
+            "source",
+            stats.get("source").expect("source stats"),
+            vec![("sink".to_string(), stats.get("sink").expect("sink stats"))],
+            receiver,
+        );
+        assert!(has_series("metrics_remove_pipe"));
+
+        stats.remove_series("metrics_remove_pipe");
+        assert!(!has_series("metrics_remove_pipe"));
+        drop(sender);
+        tap.await.expect("tap task");
+    }
+}
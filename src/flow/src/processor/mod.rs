@@ -10,21 +10,29 @@
 pub mod aggregation_processor;
 pub mod base;
 pub mod batch_processor;
+pub mod checkpoint;
 pub mod control_source_processor;
 pub mod datasource_processor;
+pub mod dead_letter;
 pub mod decoder_processor;
 pub mod encoder_processor;
 pub mod eventtime;
 pub mod filter_processor;
+pub mod hopping_window_processor;
+mod join_key;
+pub mod lookup_join_processor;
+pub mod metrics;
 pub mod processor_builder;
 pub mod project_processor;
 pub mod result_collect_processor;
+pub mod session_window_processor;
 pub mod shared_stream_processor;
 pub mod sink_processor;
 pub mod sliding_window_processor;
 pub mod state_window_processor;
 pub mod stateful_function_processor;
 pub mod stream_data;
+pub mod stream_join_processor;
 pub mod streaming_aggregation_processor;
 pub mod streaming_encoder_processor;
 pub mod tumbling_window_processor;
@@ -33,26 +41,35 @@ pub mod watermark_processor;
 pub use aggregation_processor::AggregationProcessor;
 pub use base::{Processor, ProcessorError};
 pub use batch_processor::BatchProcessor;
+pub use checkpoint::{
+    CheckpointCoordinator, CheckpointError, CheckpointTrigger, PipelineCheckpoint,
+};
 pub use control_source_processor::ControlSourceProcessor;
 pub use datasource_processor::DataSourceProcessor;
+pub use dead_letter::{DeadLetterProcessor, DeadLetterSender, ErrorPolicy};
 pub use decoder_processor::DecoderProcessor;
 pub use encoder_processor::EncoderProcessor;
 pub use eventtime::EventtimePipelineContext;
 pub use filter_processor::FilterProcessor;
+pub use hopping_window_processor::HoppingWindowProcessor;
+pub use lookup_join_processor::LookupJoinProcessor;
+pub use metrics::{ProcessorErrorRecord, ProcessorMetrics};
 pub use processor_builder::{
-    create_processor_pipeline, ProcessorPipeline, ProcessorPipelineDependencies,
+    create_processor_pipeline, PipelineMetrics, ProcessorPipeline, ProcessorPipelineDependencies,
 };
 pub use project_processor::ProjectProcessor;
 pub use result_collect_processor::ResultCollectProcessor;
+pub use session_window_processor::SessionWindowProcessor;
 pub use shared_stream_processor::SharedStreamProcessor;
 pub use sink_processor::SinkProcessor;
 pub use sliding_window_processor::SlidingWindowProcessor;
 pub use state_window_processor::StateWindowProcessor;
 pub use stateful_function_processor::StatefulFunctionProcessor;
 pub use stream_data::{ControlSignal, StreamData, StreamError};
+pub use stream_join_processor::StreamJoinProcessor;
 pub use streaming_aggregation_processor::{
     StreamingAggregationProcessor, StreamingCountAggregationProcessor,
-    StreamingTumblingAggregationProcessor,
+    StreamingHoppingAggregationProcessor, StreamingTumblingAggregationProcessor,
 };
 pub use streaming_encoder_processor::StreamingEncoderProcessor;
 pub use tumbling_window_processor::TumblingWindowProcessor;
@@ -7,18 +7,24 @@ use crate::aggregation::AggregateFunctionRegistry;
 use crate::codec::{DecoderRegistry, EncoderRegistry};
 use crate::connector::{ConnectorRegistry, MqttClientManager};
 use crate::planner::physical::PhysicalPlan;
+use crate::planner::sink::PipelineSink;
+use crate::processor::dead_letter::DeadLetterSender;
 use crate::processor::decoder_processor::EventtimeDecodeConfig;
+use crate::processor::metrics::{spawn_output_tap, PipelineStats, ProcessorMetrics};
 use crate::processor::EventtimePipelineContext;
 use crate::processor::{
-    AggregationProcessor, BatchProcessor, ControlSignal, ControlSourceProcessor,
-    DataSourceProcessor, DecoderProcessor, EncoderProcessor, FilterProcessor, Processor,
-    ProcessorError, ProjectProcessor, ResultCollectProcessor, SharedStreamProcessor, SinkProcessor,
-    SlidingWindowProcessor, StateWindowProcessor, StatefulFunctionProcessor, StreamData,
+    AggregationProcessor, BatchProcessor, CheckpointCoordinator, CheckpointTrigger, ControlSignal,
+    ControlSourceProcessor, DataSourceProcessor, DeadLetterProcessor, DecoderProcessor,
+    EncoderProcessor, ErrorPolicy, FilterProcessor, HoppingWindowProcessor, LookupJoinProcessor,
+    PipelineCheckpoint, Processor, ProcessorError, ProjectProcessor, ResultCollectProcessor,
+    SessionWindowProcessor, SharedStreamProcessor, SinkProcessor, SlidingWindowProcessor,
+    StateWindowProcessor, StatefulFunctionProcessor, StreamData, StreamJoinProcessor,
     StreamingAggregationProcessor, StreamingEncoderProcessor, TumblingWindowProcessor,
     WatermarkProcessor,
 };
 use crate::stateful::StatefulFunctionRegistry;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, mpsc};
 use tokio::task::JoinHandle;
 use uuid::Uuid;
@@ -54,10 +60,18 @@ pub enum PlanProcessor {
     Watermark(WatermarkProcessor),
     /// Tumbling window processor driven by watermarks
     TumblingWindow(TumblingWindowProcessor),
+    /// Hopping window processor driven by watermarks
+    HoppingWindow(HoppingWindowProcessor),
     /// Sliding window processor driven by watermarks (for lookahead windows)
     SlidingWindow(SlidingWindowProcessor),
+    /// Session window processor closed by inactivity gaps and watermarks
+    SessionWindow(SessionWindowProcessor),
     /// State window processor driven by open/emit conditions
     StateWindow(StateWindowProcessor),
+    /// Interval join processor created from PhysicalStreamJoin
+    StreamJoin(StreamJoinProcessor),
+    /// Stream-table join processor created from PhysicalLookupJoin
+    LookupJoin(LookupJoinProcessor),
     /// SinkProcessor created from PhysicalDataSink
     Sink(SinkProcessor),
     /// ResultCollectProcessor created from PhysicalResultCollect
@@ -73,6 +87,8 @@ pub struct ProcessorPipelineDependencies {
     aggregate_registry: Arc<AggregateFunctionRegistry>,
     stateful_registry: Arc<StatefulFunctionRegistry>,
     eventtime: Option<EventtimePipelineContext>,
+    error_policy: ErrorPolicy,
+    dead_letter: Option<PipelineSink>,
 }
 
 impl ProcessorPipelineDependencies {
@@ -93,8 +109,23 @@ impl ProcessorPipelineDependencies {
             aggregate_registry,
             stateful_registry,
             eventtime,
+            error_policy: ErrorPolicy::default(),
+            dead_letter: None,
         }
     }
+
+    /// Set the policy for records that fail to decode or evaluate.
+    ///
+    /// [`ErrorPolicy::DeadLetter`] requires the sink that receives the failed records.
+    pub fn with_error_policy(
+        mut self,
+        error_policy: ErrorPolicy,
+        dead_letter: Option<PipelineSink>,
+    ) -> Self {
+        self.error_policy = error_policy;
+        self.dead_letter = dead_letter;
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -106,6 +137,7 @@ struct ProcessorBuilderContext {
     aggregate_registry: Arc<AggregateFunctionRegistry>,
     stateful_registry: Arc<StatefulFunctionRegistry>,
     eventtime: Option<EventtimePipelineContext>,
+    error_policy: ErrorPolicy,
 }
 
 impl ProcessorBuilderContext {
@@ -155,8 +187,12 @@ impl PlanProcessor {
             PlanProcessor::StreamingAggregation(p) => p.id(),
             PlanProcessor::Watermark(p) => p.id(),
             PlanProcessor::TumblingWindow(p) => p.id(),
+            PlanProcessor::HoppingWindow(p) => p.id(),
             PlanProcessor::SlidingWindow(p) => p.id(),
+            PlanProcessor::SessionWindow(p) => p.id(),
             PlanProcessor::StateWindow(p) => p.id(),
+            PlanProcessor::StreamJoin(p) => p.id(),
+            PlanProcessor::LookupJoin(p) => p.id(),
             PlanProcessor::Sink(p) => p.id(),
             PlanProcessor::ResultCollect(p) => p.id(),
         }
@@ -168,6 +204,34 @@ impl PlanProcessor {
         }
     }
 
+    /// Register stateful processors with the pipeline's checkpoint coordinator.
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: &CheckpointCoordinator) {
+        match self {
+            PlanProcessor::StreamingAggregation(p) => {
+                p.set_checkpoint_coordinator(coordinator.clone())
+            }
+            PlanProcessor::StatefulFunction(p) => p.set_checkpoint_coordinator(coordinator.clone()),
+            PlanProcessor::SlidingWindow(p) => p.set_checkpoint_coordinator(coordinator.clone()),
+            PlanProcessor::SessionWindow(p) => p.set_checkpoint_coordinator(coordinator.clone()),
+            PlanProcessor::StateWindow(p) => p.set_checkpoint_coordinator(coordinator.clone()),
+            PlanProcessor::TumblingWindow(p) => p.set_checkpoint_coordinator(coordinator.clone()),
+            PlanProcessor::HoppingWindow(p) => p.set_checkpoint_coordinator(coordinator.clone()),
+            PlanProcessor::StreamJoin(p) => p.set_checkpoint_coordinator(coordinator.clone()),
+            // Aggregation finalizes every batch it receives and keeps nothing between them.
+            _ => {}
+        }
+    }
+
+    /// Hand the dead-letter queue to the processors that reject records.
+    pub fn set_dead_letter_sender(&mut self, sender: &DeadLetterSender) {
+        match self {
+            PlanProcessor::Decoder(p) => p.set_dead_letter_sender(sender.clone()),
+            PlanProcessor::Filter(p) => p.set_dead_letter_sender(sender.clone()),
+            PlanProcessor::Project(p) => p.set_dead_letter_sender(sender.clone()),
+            _ => {}
+        }
+    }
+
     /// Start the processor
     pub fn start(&mut self) -> tokio::task::JoinHandle<Result<(), ProcessorError>> {
         match self {
@@ -184,8 +248,12 @@ impl PlanProcessor {
             PlanProcessor::StreamingAggregation(p) => p.start(),
             PlanProcessor::Watermark(p) => p.start(),
             PlanProcessor::TumblingWindow(p) => p.start(),
+            PlanProcessor::HoppingWindow(p) => p.start(),
             PlanProcessor::SlidingWindow(p) => p.start(),
+            PlanProcessor::SessionWindow(p) => p.start(),
             PlanProcessor::StateWindow(p) => p.start(),
+            PlanProcessor::StreamJoin(p) => p.start(),
+            PlanProcessor::LookupJoin(p) => p.start(),
             PlanProcessor::Sink(p) => p.start(),
             PlanProcessor::ResultCollect(p) => p.start(),
         }
@@ -207,13 +275,44 @@ impl PlanProcessor {
             PlanProcessor::StreamingAggregation(p) => p.subscribe_output(),
             PlanProcessor::Watermark(p) => p.subscribe_output(),
             PlanProcessor::TumblingWindow(p) => p.subscribe_output(),
+            PlanProcessor::HoppingWindow(p) => p.subscribe_output(),
             PlanProcessor::SlidingWindow(p) => p.subscribe_output(),
+            PlanProcessor::SessionWindow(p) => p.subscribe_output(),
             PlanProcessor::StateWindow(p) => p.subscribe_output(),
+            PlanProcessor::StreamJoin(p) => p.subscribe_output(),
+            PlanProcessor::LookupJoin(p) => p.subscribe_output(),
             PlanProcessor::Sink(p) => p.subscribe_output(),
             PlanProcessor::ResultCollect(p) => p.subscribe_output(),
         }
     }
 
+    /// Number of messages buffered on the processor's output channel
+    pub fn output_queue_len(&self) -> Option<usize> {
+        match self {
+            PlanProcessor::Aggregation(p) => p.output_queue_len(),
+            PlanProcessor::DataSource(p) => p.output_queue_len(),
+            PlanProcessor::Decoder(p) => p.output_queue_len(),
+            PlanProcessor::SharedSource(p) => p.output_queue_len(),
+            PlanProcessor::Project(p) => p.output_queue_len(),
+            PlanProcessor::StatefulFunction(p) => p.output_queue_len(),
+            PlanProcessor::Filter(p) => p.output_queue_len(),
+            PlanProcessor::Batch(p) => p.output_queue_len(),
+            PlanProcessor::Encoder(p) => p.output_queue_len(),
+            PlanProcessor::StreamingEncoder(p) => p.output_queue_len(),
+            PlanProcessor::StreamingAggregation(p) => p.output_queue_len(),
+            PlanProcessor::Watermark(p) => p.output_queue_len(),
+            PlanProcessor::TumblingWindow(p) => p.output_queue_len(),
+            PlanProcessor::HoppingWindow(p) => p.output_queue_len(),
+            PlanProcessor::SlidingWindow(p) => p.output_queue_len(),
+            PlanProcessor::SessionWindow(p) => p.output_queue_len(),
+            PlanProcessor::StateWindow(p) => p.output_queue_len(),
+            PlanProcessor::StreamJoin(p) => p.output_queue_len(),
+            PlanProcessor::LookupJoin(p) => p.output_queue_len(),
+            PlanProcessor::Sink(p) => p.output_queue_len(),
+            PlanProcessor::ResultCollect(p) => p.output_queue_len(),
+        }
+    }
+
     /// Subscribe to the processor's control output stream
     pub fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         match self {
@@ -230,8 +329,12 @@ impl PlanProcessor {
             PlanProcessor::StreamingAggregation(p) => p.subscribe_control_output(),
             PlanProcessor::Watermark(p) => p.subscribe_control_output(),
             PlanProcessor::TumblingWindow(p) => p.subscribe_control_output(),
+            PlanProcessor::HoppingWindow(p) => p.subscribe_control_output(),
             PlanProcessor::SlidingWindow(p) => p.subscribe_control_output(),
+            PlanProcessor::SessionWindow(p) => p.subscribe_control_output(),
             PlanProcessor::StateWindow(p) => p.subscribe_control_output(),
+            PlanProcessor::StreamJoin(p) => p.subscribe_control_output(),
+            PlanProcessor::LookupJoin(p) => p.subscribe_control_output(),
             PlanProcessor::Sink(p) => p.subscribe_control_output(),
             PlanProcessor::ResultCollect(p) => p.subscribe_control_output(),
         }
@@ -253,8 +356,12 @@ impl PlanProcessor {
             PlanProcessor::StreamingAggregation(p) => p.add_input(receiver),
             PlanProcessor::Watermark(p) => p.add_input(receiver),
             PlanProcessor::TumblingWindow(p) => p.add_input(receiver),
+            PlanProcessor::HoppingWindow(p) => p.add_input(receiver),
             PlanProcessor::SlidingWindow(p) => p.add_input(receiver),
+            PlanProcessor::SessionWindow(p) => p.add_input(receiver),
             PlanProcessor::StateWindow(p) => p.add_input(receiver),
+            PlanProcessor::StreamJoin(p) => p.add_input(receiver),
+            PlanProcessor::LookupJoin(p) => p.add_input(receiver),
             PlanProcessor::Sink(p) => p.add_input(receiver),
             PlanProcessor::ResultCollect(p) => p.add_input(receiver),
         }
@@ -276,8 +383,12 @@ impl PlanProcessor {
             PlanProcessor::StreamingAggregation(p) => p.add_control_input(receiver),
             PlanProcessor::Watermark(p) => p.add_control_input(receiver),
             PlanProcessor::TumblingWindow(p) => p.add_control_input(receiver),
+            PlanProcessor::HoppingWindow(p) => p.add_control_input(receiver),
             PlanProcessor::SlidingWindow(p) => p.add_control_input(receiver),
+            PlanProcessor::SessionWindow(p) => p.add_control_input(receiver),
             PlanProcessor::StateWindow(p) => p.add_control_input(receiver),
+            PlanProcessor::StreamJoin(p) => p.add_control_input(receiver),
+            PlanProcessor::LookupJoin(p) => p.add_control_input(receiver),
             PlanProcessor::Sink(p) => p.add_control_input(receiver),
             PlanProcessor::ResultCollect(p) => p.add_control_input(receiver),
         }
@@ -301,6 +412,8 @@ pub struct ProcessorPipeline {
     pub middle_processors: Vec<PlanProcessor>,
     /// Result sink processor (data tail) if downstream forwarding is enabled
     pub result_sink: Option<ResultCollectProcessor>,
+    /// Receives records rejected under [`ErrorPolicy::DeadLetter`]
+    pub dead_letter: Option<DeadLetterProcessor>,
     /// Broadcast sender feeding the control source data input
     data_input_sender: broadcast::Sender<StreamData>,
     /// Buffered receiver that bridges external input into the data input sender
@@ -311,6 +424,25 @@ pub struct ProcessorPipeline {
     handles: Vec<JoinHandle<Result<(), ProcessorError>>>,
     /// Logical pipeline identifier used for diagnostics/subscriptions
     pipeline_id: String,
+    /// Collects processor state for checkpoints and hands restored state to processors
+    checkpoints: CheckpointCoordinator,
+    /// Processor connections as (upstream id, downstream id)
+    edges: Vec<(String, String)>,
+    /// Counters of every processor, fed by the output taps
+    stats: PipelineStats,
+    /// Tasks counting each processor's output while the pipeline runs
+    taps: Vec<JoinHandle<()>>,
+    /// When [`Self::start`] was first called
+    started_at: Option<Instant>,
+}
+
+/// Runtime counters of a pipeline; see [`ProcessorPipeline::metrics`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipelineMetrics {
+    /// Time since the pipeline was started; `None` if it never was.
+    pub uptime: Option<Duration>,
+    /// Counters of every processor, ordered by processor id.
+    pub processors: Vec<ProcessorMetrics>,
 }
 
 impl ProcessorPipeline {
@@ -319,7 +451,13 @@ impl ProcessorPipeline {
         if !self.handles.is_empty() {
             return;
         }
+        // Taps subscribe before any processor runs so no output goes uncounted.
+        self.spawn_output_taps();
+        self.started_at = Some(Instant::now());
         // Start from downstream to upstream so that consumers are ready before producers.
+        if let Some(dead_letter) = &mut self.dead_letter {
+            self.handles.push(dead_letter.start());
+        }
         if let Some(result_sink) = &mut self.result_sink {
             self.handles.push(result_sink.start());
         }
@@ -349,6 +487,64 @@ impl ProcessorPipeline {
         }
     }
 
+    fn spawn_output_taps(&mut self) {
+        let mut outputs = Vec::with_capacity(self.middle_processors.len() + 2);
+        outputs.push((
+            self.control_source.id().to_string(),
+            self.control_source.subscribe_output(),
+        ));
+        for processor in &self.middle_processors {
+            outputs.push((processor.id().to_string(), processor.subscribe_output()));
+        }
+        if let Some(result_sink) = &self.result_sink {
+            outputs.push((result_sink.id().to_string(), result_sink.subscribe_output()));
+        }
+        for (processor_id, receiver) in outputs {
+            let (Some(receiver), Some(stats)) = (receiver, self.stats.get(&processor_id)) else {
+                continue;
+            };
+            let downstream = self
+                .edges
+                .iter()
+                .filter(|(upstream, _)| *upstream == processor_id)
+                .filter_map(|(_, downstream)| {
+                    self.stats
+                        .get(downstream)
+                        .map(|stats| (downstream.clone(), stats))
+                })
+                .collect();
+            self.taps.push(spawn_output_tap(
+                &self.pipeline_id,
+                &processor_id,
+                stats,
+                downstream,
+                receiver,
+            ));
+        }
+    }
+
+    /// Snapshot the runtime counters of every processor.
+    pub fn metrics(&self) -> PipelineMetrics {
+        let processors = self.stats.snapshot(|processor_id| {
+            if processor_id == self.control_source.id() {
+                return self.control_source.output_queue_len();
+            }
+            if let Some(result_sink) = &self.result_sink {
+                if processor_id == result_sink.id() {
+                    return result_sink.output_queue_len();
+                }
+            }
+            self.middle_processors
+                .iter()
+                .find(|processor| processor.id() == processor_id)
+                .and_then(|processor| processor.output_queue_len())
+        });
+        PipelineMetrics {
+            uptime: self.started_at.map(|started_at| started_at.elapsed()),
+            processors,
+        }
+    }
+
     /// Broadcast a control signal into the pipeline, respecting its channel target.
     pub fn broadcast_control_signal(&self, signal: ControlSignal) -> Result<(), ProcessorError> {
         self.control_signal_sender
@@ -369,6 +565,11 @@ impl ProcessorPipeline {
         &self.pipeline_id
     }
 
+    /// Remove the Prometheus series this pipeline's processors export.
+    pub fn remove_metrics(&self) {
+        self.stats.remove_series(&self.pipeline_id);
+    }
+
     /// Close the pipeline gracefully using the data path.
     pub async fn close(&mut self) -> Result<(), ProcessorError> {
         self.graceful_close().await
@@ -414,6 +615,10 @@ impl ProcessorPipeline {
                 }
             }
         }
+        // Processor outputs stay open while the pipeline is alive, so taps never see them close.
+        for tap in self.taps.drain(..) {
+            tap.abort();
+        }
         Ok(())
     }
 
@@ -443,6 +648,32 @@ impl ProcessorPipeline {
     pub fn take_output(&mut self) -> Option<mpsc::Receiver<StreamData>> {
         self.output.take()
     }
+
+    /// Capture the state of every stateful processor.
+    ///
+    /// The checkpoint reflects all data sent into the pipeline before this call.
+    pub async fn checkpoint(&self) -> Result<PipelineCheckpoint, ProcessorError> {
+        self.checkpoint_trigger().trigger().await
+    }
+
+    /// Handle for triggering checkpoints without borrowing the pipeline.
+    pub fn checkpoint_trigger(&self) -> CheckpointTrigger {
+        CheckpointTrigger::new(self.input.clone(), self.checkpoints.clone())
+    }
+
+    /// Resume processors from a previous checkpoint. Must be called before [`Self::start`].
+    pub fn restore_checkpoint(
+        &mut self,
+        checkpoint: PipelineCheckpoint,
+    ) -> Result<(), ProcessorError> {
+        if !self.handles.is_empty() {
+            return Err(ProcessorError::InvalidConfiguration(
+                "cannot restore a checkpoint into a running pipeline".to_string(),
+            ));
+        }
+        self.checkpoints.restore(checkpoint);
+        Ok(())
+    }
 }
 
 /// Create a processor from a PhysicalPlan node
@@ -491,7 +722,8 @@ fn create_processor_from_plan_node(
                     Arc::clone(&schema),
                 )
                 .map_err(|err| ProcessorError::InvalidConfiguration(err.to_string()))?;
-            let mut processor = DecoderProcessor::new(plan_name.clone(), decoder);
+            let mut processor = DecoderProcessor::new(plan_name.clone(), decoder)
+                .with_error_policy(context.error_policy);
             if let Some(projection) = decoder_plan.decode_projection().cloned() {
                 processor = processor.with_decode_projection(projection);
             }
@@ -528,7 +760,8 @@ fn create_processor_from_plan_node(
             ))
         }
         PhysicalPlan::Project(project) => {
-            let processor = ProjectProcessor::new(plan_name.clone(), Arc::new(project.clone()));
+            let processor = ProjectProcessor::new(plan_name.clone(), Arc::new(project.clone()))
+                .with_error_policy(context.error_policy);
             Ok(ProcessorBuildOutput::with_processor(
                 PlanProcessor::Project(processor),
             ))
@@ -554,7 +787,8 @@ fn create_processor_from_plan_node(
             ))
         }
         PhysicalPlan::Filter(filter) => {
-            let processor = FilterProcessor::new(plan_name.clone(), Arc::new(filter.clone()));
+            let processor = FilterProcessor::new(plan_name.clone(), Arc::new(filter.clone()))
+                .with_error_policy(context.error_policy);
             Ok(ProcessorBuildOutput::with_processor(PlanProcessor::Filter(
                 processor,
             )))
@@ -638,6 +872,18 @@ fn create_processor_from_plan_node(
                 PlanProcessor::TumblingWindow(processor),
             ))
         }
+        PhysicalPlan::HoppingWindow(_) => {
+            let processor =
+                HoppingWindowProcessor::from_physical_plan(plan_name.clone(), Arc::clone(plan))
+                    .ok_or_else(|| {
+                        ProcessorError::InvalidConfiguration(
+                            "Unsupported hopping window configuration".to_string(),
+                        )
+                    })?;
+            Ok(ProcessorBuildOutput::with_processor(
+                PlanProcessor::HoppingWindow(processor),
+            ))
+        }
         PhysicalPlan::SlidingWindow(_) => {
             let processor =
                 SlidingWindowProcessor::from_physical_plan(plan_name.clone(), Arc::clone(plan))
@@ -650,6 +896,18 @@ fn create_processor_from_plan_node(
                 PlanProcessor::SlidingWindow(processor),
             ))
         }
+        PhysicalPlan::SessionWindow(_) => {
+            let processor =
+                SessionWindowProcessor::from_physical_plan(plan_name.clone(), Arc::clone(plan))
+                    .ok_or_else(|| {
+                        ProcessorError::InvalidConfiguration(
+                            "Unsupported session window configuration".to_string(),
+                        )
+                    })?;
+            Ok(ProcessorBuildOutput::with_processor(
+                PlanProcessor::SessionWindow(processor),
+            ))
+        }
         PhysicalPlan::StateWindow(_) => {
             let processor =
                 StateWindowProcessor::from_physical_plan(plan_name.clone(), Arc::clone(plan))
@@ -662,9 +920,34 @@ fn create_processor_from_plan_node(
                 PlanProcessor::StateWindow(processor),
             ))
         }
+        PhysicalPlan::StreamJoin(_) => {
+            let processor =
+                StreamJoinProcessor::from_physical_plan(plan_name.clone(), Arc::clone(plan))
+                    .ok_or_else(|| {
+                        ProcessorError::InvalidConfiguration(
+                            "Unsupported stream join configuration".to_string(),
+                        )
+                    })?;
+            Ok(ProcessorBuildOutput::with_processor(
+                PlanProcessor::StreamJoin(processor),
+            ))
+        }
+        PhysicalPlan::LookupJoin(_) => {
+            let processor =
+                LookupJoinProcessor::from_physical_plan(plan_name.clone(), Arc::clone(plan))
+                    .ok_or_else(|| {
+                        ProcessorError::InvalidConfiguration(
+                            "Unsupported lookup join configuration".to_string(),
+                        )
+                    })?;
+            Ok(ProcessorBuildOutput::with_processor(
+                PlanProcessor::LookupJoin(processor),
+            ))
+        }
         PhysicalPlan::DataSink(sink_plan) => {
             let processor_id = format!("{}_{}", plan_name, sink_plan.connector.sink_id);
-            let mut processor = SinkProcessor::new(processor_id);
+            let mut processor =
+                SinkProcessor::new(processor_id).with_retry(sink_plan.connector.retry.clone());
             if sink_plan.connector.forward_to_result {
                 processor.enable_result_forwarding();
             } else {
@@ -832,7 +1115,8 @@ fn connect_processors(
     physical_plan: Arc<PhysicalPlan>,
     processor_map: &mut ProcessorMap,
     control_source: &mut ControlSourceProcessor,
-) -> Result<(), ProcessorError> {
+) -> Result<Vec<(String, String)>, ProcessorError> {
+    let mut edges = Vec::new();
     // Build index to name mapping for quick lookup
     let mut index_to_name_map: std::collections::HashMap<i64, String> =
         std::collections::HashMap::new();
@@ -847,6 +1131,7 @@ fn connect_processors(
                     ProcessorError::InvalidConfiguration("control source output unavailable".into())
                 })?;
                 processor.add_input(receiver);
+                edges.push((control_source.id().to_string(), processor.id().to_string()));
                 if let Some(control_rx) = control_source.subscribe_control_output() {
                     processor.add_control_input(control_rx);
                 }
@@ -894,8 +1179,14 @@ fn connect_processors(
             let control_receiver = processor_map
                 .get_processor(child_plan_name)
                 .and_then(|proc| proc.subscribe_control_output());
+            let child_id = processor_map
+                .get_processor(child_plan_name)
+                .map(|proc| proc.id().to_string());
             if let Some(parent_processor) = processor_map.get_processor_mut(parent_plan_name) {
                 parent_processor.add_input(receiver);
+                if let Some(child_id) = child_id {
+                    edges.push((child_id, parent_processor.id().to_string()));
+                }
                 if let Some(control_rx) = control_receiver {
                     parent_processor.add_control_input(control_rx);
                 }
@@ -903,7 +1194,7 @@ fn connect_processors(
         }
     }
 
-    Ok(())
+    Ok(edges)
 }
 
 /// Create a complete processor pipeline from a PhysicalPlan tree.
@@ -932,10 +1223,11 @@ pub fn create_processor_pipeline(
         aggregate_registry: dependencies.aggregate_registry,
         stateful_registry: dependencies.stateful_registry,
         eventtime: dependencies.eventtime,
+        error_policy: dependencies.error_policy,
     };
     build_processors_recursive(Arc::clone(&physical_plan), &mut processor_map, &context)?;
 
-    connect_processors(
+    let edges = connect_processors(
         Arc::clone(&physical_plan),
         &mut processor_map,
         &mut control_source,
@@ -961,9 +1253,31 @@ pub fn create_processor_pipeline(
             result_sink = Some(collector);
         }
     }
+    let dead_letter = match (dependencies.error_policy, dependencies.dead_letter) {
+        (ErrorPolicy::DeadLetter, Some(sink)) => Some(create_dead_letter_processor(
+            &sink,
+            &context,
+            &mut middle_processors,
+            &control_source,
+        )?),
+        (ErrorPolicy::DeadLetter, None) => {
+            return Err(ProcessorError::InvalidConfiguration(
+                "dead_letter error policy requires a dead-letter sink".to_string(),
+            ))
+        }
+        _ => None,
+    };
     let pipeline_id = Uuid::new_v4().to_string();
+    let checkpoints = CheckpointCoordinator::new();
+    let mut stats = PipelineStats::default();
+    stats.register(control_source.id());
     for processor in &mut middle_processors {
         processor.set_pipeline_id(&pipeline_id);
+        processor.set_checkpoint_coordinator(&checkpoints);
+        stats.register(processor.id());
+    }
+    if let Some(result_sink) = &result_sink {
+        stats.register(result_sink.id());
     }
 
     Ok(ProcessorPipeline {
@@ -972,14 +1286,53 @@ pub fn create_processor_pipeline(
         control_source,
         middle_processors,
         result_sink,
+        dead_letter,
         data_input_sender,
         data_input_buffer: Some(pipeline_input_receiver),
         control_signal_sender,
         handles: Vec::new(),
         pipeline_id,
+        checkpoints,
+        edges,
+        stats,
+        taps: Vec::new(),
+        started_at: None,
     })
 }
 
+/// Build the dead-letter processor watching every processor that rejects records.
+fn create_dead_letter_processor(
+    sink: &PipelineSink,
+    context: &ProcessorBuilderContext,
+    middle_processors: &mut [PlanProcessor],
+    control_source: &ControlSourceProcessor,
+) -> Result<DeadLetterProcessor, ProcessorError> {
+    let connector = context
+        .connector_registry()
+        .instantiate_sink(
+            sink.connector.connector.kind(),
+            &sink.sink_id,
+            &sink.connector.connector,
+            context.mqtt_clients_ref(),
+        )
+        .map_err(|err| ProcessorError::InvalidConfiguration(err.to_string()))?;
+    let encoder = context
+        .encoder_registry()
+        .instantiate(&sink.connector.encoder)
+        .map_err(|err| ProcessorError::InvalidConfiguration(err.to_string()))?;
+    let mut processor =
+        DeadLetterProcessor::new(format!("dead_letter_{}", sink.sink_id), connector, encoder);
+    if let Some(sender) = processor.sender() {
+        for source in middle_processors {
+            source.set_dead_letter_sender(&sender);
+        }
+    }
+    if let Some(control_rx) = control_source.subscribe_control_output() {
+        processor.add_control_input(control_rx);
+    }
+    Ok(processor)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1044,6 +1397,7 @@ mod tests {
             aggregate_registry,
             stateful_registry,
             eventtime: None,
+            error_policy: ErrorPolicy::default(),
         };
         let result = create_processor_from_plan_node(&physical_project, &context)
             .expect("processor creation failed");
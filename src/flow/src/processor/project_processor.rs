@@ -8,6 +8,9 @@ use crate::processor::base::{
     fan_in_control_streams, fan_in_streams, forward_error, log_received_data,
     send_control_with_backpressure, send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
 };
+use crate::processor::dead_letter::{
+    collection_payload, report_record_error, DeadLetterSender, ErrorPolicy,
+};
 use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData, StreamError};
 use futures::stream::StreamExt;
 use std::sync::Arc;
@@ -33,6 +36,9 @@ pub struct ProjectProcessor {
     output: broadcast::Sender<StreamData>,
     /// Dedicated control output channel
     control_output: broadcast::Sender<ControlSignal>,
+    /// What happens to rows whose expressions fail to evaluate
+    error_policy: ErrorPolicy,
+    dead_letter: Option<DeadLetterSender>,
 }
 
 impl ProjectProcessor {
@@ -47,9 +53,22 @@ impl ProjectProcessor {
             control_inputs: Vec::new(),
             output,
             control_output,
+            error_policy: ErrorPolicy::default(),
+            dead_letter: None,
         }
     }
 
+    /// Decide what happens to rows whose expressions fail to evaluate.
+    pub fn with_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.error_policy = policy;
+        self
+    }
+
+    /// Queue failed records for the pipeline's dead-letter processor.
+    pub fn set_dead_letter_sender(&mut self, sender: DeadLetterSender) {
+        self.dead_letter = Some(sender);
+    }
+
     /// Create a ProjectProcessor from a PhysicalPlan
     /// Returns None if the plan is not a PhysicalProject
     pub fn from_physical_plan(id: impl Into<String>, plan: Arc<PhysicalPlan>) -> Option<Self> {
@@ -84,6 +103,8 @@ impl Processor for ProjectProcessor {
         let mut control_active = !control_streams.is_empty();
         let output = self.output.clone();
         let control_output = self.control_output.clone();
+        let error_policy = self.error_policy;
+        let dead_letter = self.dead_letter.take();
         let fields = self.physical_project.fields.clone();
         tracing::info!(processor_id = %id, "project processor starting");
 
@@ -115,10 +136,15 @@ impl Processor for ProjectProcessor {
                                         send_with_backpressure(&output, projected_data).await?;
                                     }
                                     Err(e) => {
-                                        let error_data = StreamData::error(
-                                            StreamError::new(e.to_string()).with_source(id.clone()),
-                                        );
-                                        send_with_backpressure(&output, error_data).await?;
+                                        let mut error = StreamError::new(e.to_string())
+                                            .with_source(id.clone())
+                                            .with_timestamp(std::time::SystemTime::now());
+                                        if error_policy.keeps_payload() {
+                                            if let Some(payload) = collection_payload(collection.as_ref()) {
+                                                error = error.with_payload(payload);
+                                            }
+                                        }
+                                        report_record_error(&output, error_policy, dead_letter.as_ref(), error).await?;
                                     }
                                 }
                             }
@@ -156,6 +182,10 @@ impl Processor for ProjectProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -172,6 +172,10 @@ impl Processor for ResultCollectProcessor {
         Some(self.broadcast_output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.broadcast_output.len())
+    }
+
     fn add_input(&mut self, receiver: broadcast::Receiver<StreamData>) {
         self.inputs.push(receiver);
     }
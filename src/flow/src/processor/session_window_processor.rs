@@ -0,0 +1,491 @@
+//! SessionWindowProcessor - groups rows into sessions separated by inactivity.
+//!
+//! Semantics:
+//! - Each partition (from `OVER (PARTITION BY ...)`, or a single global one) holds one open session.
+//! - A row at `ts` extends the session unless `ts >= last + gap`, or `ts >= start + max_duration`
+//!   when a maximum is set; in that case the open session is emitted and a new one starts.
+//! - Watermarks emit every session that can no longer be extended, oldest first.
+//! - Graceful end emits all open sessions.
+
+use crate::expr::ScalarExpr;
+use crate::planner::logical::TimeUnit;
+use crate::planner::physical::{PhysicalPlan, PhysicalSessionWindow};
+use crate::processor::base::{
+    fan_in_control_streams, fan_in_streams, forward_error, send_control_with_backpressure,
+    send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+};
+use crate::processor::checkpoint::{report_state, take_restored_state, TupleSnapshot};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::StreamExt;
+
+/// Time span covered by an open session.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct SessionBounds {
+    pub(crate) start: SystemTime,
+    pub(crate) last: SystemTime,
+}
+
+impl SessionBounds {
+    pub(crate) fn new(ts: SystemTime) -> Self {
+        Self {
+            start: ts,
+            last: ts,
+        }
+    }
+
+    pub(crate) fn extend(&mut self, ts: SystemTime) {
+        self.start = self.start.min(ts);
+        self.last = self.last.max(ts);
+    }
+
+    /// Whether the session can no longer take rows at time `at`.
+    pub(crate) fn is_closed_at(
+        &self,
+        at: SystemTime,
+        gap: Duration,
+        max_duration: Option<Duration>,
+    ) -> bool {
+        self.last + gap <= at || max_duration.is_some_and(|max| self.start + max <= at)
+    }
+}
+
+/// Evaluate the `PARTITION BY` expressions of a session window; `None` is the global partition.
+pub(crate) fn session_partition_key(
+    partition_by: &[ScalarExpr],
+    tuple: &crate::model::Tuple,
+) -> Result<Option<String>, String> {
+    if partition_by.is_empty() {
+        return Ok(None);
+    }
+    let mut key_values = Vec::with_capacity(partition_by.len());
+    for expr in partition_by {
+        key_values.push(
+            expr.eval_with_tuple(tuple)
+                .map_err(|e| format!("failed to evaluate sessionwindow partition key: {e}"))?,
+        );
+    }
+    Ok(Some(format!("{:?}", key_values)))
+}
+
+pub(crate) fn session_durations(
+    time_unit: TimeUnit,
+    gap: u64,
+    max_duration: Option<u64>,
+) -> (Duration, Option<Duration>) {
+    (
+        time_unit.duration(gap),
+        max_duration.map(|max| time_unit.duration(max)),
+    )
+}
+
+struct Session {
+    bounds: SessionBounds,
+    rows: Vec<crate::model::Tuple>,
+}
+
+/// Checkpointed form of a [`Session`]; bounds are rebuilt from the row timestamps.
+#[derive(Serialize, Deserialize)]
+struct SessionSnapshot {
+    key: Option<String>,
+    rows: Vec<TupleSnapshot>,
+}
+
+pub struct SessionWindowProcessor {
+    id: String,
+    physical: Arc<PhysicalSessionWindow>,
+    inputs: Vec<broadcast::Receiver<StreamData>>,
+    control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
+    output: broadcast::Sender<StreamData>,
+    control_output: broadcast::Sender<ControlSignal>,
+    checkpoint: Option<CheckpointCoordinator>,
+}
+
+impl SessionWindowProcessor {
+    pub fn new(id: impl Into<String>, physical: Arc<PhysicalSessionWindow>) -> Self {
+        let (output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let (control_output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        Self {
+            id: id.into(),
+            physical,
+            inputs: Vec::new(),
+            control_inputs: Vec::new(),
+            output,
+            control_output,
+            checkpoint: None,
+        }
+    }
+
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
+    pub fn from_physical_plan(id: impl Into<String>, plan: Arc<PhysicalPlan>) -> Option<Self> {
+        match plan.as_ref() {
+            PhysicalPlan::SessionWindow(window) => {
+                Some(Self::new(id, Arc::new(window.as_ref().clone())))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Processor for SessionWindowProcessor {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn start(&mut self) -> tokio::task::JoinHandle<Result<(), ProcessorError>> {
+        let id = self.id.clone();
+        let mut input_streams = fan_in_streams(std::mem::take(&mut self.inputs));
+        let control_receivers = std::mem::take(&mut self.control_inputs);
+        let mut control_streams = fan_in_control_streams(control_receivers);
+        let mut control_active = !control_streams.is_empty();
+        let output = self.output.clone();
+        let control_output = self.control_output.clone();
+
+        let (gap, max_duration) = session_durations(
+            self.physical.time_unit,
+            self.physical.gap,
+            self.physical.max_duration,
+        );
+        let mut state = SessionState {
+            gap,
+            max_duration,
+            partition_by: self.physical.partition_by_scalars.clone(),
+            sessions: HashMap::new(),
+            output: output.clone(),
+        };
+        let checkpoint = self.checkpoint.clone();
+
+        tokio::spawn(async move {
+            if let Some(snapshot) =
+                take_restored_state::<Vec<SessionSnapshot>>(checkpoint.as_ref(), &id, &output)
+                    .await?
+            {
+                state.restore(snapshot);
+            }
+            loop {
+                tokio::select! {
+                    biased;
+                    control_item = control_streams.next(), if control_active => {
+                        if let Some(Ok(control_signal)) = control_item {
+                            let is_terminal = control_signal.is_terminal();
+                            send_control_with_backpressure(&control_output, control_signal).await?;
+                            if is_terminal {
+                                tracing::info!(processor_id = %id, "stopped");
+                                return Ok(());
+                            }
+                            continue;
+                        } else {
+                            control_active = false;
+                        }
+                    }
+                    item = input_streams.next() => {
+                        match item {
+                            Some(Ok(StreamData::Collection(collection))) => {
+                                let tuples = match collection.into_rows() {
+                                    Ok(rows) => rows,
+                                    Err(e) => {
+                                        forward_error(&output, &id, format!("failed to extract rows: {e}")).await?;
+                                        continue;
+                                    }
+                                };
+                                for tuple in tuples {
+                                    let key = match session_partition_key(&state.partition_by, &tuple) {
+                                        Ok(key) => key,
+                                        Err(e) => {
+                                            forward_error(&output, &id, e).await?;
+                                            continue;
+                                        }
+                                    };
+                                    state.add_tuple(key, tuple).await?;
+                                }
+                            }
+                            Some(Ok(StreamData::Watermark(ts))) => {
+                                state.flush_up_to(Some(ts)).await?;
+                            }
+                            Some(Ok(StreamData::Control(signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&signal, &checkpoint) {
+                                    report_state(checkpoint, *checkpoint_id, &id, &state.snapshot(), &output).await?;
+                                }
+                                let is_terminal = signal.is_terminal();
+                                let is_graceful = matches!(signal, ControlSignal::StreamGracefulEnd);
+                                send_with_backpressure(&output, StreamData::control(signal)).await?;
+                                if is_terminal {
+                                    if is_graceful {
+                                        state.flush_up_to(None).await?;
+                                    }
+                                    tracing::info!(processor_id = %id, "stopped");
+                                    return Ok(());
+                                }
+                            }
+                            Some(Ok(other)) => {
+                                let is_terminal = other.is_terminal();
+                                send_with_backpressure(&output, other).await?;
+                                if is_terminal {
+                                    tracing::info!(processor_id = %id, "stopped");
+                                    return Ok(());
+                                }
+                            }
+                            Some(Err(BroadcastStreamRecvError::Lagged(skipped))) => {
+                                forward_error(&output, &id, format!("SessionWindowProcessor input lagged by {skipped} messages")).await?;
+                            }
+                            None => {
+                                tracing::info!(processor_id = %id, "stopped");
+                                return Ok(());
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    fn subscribe_output(&self) -> Option<broadcast::Receiver<StreamData>> {
+        Some(self.output.subscribe())
+    }
+
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
+    fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
+        Some(self.control_output.subscribe())
+    }
+
+    fn add_input(&mut self, receiver: broadcast::Receiver<StreamData>) {
+        self.inputs.push(receiver);
+    }
+
+    fn add_control_input(&mut self, receiver: broadcast::Receiver<ControlSignal>) {
+        self.control_inputs.push(receiver);
+    }
+}
+
+struct SessionState {
+    gap: Duration,
+    max_duration: Option<Duration>,
+    partition_by: Vec<ScalarExpr>,
+    sessions: HashMap<Option<String>, Session>,
+    output: broadcast::Sender<StreamData>,
+}
+
+impl SessionState {
+    /// Append a row to its partition's session, emitting the session it closes if any.
+    async fn add_tuple(
+        &mut self,
+        key: Option<String>,
+        tuple: crate::model::Tuple,
+    ) -> Result<(), ProcessorError> {
+        let ts = tuple.timestamp;
+        if let Some(session) = self.sessions.get_mut(&key) {
+            if !session.bounds.is_closed_at(ts, self.gap, self.max_duration) {
+                session.bounds.extend(ts);
+                session.rows.push(tuple);
+                return Ok(());
+            }
+        }
+        let session = Session {
+            bounds: SessionBounds::new(ts),
+            rows: vec![tuple],
+        };
+        if let Some(closed) = self.sessions.insert(key, session) {
+            self.emit(closed).await?;
+        }
+        Ok(())
+    }
+
+    /// Emit sessions closed by `watermark`, or every open session when `None`.
+    async fn flush_up_to(&mut self, watermark: Option<SystemTime>) -> Result<(), ProcessorError> {
+        let closed_keys: Vec<_> = self
+            .sessions
+            .iter()
+            .filter(|(_, session)| {
+                watermark
+                    .is_none_or(|ts| session.bounds.is_closed_at(ts, self.gap, self.max_duration))
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        let mut closed: Vec<_> = closed_keys
+            .iter()
+            .filter_map(|key| self.sessions.remove(key))
+            .collect();
+        closed.sort_by_key(|session| session.bounds.start);
+        for session in closed {
+            self.emit(session).await?;
+        }
+        Ok(())
+    }
+
+    async fn emit(&self, session: Session) -> Result<(), ProcessorError> {
+        let batch = crate::model::RecordBatch::new(session.rows)
+            .map_err(|e| ProcessorError::ProcessingError(e.to_string()))?;
+        send_with_backpressure(&self.output, StreamData::collection(Box::new(batch))).await
+    }
+
+    fn snapshot(&self) -> Vec<SessionSnapshot> {
+        self.sessions
+            .iter()
+            .map(|(key, session)| SessionSnapshot {
+                key: key.clone(),
+                rows: session.rows.iter().map(TupleSnapshot::from).collect(),
+            })
+            .collect()
+    }
+
+    fn restore(&mut self, snapshot: Vec<SessionSnapshot>) {
+        for session in snapshot {
+            let rows: Vec<_> = session
+                .rows
+                .into_iter()
+                .map(TupleSnapshot::into_tuple)
+                .collect();
+            let Some(first) = rows.first() else {
+                continue;
+            };
+            let mut bounds = SessionBounds::new(first.timestamp);
+            for row in &rows {
+                bounds.extend(row.timestamp);
+            }
+            self.sessions.insert(session.key, Session { bounds, rows });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::scalar::ColumnRef;
+    use datatypes::Value;
+    use sqlparser::ast::{Expr, Ident};
+    use std::time::UNIX_EPOCH;
+
+    fn tuple_with_key_at(sec: u64, key: i64) -> crate::model::Tuple {
+        let mut tuple =
+            crate::model::Tuple::with_timestamp(Vec::new(), UNIX_EPOCH + Duration::from_secs(sec));
+        tuple.add_affiliate_column(Arc::new("k".to_string()), Value::Int64(key));
+        tuple
+    }
+
+    fn start_processor(
+        max_duration: Option<u64>,
+        partition_by: Vec<ScalarExpr>,
+    ) -> (
+        broadcast::Sender<StreamData>,
+        broadcast::Receiver<StreamData>,
+    ) {
+        let partition_by_exprs = partition_by
+            .iter()
+            .map(|_| Expr::Identifier(Ident::new("k")))
+            .collect();
+        let physical = PhysicalSessionWindow::new(
+            TimeUnit::Seconds,
+            5,
+            max_duration,
+            partition_by_exprs,
+            partition_by,
+            Vec::new(),
+            0,
+        );
+        let mut processor = SessionWindowProcessor::new("sw", Arc::new(physical));
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+        (input, output_rx)
+    }
+
+    async fn next_batch_keys(output_rx: &mut broadcast::Receiver<StreamData>) -> Vec<i64> {
+        loop {
+            match output_rx.recv().await.unwrap() {
+                StreamData::Collection(collection) => {
+                    return collection
+                        .rows()
+                        .iter()
+                        .map(|row| match row.value_by_name("", "k") {
+                            Some(Value::Int64(k)) => *k,
+                            other => panic!("unexpected key {other:?}"),
+                        })
+                        .collect();
+                }
+                StreamData::Control(_) => {}
+                other => panic!("unexpected output: {}", other.description()),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn sessionwindow_splits_on_gap_and_flushes_on_watermark() {
+        let (input, mut output_rx) = start_processor(None, Vec::new());
+
+        // 1, 3 and 7 chain within the 5s gap; 20 starts a new session.
+        let batch = crate::model::RecordBatch::new(vec![
+            tuple_with_key_at(1, 1),
+            tuple_with_key_at(3, 1),
+            tuple_with_key_at(7, 1),
+            tuple_with_key_at(20, 2),
+        ])
+        .expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+        assert_eq!(next_batch_keys(&mut output_rx).await, vec![1, 1, 1]);
+
+        // The second session stays open until the watermark passes 20 + 5.
+        assert!(input
+            .send(StreamData::watermark(UNIX_EPOCH + Duration::from_secs(24)))
+            .is_ok());
+        tokio::task::yield_now().await;
+        assert!(output_rx.try_recv().is_err());
+        assert!(input
+            .send(StreamData::watermark(UNIX_EPOCH + Duration::from_secs(25)))
+            .is_ok());
+        assert_eq!(next_batch_keys(&mut output_rx).await, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn sessionwindow_max_duration_caps_session() {
+        let (input, mut output_rx) = start_processor(Some(6), Vec::new());
+
+        let batch = crate::model::RecordBatch::new(vec![
+            tuple_with_key_at(0, 1),
+            tuple_with_key_at(4, 1),
+            tuple_with_key_at(8, 2),
+        ])
+        .expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+        assert_eq!(next_batch_keys(&mut output_rx).await, vec![1, 1]);
+    }
+
+    #[tokio::test]
+    async fn sessionwindow_partitions_are_independent() {
+        let key_expr = ScalarExpr::Column(ColumnRef::ByName {
+            column_name: "k".to_string(),
+        });
+        let (input, mut output_rx) = start_processor(None, vec![key_expr]);
+
+        let batch = crate::model::RecordBatch::new(vec![
+            tuple_with_key_at(1, 1),
+            tuple_with_key_at(4, 2),
+            tuple_with_key_at(5, 1),
+            tuple_with_key_at(12, 2),
+        ])
+        .expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+        // Key 2 went quiet after 4, so 12 closes its first session; key 1 is still open.
+        assert_eq!(next_batch_keys(&mut output_rx).await, vec![2]);
+
+        assert!(input
+            .send(StreamData::control(ControlSignal::StreamGracefulEnd))
+            .is_ok());
+        assert_eq!(next_batch_keys(&mut output_rx).await, vec![1, 1]);
+        assert_eq!(next_batch_keys(&mut output_rx).await, vec![2]);
+    }
+}
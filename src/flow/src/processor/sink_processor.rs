@@ -1,5 +1,6 @@
 //! SinkProcessor - routes collections to SinkConnectors and forwards results.
-use crate::connector::SinkConnector;
+use crate::connector::{SinkConnector, SinkConnectorError};
+use crate::planner::sink::SinkRetryProps;
 use crate::processor::base::{
     fan_in_control_streams, fan_in_streams, forward_error, log_received_data,
     send_control_with_backpressure, send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
@@ -7,12 +8,43 @@ use crate::processor::base::{
 use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData};
 use futures::stream::StreamExt;
 use once_cell::sync::Lazy;
-use prometheus::{register_int_counter_vec, IntCounterVec};
+use prometheus::{
+    register_int_counter_vec, register_int_gauge_vec, IntCounter, IntCounterVec, IntGauge,
+    IntGaugeVec,
+};
+use std::collections::VecDeque;
+use std::time::Duration;
 use tokio::sync::broadcast;
+use tokio::time::Instant;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 
 struct ConnectorBinding {
     connector: Box<dyn SinkConnector>,
+    retry: SinkRetryProps,
+    /// Payloads (with their row counts) held back while the connector is unavailable
+    buffer: VecDeque<(Vec<u8>, u64)>,
+    /// When the buffer is next flushed, and how long to wait after that if flushing fails
+    next_flush: Option<Instant>,
+    flush_backoff: Duration,
+    metrics: DeliveryMetrics,
+}
+
+struct DeliveryMetrics {
+    records_out: IntCounter,
+    retries: IntCounter,
+    dropped: IntCounter,
+    buffered: IntGauge,
+}
+
+impl DeliveryMetrics {
+    fn new(processor_id: &str) -> Self {
+        Self {
+            records_out: SINK_RECORDS_OUT.with_label_values(&[processor_id]),
+            retries: SINK_RETRIES.with_label_values(&[processor_id]),
+            dropped: SINK_DROPPED.with_label_values(&[processor_id]),
+            buffered: SINK_BUFFERED.with_label_values(&[processor_id]),
+        }
+    }
 }
 
 impl ConnectorBinding {
@@ -27,16 +59,122 @@ impl ConnectorBinding {
             .map_err(|err| ProcessorError::ProcessingError(err.to_string()))
     }
 
-    async fn publish(&mut self, payload: &[u8]) -> Result<(), ProcessorError> {
-        self.connector
-            .send(payload)
-            .await
-            .map_err(|err| ProcessorError::ProcessingError(err.to_string()))?;
+    /// Deliver a payload, retrying and buffering it according to the retry policy.
+    ///
+    /// Returns an error only when the payload was dropped.
+    async fn publish(&mut self, payload: &[u8], rows: u64) -> Result<(), ProcessorError> {
+        if !self.buffer.is_empty() {
+            // Keep delivery order: queue behind payloads waiting for the connector.
+            self.push_buffer(payload.to_vec(), rows);
+            return Ok(());
+        }
+        match self.send_with_retry(payload).await {
+            Ok(()) => {
+                self.metrics.records_out.inc_by(rows);
+                Ok(())
+            }
+            Err(SinkConnectorError::Unavailable(_)) if self.retry.buffer_capacity > 0 => {
+                self.push_buffer(payload.to_vec(), rows);
+                self.flush_backoff = self.retry.initial_backoff;
+                self.next_flush = Some(Instant::now() + self.flush_backoff);
+                Ok(())
+            }
+            Err(err) => {
+                self.metrics.dropped.inc_by(rows);
+                Err(ProcessorError::ProcessingError(err.to_string()))
+            }
+        }
+    }
+
+    async fn send_with_retry(&mut self, payload: &[u8]) -> Result<(), SinkConnectorError> {
+        let mut backoff = self.retry.initial_backoff;
+        let mut attempt = 0;
+        loop {
+            match self.connector.send(payload).await {
+                Ok(()) => return Ok(()),
+                Err(err) if attempt < self.retry.max_retries => {
+                    attempt += 1;
+                    self.metrics.retries.inc();
+                    tracing::warn!(
+                        connector_id = %self.connector.id(),
+                        attempt = attempt,
+                        backoff_ms = backoff.as_millis() as u64,
+                        error = %err,
+                        "sink send failed, retrying"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = self.retry.next_backoff(backoff);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    fn push_buffer(&mut self, payload: Vec<u8>, rows: u64) {
+        if self.buffer.len() >= self.retry.buffer_capacity {
+            if let Some((_, dropped_rows)) = self.buffer.pop_front() {
+                self.metrics.dropped.inc_by(dropped_rows);
+                tracing::warn!(
+                    connector_id = %self.connector.id(),
+                    "sink buffer full, dropping oldest payload"
+                );
+            }
+        }
+        self.buffer.push_back((payload, rows));
+        self.metrics.buffered.set(self.buffer.len() as i64);
+    }
 
+    /// Try to deliver the buffered payloads once, in order.
+    ///
+    /// Stops at the first payload the connector is still unavailable for and schedules the next
+    /// attempt with a doubled backoff. Payloads failing for any other reason are dropped.
+    async fn flush_buffer(&mut self) -> Result<(), ProcessorError> {
+        while let Some((payload, rows)) = self.buffer.front() {
+            let rows = *rows;
+            match self.connector.send(payload).await {
+                Ok(()) => {
+                    self.metrics.records_out.inc_by(rows);
+                    self.buffer.pop_front();
+                    self.metrics.buffered.set(self.buffer.len() as i64);
+                }
+                Err(SinkConnectorError::Unavailable(_)) => {
+                    self.metrics.retries.inc();
+                    self.flush_backoff = self.retry.next_backoff(self.flush_backoff);
+                    self.next_flush = Some(Instant::now() + self.flush_backoff);
+                    return Ok(());
+                }
+                Err(err) => {
+                    self.buffer.pop_front();
+                    self.metrics.buffered.set(self.buffer.len() as i64);
+                    self.metrics.dropped.inc_by(rows);
+                    self.next_flush = Some(Instant::now());
+                    return Err(ProcessorError::ProcessingError(err.to_string()));
+                }
+            }
+        }
+        self.next_flush = None;
         Ok(())
     }
 
+    /// Deliver what is still buffered, then close the connector.
     async fn close(&mut self) -> Result<(), ProcessorError> {
+        while let Some((payload, rows)) = self.buffer.pop_front() {
+            match self.send_with_retry(&payload).await {
+                Ok(()) => self.metrics.records_out.inc_by(rows),
+                Err(err) => {
+                    let dropped = rows + self.buffer.drain(..).map(|(_, rows)| rows).sum::<u64>();
+                    self.metrics.dropped.inc_by(dropped);
+                    tracing::error!(
+                        connector_id = %self.connector.id(),
+                        dropped_rows = dropped,
+                        error = %err,
+                        "sink closed with undelivered payloads"
+                    );
+                }
+            }
+        }
+        self.metrics.buffered.set(0);
+        self.next_flush = None;
         self.connector
             .close()
             .await
@@ -55,7 +193,8 @@ pub struct SinkProcessor {
     control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
     output: broadcast::Sender<StreamData>,
     control_output: broadcast::Sender<ControlSignal>,
-    connector: Option<ConnectorBinding>,
+    connector: Option<Box<dyn SinkConnector>>,
+    retry: SinkRetryProps,
     forward_to_result: bool,
 }
 
@@ -77,6 +216,33 @@ static SINK_RECORDS_OUT: Lazy<IntCounterVec> = Lazy::new(|| {
     .expect("create sink records_out counter vec")
 });
 
+static SINK_RETRIES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "sink_processor_retries_total",
+        "Failed sink deliveries that were retried",
+        &["processor"]
+    )
+    .expect("create sink retries counter vec")
+});
+
+static SINK_DROPPED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "sink_processor_dropped_total",
+        "Rows dropped by sink processors after delivery failed",
+        &["processor"]
+    )
+    .expect("create sink dropped counter vec")
+});
+
+static SINK_BUFFERED: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "sink_processor_buffered_payloads",
+        "Payloads buffered by sink processors while their connector is unavailable",
+        &["processor"]
+    )
+    .expect("create sink buffered gauge vec")
+});
+
 impl SinkProcessor {
     /// Create a new sink processor with the provided identifier.
     pub fn new(id: impl Into<String>) -> Self {
@@ -89,6 +255,7 @@ impl SinkProcessor {
             output,
             control_output,
             connector: None,
+            retry: SinkRetryProps::default(),
             forward_to_result: false,
         }
     }
@@ -105,7 +272,13 @@ impl SinkProcessor {
 
     /// Register a connector binding.
     pub fn add_connector(&mut self, connector: Box<dyn SinkConnector>) {
-        self.connector = Some(ConnectorBinding { connector });
+        self.connector = Some(connector);
+    }
+
+    /// Configure retries and buffering for failed deliveries.
+    pub fn with_retry(mut self, retry: SinkRetryProps) -> Self {
+        self.retry = retry;
+        self
     }
 
     async fn handle_payload(
@@ -117,12 +290,7 @@ impl SinkProcessor {
         SINK_RECORDS_IN
             .with_label_values(&[processor_id])
             .inc_by(row_count);
-        connector.publish(payload).await?;
-
-        SINK_RECORDS_OUT
-            .with_label_values(&[processor_id])
-            .inc_by(row_count);
-        Ok(())
+        connector.publish(payload, row_count).await
     }
 
     async fn handle_terminal(connector: &mut ConnectorBinding) -> Result<(), ProcessorError> {
@@ -144,7 +312,7 @@ impl Processor for SinkProcessor {
         let forward_data = self.forward_to_result;
         let control_output = self.control_output.clone();
 
-        let Some(mut connector) = self.connector.take() else {
+        let Some(connector) = self.connector.take() else {
             return tokio::spawn(async {
                 Err(ProcessorError::InvalidConfiguration(
                     "sink connector missing".to_string(),
@@ -152,6 +320,14 @@ impl Processor for SinkProcessor {
             });
         };
         let processor_id = self.id.clone();
+        let mut connector = ConnectorBinding {
+            connector,
+            retry: self.retry.clone(),
+            buffer: VecDeque::new(),
+            next_flush: None,
+            flush_backoff: self.retry.initial_backoff,
+            metrics: DeliveryMetrics::new(&processor_id),
+        };
         tracing::info!(processor_id = %processor_id, "sink processor starting");
 
         tokio::spawn(async move {
@@ -174,6 +350,14 @@ impl Processor for SinkProcessor {
                             control_active = false;
                         }
                     }
+                    _ = tokio::time::sleep_until(connector.next_flush.unwrap_or_else(Instant::now)),
+                        if connector.next_flush.is_some() =>
+                    {
+                        if let Err(err) = connector.flush_buffer().await {
+                            tracing::error!(processor_id = %processor_id, error = %err, "buffered payload dropped");
+                            forward_error(&output, &processor_id, err.to_string()).await?;
+                        }
+                    }
                     item = input_streams.next() => {
                         match item {
                             Some(Ok(StreamData::Encoded { collection, payload })) => {
@@ -259,6 +443,10 @@ impl Processor for SinkProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
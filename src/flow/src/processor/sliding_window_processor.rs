@@ -3,13 +3,16 @@
 //! Processing-time mode assumes tuple timestamps are non-decreasing.
 //! Window flushing for lookahead windows is driven by incoming watermarks.
 
-use crate::planner::logical::TimeUnit;
 use crate::planner::physical::{PhysicalPlan, PhysicalSlidingWindow};
 use crate::processor::base::{
     fan_in_control_streams, fan_in_streams, forward_error, send_control_with_backpressure,
     send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
 };
-use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData};
+use crate::processor::checkpoint::{report_state, take_restored_state, TupleSnapshot};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
+};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
@@ -25,18 +28,17 @@ pub struct SlidingWindowProcessor {
     control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
     output: broadcast::Sender<StreamData>,
     control_output: broadcast::Sender<ControlSignal>,
+    checkpoint: Option<CheckpointCoordinator>,
 }
 
 impl SlidingWindowProcessor {
     pub fn new(id: impl Into<String>, physical: Arc<PhysicalSlidingWindow>) -> Self {
         let (output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
         let (control_output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
-        let lookback = match physical.time_unit {
-            TimeUnit::Seconds => Duration::from_secs(physical.lookback),
-        };
-        let lookahead = match physical.time_unit {
-            TimeUnit::Seconds => physical.lookahead.map(Duration::from_secs),
-        };
+        let lookback = physical.time_unit.duration(physical.lookback);
+        let lookahead = physical
+            .lookahead
+            .map(|lookahead| physical.time_unit.duration(lookahead));
         Self {
             id: id.into(),
             lookback,
@@ -45,9 +47,15 @@ impl SlidingWindowProcessor {
             control_inputs: Vec::new(),
             output,
             control_output,
+            checkpoint: None,
         }
     }
 
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
     pub fn from_physical_plan(id: impl Into<String>, plan: Arc<PhysicalPlan>) -> Option<Self> {
         match plan.as_ref() {
             PhysicalPlan::SlidingWindow(window) => Some(Self::new(id, Arc::new(window.clone()))),
@@ -74,8 +82,14 @@ impl Processor for SlidingWindowProcessor {
         let lookahead = self.lookahead;
 
         let mut state = ProcessingState::new(lookback, lookahead, output.clone());
+        let checkpoint = self.checkpoint.clone();
 
         tokio::spawn(async move {
+            if let Some(snapshot) =
+                take_restored_state::<SlidingSnapshot>(checkpoint.as_ref(), &id, &output).await?
+            {
+                state.restore(snapshot);
+            }
             loop {
                 tokio::select! {
                     biased;
@@ -103,6 +117,9 @@ impl Processor for SlidingWindowProcessor {
                                 state.flush_up_to(ts).await?;
                             }
                             Some(Ok(StreamData::Control(signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&signal, &checkpoint) {
+                                    report_state(checkpoint, *checkpoint_id, &id, &state.snapshot(), &output).await?;
+                                }
                                 let is_terminal = signal.is_terminal();
                                 let is_graceful = matches!(signal, ControlSignal::StreamGracefulEnd);
                                 send_with_backpressure(&output, StreamData::control(signal)).await?;
@@ -144,6 +161,10 @@ impl Processor for SlidingWindowProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -157,12 +178,19 @@ impl Processor for SlidingWindowProcessor {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct WindowRequest {
     start: SystemTime,
     end: SystemTime,
 }
 
+/// Checkpointed buffer of a sliding window; `pending` is only used with lookahead.
+#[derive(Serialize, Deserialize)]
+struct SlidingSnapshot {
+    rows: Vec<TupleSnapshot>,
+    pending: Vec<WindowRequest>,
+}
+
 /// Processing-time sliding window state (monotonic timestamps).
 enum ProcessingState {
     WithLookahead(ProcessingWithLookaheadState),
@@ -206,6 +234,34 @@ impl ProcessingState {
             ProcessingState::WithoutLookahead(state) => state.flush_all().await,
         }
     }
+
+    fn snapshot(&self) -> SlidingSnapshot {
+        let (rows, pending) = match self {
+            ProcessingState::WithLookahead(state) => {
+                (&state.rows, state.pending.iter().cloned().collect())
+            }
+            ProcessingState::WithoutLookahead(state) => (&state.rows, Vec::new()),
+        };
+        SlidingSnapshot {
+            rows: rows.iter().map(TupleSnapshot::from).collect(),
+            pending,
+        }
+    }
+
+    fn restore(&mut self, snapshot: SlidingSnapshot) {
+        let rows = snapshot
+            .rows
+            .into_iter()
+            .map(TupleSnapshot::into_tuple)
+            .collect();
+        match self {
+            ProcessingState::WithLookahead(state) => {
+                state.rows = rows;
+                state.pending = snapshot.pending.into();
+            }
+            ProcessingState::WithoutLookahead(state) => state.rows = rows,
+        }
+    }
 }
 
 struct ProcessingWithoutLookaheadState {
@@ -375,6 +431,7 @@ impl ProcessingWithLookaheadState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::planner::logical::TimeUnit;
     use std::time::UNIX_EPOCH;
 
     fn tuple_at(sec: u64) -> crate::model::Tuple {
@@ -10,8 +10,12 @@ use crate::processor::base::{
     fan_in_control_streams, fan_in_streams, forward_error, send_control_with_backpressure,
     send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
 };
-use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData};
+use crate::processor::checkpoint::{report_state, take_restored_state, TupleSnapshot};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
+};
 use datatypes::Value;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use tokio::sync::broadcast;
@@ -23,6 +27,14 @@ struct PartitionState {
     rows: VecDeque<crate::model::Tuple>,
 }
 
+/// Checkpointed form of a [`PartitionState`].
+#[derive(Serialize, Deserialize)]
+struct PartitionSnapshot {
+    key: Option<String>,
+    active: bool,
+    rows: Vec<TupleSnapshot>,
+}
+
 pub struct StateWindowProcessor {
     id: String,
     physical: Arc<PhysicalStateWindow>,
@@ -30,6 +42,7 @@ pub struct StateWindowProcessor {
     control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
     output: broadcast::Sender<StreamData>,
     control_output: broadcast::Sender<ControlSignal>,
+    checkpoint: Option<CheckpointCoordinator>,
 }
 
 impl StateWindowProcessor {
@@ -43,9 +56,15 @@ impl StateWindowProcessor {
             control_inputs: Vec::new(),
             output,
             control_output,
+            checkpoint: None,
         }
     }
 
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
     pub fn from_physical_plan(id: impl Into<String>, plan: Arc<PhysicalPlan>) -> Option<Self> {
         match plan.as_ref() {
             PhysicalPlan::StateWindow(window) => {
@@ -73,9 +92,28 @@ impl Processor for StateWindowProcessor {
         let open_expr = self.physical.open_scalar.clone();
         let emit_expr = self.physical.emit_scalar.clone();
         let partition_by_exprs = self.physical.partition_by_scalars.clone();
+        let checkpoint = self.checkpoint.clone();
 
         tokio::spawn(async move {
             let mut partitions: HashMap<Option<String>, PartitionState> = HashMap::new();
+            if let Some(snapshot) =
+                take_restored_state::<Vec<PartitionSnapshot>>(checkpoint.as_ref(), &id, &output)
+                    .await?
+            {
+                for partition in snapshot {
+                    partitions.insert(
+                        partition.key,
+                        PartitionState {
+                            active: partition.active,
+                            rows: partition
+                                .rows
+                                .into_iter()
+                                .map(TupleSnapshot::into_tuple)
+                                .collect(),
+                        },
+                    );
+                }
+            }
 
             loop {
                 tokio::select! {
@@ -191,6 +229,17 @@ impl Processor for StateWindowProcessor {
                                 send_with_backpressure(&output, StreamData::watermark(ts)).await?;
                             }
                             Some(Ok(StreamData::Control(signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&signal, &checkpoint) {
+                                    let state: Vec<_> = partitions
+                                        .iter()
+                                        .map(|(key, state)| PartitionSnapshot {
+                                            key: key.clone(),
+                                            active: state.active,
+                                            rows: state.rows.iter().map(TupleSnapshot::from).collect(),
+                                        })
+                                        .collect();
+                                    report_state(checkpoint, *checkpoint_id, &id, &state, &output).await?;
+                                }
                                 let is_terminal = signal.is_terminal();
                                 let is_graceful = matches!(signal, ControlSignal::StreamGracefulEnd);
                                 send_with_backpressure(&output, StreamData::control(signal)).await?;
@@ -241,6 +290,10 @@ impl Processor for StateWindowProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
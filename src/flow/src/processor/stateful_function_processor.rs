@@ -6,8 +6,12 @@ use crate::processor::base::{
     fan_in_control_streams, fan_in_streams, forward_error, log_received_data,
     send_control_with_backpressure, send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
 };
-use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData, StreamError};
+use crate::processor::checkpoint::{report_state, take_restored_state};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData, StreamError,
+};
 use crate::stateful::{StatefulFunctionInstance, StatefulFunctionRegistry};
+use datatypes::Value;
 use futures::stream::StreamExt;
 use std::sync::Arc;
 use tokio::sync::broadcast;
@@ -27,6 +31,7 @@ pub struct StatefulFunctionProcessor {
     control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
     output: broadcast::Sender<StreamData>,
     control_output: broadcast::Sender<ControlSignal>,
+    checkpoint: Option<CheckpointCoordinator>,
 }
 
 impl StatefulFunctionProcessor {
@@ -68,9 +73,15 @@ impl StatefulFunctionProcessor {
             control_inputs: Vec::new(),
             output,
             control_output,
+            checkpoint: None,
         })
     }
 
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
     pub fn from_physical_plan(
         id: impl Into<String>,
         plan: Arc<PhysicalPlan>,
@@ -86,6 +97,24 @@ impl StatefulFunctionProcessor {
         }
     }
 
+    /// Restore per-call state, one state vector per call in plan order.
+    fn restore_calls(
+        calls: &mut [StatefulProcessorCall],
+        states: Vec<Vec<Value>>,
+    ) -> Result<(), String> {
+        if calls.len() != states.len() {
+            return Err(format!(
+                "checkpoint has state for {} stateful calls, expected {}",
+                states.len(),
+                calls.len()
+            ));
+        }
+        for (call, state) in calls.iter_mut().zip(states) {
+            call.instance.restore(state)?;
+        }
+        Ok(())
+    }
+
     fn apply_stateful(
         collection: Box<dyn Collection>,
         calls: &mut [StatefulProcessorCall],
@@ -133,9 +162,18 @@ impl Processor for StatefulFunctionProcessor {
         let control_output = self.control_output.clone();
         let mut calls = std::mem::take(&mut self.calls);
         let _physical_stateful = Arc::clone(&self.physical_stateful);
+        let checkpoint = self.checkpoint.clone();
 
         tracing::info!(processor_id = %id, "stateful function processor starting");
         tokio::spawn(async move {
+            if let Some(states) =
+                take_restored_state::<Vec<Vec<Value>>>(checkpoint.as_ref(), &id, &output).await?
+            {
+                if let Err(err) = Self::restore_calls(&mut calls, states) {
+                    forward_error(&output, &id, format!("failed to restore checkpoint: {err}"))
+                        .await?;
+                }
+            }
             loop {
                 tokio::select! {
                     biased;
@@ -167,6 +205,13 @@ impl Processor for StatefulFunctionProcessor {
                                     }
                                 }
                             }
+                            Some(Ok(StreamData::Control(ControlSignal::Checkpoint { checkpoint_id }))) => {
+                                if let Some(checkpoint) = &checkpoint {
+                                    let state: Vec<_> = calls.iter().map(|call| call.instance.state()).collect();
+                                    report_state(checkpoint, checkpoint_id, &id, &state, &output).await?;
+                                }
+                                send_with_backpressure(&output, StreamData::control(ControlSignal::Checkpoint { checkpoint_id })).await?;
+                            }
                             Some(Ok(data)) => {
                                 log_received_data(&id, &data);
                                 let is_terminal = data.is_terminal();
@@ -201,6 +246,10 @@ impl Processor for StatefulFunctionProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
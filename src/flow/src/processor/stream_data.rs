@@ -12,17 +12,23 @@ pub enum ControlSignal {
     StreamGracefulEnd,
     /// Immediate stream end propagated via the control channel
     StreamQuickEnd,
+    /// Checkpoint barrier propagated via the data channel, so every stateful processor
+    /// snapshots its state after all rows that entered the pipeline before the barrier
+    Checkpoint { checkpoint_id: u64 },
 }
 
 impl ControlSignal {
     /// Whether this signal should propagate via the control channel
     pub fn routes_via_control(&self) -> bool {
-        !matches!(self, ControlSignal::StreamGracefulEnd)
+        !self.routes_via_data()
     }
 
     /// Whether this signal should propagate via the data channel
     pub fn routes_via_data(&self) -> bool {
-        matches!(self, ControlSignal::StreamGracefulEnd)
+        matches!(
+            self,
+            ControlSignal::StreamGracefulEnd | ControlSignal::Checkpoint { .. }
+        )
     }
 
     /// Whether this signal indicates stream termination
@@ -63,6 +69,8 @@ pub struct StreamError {
     pub source: Option<String>,
     /// Optional timestamp when the error occurred
     pub timestamp: Option<std::time::SystemTime>,
+    /// Optional copy of the record that failed, kept for dead-letter delivery
+    pub payload: Option<Vec<u8>>,
 }
 
 impl StreamError {
@@ -72,6 +80,7 @@ impl StreamError {
             message: message.into(),
             source: None,
             timestamp: None,
+            payload: None,
         }
     }
 
@@ -86,6 +95,12 @@ impl StreamError {
         self.timestamp = Some(timestamp);
         self
     }
+
+    /// Create a new stream error carrying the failed record
+    pub fn with_payload(mut self, payload: Vec<u8>) -> Self {
+        self.payload = Some(payload);
+        self
+    }
 }
 
 impl std::fmt::Display for StreamError {
@@ -0,0 +1,762 @@
+//! StreamJoinProcessor - interval-bounded inner join between two streams.
+//!
+//! Rows from each side are buffered per join key. An incoming row probes the opposite side's
+//! buffer and emits one joined tuple per match whose timestamp lies within the join interval.
+//!
+//! Each input is one side of the join and tracks its own progress: the latest watermark or row
+//! timestamp received on it. The join frontier is the minimum over the inputs, so a lagging side
+//! holds back eviction instead of having its rows dropped as late. Buffered rows are evicted
+//! once they fall behind `frontier - within`, and only frontier advances are forwarded as
+//! watermarks.
+
+use crate::expr::ScalarExpr;
+use crate::model::{RecordBatch, Tuple};
+use crate::planner::physical::{PhysicalPlan, PhysicalStreamJoin};
+use crate::processor::base::{
+    fan_in_control_streams, forward_error, log_received_data, send_control_with_backpressure,
+    send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+};
+use crate::processor::checkpoint::{report_state, take_restored_state, TupleSnapshot};
+use crate::processor::join_key::{eval_join_key, KeyPart};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
+};
+use datatypes::Value;
+use futures::stream::{SelectAll, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+
+/// StreamJoinProcessor - joins rows of two upstream streams on equality keys
+pub struct StreamJoinProcessor {
+    /// Processor identifier
+    id: String,
+    /// Physical join configuration
+    physical_join: Arc<PhysicalStreamJoin>,
+    /// Input channels for receiving data (one per side)
+    inputs: Vec<broadcast::Receiver<StreamData>>,
+    /// Control input channels
+    control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
+    /// Broadcast channel for downstream processors
+    output: broadcast::Sender<StreamData>,
+    /// Dedicated control output channel
+    control_output: broadcast::Sender<ControlSignal>,
+    checkpoint: Option<CheckpointCoordinator>,
+}
+
+impl StreamJoinProcessor {
+    /// Create a new StreamJoinProcessor from PhysicalStreamJoin
+    pub fn new(id: impl Into<String>, physical_join: Arc<PhysicalStreamJoin>) -> Self {
+        let (output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let (control_output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        Self {
+            id: id.into(),
+            physical_join,
+            inputs: Vec::new(),
+            control_inputs: Vec::new(),
+            output,
+            control_output,
+            checkpoint: None,
+        }
+    }
+
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
+    /// Create a StreamJoinProcessor from a PhysicalPlan
+    /// Returns None if the plan is not a PhysicalStreamJoin
+    pub fn from_physical_plan(id: impl Into<String>, plan: Arc<PhysicalPlan>) -> Option<Self> {
+        match plan.as_ref() {
+            PhysicalPlan::StreamJoin(join) => Some(Self::new(id, Arc::new(join.clone()))),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JoinSide {
+    Left,
+    Right,
+}
+
+/// Buffered rows of one side, grouped by their normalized key values.
+type SideBuffer = HashMap<Vec<KeyPart>, VecDeque<Tuple>>;
+
+/// Inputs merged into one stream of `(input index, data)`.
+type TaggedInputStream = SelectAll<
+    Pin<Box<dyn Stream<Item = (usize, Result<StreamData, BroadcastStreamRecvError>)> + Send>>,
+>;
+
+fn tag_inputs(inputs: Vec<broadcast::Receiver<StreamData>>) -> TaggedInputStream {
+    let mut streams: TaggedInputStream = SelectAll::new();
+    for (index, receiver) in inputs.into_iter().enumerate() {
+        streams.push(Box::pin(
+            BroadcastStream::new(receiver).map(move |item| (index, item)),
+        ));
+    }
+    streams
+}
+
+/// Checkpointed join buffers; keys are re-evaluated on restore.
+#[derive(Serialize, Deserialize)]
+struct JoinSnapshot {
+    left_rows: Vec<TupleSnapshot>,
+    right_rows: Vec<TupleSnapshot>,
+    input_progress: Vec<Option<SystemTime>>,
+}
+
+struct JoinState {
+    physical: Arc<PhysicalStreamJoin>,
+    left_rows: SideBuffer,
+    right_rows: SideBuffer,
+    /// Latest watermark or row timestamp received on each input
+    input_progress: Vec<Option<SystemTime>>,
+}
+
+impl JoinState {
+    fn new(physical: Arc<PhysicalStreamJoin>, input_count: usize) -> Self {
+        Self {
+            physical,
+            left_rows: HashMap::new(),
+            right_rows: HashMap::new(),
+            input_progress: vec![None; input_count.max(1)],
+        }
+    }
+
+    fn snapshot(&self) -> JoinSnapshot {
+        let rows =
+            |buffer: &SideBuffer| buffer.values().flatten().map(TupleSnapshot::from).collect();
+        JoinSnapshot {
+            left_rows: rows(&self.left_rows),
+            right_rows: rows(&self.right_rows),
+            input_progress: self.input_progress.clone(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: JoinSnapshot) -> Result<(), ProcessorError> {
+        for (side, rows) in [
+            (JoinSide::Left, snapshot.left_rows),
+            (JoinSide::Right, snapshot.right_rows),
+        ] {
+            for tuple in rows.into_iter().map(TupleSnapshot::into_tuple) {
+                let Some(key) = self.key_of(side, &tuple)? else {
+                    continue;
+                };
+                let buffer = match side {
+                    JoinSide::Left => &mut self.left_rows,
+                    JoinSide::Right => &mut self.right_rows,
+                };
+                buffer.entry(key).or_default().push_back(tuple);
+            }
+        }
+        // The number of inputs is fixed by the plan; keep it if the snapshot disagrees.
+        if snapshot.input_progress.len() == self.input_progress.len() {
+            self.input_progress = snapshot.input_progress;
+        }
+        Ok(())
+    }
+
+    /// Minimum progress over the inputs, or `None` until every input has reported some.
+    fn frontier(&self) -> Option<SystemTime> {
+        self.input_progress
+            .iter()
+            .copied()
+            .try_fold(None, |min: Option<SystemTime>, progress| {
+                progress.map(|ts| Some(min.map_or(ts, |min| min.min(ts))))
+            })
+            .flatten()
+    }
+
+    fn side_of(&self, tuple: &Tuple) -> Result<JoinSide, ProcessorError> {
+        let has_source = |source: &str| tuple.message_by_source(source).is_some();
+        match (
+            has_source(&self.physical.left_source),
+            has_source(&self.physical.right_source),
+        ) {
+            (true, false) => Ok(JoinSide::Left),
+            (false, true) => Ok(JoinSide::Right),
+            _ => Err(ProcessorError::ProcessingError(format!(
+                "row does not belong to exactly one of join sources {} and {}",
+                self.physical.left_source, self.physical.right_source
+            ))),
+        }
+    }
+
+    /// Evaluate the join key of `tuple`; rows with a NULL key never match.
+    fn key_of(
+        &self,
+        side: JoinSide,
+        tuple: &Tuple,
+    ) -> Result<Option<Vec<KeyPart>>, ProcessorError> {
+        let exprs = match side {
+            JoinSide::Left => &self.physical.left_keys,
+            JoinSide::Right => &self.physical.right_keys,
+        };
+        eval_join_key(exprs, tuple)
+    }
+
+    /// Buffer `tuple`, received on `input`, and return the joined rows it produces against the
+    /// opposite side.
+    fn process_row(&mut self, input: usize, tuple: Tuple) -> Result<Vec<Tuple>, ProcessorError> {
+        let side = self.side_of(&tuple)?;
+        self.advance(input, tuple.timestamp);
+
+        if let Some(min_ts) = self.min_retained() {
+            if tuple.timestamp < min_ts {
+                // Late row: every possible partner has already been evicted.
+                return Ok(Vec::new());
+            }
+        }
+
+        let Some(key) = self.key_of(side, &tuple)? else {
+            return Ok(Vec::new());
+        };
+
+        let within = self.physical.within;
+        let (own, other) = match side {
+            JoinSide::Left => (&mut self.left_rows, &self.right_rows),
+            JoinSide::Right => (&mut self.right_rows, &self.left_rows),
+        };
+
+        let mut joined = Vec::new();
+        if let Some(candidates) = other.get(&key) {
+            for candidate in candidates {
+                if abs_diff(tuple.timestamp, candidate.timestamp) > within {
+                    continue;
+                }
+                let combined = match side {
+                    JoinSide::Left => combine(&tuple, candidate),
+                    JoinSide::Right => combine(candidate, &tuple),
+                };
+                if residuals_hold(&self.physical.residual_predicates, &combined)? {
+                    joined.push(combined);
+                }
+            }
+        }
+
+        own.entry(key).or_default().push_back(tuple);
+        Ok(joined)
+    }
+
+    /// Record progress of `input` and evict rows that can no longer match.
+    ///
+    /// Returns the new frontier when it moved forward.
+    fn advance(&mut self, input: usize, ts: SystemTime) -> Option<SystemTime> {
+        let before = self.frontier();
+        let slot = self.input_progress.get_mut(input)?;
+        if slot.is_some_and(|progress| progress >= ts) {
+            return None;
+        }
+        *slot = Some(ts);
+        let frontier = self
+            .frontier()
+            .filter(|frontier| before < Some(*frontier))?;
+        if let Some(min_ts) = self.min_retained() {
+            evict_before(&mut self.left_rows, min_ts);
+            evict_before(&mut self.right_rows, min_ts);
+        }
+        Some(frontier)
+    }
+
+    fn min_retained(&self) -> Option<SystemTime> {
+        self.frontier()
+            .and_then(|frontier| frontier.checked_sub(self.physical.within))
+    }
+}
+
+fn abs_diff(a: SystemTime, b: SystemTime) -> Duration {
+    a.duration_since(b)
+        .or_else(|_| b.duration_since(a))
+        .unwrap_or(Duration::ZERO)
+}
+
+fn combine(left: &Tuple, right: &Tuple) -> Tuple {
+    let mut messages = Vec::with_capacity(left.messages().len() + right.messages().len());
+    messages.extend(left.messages().iter().cloned());
+    messages.extend(right.messages().iter().cloned());
+    Tuple::with_timestamp(messages, left.timestamp.max(right.timestamp))
+}
+
+fn residuals_hold(predicates: &[ScalarExpr], tuple: &Tuple) -> Result<bool, ProcessorError> {
+    for predicate in predicates {
+        let value = predicate.eval_with_tuple(tuple).map_err(|e| {
+            ProcessorError::ProcessingError(format!("failed to evaluate join condition: {e}"))
+        })?;
+        if !matches!(value, Value::Bool(true)) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn evict_before(buffer: &mut SideBuffer, min_ts: SystemTime) {
+    buffer.retain(|_, rows| {
+        rows.retain(|row| row.timestamp >= min_ts);
+        !rows.is_empty()
+    });
+}
+
+/// Checkpoint barrier copies received per input.
+///
+/// Every input forwards its own copy of a barrier. A checkpoint is taken once all inputs have
+/// delivered it; an input that already ended counts as having delivered every barrier.
+struct BarrierTracker {
+    input_count: usize,
+    ended: HashSet<usize>,
+    pending: BTreeMap<u64, HashSet<usize>>,
+}
+
+impl BarrierTracker {
+    fn new(input_count: usize) -> Self {
+        Self {
+            input_count: input_count.max(1),
+            ended: HashSet::new(),
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// Record the copy of `checkpoint_id` received on `input`; true once every input sent it.
+    fn arrive(&mut self, checkpoint_id: u64, input: usize) -> bool {
+        let inputs = self
+            .pending
+            .entry(checkpoint_id)
+            .or_insert_with(|| self.ended.clone());
+        inputs.insert(input);
+        if inputs.len() < self.input_count {
+            return false;
+        }
+        self.pending.remove(&checkpoint_id);
+        true
+    }
+
+    /// Record that `input` ended; returns the checkpoints only it was holding back, oldest first.
+    fn end(&mut self, input: usize) -> Vec<u64> {
+        self.ended.insert(input);
+        let mut complete = Vec::new();
+        for (checkpoint_id, inputs) in &mut self.pending {
+            inputs.insert(input);
+            if inputs.len() >= self.input_count {
+                complete.push(*checkpoint_id);
+            }
+        }
+        for checkpoint_id in &complete {
+            self.pending.remove(checkpoint_id);
+        }
+        complete
+    }
+}
+
+impl Processor for StreamJoinProcessor {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn start(&mut self) -> tokio::task::JoinHandle<Result<(), ProcessorError>> {
+        let id = self.id.clone();
+        // Both sides must finish before a graceful end is forwarded.
+        let mut pending_ends = self.inputs.len().max(1);
+        let mut barriers = BarrierTracker::new(self.inputs.len());
+        let mut state = JoinState::new(Arc::clone(&self.physical_join), self.inputs.len());
+        let mut input_streams = tag_inputs(std::mem::take(&mut self.inputs));
+        let control_receivers = std::mem::take(&mut self.control_inputs);
+        let mut control_streams = fan_in_control_streams(control_receivers);
+        let mut control_active = !control_streams.is_empty();
+        let output = self.output.clone();
+        let control_output = self.control_output.clone();
+        let checkpoint = self.checkpoint.clone();
+        tracing::info!(processor_id = %id, "stream join processor starting");
+
+        tokio::spawn(async move {
+            if let Some(snapshot) =
+                take_restored_state::<JoinSnapshot>(checkpoint.as_ref(), &id, &output).await?
+            {
+                if let Err(err) = state.restore(snapshot) {
+                    forward_error(&output, &id, format!("failed to restore checkpoint: {err}"))
+                        .await?;
+                }
+            }
+            loop {
+                tokio::select! {
+                    biased;
+                    control_item = control_streams.next(), if control_active => {
+                        if let Some(Ok(control_signal)) = control_item {
+                            let is_terminal = control_signal.is_terminal();
+                            send_control_with_backpressure(&control_output, control_signal).await?;
+                            if is_terminal {
+                                tracing::info!(processor_id = %id, "received StreamEnd (control)");
+                                tracing::info!(processor_id = %id, "stopped");
+                                return Ok(());
+                            }
+                            continue;
+                        } else {
+                            control_active = false;
+                        }
+                    }
+                    item = input_streams.next() => {
+                        let (input, item) = match item {
+                            Some((input, item)) => (input, Some(item)),
+                            None => (0, None),
+                        };
+                        match item {
+                            Some(Ok(StreamData::Collection(collection))) => {
+                                log_received_data(&id, &StreamData::Collection(collection.clone()));
+                                let rows = match collection.into_rows() {
+                                    Ok(rows) => rows,
+                                    Err(e) => {
+                                        forward_error(&output, &id, format!("failed to extract rows: {e}")).await?;
+                                        continue;
+                                    }
+                                };
+                                let mut joined = Vec::new();
+                                for row in rows {
+                                    match state.process_row(input, row) {
+                                        Ok(rows) => joined.extend(rows),
+                                        Err(e) => forward_error(&output, &id, e.to_string()).await?,
+                                    }
+                                }
+                                if joined.is_empty() {
+                                    continue;
+                                }
+                                match RecordBatch::new(joined) {
+                                    Ok(batch) => {
+                                        send_with_backpressure(&output, StreamData::collection(Box::new(batch))).await?;
+                                    }
+                                    Err(e) => forward_error(&output, &id, e.to_string()).await?,
+                                }
+                            }
+                            Some(Ok(StreamData::Watermark(ts))) => {
+                                // Only the minimum over both sides is safe to forward.
+                                if let Some(frontier) = state.advance(input, ts) {
+                                    send_with_backpressure(&output, StreamData::watermark(frontier)).await?;
+                                }
+                            }
+                            Some(Ok(StreamData::Control(ControlSignal::StreamGracefulEnd))) => {
+                                for checkpoint_id in barriers.end(input) {
+                                    if let Some(checkpoint) = &checkpoint {
+                                        report_state(checkpoint, checkpoint_id, &id, &state.snapshot(), &output).await?;
+                                    }
+                                    send_with_backpressure(&output, StreamData::control(ControlSignal::Checkpoint { checkpoint_id })).await?;
+                                }
+                                pending_ends = pending_ends.saturating_sub(1);
+                                if pending_ends == 0 {
+                                    send_with_backpressure(&output, StreamData::stream_end()).await?;
+                                    tracing::info!(processor_id = %id, "received StreamEnd (data)");
+                                    tracing::info!(processor_id = %id, "stopped");
+                                    return Ok(());
+                                }
+                            }
+                            Some(Ok(StreamData::Control(ControlSignal::Checkpoint { checkpoint_id }))) => {
+                                // Snapshot and forward once, after the barrier came through every input.
+                                if !barriers.arrive(checkpoint_id, input) {
+                                    continue;
+                                }
+                                if let Some(checkpoint) = &checkpoint {
+                                    report_state(checkpoint, checkpoint_id, &id, &state.snapshot(), &output).await?;
+                                }
+                                send_with_backpressure(&output, StreamData::control(ControlSignal::Checkpoint { checkpoint_id })).await?;
+                            }
+                            Some(Ok(data)) => {
+                                log_received_data(&id, &data);
+                                let is_terminal = data.is_terminal();
+                                send_with_backpressure(&output, data).await?;
+                                if is_terminal {
+                                    tracing::info!(processor_id = %id, "received StreamEnd (data)");
+                                    tracing::info!(processor_id = %id, "stopped");
+                                    return Ok(());
+                                }
+                            }
+                            Some(Err(BroadcastStreamRecvError::Lagged(skipped))) => {
+                                let message = format!(
+                                    "StreamJoinProcessor input lagged by {} messages",
+                                    skipped
+                                );
+                                tracing::warn!(processor_id = %id, skipped = skipped, "input lagged");
+                                forward_error(&output, &id, message).await?;
+                            }
+                            None => {
+                                tracing::info!(processor_id = %id, "stopped");
+                                return Ok(());
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    fn subscribe_output(&self) -> Option<broadcast::Receiver<StreamData>> {
+        Some(self.output.subscribe())
+    }
+
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
+    fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
+        Some(self.control_output.subscribe())
+    }
+
+    fn add_input(&mut self, receiver: broadcast::Receiver<StreamData>) {
+        self.inputs.push(receiver);
+    }
+
+    fn add_control_input(&mut self, receiver: broadcast::Receiver<ControlSignal>) {
+        self.control_inputs.push(receiver);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Message;
+    use sqlparser::ast::{Expr, Ident};
+    use std::time::UNIX_EPOCH;
+
+    fn row(source: &str, id: i64, sec: u64) -> Tuple {
+        row_with_key(source, Value::Int64(id), sec)
+    }
+
+    fn row_with_key(source: &str, key: Value, sec: u64) -> Tuple {
+        let message = Message::new(source, vec![Arc::from("id")], vec![Arc::new(key)]);
+        Tuple::with_timestamp(
+            vec![Arc::new(message)],
+            UNIX_EPOCH + Duration::from_secs(sec),
+        )
+    }
+
+    fn join_processor(within: Duration) -> StreamJoinProcessor {
+        let key_expr = Expr::BinaryOp {
+            left: Box::new(Expr::CompoundIdentifier(vec![
+                Ident::new("l"),
+                Ident::new("id"),
+            ])),
+            op: sqlparser::ast::BinaryOperator::Eq,
+            right: Box::new(Expr::CompoundIdentifier(vec![
+                Ident::new("r"),
+                Ident::new("id"),
+            ])),
+        };
+        let physical = PhysicalStreamJoin::new(
+            "l".to_string(),
+            "r".to_string(),
+            key_expr.clone(),
+            vec![key_expr],
+            vec![ScalarExpr::column_with_index("l", "id", Some(0)).unwrap()],
+            vec![ScalarExpr::column_with_index("r", "id", Some(0)).unwrap()],
+            Vec::new(),
+            within,
+            Vec::new(),
+            0,
+        );
+        StreamJoinProcessor::new("join", Arc::new(physical))
+    }
+
+    async fn send_rows(input: &broadcast::Sender<StreamData>, rows: Vec<Tuple>) {
+        let batch = RecordBatch::new(rows).expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+    }
+
+    #[tokio::test]
+    async fn stream_join_matches_rows_within_interval() {
+        let mut processor = join_processor(Duration::from_secs(10));
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        send_rows(&input, vec![row("l", 1, 100), row("l", 2, 100)]).await;
+        send_rows(&input, vec![row("r", 1, 105), row("r", 3, 105)]).await;
+
+        match output_rx.recv().await.unwrap() {
+            StreamData::Collection(collection) => {
+                let rows = collection.rows();
+                assert_eq!(rows.len(), 1);
+                assert_eq!(rows[0].value_by_index("l", 0), Some(&Value::Int64(1)));
+                assert_eq!(rows[0].value_by_index("r", 0), Some(&Value::Int64(1)));
+                assert_eq!(rows[0].timestamp, UNIX_EPOCH + Duration::from_secs(105));
+            }
+            other => panic!("unexpected output: {}", other.description()),
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_join_skips_rows_outside_interval() {
+        let mut processor = join_processor(Duration::from_secs(10));
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        send_rows(&input, vec![row("l", 1, 100)]).await;
+        send_rows(&input, vec![row("r", 1, 111)]).await;
+        assert!(input.send(StreamData::stream_end()).is_ok());
+
+        // The row pair is 11s apart, so the only output is the end signal, forwarded once
+        // after both inputs finish.
+        loop {
+            match output_rx.recv().await.unwrap() {
+                StreamData::Control(ControlSignal::StreamGracefulEnd) => break,
+                StreamData::Collection(collection) => {
+                    assert!(collection.rows().is_empty(), "unexpected join output");
+                }
+                _ => {}
+            }
+        }
+        assert!(output_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn join_keys_match_across_numeric_types() {
+        let processor = join_processor(Duration::from_secs(10));
+        let mut state = JoinState::new(Arc::clone(&processor.physical_join), 1);
+
+        state
+            .process_row(0, row_with_key("l", Value::Int32(1), 100))
+            .unwrap();
+        state
+            .process_row(0, row_with_key("l", Value::Float64(2.5), 100))
+            .unwrap();
+        let joined = state
+            .process_row(0, row_with_key("r", Value::Int64(1), 101))
+            .unwrap();
+        assert_eq!(joined.len(), 1);
+        let joined = state
+            .process_row(0, row_with_key("r", Value::Float64(1.0), 101))
+            .unwrap();
+        assert_eq!(joined.len(), 1);
+        let joined = state
+            .process_row(0, row_with_key("r", Value::Float32(2.5), 101))
+            .unwrap();
+        assert_eq!(joined.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn stream_join_waits_for_lagging_side() {
+        let mut processor = join_processor(Duration::from_secs(10));
+        let (left, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let (right, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(left.subscribe());
+        processor.add_input(right.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        // The left side runs far ahead; its watermark must neither evict its buffered row nor
+        // make the right side's rows late.
+        send_rows(&left, vec![row("l", 1, 100)]).await;
+        assert!(left
+            .send(StreamData::watermark(UNIX_EPOCH + Duration::from_secs(500)))
+            .is_ok());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        send_rows(&right, vec![row("r", 1, 105)]).await;
+
+        match output_rx.recv().await.unwrap() {
+            StreamData::Collection(collection) => {
+                let rows = collection.rows();
+                assert_eq!(rows.len(), 1);
+                assert_eq!(rows[0].value_by_index("l", 0), Some(&Value::Int64(1)));
+            }
+            other => panic!("unexpected output: {}", other.description()),
+        }
+
+        // Only the minimum over both sides is forwarded downstream.
+        assert!(right
+            .send(StreamData::watermark(UNIX_EPOCH + Duration::from_secs(300)))
+            .is_ok());
+        match output_rx.recv().await.unwrap() {
+            StreamData::Watermark(ts) => {
+                assert_eq!(ts, UNIX_EPOCH + Duration::from_secs(300));
+            }
+            other => panic!("unexpected output: {}", other.description()),
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_join_resumes_buffers_from_checkpoint() {
+        let coordinator = CheckpointCoordinator::new();
+        let mut processor = join_processor(Duration::from_secs(10));
+        processor.set_checkpoint_coordinator(coordinator.clone());
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let _handle = processor.start();
+
+        send_rows(&input, vec![row("l", 1, 100), row("l", 2, 100)]).await;
+        let (checkpoint_id, receiver) = coordinator.begin();
+        assert!(input
+            .send(StreamData::control(ControlSignal::Checkpoint {
+                checkpoint_id
+            }))
+            .is_ok());
+        let checkpoint = receiver.await.expect("checkpoint completes");
+
+        let restored = CheckpointCoordinator::new();
+        restored.restore(checkpoint);
+        let mut processor = join_processor(Duration::from_secs(10));
+        processor.set_checkpoint_coordinator(restored);
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        send_rows(&input, vec![row("r", 2, 103)]).await;
+        match output_rx.recv().await.unwrap() {
+            StreamData::Collection(collection) => {
+                let rows = collection.rows();
+                assert_eq!(rows.len(), 1);
+                assert_eq!(rows[0].value_by_index("l", 0), Some(&Value::Int64(2)));
+            }
+            other => panic!("unexpected output: {}", other.description()),
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_join_takes_checkpoint_once_both_inputs_delivered_it() {
+        let coordinator = CheckpointCoordinator::new();
+        let mut processor = join_processor(Duration::from_secs(10));
+        processor.set_checkpoint_coordinator(coordinator.clone());
+        let (left, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let (right, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(left.subscribe());
+        processor.add_input(right.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        let (checkpoint_id, mut receiver) = coordinator.begin();
+        let barrier = || StreamData::control(ControlSignal::Checkpoint { checkpoint_id });
+        assert!(left.send(barrier()).is_ok());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(receiver.try_recv().is_err());
+        assert!(output_rx.try_recv().is_err());
+
+        assert!(right.send(barrier()).is_ok());
+        receiver.await.expect("checkpoint completes");
+        assert!(matches!(
+            output_rx.recv().await.unwrap(),
+            StreamData::Control(ControlSignal::Checkpoint { checkpoint_id: id }) if id == checkpoint_id
+        ));
+
+        // A side that ended no longer holds back later barriers.
+        assert!(left
+            .send(StreamData::control(ControlSignal::StreamGracefulEnd))
+            .is_ok());
+        let (next_id, receiver) = coordinator.begin();
+        assert!(right
+            .send(StreamData::control(ControlSignal::Checkpoint {
+                checkpoint_id: next_id
+            }))
+            .is_ok());
+        receiver.await.expect("checkpoint completes");
+        assert!(matches!(
+            output_rx.recv().await.unwrap(),
+            StreamData::Control(ControlSignal::Checkpoint { checkpoint_id: id }) if id == next_id
+        ));
+        assert!(output_rx.try_recv().is_err());
+    }
+}
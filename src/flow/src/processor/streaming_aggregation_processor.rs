@@ -6,8 +6,10 @@ use crate::model::{Collection, RecordBatch};
 use crate::planner::physical::{
     AggregateCall, PhysicalPlan, PhysicalStreamingAggregation, StreamingWindowSpec,
 };
-use crate::processor::{Processor, ProcessorError};
+use crate::processor::checkpoint::TupleSnapshot;
+use crate::processor::{CheckpointCoordinator, Processor, ProcessorError};
 use datatypes::Value;
+use serde::{Deserialize, Serialize};
 use sqlparser::ast::Expr;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
@@ -16,14 +18,20 @@ use tokio::sync::broadcast;
 
 #[path = "streaming_count_aggregation_processor.rs"]
 mod streaming_count_aggregation_processor;
+#[path = "streaming_hopping_aggregation_processor.rs"]
+mod streaming_hopping_aggregation_processor;
 #[path = "streaming_tumbling_aggregation_processor.rs"]
 mod streaming_tumbling_aggregation_processor;
 
 pub use streaming_count_aggregation_processor::StreamingCountAggregationProcessor;
+pub use streaming_hopping_aggregation_processor::StreamingHoppingAggregationProcessor;
 #[path = "streaming_sliding_aggregation_processor.rs"]
 mod streaming_sliding_aggregation_processor;
 pub use streaming_sliding_aggregation_processor::StreamingSlidingAggregationProcessor;
 pub use streaming_tumbling_aggregation_processor::StreamingTumblingAggregationProcessor;
+#[path = "streaming_session_aggregation_processor.rs"]
+mod streaming_session_aggregation_processor;
+pub use streaming_session_aggregation_processor::StreamingSessionAggregationProcessor;
 #[path = "streaming_state_aggregation_processor.rs"]
 mod streaming_state_aggregation_processor;
 pub use streaming_state_aggregation_processor::StreamingStateAggregationProcessor;
@@ -32,7 +40,9 @@ pub use streaming_state_aggregation_processor::StreamingStateAggregationProcesso
 pub enum StreamingAggregationProcessor {
     Count(StreamingCountAggregationProcessor),
     Tumbling(StreamingTumblingAggregationProcessor),
+    Hopping(StreamingHoppingAggregationProcessor),
     Sliding(StreamingSlidingAggregationProcessor),
+    Session(StreamingSessionAggregationProcessor),
     State(StreamingStateAggregationProcessor),
 }
 
@@ -63,9 +73,15 @@ impl StreamingAggregationProcessor {
                     aggregate_registry,
                 ))
             }
+            StreamingWindowSpec::Hopping { .. } => StreamingAggregationProcessor::Hopping(
+                StreamingHoppingAggregationProcessor::new(id, physical, aggregate_registry),
+            ),
             StreamingWindowSpec::Sliding { .. } => StreamingAggregationProcessor::Sliding(
                 StreamingSlidingAggregationProcessor::new(id, physical, aggregate_registry),
             ),
+            StreamingWindowSpec::Session { .. } => StreamingAggregationProcessor::Session(
+                StreamingSessionAggregationProcessor::new(id, physical, aggregate_registry),
+            ),
             StreamingWindowSpec::State { .. } => StreamingAggregationProcessor::State(
                 StreamingStateAggregationProcessor::new(id, physical, aggregate_registry),
             ),
@@ -86,6 +102,18 @@ impl StreamingAggregationProcessor {
             _ => None,
         }
     }
+
+    /// Include the window state of this processor in the pipeline's checkpoints.
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        match self {
+            StreamingAggregationProcessor::Count(p) => p.set_checkpoint_coordinator(coordinator),
+            StreamingAggregationProcessor::Tumbling(p) => p.set_checkpoint_coordinator(coordinator),
+            StreamingAggregationProcessor::Hopping(p) => p.set_checkpoint_coordinator(coordinator),
+            StreamingAggregationProcessor::Sliding(p) => p.set_checkpoint_coordinator(coordinator),
+            StreamingAggregationProcessor::Session(p) => p.set_checkpoint_coordinator(coordinator),
+            StreamingAggregationProcessor::State(p) => p.set_checkpoint_coordinator(coordinator),
+        }
+    }
 }
 
 impl Processor for StreamingAggregationProcessor {
@@ -93,7 +121,9 @@ impl Processor for StreamingAggregationProcessor {
         match self {
             StreamingAggregationProcessor::Count(p) => p.id(),
             StreamingAggregationProcessor::Tumbling(p) => p.id(),
+            StreamingAggregationProcessor::Hopping(p) => p.id(),
             StreamingAggregationProcessor::Sliding(p) => p.id(),
+            StreamingAggregationProcessor::Session(p) => p.id(),
             StreamingAggregationProcessor::State(p) => p.id(),
         }
     }
@@ -102,7 +132,9 @@ impl Processor for StreamingAggregationProcessor {
         match self {
             StreamingAggregationProcessor::Count(p) => p.start(),
             StreamingAggregationProcessor::Tumbling(p) => p.start(),
+            StreamingAggregationProcessor::Hopping(p) => p.start(),
             StreamingAggregationProcessor::Sliding(p) => p.start(),
+            StreamingAggregationProcessor::Session(p) => p.start(),
             StreamingAggregationProcessor::State(p) => p.start(),
         }
     }
@@ -111,18 +143,33 @@ impl Processor for StreamingAggregationProcessor {
         match self {
             StreamingAggregationProcessor::Count(p) => p.subscribe_output(),
             StreamingAggregationProcessor::Tumbling(p) => p.subscribe_output(),
+            StreamingAggregationProcessor::Hopping(p) => p.subscribe_output(),
             StreamingAggregationProcessor::Sliding(p) => p.subscribe_output(),
+            StreamingAggregationProcessor::Session(p) => p.subscribe_output(),
             StreamingAggregationProcessor::State(p) => p.subscribe_output(),
         }
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        match self {
+            StreamingAggregationProcessor::Count(p) => p.output_queue_len(),
+            StreamingAggregationProcessor::Tumbling(p) => p.output_queue_len(),
+            StreamingAggregationProcessor::Hopping(p) => p.output_queue_len(),
+            StreamingAggregationProcessor::Sliding(p) => p.output_queue_len(),
+            StreamingAggregationProcessor::Session(p) => p.output_queue_len(),
+            StreamingAggregationProcessor::State(p) => p.output_queue_len(),
+        }
+    }
+
     fn subscribe_control_output(
         &self,
     ) -> Option<broadcast::Receiver<crate::processor::ControlSignal>> {
         match self {
             StreamingAggregationProcessor::Count(p) => p.subscribe_control_output(),
             StreamingAggregationProcessor::Tumbling(p) => p.subscribe_control_output(),
+            StreamingAggregationProcessor::Hopping(p) => p.subscribe_control_output(),
             StreamingAggregationProcessor::Sliding(p) => p.subscribe_control_output(),
+            StreamingAggregationProcessor::Session(p) => p.subscribe_control_output(),
             StreamingAggregationProcessor::State(p) => p.subscribe_control_output(),
         }
     }
@@ -131,7 +178,9 @@ impl Processor for StreamingAggregationProcessor {
         match self {
             StreamingAggregationProcessor::Count(p) => p.add_input(receiver),
             StreamingAggregationProcessor::Tumbling(p) => p.add_input(receiver),
+            StreamingAggregationProcessor::Hopping(p) => p.add_input(receiver),
             StreamingAggregationProcessor::Sliding(p) => p.add_input(receiver),
+            StreamingAggregationProcessor::Session(p) => p.add_input(receiver),
             StreamingAggregationProcessor::State(p) => p.add_input(receiver),
         }
     }
@@ -143,7 +192,9 @@ impl Processor for StreamingAggregationProcessor {
         match self {
             StreamingAggregationProcessor::Count(p) => p.add_control_input(receiver),
             StreamingAggregationProcessor::Tumbling(p) => p.add_control_input(receiver),
+            StreamingAggregationProcessor::Hopping(p) => p.add_control_input(receiver),
             StreamingAggregationProcessor::Sliding(p) => p.add_control_input(receiver),
+            StreamingAggregationProcessor::Session(p) => p.add_control_input(receiver),
             StreamingAggregationProcessor::State(p) => p.add_control_input(receiver),
         }
     }
@@ -162,9 +213,21 @@ struct GroupState {
     accumulators: Vec<Box<dyn AggregateAccumulator>>,
     last_tuple: crate::model::Tuple,
     key_values: Vec<Value>,
+    /// Rows folded into the group and not retracted since.
+    rows: u64,
+}
+
+/// Checkpointed form of a [`GroupState`].
+#[derive(Serialize, Deserialize)]
+struct GroupStateSnapshot {
+    accumulators: Vec<Vec<Value>>,
+    last_tuple: TupleSnapshot,
+    key_values: Vec<Value>,
+    #[serde(default)]
+    rows: u64,
 }
 
-/// Shared aggregation logic reused by count and tumbling windows.
+/// Shared aggregation logic reused by the count, tumbling, hopping, state and session windows.
 struct AggregationWorker {
     physical: Arc<PhysicalStreamingAggregation>,
     aggregate_registry: Arc<AggregateFunctionRegistry>,
@@ -201,22 +264,19 @@ impl AggregationWorker {
                     accumulators,
                     last_tuple: tuple.clone(),
                     key_values: key_values.clone(),
+                    rows: 0,
                 })
             }
         };
 
         entry.last_tuple = tuple.clone();
         entry.key_values = key_values;
+        entry.rows += 1;
 
         for (idx, call) in self.physical.aggregate_calls.iter().enumerate() {
-            let mut args = Vec::new();
-            for arg_expr in &call.args {
-                args.push(
-                    arg_expr
-                        .eval_with_tuple(tuple)
-                        .map_err(|e| format!("Failed to evaluate aggregate argument: {}", e))?,
-                );
-            }
+            let Some(args) = call.eval_args(tuple)? else {
+                continue;
+            };
             entry
                 .accumulators
                 .get_mut(idx)
@@ -227,6 +287,100 @@ impl AggregationWorker {
         Ok(())
     }
 
+    fn snapshot(&self) -> Vec<GroupStateSnapshot> {
+        self.groups
+            .values()
+            .map(|state| GroupStateSnapshot {
+                accumulators: state.accumulators.iter().map(|acc| acc.state()).collect(),
+                last_tuple: TupleSnapshot::from(&state.last_tuple),
+                key_values: state.key_values.clone(),
+                rows: state.rows,
+            })
+            .collect()
+    }
+
+    /// Replace the groups with checkpointed ones; the current groups are kept on error.
+    fn restore(&mut self, snapshot: Vec<GroupStateSnapshot>) -> Result<(), String> {
+        let mut groups = HashMap::with_capacity(snapshot.len());
+        for group in snapshot {
+            let accumulators = restore_accumulators(
+                &self.physical.aggregate_calls,
+                self.aggregate_registry.as_ref(),
+                group.accumulators,
+            )?;
+            groups.insert(
+                format!("{:?}", group.key_values),
+                GroupState {
+                    accumulators,
+                    last_tuple: group.last_tuple.into_tuple(),
+                    key_values: group.key_values,
+                    rows: group.rows,
+                },
+            );
+        }
+        self.groups = groups;
+        Ok(())
+    }
+
+    /// Fold another worker's partial group states into this one, e.g. a pane into its window.
+    fn merge(&mut self, other: &AggregationWorker) -> Result<(), String> {
+        for (key, state) in &other.groups {
+            match self.groups.entry(key.clone()) {
+                Entry::Occupied(o) => {
+                    let entry = o.into_mut();
+                    for (accumulator, partial) in
+                        entry.accumulators.iter_mut().zip(&state.accumulators)
+                    {
+                        accumulator.merge(partial.state())?;
+                    }
+                    entry.last_tuple = state.last_tuple.clone();
+                    entry.key_values = state.key_values.clone();
+                    entry.rows += state.rows;
+                }
+                Entry::Vacant(v) => {
+                    let accumulators = restore_accumulators(
+                        &self.physical.aggregate_calls,
+                        self.aggregate_registry.as_ref(),
+                        state.accumulators.iter().map(|acc| acc.state()).collect(),
+                    )?;
+                    v.insert(GroupState {
+                        accumulators,
+                        last_tuple: state.last_tuple.clone(),
+                        key_values: state.key_values.clone(),
+                        rows: state.rows,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Undo an earlier [`AggregationWorker::update_groups`] for `tuple`, dropping its group once
+    /// every row of the group has been retracted.
+    fn retract_groups(&mut self, tuple: &crate::model::Tuple) -> Result<(), String> {
+        let key_repr = format!("{:?}", self.evaluate_group_by(tuple)?);
+        let Some(entry) = self.groups.get_mut(&key_repr) else {
+            return Err(format!(
+                "cannot retract a row from unknown group {key_repr}"
+            ));
+        };
+        for (call, accumulator) in self
+            .physical
+            .aggregate_calls
+            .iter()
+            .zip(entry.accumulators.iter_mut())
+        {
+            if let Some(args) = call.eval_args(tuple)? {
+                accumulator.retract(&args)?;
+            }
+        }
+        entry.rows = entry.rows.saturating_sub(1);
+        if entry.rows == 0 {
+            self.groups.remove(&key_repr);
+        }
+        Ok(())
+    }
+
     fn evaluate_group_by(&self, tuple: &crate::model::Tuple) -> Result<Vec<Value>, String> {
         let mut values = Vec::with_capacity(self.group_by_meta.len());
         for meta in &self.group_by_meta {
@@ -240,17 +394,23 @@ impl AggregationWorker {
     }
 
     fn finalize_current_window(&mut self) -> Result<Option<Box<dyn Collection>>, String> {
+        let output = self.current_output();
+        self.groups.clear();
+        output
+    }
+
+    /// Finalize every group without resetting it, for windows that keep accumulating.
+    fn current_output(&self) -> Result<Option<Box<dyn Collection>>, String> {
         if self.groups.is_empty() {
-            self.groups.clear();
             return Ok(None);
         }
 
         let mut output_tuples = Vec::with_capacity(self.groups.len());
-        for (_key, mut state) in self.groups.drain() {
+        for state in self.groups.values() {
             let tuple = finalize_group(
                 &self.physical.aggregate_calls,
                 &self.group_by_meta,
-                &mut state.accumulators,
+                &state.accumulators,
                 &state.last_tuple,
                 &state.key_values,
             )?;
@@ -266,7 +426,7 @@ impl AggregationWorker {
 fn finalize_group(
     aggregate_calls: &[AggregateCall],
     group_by_meta: &[GroupByMeta],
-    accumulators: &mut [Box<dyn AggregateAccumulator>],
+    accumulators: &[Box<dyn AggregateAccumulator>],
     last_tuple: &crate::model::Tuple,
     key_values: &[Value],
 ) -> Result<crate::model::Tuple, String> {
@@ -274,7 +434,7 @@ fn finalize_group(
     use std::sync::Arc;
 
     let mut affiliate_entries = Vec::new();
-    for (call, accumulator) in aggregate_calls.iter().zip(accumulators.iter_mut()) {
+    for (call, accumulator) in aggregate_calls.iter().zip(accumulators.iter()) {
         affiliate_entries.push((Arc::new(call.output_column.clone()), accumulator.finalize()));
     }
 
@@ -303,15 +463,28 @@ fn create_accumulators_static(
     aggregate_calls: &[AggregateCall],
     registry: &AggregateFunctionRegistry,
 ) -> Result<Vec<Box<dyn AggregateAccumulator>>, String> {
-    let mut accumulators = Vec::new();
-    for call in aggregate_calls {
-        if call.distinct {
-            return Err("DISTINCT aggregates are not supported yet".to_string());
-        }
-        let function = registry
-            .get(&call.func_name)
-            .ok_or_else(|| format!("Aggregate function '{}' not found", call.func_name))?;
-        accumulators.push(function.create_accumulator());
+    aggregate_calls
+        .iter()
+        .map(|call| call.create_accumulator(registry))
+        .collect()
+}
+
+/// Recreate accumulators from checkpointed state, one state vector per aggregate call.
+fn restore_accumulators(
+    aggregate_calls: &[AggregateCall],
+    registry: &AggregateFunctionRegistry,
+    states: Vec<Vec<Value>>,
+) -> Result<Vec<Box<dyn AggregateAccumulator>>, String> {
+    let mut accumulators = create_accumulators_static(aggregate_calls, registry)?;
+    if accumulators.len() != states.len() {
+        return Err(format!(
+            "checkpoint has {} accumulators per group, expected {}",
+            states.len(),
+            accumulators.len()
+        ));
+    }
+    for (accumulator, state) in accumulators.iter_mut().zip(states) {
+        accumulator.restore(state)?;
     }
     Ok(accumulators)
 }
@@ -1,13 +1,17 @@
-use super::{build_group_by_meta, AggregationWorker, GroupByMeta};
+use super::{build_group_by_meta, AggregationWorker, GroupByMeta, GroupStateSnapshot};
 use crate::aggregation::AggregateFunctionRegistry;
 use crate::model::Collection;
 use crate::planner::physical::PhysicalStreamingAggregation;
 use crate::processor::base::{
-    fan_in_control_streams, fan_in_streams, log_received_data, send_control_with_backpressure,
-    send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+    fan_in_control_streams, fan_in_streams, forward_error, log_received_data,
+    send_control_with_backpressure, send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+};
+use crate::processor::checkpoint::{report_state, take_restored_state};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
 };
-use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData};
 use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::broadcast;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
@@ -33,6 +37,13 @@ impl CountWindowState {
     }
 }
 
+/// Checkpointed state of a count window.
+#[derive(Serialize, Deserialize)]
+struct CountSnapshot {
+    seen: u64,
+    groups: Vec<GroupStateSnapshot>,
+}
+
 /// Data-driven count window implementation.
 pub struct StreamingCountAggregationProcessor {
     id: String,
@@ -44,6 +55,7 @@ pub struct StreamingCountAggregationProcessor {
     control_output: broadcast::Sender<ControlSignal>,
     group_by_meta: Vec<GroupByMeta>,
     target: u64,
+    checkpoint: Option<CheckpointCoordinator>,
 }
 
 impl StreamingCountAggregationProcessor {
@@ -67,9 +79,15 @@ impl StreamingCountAggregationProcessor {
             control_output,
             group_by_meta,
             target,
+            checkpoint: None,
         }
     }
 
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
     fn process_collection(
         worker: &mut AggregationWorker,
         window_state: &mut CountWindowState,
@@ -111,10 +129,22 @@ impl Processor for StreamingCountAggregationProcessor {
         let physical = Arc::clone(&self.physical);
         let group_by_meta = self.group_by_meta.clone();
         let target = self.target;
+        let checkpoint = self.checkpoint.clone();
 
         tokio::spawn(async move {
             let mut worker = AggregationWorker::new(physical, aggregate_registry, group_by_meta);
             let mut window_state = CountWindowState::new(target);
+            if let Some(snapshot) =
+                take_restored_state::<CountSnapshot>(checkpoint.as_ref(), &id, &output).await?
+            {
+                match worker.restore(snapshot.groups) {
+                    Ok(()) => window_state.seen = snapshot.seen,
+                    Err(err) => {
+                        forward_error(&output, &id, format!("failed to restore checkpoint: {err}"))
+                            .await?
+                    }
+                }
+            }
             let mut stream_ended = false;
 
             loop {
@@ -148,6 +178,13 @@ impl Processor for StreamingCountAggregationProcessor {
                                 }
                             }
                             Some(Ok(StreamData::Control(control_signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&control_signal, &checkpoint) {
+                                    let state = CountSnapshot {
+                                        seen: window_state.seen,
+                                        groups: worker.snapshot(),
+                                    };
+                                    report_state(checkpoint, *checkpoint_id, &id, &state, &output).await?;
+                                }
                                 let is_terminal = control_signal.is_terminal();
                                 send_with_backpressure(&output, StreamData::control(control_signal)).await?;
                                 if is_terminal {
@@ -183,6 +220,10 @@ impl Processor for StreamingCountAggregationProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
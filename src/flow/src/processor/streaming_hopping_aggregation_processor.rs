@@ -0,0 +1,444 @@
+use super::{build_group_by_meta, AggregationWorker, GroupByMeta, GroupStateSnapshot};
+use crate::aggregation::AggregateFunctionRegistry;
+use crate::planner::physical::{PhysicalStreamingAggregation, StreamingWindowSpec};
+use crate::processor::base::{
+    fan_in_control_streams, fan_in_streams, forward_error, log_received_data,
+    send_control_with_backpressure, send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+};
+use crate::processor::checkpoint::{report_state, take_restored_state};
+use crate::processor::hopping_window_processor::first_hopping_window_start;
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
+};
+use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+
+/// Time-driven hopping window implementation.
+///
+/// Rows are aggregated once into panes of `gcd(size, hop)` milliseconds. Every window is a run of
+/// consecutive panes, so closing a window merges the pane accumulators instead of replaying rows.
+pub struct StreamingHoppingAggregationProcessor {
+    id: String,
+    physical: Arc<PhysicalStreamingAggregation>,
+    aggregate_registry: Arc<AggregateFunctionRegistry>,
+    inputs: Vec<broadcast::Receiver<StreamData>>,
+    control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
+    output: broadcast::Sender<StreamData>,
+    control_output: broadcast::Sender<ControlSignal>,
+    group_by_meta: Vec<GroupByMeta>,
+    checkpoint: Option<CheckpointCoordinator>,
+}
+
+impl StreamingHoppingAggregationProcessor {
+    pub fn new(
+        id: impl Into<String>,
+        physical: Arc<PhysicalStreamingAggregation>,
+        aggregate_registry: Arc<AggregateFunctionRegistry>,
+    ) -> Self {
+        let group_by_meta =
+            build_group_by_meta(&physical.group_by_exprs, &physical.group_by_scalars);
+        let (output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let (control_output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        Self {
+            id: id.into(),
+            physical,
+            aggregate_registry,
+            inputs: Vec::new(),
+            control_inputs: Vec::new(),
+            output,
+            control_output,
+            group_by_meta,
+            checkpoint: None,
+        }
+    }
+
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Processor for StreamingHoppingAggregationProcessor {
+    fn id(&self) -> &str {
+        self.id()
+    }
+
+    fn start(&mut self) -> tokio::task::JoinHandle<Result<(), ProcessorError>> {
+        let id = self.id.clone();
+        let mut input_streams = fan_in_streams(std::mem::take(&mut self.inputs));
+        let control_receivers = std::mem::take(&mut self.control_inputs);
+        let control_active = !control_receivers.is_empty();
+        let mut control_streams = fan_in_control_streams(control_receivers);
+        let output = self.output.clone();
+        let control_output = self.control_output.clone();
+        let aggregate_registry = Arc::clone(&self.aggregate_registry);
+        let physical = Arc::clone(&self.physical);
+        let group_by_meta = self.group_by_meta.clone();
+        let (size_ms, hop_ms) = match physical.window {
+            StreamingWindowSpec::Hopping {
+                time_unit,
+                size,
+                hop,
+            } => {
+                let size = (time_unit.duration(size).as_millis() as u64).max(1);
+                let hop = time_unit.duration(hop).as_millis() as u64;
+                (size, hop.clamp(1, size))
+            }
+            _ => unreachable!("hopping processor requires hopping window spec"),
+        };
+        let checkpoint = self.checkpoint.clone();
+
+        tokio::spawn(async move {
+            let mut window_state = PaneState::new(
+                size_ms,
+                hop_ms,
+                Arc::clone(&physical),
+                Arc::clone(&aggregate_registry),
+                group_by_meta.clone(),
+            );
+            if let Some(snapshot) =
+                take_restored_state::<HoppingSnapshot>(checkpoint.as_ref(), &id, &output).await?
+            {
+                if let Err(err) = window_state.restore(snapshot) {
+                    forward_error(&output, &id, format!("failed to restore checkpoint: {err}"))
+                        .await?;
+                }
+            }
+            let mut stream_ended = false;
+
+            loop {
+                tokio::select! {
+                    biased;
+                    Some(ctrl) = control_streams.next(), if control_active => {
+                        if let Ok(control_signal) = ctrl {
+                            let is_terminal = control_signal.is_terminal();
+                            send_control_with_backpressure(&control_output, control_signal).await?;
+                            if is_terminal {
+                                stream_ended = true;
+                                break;
+                            }
+                        }
+                    }
+                    data_item = input_streams.next() => {
+                        match data_item {
+                            Some(Ok(StreamData::Collection(collection))) => {
+                                log_received_data(&id, &StreamData::Collection(collection.clone()));
+                                for row in collection.rows() {
+                                    window_state.add_row(row).map_err(|e| ProcessorError::ProcessingError(format!("Failed to update window state: {e}")))?;
+                                }
+                            }
+                            Some(Ok(StreamData::Watermark(ts))) => {
+                                let watermark_ms = to_millis(ts, "watermark")?;
+                                window_state.flush(Some(watermark_ms), &output).await?;
+                            }
+                            Some(Ok(StreamData::Control(control_signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&control_signal, &checkpoint) {
+                                    report_state(checkpoint, *checkpoint_id, &id, &window_state.snapshot(), &output).await?;
+                                }
+                                let is_terminal = control_signal.is_terminal();
+                                let is_graceful = matches!(control_signal, ControlSignal::StreamGracefulEnd);
+                                send_with_backpressure(&output, StreamData::control(control_signal)).await?;
+                                if is_terminal {
+                                    if is_graceful {
+                                        window_state.flush(None, &output).await?;
+                                    }
+                                    stream_ended = true;
+                                    break;
+                                }
+                            }
+                            Some(Ok(other)) => {
+                                log_received_data(&id, &other);
+                                send_with_backpressure(&output, other).await?;
+                            }
+                            Some(Err(BroadcastStreamRecvError::Lagged(n))) => {
+                                tracing::warn!(processor_id = %id, skipped = n, "input lagged");
+                            }
+                            None => {
+                                tracing::info!(processor_id = %id, "all input streams ended");
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+
+            if stream_ended {
+                send_control_with_backpressure(&control_output, ControlSignal::StreamGracefulEnd)
+                    .await?;
+            }
+            Ok(())
+        })
+    }
+
+    fn subscribe_output(&self) -> Option<broadcast::Receiver<StreamData>> {
+        Some(self.output.subscribe())
+    }
+
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
+    fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
+        Some(self.control_output.subscribe())
+    }
+
+    fn add_input(&mut self, receiver: broadcast::Receiver<StreamData>) {
+        self.inputs.push(receiver);
+    }
+
+    fn add_control_input(&mut self, receiver: broadcast::Receiver<ControlSignal>) {
+        self.control_inputs.push(receiver);
+    }
+}
+
+/// Checkpointed form of a single pane.
+#[derive(Serialize, Deserialize)]
+struct PaneSnapshot {
+    start_ms: u64,
+    groups: Vec<GroupStateSnapshot>,
+}
+
+/// Checkpointed form of a [`PaneState`].
+#[derive(Serialize, Deserialize)]
+struct HoppingSnapshot {
+    next_window_start: Option<u64>,
+    panes: Vec<PaneSnapshot>,
+}
+
+/// Partial aggregates per pane, keyed by pane start; assumes roughly increasing timestamps.
+struct PaneState {
+    panes: BTreeMap<u64, AggregationWorker>,
+    size_ms: u64,
+    hop_ms: u64,
+    pane_ms: u64,
+    /// Start of the next window to emit; panes before it are no longer needed.
+    next_window_start: Option<u64>,
+    physical: Arc<PhysicalStreamingAggregation>,
+    aggregate_registry: Arc<AggregateFunctionRegistry>,
+    group_by_meta: Vec<GroupByMeta>,
+}
+
+impl PaneState {
+    fn new(
+        size_ms: u64,
+        hop_ms: u64,
+        physical: Arc<PhysicalStreamingAggregation>,
+        aggregate_registry: Arc<AggregateFunctionRegistry>,
+        group_by_meta: Vec<GroupByMeta>,
+    ) -> Self {
+        Self {
+            panes: BTreeMap::new(),
+            size_ms,
+            hop_ms,
+            pane_ms: gcd(size_ms, hop_ms),
+            next_window_start: None,
+            physical,
+            aggregate_registry,
+            group_by_meta,
+        }
+    }
+
+    fn new_worker(&self) -> AggregationWorker {
+        AggregationWorker::new(
+            Arc::clone(&self.physical),
+            Arc::clone(&self.aggregate_registry),
+            self.group_by_meta.clone(),
+        )
+    }
+
+    fn add_row(&mut self, row: &crate::model::Tuple) -> Result<(), String> {
+        let ts_ms = row
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| format!("invalid timestamp: {e}"))?
+            .as_millis() as u64;
+        let pane_start = ts_ms / self.pane_ms * self.pane_ms;
+        if !self.panes.contains_key(&pane_start) {
+            let worker = self.new_worker();
+            self.panes.insert(pane_start, worker);
+        }
+        self.panes
+            .get_mut(&pane_start)
+            .expect("pane exists")
+            .update_groups(row)
+    }
+
+    fn snapshot(&self) -> HoppingSnapshot {
+        HoppingSnapshot {
+            next_window_start: self.next_window_start,
+            panes: self
+                .panes
+                .iter()
+                .map(|(start_ms, worker)| PaneSnapshot {
+                    start_ms: *start_ms,
+                    groups: worker.snapshot(),
+                })
+                .collect(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: HoppingSnapshot) -> Result<(), String> {
+        let mut panes = BTreeMap::new();
+        for pane in snapshot.panes {
+            let mut worker = self.new_worker();
+            worker.restore(pane.groups)?;
+            panes.insert(pane.start_ms, worker);
+        }
+        self.panes = panes;
+        self.next_window_start = snapshot.next_window_start;
+        Ok(())
+    }
+
+    /// Emit every window ending at or before `watermark_ms`, or all remaining windows when `None`.
+    async fn flush(
+        &mut self,
+        watermark_ms: Option<u64>,
+        output: &broadcast::Sender<StreamData>,
+    ) -> Result<(), ProcessorError> {
+        while let Some(first_pane) = self.panes.keys().next().copied() {
+            let first = first_hopping_window_start(first_pane, self.size_ms, self.hop_ms);
+            let start = self.next_window_start.map_or(first, |next| next.max(first));
+            let end = start.saturating_add(self.size_ms);
+            if watermark_ms.is_some_and(|watermark| end > watermark) {
+                break;
+            }
+
+            let mut window = self.new_worker();
+            for pane in self.panes.range(start..end).map(|(_, pane)| pane) {
+                window
+                    .merge(pane)
+                    .map_err(ProcessorError::ProcessingError)?;
+            }
+
+            let next_start = start.saturating_add(self.hop_ms);
+            self.next_window_start = Some(next_start);
+            self.panes = self.panes.split_off(&next_start);
+
+            if let Some(batch) = window
+                .finalize_current_window()
+                .map_err(ProcessorError::ProcessingError)?
+            {
+                send_with_backpressure(output, StreamData::Collection(batch)).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a.max(1)
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn to_millis(ts: SystemTime, label: &str) -> Result<u64, ProcessorError> {
+    ts.duration_since(UNIX_EPOCH)
+        .map_err(|e| ProcessorError::ProcessingError(format!("invalid {label}: {e}")))
+        .map(|d| d.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::scalar::ColumnRef;
+    use crate::expr::ScalarExpr;
+    use crate::planner::logical::TimeUnit;
+    use crate::planner::physical::AggregateCall;
+    use datatypes::Value;
+    use sqlparser::ast::{Expr, Ident};
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    fn tuple_at(sec: u64, a: i64) -> crate::model::Tuple {
+        let mut tuple =
+            crate::model::Tuple::with_timestamp(Vec::new(), UNIX_EPOCH + Duration::from_secs(sec));
+        tuple.add_affiliate_column(Arc::new("a".to_string()), Value::Int64(a));
+        tuple
+    }
+
+    fn make_physical(size: u64, hop: u64) -> Arc<PhysicalStreamingAggregation> {
+        let call = AggregateCall {
+            output_column: "col_1".to_string(),
+            func_name: "sum".to_string(),
+            args: vec![ScalarExpr::Column(ColumnRef::ByName {
+                column_name: "a".to_string(),
+            })],
+            distinct: false,
+            filter: None,
+        };
+        let mut mappings = HashMap::new();
+        mappings.insert("col_1".to_string(), Expr::Identifier(Ident::new("a")));
+
+        Arc::new(PhysicalStreamingAggregation::new(
+            StreamingWindowSpec::Hopping {
+                time_unit: TimeUnit::Seconds,
+                size,
+                hop,
+            },
+            mappings,
+            Vec::new(),
+            vec![call],
+            Vec::new(),
+            Vec::new(),
+            0,
+        ))
+    }
+
+    async fn next_sum(output_rx: &mut broadcast::Receiver<StreamData>) -> Value {
+        match output_rx.recv().await.unwrap() {
+            StreamData::Collection(collection) => {
+                assert_eq!(collection.rows().len(), 1);
+                collection.rows()[0]
+                    .value_by_name("", "col_1")
+                    .cloned()
+                    .expect("sum column")
+            }
+            other => panic!("unexpected output: {}", other.description()),
+        }
+    }
+
+    #[tokio::test]
+    async fn streaming_hopping_agg_merges_panes_per_window() {
+        // size=6, hop=4 gives 2s panes; windows [0,6), [4,10), [8,14).
+        let mut processor = StreamingHoppingAggregationProcessor::new(
+            "h",
+            make_physical(6, 4),
+            AggregateFunctionRegistry::with_builtins(),
+        );
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        let rows = vec![tuple_at(1, 1), tuple_at(5, 10), tuple_at(9, 100)];
+        let batch = crate::model::RecordBatch::new(rows).expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+        assert!(input
+            .send(StreamData::watermark(UNIX_EPOCH + Duration::from_secs(10)))
+            .is_ok());
+
+        assert_eq!(next_sum(&mut output_rx).await, Value::Int64(11));
+        assert_eq!(next_sum(&mut output_rx).await, Value::Int64(110));
+
+        // The last window is only flushed on a graceful end.
+        assert!(input
+            .send(StreamData::control(ControlSignal::StreamGracefulEnd))
+            .is_ok());
+        assert!(matches!(
+            output_rx.recv().await.unwrap(),
+            StreamData::Control(ControlSignal::StreamGracefulEnd)
+        ));
+        assert_eq!(next_sum(&mut output_rx).await, Value::Int64(100));
+    }
+}
@@ -0,0 +1,431 @@
+use super::{build_group_by_meta, AggregationWorker, GroupByMeta, GroupStateSnapshot};
+use crate::aggregation::AggregateFunctionRegistry;
+use crate::planner::physical::{PhysicalStreamingAggregation, StreamingWindowSpec};
+use crate::processor::base::{
+    fan_in_control_streams, fan_in_streams, forward_error, send_control_with_backpressure,
+    send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+};
+use crate::processor::checkpoint::{report_state, take_restored_state};
+use crate::processor::session_window_processor::{
+    session_durations, session_partition_key, SessionBounds,
+};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
+};
+use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+
+struct SessionAggState {
+    bounds: SessionBounds,
+    worker: AggregationWorker,
+}
+
+/// Checkpointed form of a [`SessionAggState`].
+#[derive(Serialize, Deserialize)]
+struct SessionSnapshot {
+    key: Option<String>,
+    bounds: SessionBounds,
+    groups: Vec<GroupStateSnapshot>,
+}
+
+pub struct StreamingSessionAggregationProcessor {
+    id: String,
+    physical: Arc<PhysicalStreamingAggregation>,
+    aggregate_registry: Arc<AggregateFunctionRegistry>,
+    inputs: Vec<broadcast::Receiver<StreamData>>,
+    control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
+    output: broadcast::Sender<StreamData>,
+    control_output: broadcast::Sender<ControlSignal>,
+    group_by_meta: Vec<GroupByMeta>,
+    checkpoint: Option<CheckpointCoordinator>,
+}
+
+impl StreamingSessionAggregationProcessor {
+    pub fn new(
+        id: impl Into<String>,
+        physical: Arc<PhysicalStreamingAggregation>,
+        aggregate_registry: Arc<AggregateFunctionRegistry>,
+    ) -> Self {
+        let group_by_meta =
+            build_group_by_meta(&physical.group_by_exprs, &physical.group_by_scalars);
+        let (output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        let (control_output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        Self {
+            id: id.into(),
+            physical,
+            aggregate_registry,
+            inputs: Vec::new(),
+            control_inputs: Vec::new(),
+            output,
+            control_output,
+            group_by_meta,
+            checkpoint: None,
+        }
+    }
+
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Processor for StreamingSessionAggregationProcessor {
+    fn id(&self) -> &str {
+        self.id()
+    }
+
+    fn start(&mut self) -> tokio::task::JoinHandle<Result<(), ProcessorError>> {
+        let id = self.id.clone();
+        let mut input_streams = fan_in_streams(std::mem::take(&mut self.inputs));
+        let control_receivers = std::mem::take(&mut self.control_inputs);
+        let control_active = !control_receivers.is_empty();
+        let mut control_streams = fan_in_control_streams(control_receivers);
+        let output = self.output.clone();
+        let control_output = self.control_output.clone();
+        let aggregate_registry = Arc::clone(&self.aggregate_registry);
+        let physical = Arc::clone(&self.physical);
+        let group_by_meta = self.group_by_meta.clone();
+
+        let (gap, max_duration, partition_by_scalars) = match physical.window.clone() {
+            StreamingWindowSpec::Session {
+                time_unit,
+                gap,
+                max_duration,
+                partition_by_scalars,
+                ..
+            } => {
+                let (gap, max_duration) = session_durations(time_unit, gap, max_duration);
+                (gap, max_duration, partition_by_scalars)
+            }
+            other => unreachable!("session processor requires session window spec, got {other:?}"),
+        };
+        let checkpoint = self.checkpoint.clone();
+
+        tokio::spawn(async move {
+            let new_worker = || {
+                AggregationWorker::new(
+                    Arc::clone(&physical),
+                    Arc::clone(&aggregate_registry),
+                    group_by_meta.clone(),
+                )
+            };
+            let mut sessions: HashMap<Option<String>, SessionAggState> = HashMap::new();
+            if let Some(snapshot) =
+                take_restored_state::<Vec<SessionSnapshot>>(checkpoint.as_ref(), &id, &output)
+                    .await?
+            {
+                for session in snapshot {
+                    let mut worker = new_worker();
+                    if let Err(err) = worker.restore(session.groups) {
+                        forward_error(&output, &id, format!("failed to restore checkpoint: {err}"))
+                            .await?;
+                        sessions.clear();
+                        break;
+                    }
+                    sessions.insert(
+                        session.key,
+                        SessionAggState {
+                            bounds: session.bounds,
+                            worker,
+                        },
+                    );
+                }
+            }
+            loop {
+                tokio::select! {
+                    biased;
+                    Some(ctrl) = control_streams.next(), if control_active => {
+                        if let Ok(control_signal) = ctrl {
+                            let is_terminal = control_signal.is_terminal();
+                            send_control_with_backpressure(&control_output, control_signal).await?;
+                            if is_terminal {
+                                break;
+                            }
+                        }
+                    }
+                    data_item = input_streams.next() => {
+                        match data_item {
+                            Some(Ok(StreamData::Collection(collection))) => {
+                                let tuples = match collection.into_rows() {
+                                    Ok(rows) => rows,
+                                    Err(e) => {
+                                        forward_error(&output, &id, format!("failed to extract rows: {e}")).await?;
+                                        continue;
+                                    }
+                                };
+
+                                for tuple in tuples {
+                                    let key = match session_partition_key(&partition_by_scalars, &tuple) {
+                                        Ok(key) => key,
+                                        Err(e) => {
+                                            forward_error(&output, &id, e).await?;
+                                            continue;
+                                        }
+                                    };
+                                    let ts = tuple.timestamp;
+                                    let extends = sessions
+                                        .get(&key)
+                                        .is_some_and(|session| !session.bounds.is_closed_at(ts, gap, max_duration));
+                                    if extends {
+                                        if let Some(session) = sessions.get_mut(&key) {
+                                            session.bounds.extend(ts);
+                                        }
+                                    } else {
+                                        let opened = SessionAggState {
+                                            bounds: SessionBounds::new(ts),
+                                            worker: new_worker(),
+                                        };
+                                        if let Some(closed) = sessions.insert(key.clone(), opened) {
+                                            emit_session(&output, &id, closed).await?;
+                                        }
+                                    }
+                                    if let Some(session) = sessions.get_mut(&key) {
+                                        if let Err(e) = session.worker.update_groups(&tuple) {
+                                            forward_error(&output, &id, e).await?;
+                                        }
+                                    }
+                                }
+                            }
+                            Some(Ok(StreamData::Watermark(ts))) => {
+                                flush_sessions(&mut sessions, Some(ts), gap, max_duration, &output, &id).await?;
+                            }
+                            Some(Ok(StreamData::Control(control_signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&control_signal, &checkpoint) {
+                                    let state: Vec<_> = sessions
+                                        .iter()
+                                        .map(|(key, session)| SessionSnapshot {
+                                            key: key.clone(),
+                                            bounds: session.bounds,
+                                            groups: session.worker.snapshot(),
+                                        })
+                                        .collect();
+                                    report_state(checkpoint, *checkpoint_id, &id, &state, &output).await?;
+                                }
+                                let is_terminal = control_signal.is_terminal();
+                                let is_graceful = matches!(control_signal, ControlSignal::StreamGracefulEnd);
+                                send_with_backpressure(&output, StreamData::control(control_signal)).await?;
+                                if is_terminal {
+                                    if is_graceful {
+                                        flush_sessions(&mut sessions, None, gap, max_duration, &output, &id).await?;
+                                    }
+                                    break;
+                                }
+                            }
+                            Some(Ok(other)) => {
+                                let is_terminal = other.is_terminal();
+                                send_with_backpressure(&output, other).await?;
+                                if is_terminal {
+                                    break;
+                                }
+                            }
+                            Some(Err(BroadcastStreamRecvError::Lagged(skipped))) => {
+                                forward_error(&output, &id, format!("StreamingSessionAggregationProcessor input lagged by {skipped} messages")).await?;
+                            }
+                            None => {
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+
+            send_control_with_backpressure(&control_output, ControlSignal::StreamGracefulEnd)
+                .await?;
+            Ok(())
+        })
+    }
+
+    fn subscribe_output(&self) -> Option<broadcast::Receiver<StreamData>> {
+        Some(self.output.subscribe())
+    }
+
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
+    fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
+        Some(self.control_output.subscribe())
+    }
+
+    fn add_input(&mut self, receiver: broadcast::Receiver<StreamData>) {
+        self.inputs.push(receiver);
+    }
+
+    fn add_control_input(&mut self, receiver: broadcast::Receiver<ControlSignal>) {
+        self.control_inputs.push(receiver);
+    }
+}
+
+/// Emit sessions closed by `watermark` oldest first, or every open session when `None`.
+async fn flush_sessions(
+    sessions: &mut HashMap<Option<String>, SessionAggState>,
+    watermark: Option<SystemTime>,
+    gap: Duration,
+    max_duration: Option<Duration>,
+    output: &broadcast::Sender<StreamData>,
+    id: &str,
+) -> Result<(), ProcessorError> {
+    let closed_keys: Vec<_> = sessions
+        .iter()
+        .filter(|(_, session)| {
+            watermark.is_none_or(|ts| session.bounds.is_closed_at(ts, gap, max_duration))
+        })
+        .map(|(key, _)| key.clone())
+        .collect();
+    let mut closed: Vec<_> = closed_keys
+        .iter()
+        .filter_map(|key| sessions.remove(key))
+        .collect();
+    closed.sort_by_key(|session| session.bounds.start);
+    for session in closed {
+        emit_session(output, id, session).await?;
+    }
+    Ok(())
+}
+
+async fn emit_session(
+    output: &broadcast::Sender<StreamData>,
+    id: &str,
+    mut session: SessionAggState,
+) -> Result<(), ProcessorError> {
+    match session.worker.finalize_current_window() {
+        Ok(Some(batch)) => send_with_backpressure(output, StreamData::Collection(batch)).await,
+        Ok(None) => Ok(()),
+        Err(e) => forward_error(output, id, e).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::scalar::ColumnRef;
+    use crate::expr::ScalarExpr;
+    use crate::planner::logical::TimeUnit;
+    use crate::planner::physical::AggregateCall;
+    use datatypes::Value;
+    use sqlparser::ast::{Expr, Ident};
+    use std::time::UNIX_EPOCH;
+
+    fn col(name: &str) -> ScalarExpr {
+        ScalarExpr::Column(ColumnRef::ByName {
+            column_name: name.to_string(),
+        })
+    }
+
+    fn tuple_at(sec: u64, key: i64, a: i64) -> crate::model::Tuple {
+        let mut tuple =
+            crate::model::Tuple::with_timestamp(Vec::new(), UNIX_EPOCH + Duration::from_secs(sec));
+        tuple.add_affiliate_column(Arc::new("k".to_string()), Value::Int64(key));
+        tuple.add_affiliate_column(Arc::new("a".to_string()), Value::Int64(a));
+        tuple
+    }
+
+    fn make_physical() -> Arc<PhysicalStreamingAggregation> {
+        let call = AggregateCall {
+            output_column: "col_1".to_string(),
+            func_name: "sum".to_string(),
+            args: vec![col("a")],
+            distinct: false,
+            filter: None,
+        };
+        let window = StreamingWindowSpec::Session {
+            time_unit: TimeUnit::Seconds,
+            gap: 5,
+            max_duration: None,
+            partition_by_exprs: vec![Expr::Identifier(Ident::new("k"))],
+            partition_by_scalars: vec![col("k")],
+        };
+        Arc::new(PhysicalStreamingAggregation::new(
+            window,
+            HashMap::new(),
+            Vec::new(),
+            vec![call],
+            Vec::new(),
+            Vec::new(),
+            0,
+        ))
+    }
+
+    async fn next_sum(output_rx: &mut broadcast::Receiver<StreamData>) -> Value {
+        loop {
+            match output_rx.recv().await.unwrap() {
+                StreamData::Collection(collection) => {
+                    assert_eq!(collection.rows().len(), 1);
+                    return collection.rows()[0]
+                        .value_by_name("", "col_1")
+                        .cloned()
+                        .unwrap_or(Value::Null);
+                }
+                StreamData::Control(_) => {}
+                other => panic!("unexpected output: {}", other.description()),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn streaming_session_agg_sums_per_partition_session() {
+        let aggregate_registry = AggregateFunctionRegistry::with_builtins();
+        let coordinator = CheckpointCoordinator::new();
+        let mut processor = StreamingSessionAggregationProcessor::new(
+            "s",
+            make_physical(),
+            Arc::clone(&aggregate_registry),
+        );
+        processor.set_checkpoint_coordinator(coordinator.clone());
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let _handle = processor.start();
+
+        let batch = crate::model::RecordBatch::new(vec![
+            tuple_at(1, 1, 1),
+            tuple_at(2, 2, 10),
+            tuple_at(4, 1, 2),
+        ])
+        .expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+        let (checkpoint_id, receiver) = coordinator.begin();
+        assert!(input
+            .send(StreamData::control(ControlSignal::Checkpoint {
+                checkpoint_id
+            }))
+            .is_ok());
+        let checkpoint = receiver.await.expect("checkpoint completes");
+
+        // Resume from the checkpoint and keep both sessions going.
+        let restored = CheckpointCoordinator::new();
+        restored.restore(checkpoint);
+        let mut processor = StreamingSessionAggregationProcessor::new(
+            "s",
+            make_physical(),
+            Arc::clone(&aggregate_registry),
+        );
+        processor.set_checkpoint_coordinator(restored);
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        let batch = crate::model::RecordBatch::new(vec![tuple_at(6, 2, 20), tuple_at(8, 1, 3)])
+            .expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+
+        // Key 1 last saw 8 and closes at 13; key 2 last saw 6 and closes at 11.
+        assert!(input
+            .send(StreamData::watermark(UNIX_EPOCH + Duration::from_secs(11)))
+            .is_ok());
+        assert_eq!(next_sum(&mut output_rx).await, Value::Int64(30));
+        assert!(input
+            .send(StreamData::watermark(UNIX_EPOCH + Duration::from_secs(13)))
+            .is_ok());
+        assert_eq!(next_sum(&mut output_rx).await, Value::Int64(6));
+    }
+}
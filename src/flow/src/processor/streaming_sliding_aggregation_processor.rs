@@ -1,14 +1,21 @@
-use super::{build_group_by_meta, create_accumulators_static, GroupByMeta};
+use super::{
+    build_group_by_meta, create_accumulators_static, restore_accumulators, AggregationWorker,
+    GroupByMeta, GroupStateSnapshot,
+};
 use crate::aggregation::AggregateFunctionRegistry;
-use crate::model::{AffiliateRow, RecordBatch};
+use crate::model::{AffiliateRow, Collection, RecordBatch, Tuple};
 use crate::planner::physical::{PhysicalStreamingAggregation, StreamingWindowSpec};
 use crate::processor::base::{
-    fan_in_control_streams, fan_in_streams, send_control_with_backpressure, send_with_backpressure,
-    DEFAULT_CHANNEL_CAPACITY,
+    fan_in_control_streams, fan_in_streams, forward_error, send_control_with_backpressure,
+    send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+};
+use crate::processor::checkpoint::{report_state, take_restored_state, TupleSnapshot};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
 };
-use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData};
 use datatypes::Value;
 use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
@@ -25,6 +32,10 @@ use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 ///   - immediately when `delay == 0` (per-tuple trigger),
 ///   - or when receiving deadline watermarks from upstream (`delay > 0`).
 ///
+/// Without a delay, when every aggregate call can retract rows (`sum`, `count`, `avg`, ...), a single
+/// set of groups is kept instead: rows that leave the lookback are retracted and the trigger row is
+/// added, so each tuple costs O(1) rather than one update per active window.
+///
 /// Notes:
 /// - This is processing-time only: tuple timestamps are assumed to be non-decreasing.
 /// - For `slidingwindow('ss', length)`, we treat `length` as the window length and `delay = 0`.
@@ -38,8 +49,9 @@ pub struct StreamingSlidingAggregationProcessor {
     output: broadcast::Sender<StreamData>,
     control_output: broadcast::Sender<ControlSignal>,
     group_by_meta: Vec<GroupByMeta>,
-    length_secs: u64,
-    delay_secs: u64,
+    length_ms: u64,
+    delay_ms: u64,
+    checkpoint: Option<CheckpointCoordinator>,
 }
 
 struct WindowGroupState {
@@ -49,10 +61,178 @@ struct WindowGroupState {
 }
 
 struct IncAggWindow {
-    start_secs: u64,
+    start_ms: u64,
     groups: HashMap<String, WindowGroupState>,
 }
 
+/// Checkpointed form of an [`IncAggWindow`].
+#[derive(Serialize, Deserialize)]
+struct WindowSnapshot {
+    start_ms: u64,
+    groups: Vec<GroupStateSnapshot>,
+}
+
+/// Running groups for the retracting (`delay == 0`) path.
+///
+/// Consecutive trigger ranges `[t - length, t]` differ only by the rows that fell out of the
+/// lookback and by the trigger row itself.
+struct RetractingWindow {
+    worker: AggregationWorker,
+    /// Rows inside the current range, oldest first, keyed by their timestamp in milliseconds.
+    rows: VecDeque<(u64, Tuple)>,
+}
+
+/// Checkpointed form of a [`RetractingWindow`].
+#[derive(Serialize, Deserialize)]
+struct RetractingSnapshot {
+    groups: Vec<GroupStateSnapshot>,
+    rows: Vec<TupleSnapshot>,
+}
+
+impl RetractingWindow {
+    /// Slide the range to end at `now_ms`, add `tuple` and finalize every group.
+    fn push(
+        &mut self,
+        now_ms: u64,
+        length_ms: u64,
+        tuple: Tuple,
+    ) -> Result<Option<Box<dyn Collection>>, String> {
+        while let Some((ts_ms, _)) = self.rows.front() {
+            if ts_ms.saturating_add(length_ms) >= now_ms {
+                break;
+            }
+            if let Some((_, expired)) = self.rows.pop_front() {
+                self.worker.retract_groups(&expired)?;
+            }
+        }
+        self.worker.update_groups(&tuple)?;
+        self.rows.push_back((now_ms, tuple));
+        self.worker.current_output()
+    }
+
+    fn snapshot(&self) -> RetractingSnapshot {
+        RetractingSnapshot {
+            groups: self.worker.snapshot(),
+            rows: self
+                .rows
+                .iter()
+                .map(|(_, tuple)| TupleSnapshot::from(tuple))
+                .collect(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: RetractingSnapshot) -> Result<(), String> {
+        let mut rows = VecDeque::with_capacity(snapshot.rows.len());
+        for row in snapshot.rows {
+            let tuple = row.into_tuple();
+            let ts_ms = to_epoch_millis(tuple.timestamp).map_err(|e| e.to_string())?;
+            rows.push_back((ts_ms, tuple));
+        }
+        self.worker.restore(snapshot.groups)?;
+        self.rows = rows;
+        Ok(())
+    }
+}
+
+/// Window state of either execution path.
+enum SlidingState {
+    Windows(VecDeque<IncAggWindow>),
+    Retracting(RetractingWindow),
+}
+
+/// Checkpointed form of a [`SlidingState`].
+#[derive(Serialize, Deserialize)]
+enum SlidingSnapshot {
+    Windows(Vec<WindowSnapshot>),
+    Retracting(RetractingSnapshot),
+}
+
+impl SlidingState {
+    fn snapshot(&self) -> SlidingSnapshot {
+        match self {
+            SlidingState::Windows(windows) => {
+                SlidingSnapshot::Windows(windows.iter().map(IncAggWindow::snapshot).collect())
+            }
+            SlidingState::Retracting(window) => SlidingSnapshot::Retracting(window.snapshot()),
+        }
+    }
+
+    fn restore(
+        &mut self,
+        snapshot: SlidingSnapshot,
+        physical: &PhysicalStreamingAggregation,
+        aggregate_registry: &AggregateFunctionRegistry,
+    ) -> Result<(), String> {
+        match (self, snapshot) {
+            (SlidingState::Windows(windows), SlidingSnapshot::Windows(snapshot)) => {
+                *windows = snapshot
+                    .into_iter()
+                    .map(|window| IncAggWindow::restore(window, physical, aggregate_registry))
+                    .collect::<Result<_, _>>()?;
+                Ok(())
+            }
+            (SlidingState::Retracting(window), SlidingSnapshot::Retracting(snapshot)) => {
+                window.restore(snapshot)
+            }
+            _ => {
+                Err("checkpoint was taken by a different sliding aggregation strategy".to_string())
+            }
+        }
+    }
+}
+
+fn to_epoch_millis(ts: SystemTime) -> Result<u64, ProcessorError> {
+    Ok(ts
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| ProcessorError::ProcessingError(format!("invalid timestamp: {e}")))?
+        .as_millis() as u64)
+}
+
+impl IncAggWindow {
+    fn snapshot(&self) -> WindowSnapshot {
+        WindowSnapshot {
+            start_ms: self.start_ms,
+            groups: self
+                .groups
+                .values()
+                .map(|state| GroupStateSnapshot {
+                    accumulators: state.accumulators.iter().map(|acc| acc.state()).collect(),
+                    last_tuple: TupleSnapshot::from(&state.last_tuple),
+                    key_values: state.key_values.clone(),
+                    rows: 0,
+                })
+                .collect(),
+        }
+    }
+
+    fn restore(
+        snapshot: WindowSnapshot,
+        physical: &PhysicalStreamingAggregation,
+        aggregate_registry: &AggregateFunctionRegistry,
+    ) -> Result<Self, String> {
+        let mut groups = HashMap::with_capacity(snapshot.groups.len());
+        for group in snapshot.groups {
+            let accumulators = restore_accumulators(
+                &physical.aggregate_calls,
+                aggregate_registry,
+                group.accumulators,
+            )?;
+            groups.insert(
+                format!("{:?}", group.key_values),
+                WindowGroupState {
+                    accumulators,
+                    last_tuple: group.last_tuple.into_tuple(),
+                    key_values: group.key_values,
+                },
+            );
+        }
+        Ok(Self {
+            start_ms: snapshot.start_ms,
+            groups,
+        })
+    }
+}
+
 impl StreamingSlidingAggregationProcessor {
     pub fn new(
         id: impl Into<String>,
@@ -64,12 +244,17 @@ impl StreamingSlidingAggregationProcessor {
         let (output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
         let (control_output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
 
-        let (length_secs, delay_secs) = match physical.window {
+        let (length_ms, delay_ms) = match physical.window {
             StreamingWindowSpec::Sliding {
-                time_unit: _,
+                time_unit,
                 lookback,
                 lookahead,
-            } => (lookback.max(1), lookahead.unwrap_or(0)),
+            } => (
+                (time_unit.duration(lookback).as_millis() as u64).max(1),
+                lookahead.map_or(0, |lookahead| {
+                    time_unit.duration(lookahead).as_millis() as u64
+                }),
+            ),
             _ => unreachable!("sliding processor requires sliding window spec"),
         };
 
@@ -82,23 +267,23 @@ impl StreamingSlidingAggregationProcessor {
             output,
             control_output,
             group_by_meta,
-            length_secs,
-            delay_secs,
+            length_ms,
+            delay_ms,
+            checkpoint: None,
         }
     }
 
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
     pub fn id(&self) -> &str {
         &self.id
     }
 
     fn validate_supported_aggregates(&self) -> Result<(), ProcessorError> {
         for call in &self.physical.aggregate_calls {
-            if call.distinct {
-                return Err(ProcessorError::InvalidConfiguration(
-                    "DISTINCT aggregates are not supported in streaming sliding aggregation"
-                        .to_string(),
-                ));
-            }
             if !self
                 .aggregate_registry
                 .supports_incremental(&call.func_name)
@@ -133,34 +318,50 @@ impl Processor for StreamingSlidingAggregationProcessor {
         let physical = Arc::clone(&self.physical);
         let aggregate_registry = Arc::clone(&self.aggregate_registry);
         let group_by_meta = self.group_by_meta.clone();
-        let length_secs = self.length_secs;
-        let delay_secs = self.delay_secs;
+        let length_ms = self.length_ms;
+        let delay_ms = self.delay_ms;
+        let checkpoint = self.checkpoint.clone();
+        let retracting = delay_ms == 0
+            && physical
+                .aggregate_calls
+                .iter()
+                .all(|call| call.supports_retract(aggregate_registry.as_ref()));
 
         tokio::spawn(async move {
-            let mut windows: VecDeque<IncAggWindow> = VecDeque::new();
-            let mut stream_ended = false;
-
-            fn to_epoch_secs(ts: SystemTime) -> Result<u64, ProcessorError> {
-                Ok(ts
-                    .duration_since(UNIX_EPOCH)
-                    .map_err(|e| {
-                        ProcessorError::ProcessingError(format!("invalid timestamp: {e}"))
-                    })?
-                    .as_secs())
+            let mut state = if retracting {
+                SlidingState::Retracting(RetractingWindow {
+                    worker: AggregationWorker::new(
+                        Arc::clone(&physical),
+                        Arc::clone(&aggregate_registry),
+                        group_by_meta.clone(),
+                    ),
+                    rows: VecDeque::new(),
+                })
+            } else {
+                SlidingState::Windows(VecDeque::new())
+            };
+            if let Some(snapshot) =
+                take_restored_state::<SlidingSnapshot>(checkpoint.as_ref(), &id, &output).await?
+            {
+                if let Err(err) = state.restore(snapshot, &physical, aggregate_registry.as_ref()) {
+                    forward_error(&output, &id, format!("failed to restore checkpoint: {err}"))
+                        .await?;
+                }
             }
+            let mut stream_ended = false;
 
             fn gc_windows(
                 windows: &mut VecDeque<IncAggWindow>,
-                now_secs: u64,
-                length_secs: u64,
-                delay_secs: u64,
+                now_ms: u64,
+                length_ms: u64,
+                delay_ms: u64,
             ) {
                 while let Some(front) = windows.front() {
                     if front
-                        .start_secs
-                        .saturating_add(length_secs)
-                        .saturating_add(delay_secs)
-                        < now_secs
+                        .start_ms
+                        .saturating_add(length_ms)
+                        .saturating_add(delay_ms)
+                        < now_ms
                     {
                         windows.pop_front();
                     } else {
@@ -206,14 +407,12 @@ impl Processor for StreamingSlidingAggregationProcessor {
                 entry.key_values = key_values;
 
                 for (idx, call) in physical.aggregate_calls.iter().enumerate() {
-                    let mut args = Vec::with_capacity(call.args.len());
-                    for arg_expr in &call.args {
-                        args.push(arg_expr.eval_with_tuple(tuple).map_err(|e| {
-                            ProcessorError::ProcessingError(format!(
-                                "failed to evaluate aggregate argument: {e}"
-                            ))
-                        })?);
-                    }
+                    let Some(args) = call
+                        .eval_args(tuple)
+                        .map_err(ProcessorError::ProcessingError)?
+                    else {
+                        continue;
+                    };
                     entry
                         .accumulators
                         .get_mut(idx)
@@ -305,21 +504,34 @@ impl Processor for StreamingSlidingAggregationProcessor {
                                 })?;
 
                                 for tuple in rows {
-                                    let now_secs = to_epoch_secs(tuple.timestamp)?;
-                                    gc_windows(&mut windows, now_secs, length_secs, delay_secs);
+                                    let now_ms = to_epoch_millis(tuple.timestamp)?;
+                                    let windows = match &mut state {
+                                        SlidingState::Retracting(window) => {
+                                            if let Some(batch) = window
+                                                .push(now_ms, length_ms, tuple)
+                                                .map_err(ProcessorError::ProcessingError)?
+                                            {
+                                                send_with_backpressure(&output, StreamData::collection(batch))
+                                                    .await?;
+                                            }
+                                            continue;
+                                        }
+                                        SlidingState::Windows(windows) => windows,
+                                    };
+                                    gc_windows(windows, now_ms, length_ms, delay_ms);
 
                                     windows.push_back(IncAggWindow {
-                                        start_secs: now_secs,
+                                        start_ms: now_ms,
                                         groups: HashMap::new(),
                                     });
 
                                     for window in windows.iter_mut() {
-                                        if window.start_secs <= now_secs
+                                        if window.start_ms <= now_ms
                                             && window
-                                                .start_secs
-                                                .saturating_add(length_secs)
-                                                .saturating_add(delay_secs)
-                                                > now_secs
+                                                .start_ms
+                                                .saturating_add(length_ms)
+                                                .saturating_add(delay_ms)
+                                                >= now_ms
                                         {
                                             update_window_with_tuple(
                                                 &physical,
@@ -331,33 +543,54 @@ impl Processor for StreamingSlidingAggregationProcessor {
                                         }
                                     }
 
-                                    if delay_secs == 0 {
-                                        emit_oldest_window(&output, &physical, &group_by_meta, &windows)
+                                    if delay_ms == 0 {
+                                        emit_oldest_window(&output, &physical, &group_by_meta, windows)
                                             .await?;
                                     }
                                 }
                             }
                             Some(Ok(StreamData::Watermark(ts))) => {
-                                if delay_secs == 0 {
+                                let SlidingState::Windows(windows) = &mut state else {
+                                    continue;
+                                };
+                                if delay_ms == 0 {
                                     continue;
                                 }
-                                let now_secs = to_epoch_secs(ts)?;
-                                gc_windows(&mut windows, now_secs, length_secs, delay_secs);
+                                let now_ms = to_epoch_millis(ts)?;
+                                gc_windows(windows, now_ms, length_ms, delay_ms);
                                 if let Some(front) = windows.front() {
-                                    if front.start_secs.saturating_add(delay_secs) <= now_secs {
-                                        emit_oldest_window(&output, &physical, &group_by_meta, &windows)
+                                    if front.start_ms.saturating_add(delay_ms) <= now_ms {
+                                        emit_oldest_window(&output, &physical, &group_by_meta, windows)
                                             .await?;
                                     }
                                 }
                             }
                             Some(Ok(StreamData::Control(control_signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&control_signal, &checkpoint) {
+                                    let snapshot = state.snapshot();
+                                    report_state(checkpoint, *checkpoint_id, &id, &snapshot, &output).await?;
+                                }
                                 let is_terminal = control_signal.is_terminal();
                                 let is_graceful = matches!(control_signal, ControlSignal::StreamGracefulEnd);
                                 send_with_backpressure(&output, StreamData::control(control_signal)).await?;
                                 if is_terminal {
                                     if is_graceful {
-                                        emit_oldest_window(&output, &physical, &group_by_meta, &windows)
-                                            .await?;
+                                        match &state {
+                                            SlidingState::Windows(windows) => {
+                                                emit_oldest_window(&output, &physical, &group_by_meta, windows)
+                                                    .await?;
+                                            }
+                                            SlidingState::Retracting(window) => {
+                                                if let Some(batch) = window
+                                                    .worker
+                                                    .current_output()
+                                                    .map_err(ProcessorError::ProcessingError)?
+                                                {
+                                                    send_with_backpressure(&output, StreamData::collection(batch))
+                                                        .await?;
+                                                }
+                                            }
+                                        }
                                     }
                                     stream_ended = true;
                                     break;
@@ -390,6 +623,10 @@ impl Processor for StreamingSlidingAggregationProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -402,3 +639,98 @@ impl Processor for StreamingSlidingAggregationProcessor {
         self.control_inputs.push(receiver);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::scalar::ColumnRef;
+    use crate::expr::ScalarExpr;
+    use crate::planner::logical::TimeUnit;
+    use crate::planner::physical::AggregateCall;
+    use sqlparser::ast::{Expr, Ident};
+    use std::time::Duration;
+
+    fn tuple_at(sec: u64, a: i64) -> Tuple {
+        let mut tuple = Tuple::with_timestamp(Vec::new(), UNIX_EPOCH + Duration::from_secs(sec));
+        tuple.add_affiliate_column(Arc::new("a".to_string()), Value::Int64(a));
+        tuple
+    }
+
+    fn make_physical(func_names: &[&str]) -> Arc<PhysicalStreamingAggregation> {
+        let mut mappings = HashMap::new();
+        let mut calls = Vec::new();
+        for (idx, func_name) in func_names.iter().enumerate() {
+            let output_column = format!("col_{}", idx + 1);
+            mappings.insert(output_column.clone(), Expr::Identifier(Ident::new("a")));
+            calls.push(AggregateCall {
+                output_column,
+                func_name: func_name.to_string(),
+                args: vec![ScalarExpr::Column(ColumnRef::ByName {
+                    column_name: "a".to_string(),
+                })],
+                distinct: false,
+                filter: None,
+            });
+        }
+
+        Arc::new(PhysicalStreamingAggregation::new(
+            StreamingWindowSpec::Sliding {
+                time_unit: TimeUnit::Seconds,
+                lookback: 2,
+                lookahead: None,
+            },
+            mappings,
+            Vec::new(),
+            calls,
+            Vec::new(),
+            Vec::new(),
+            0,
+        ))
+    }
+
+    async fn run_sums(func_names: &[&str]) -> Vec<Value> {
+        let mut processor = StreamingSlidingAggregationProcessor::new(
+            "s",
+            make_physical(func_names),
+            AggregateFunctionRegistry::with_builtins(),
+        );
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        let rows = vec![
+            tuple_at(0, 1),
+            tuple_at(1, 10),
+            tuple_at(2, 100),
+            tuple_at(5, 1000),
+        ];
+        let batch = RecordBatch::new(rows).expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+
+        let mut sums = Vec::new();
+        for _ in 0..4 {
+            match output_rx.recv().await.unwrap() {
+                StreamData::Collection(collection) => {
+                    assert_eq!(collection.rows().len(), 1);
+                    sums.push(
+                        collection.rows()[0]
+                            .value_by_name("", "col_1")
+                            .cloned()
+                            .expect("sum column"),
+                    );
+                }
+                other => panic!("unexpected output: {}", other.description()),
+            }
+        }
+        sums
+    }
+
+    #[tokio::test]
+    async fn streaming_sliding_agg_retracts_expired_rows() {
+        let expected: Vec<_> = [1, 11, 111, 1000].into_iter().map(Value::Int64).collect();
+        assert_eq!(run_sums(&["sum", "count"]).await, expected);
+        // `max` cannot retract, so this runs on the per-window path and must agree.
+        assert_eq!(run_sums(&["sum", "max"]).await, expected);
+    }
+}
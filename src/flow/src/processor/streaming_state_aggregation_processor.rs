@@ -1,13 +1,17 @@
-use super::{build_group_by_meta, AggregationWorker, GroupByMeta};
+use super::{build_group_by_meta, AggregationWorker, GroupByMeta, GroupStateSnapshot};
 use crate::aggregation::AggregateFunctionRegistry;
 use crate::planner::physical::{PhysicalStreamingAggregation, StreamingWindowSpec};
 use crate::processor::base::{
     fan_in_control_streams, fan_in_streams, forward_error, send_control_with_backpressure,
     send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
 };
-use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData};
+use crate::processor::checkpoint::{report_state, take_restored_state};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
+};
 use datatypes::Value;
 use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::broadcast;
@@ -18,6 +22,14 @@ struct PartitionAggState {
     worker: AggregationWorker,
 }
 
+/// Checkpointed form of a [`PartitionAggState`].
+#[derive(Serialize, Deserialize)]
+struct PartitionSnapshot {
+    key: Option<String>,
+    active: bool,
+    groups: Vec<GroupStateSnapshot>,
+}
+
 pub struct StreamingStateAggregationProcessor {
     id: String,
     physical: Arc<PhysicalStreamingAggregation>,
@@ -27,6 +39,7 @@ pub struct StreamingStateAggregationProcessor {
     output: broadcast::Sender<StreamData>,
     control_output: broadcast::Sender<ControlSignal>,
     group_by_meta: Vec<GroupByMeta>,
+    checkpoint: Option<CheckpointCoordinator>,
 }
 
 impl StreamingStateAggregationProcessor {
@@ -48,9 +61,15 @@ impl StreamingStateAggregationProcessor {
             output,
             control_output,
             group_by_meta,
+            checkpoint: None,
         }
     }
 
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
     pub fn id(&self) -> &str {
         &self.id
     }
@@ -91,9 +110,35 @@ impl Processor for StreamingStateAggregationProcessor {
                 ),
                 other => unreachable!("state processor requires state window spec, got {other:?}"),
             };
+        let checkpoint = self.checkpoint.clone();
 
         tokio::spawn(async move {
             let mut partitions: HashMap<Option<String>, PartitionAggState> = HashMap::new();
+            if let Some(snapshot) =
+                take_restored_state::<Vec<PartitionSnapshot>>(checkpoint.as_ref(), &id, &output)
+                    .await?
+            {
+                for partition in snapshot {
+                    let mut worker = AggregationWorker::new(
+                        Arc::clone(&physical),
+                        Arc::clone(&aggregate_registry),
+                        group_by_meta.clone(),
+                    );
+                    if let Err(err) = worker.restore(partition.groups) {
+                        forward_error(&output, &id, format!("failed to restore checkpoint: {err}"))
+                            .await?;
+                        partitions.clear();
+                        break;
+                    }
+                    partitions.insert(
+                        partition.key,
+                        PartitionAggState {
+                            active: partition.active,
+                            worker,
+                        },
+                    );
+                }
+            }
             loop {
                 tokio::select! {
                     biased;
@@ -231,6 +276,17 @@ impl Processor for StreamingStateAggregationProcessor {
                                 send_with_backpressure(&output, StreamData::watermark(ts)).await?;
                             }
                             Some(Ok(StreamData::Control(control_signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&control_signal, &checkpoint) {
+                                    let state: Vec<_> = partitions
+                                        .iter()
+                                        .map(|(key, state)| PartitionSnapshot {
+                                            key: key.clone(),
+                                            active: state.active,
+                                            groups: state.worker.snapshot(),
+                                        })
+                                        .collect();
+                                    report_state(checkpoint, *checkpoint_id, &id, &state, &output).await?;
+                                }
                                 let is_terminal = control_signal.is_terminal();
                                 let is_graceful = matches!(control_signal, ControlSignal::StreamGracefulEnd);
                                 send_with_backpressure(&output, StreamData::control(control_signal)).await?;
@@ -282,6 +338,10 @@ impl Processor for StreamingStateAggregationProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -335,6 +395,7 @@ mod tests {
             func_name: "sum".to_string(),
             args: vec![col("a")],
             distinct: false,
+            filter: None,
         };
 
         let mut mappings = HashMap::new();
@@ -411,6 +472,67 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn streaming_state_agg_resumes_from_checkpoint() {
+        let aggregate_registry = AggregateFunctionRegistry::with_builtins();
+        let physical = make_physical(col("open"), col("emit"), Vec::new());
+
+        let coordinator = CheckpointCoordinator::new();
+        let mut processor = StreamingStateAggregationProcessor::new(
+            "s",
+            Arc::clone(&physical),
+            Arc::clone(&aggregate_registry),
+        );
+        processor.set_checkpoint_coordinator(coordinator.clone());
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let _handle = processor.start();
+
+        let open_row = tuple_with(&[
+            ("open", Value::Bool(true)),
+            ("emit", Value::Bool(false)),
+            ("a", Value::Int64(5)),
+        ]);
+        let batch = crate::model::RecordBatch::new(vec![open_row]).expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+        let (checkpoint_id, receiver) = coordinator.begin();
+        assert!(input
+            .send(StreamData::control(ControlSignal::Checkpoint {
+                checkpoint_id
+            }))
+            .is_ok());
+        let checkpoint = receiver.await.expect("checkpoint completes");
+
+        let restored = CheckpointCoordinator::new();
+        restored.restore(checkpoint);
+        let mut processor = StreamingStateAggregationProcessor::new(
+            "s",
+            Arc::clone(&physical),
+            Arc::clone(&aggregate_registry),
+        );
+        processor.set_checkpoint_coordinator(restored);
+        let (input, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
+        processor.add_input(input.subscribe());
+        let mut output_rx = processor.subscribe_output().unwrap();
+        let _handle = processor.start();
+
+        let emit_row = tuple_with(&[
+            ("open", Value::Bool(false)),
+            ("emit", Value::Bool(true)),
+            ("a", Value::Int64(2)),
+        ]);
+        let batch = crate::model::RecordBatch::new(vec![emit_row]).expect("batch");
+        assert!(input.send(StreamData::collection(Box::new(batch))).is_ok());
+
+        match output_rx.recv().await.unwrap() {
+            StreamData::Collection(collection) => {
+                let out = collection.rows().first().unwrap();
+                assert_eq!(out.value_by_name("", "col_1"), Some(&Value::Int64(7)));
+            }
+            other => panic!("unexpected output: {}", other.description()),
+        }
+    }
+
     #[tokio::test]
     async fn streaming_state_agg_partitioned_by_key_isolated() {
         let aggregate_registry = AggregateFunctionRegistry::with_builtins();
@@ -1,12 +1,16 @@
-use super::{build_group_by_meta, AggregationWorker, GroupByMeta};
+use super::{build_group_by_meta, AggregationWorker, GroupByMeta, GroupStateSnapshot};
 use crate::aggregation::AggregateFunctionRegistry;
 use crate::planner::physical::{PhysicalStreamingAggregation, StreamingWindowSpec};
 use crate::processor::base::{
-    fan_in_control_streams, fan_in_streams, log_received_data, send_control_with_backpressure,
-    send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+    fan_in_control_streams, fan_in_streams, forward_error, log_received_data,
+    send_control_with_backpressure, send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
+};
+use crate::processor::checkpoint::{report_state, take_restored_state};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
 };
-use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData};
 use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -23,6 +27,7 @@ pub struct StreamingTumblingAggregationProcessor {
     output: broadcast::Sender<StreamData>,
     control_output: broadcast::Sender<ControlSignal>,
     group_by_meta: Vec<GroupByMeta>,
+    checkpoint: Option<CheckpointCoordinator>,
 }
 
 impl StreamingTumblingAggregationProcessor {
@@ -44,9 +49,15 @@ impl StreamingTumblingAggregationProcessor {
             output,
             control_output,
             group_by_meta,
+            checkpoint: None,
         }
     }
 
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
     pub fn id(&self) -> &str {
         &self.id
     }
@@ -68,21 +79,30 @@ impl Processor for StreamingTumblingAggregationProcessor {
         let aggregate_registry = Arc::clone(&self.aggregate_registry);
         let physical = Arc::clone(&self.physical);
         let group_by_meta = self.group_by_meta.clone();
-        let len_secs = match physical.window {
-            StreamingWindowSpec::Tumbling {
-                time_unit: _,
-                length,
-            } => length,
+        let len_ms = match physical.window {
+            StreamingWindowSpec::Tumbling { time_unit, length } => {
+                time_unit.duration(length).as_millis() as u64
+            }
             _ => unreachable!("tumbling processor requires tumbling window spec"),
         };
+        let checkpoint = self.checkpoint.clone();
 
         tokio::spawn(async move {
             let mut window_state = ProcessingWindowState::new(
-                len_secs,
+                len_ms,
                 Arc::clone(&physical),
                 Arc::clone(&aggregate_registry),
                 group_by_meta.clone(),
             );
+            if let Some(snapshot) =
+                take_restored_state::<Vec<WindowSnapshot>>(checkpoint.as_ref(), &id, &output)
+                    .await?
+            {
+                if let Err(err) = window_state.restore(snapshot) {
+                    forward_error(&output, &id, format!("failed to restore checkpoint: {err}"))
+                        .await?;
+                }
+            }
             let mut stream_ended = false;
 
             loop {
@@ -110,6 +130,9 @@ impl Processor for StreamingTumblingAggregationProcessor {
                                 window_state.flush_until(ts, &output).await?;
                             }
                             Some(Ok(StreamData::Control(control_signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&control_signal, &checkpoint) {
+                                    report_state(checkpoint, *checkpoint_id, &id, &window_state.snapshot(), &output).await?;
+                                }
                                 let is_terminal = control_signal.is_terminal();
                                 let is_graceful = matches!(control_signal, ControlSignal::StreamGracefulEnd);
                                 send_with_backpressure(&output, StreamData::control(control_signal)).await?;
@@ -149,6 +172,10 @@ impl Processor for StreamingTumblingAggregationProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -164,22 +191,22 @@ impl Processor for StreamingTumblingAggregationProcessor {
 
 /// Per-window aggregation state.
 struct WindowAggState {
-    start_secs: u64,
-    end_secs: u64,
+    start_ms: u64,
+    end_ms: u64,
     worker: AggregationWorker,
 }
 
 impl WindowAggState {
     fn new(
-        start_secs: u64,
-        len_secs: u64,
+        start_ms: u64,
+        len_ms: u64,
         physical: Arc<PhysicalStreamingAggregation>,
         aggregate_registry: Arc<AggregateFunctionRegistry>,
         group_by_meta: Vec<GroupByMeta>,
     ) -> Self {
         Self {
-            start_secs,
-            end_secs: start_secs.saturating_add(len_secs),
+            start_ms,
+            end_ms: start_ms.saturating_add(len_ms),
             worker: AggregationWorker::new(
                 Arc::clone(&physical),
                 Arc::clone(&aggregate_registry),
@@ -189,10 +216,17 @@ impl WindowAggState {
     }
 }
 
+/// Checkpointed form of a [`WindowAggState`]; the end is derived from the window length.
+#[derive(Serialize, Deserialize)]
+struct WindowSnapshot {
+    start_ms: u64,
+    groups: Vec<GroupStateSnapshot>,
+}
+
 /// Processing-time windows assuming monotonically increasing timestamps.
 struct ProcessingWindowState {
     windows: VecDeque<WindowAggState>,
-    len_secs: u64,
+    len_ms: u64,
     physical: Arc<PhysicalStreamingAggregation>,
     aggregate_registry: Arc<AggregateFunctionRegistry>,
     group_by_meta: Vec<GroupByMeta>,
@@ -200,14 +234,14 @@ struct ProcessingWindowState {
 
 impl ProcessingWindowState {
     fn new(
-        len_secs: u64,
+        len_ms: u64,
         physical: Arc<PhysicalStreamingAggregation>,
         aggregate_registry: Arc<AggregateFunctionRegistry>,
         group_by_meta: Vec<GroupByMeta>,
     ) -> Self {
         Self {
             windows: VecDeque::new(),
-            len_secs,
+            len_ms,
             physical,
             aggregate_registry,
             group_by_meta,
@@ -215,15 +249,15 @@ impl ProcessingWindowState {
     }
 
     fn add_row(&mut self, row: &crate::model::Tuple) -> Result<(), String> {
-        let start_secs = window_start_secs_str(row.timestamp, self.len_secs)?;
+        let start_ms = window_start_ms_str(row.timestamp, self.len_ms)?;
         if let Some(back) = self.windows.back_mut() {
-            if back.start_secs == start_secs {
+            if back.start_ms == start_ms {
                 return back.worker.update_groups(row);
             }
         }
         let new_state = WindowAggState::new(
-            start_secs,
-            self.len_secs,
+            start_ms,
+            self.len_ms,
             Arc::clone(&self.physical),
             Arc::clone(&self.aggregate_registry),
             self.group_by_meta.clone(),
@@ -236,14 +270,41 @@ impl ProcessingWindowState {
             .update_groups(row)
     }
 
+    fn snapshot(&self) -> Vec<WindowSnapshot> {
+        self.windows
+            .iter()
+            .map(|state| WindowSnapshot {
+                start_ms: state.start_ms,
+                groups: state.worker.snapshot(),
+            })
+            .collect()
+    }
+
+    fn restore(&mut self, snapshot: Vec<WindowSnapshot>) -> Result<(), String> {
+        let mut windows = VecDeque::with_capacity(snapshot.len());
+        for window in snapshot {
+            let mut state = WindowAggState::new(
+                window.start_ms,
+                self.len_ms,
+                Arc::clone(&self.physical),
+                Arc::clone(&self.aggregate_registry),
+                self.group_by_meta.clone(),
+            );
+            state.worker.restore(window.groups)?;
+            windows.push_back(state);
+        }
+        self.windows = windows;
+        Ok(())
+    }
+
     async fn flush_until(
         &mut self,
         watermark: SystemTime,
         output: &broadcast::Sender<StreamData>,
     ) -> Result<(), ProcessorError> {
-        let watermark_secs = to_secs(watermark, "watermark")?;
+        let watermark_ms = to_millis(watermark, "watermark")?;
         while let Some(front) = self.windows.front() {
-            if front.end_secs > watermark_secs {
+            if front.end_ms > watermark_ms {
                 break;
             }
             let mut state = self.windows.pop_front().expect("front exists");
@@ -275,17 +336,17 @@ impl ProcessingWindowState {
     }
 }
 
-fn window_start_secs_str(ts: SystemTime, len_secs: u64) -> Result<u64, String> {
-    let secs = ts
+fn window_start_ms_str(ts: SystemTime, len_ms: u64) -> Result<u64, String> {
+    let ts_ms = ts
         .duration_since(UNIX_EPOCH)
         .map_err(|e| format!("invalid timestamp: {e}"))?
-        .as_secs();
-    let len = len_secs.max(1);
-    Ok(secs / len * len)
+        .as_millis() as u64;
+    let len = len_ms.max(1);
+    Ok(ts_ms / len * len)
 }
 
-fn to_secs(ts: SystemTime, label: &str) -> Result<u64, ProcessorError> {
+fn to_millis(ts: SystemTime, label: &str) -> Result<u64, ProcessorError> {
     ts.duration_since(UNIX_EPOCH)
         .map_err(|e| ProcessorError::ProcessingError(format!("invalid {label}: {e}")))
-        .map(|d| d.as_secs())
+        .map(|d| d.as_millis() as u64)
 }
@@ -1,13 +1,16 @@
 //! TumblingWindowProcessor - buffers rows by tumbling windows and flushes on watermarks.
 //!
 
-use crate::planner::logical::TimeUnit;
 use crate::planner::physical::{PhysicalPlan, PhysicalTumblingWindow};
 use crate::processor::base::{
     fan_in_control_streams, fan_in_streams, forward_error, send_control_with_backpressure,
     send_with_backpressure, DEFAULT_CHANNEL_CAPACITY,
 };
-use crate::processor::{ControlSignal, Processor, ProcessorError, StreamData};
+use crate::processor::checkpoint::{report_state, take_restored_state, TupleSnapshot};
+use crate::processor::{
+    CheckpointCoordinator, ControlSignal, Processor, ProcessorError, StreamData,
+};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -22,15 +25,14 @@ pub struct TumblingWindowProcessor {
     control_inputs: Vec<broadcast::Receiver<ControlSignal>>,
     output: broadcast::Sender<StreamData>,
     control_output: broadcast::Sender<ControlSignal>,
+    checkpoint: Option<CheckpointCoordinator>,
 }
 
 impl TumblingWindowProcessor {
     pub fn new(id: impl Into<String>, physical: Arc<PhysicalTumblingWindow>) -> Self {
         let (output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
         let (control_output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
-        let length = match physical.time_unit {
-            TimeUnit::Seconds => Duration::from_secs(physical.length),
-        };
+        let length = physical.time_unit.duration(physical.length);
         Self {
             id: id.into(),
             window_length: length,
@@ -38,9 +40,15 @@ impl TumblingWindowProcessor {
             control_inputs: Vec::new(),
             output,
             control_output,
+            checkpoint: None,
         }
     }
 
+    pub fn set_checkpoint_coordinator(&mut self, coordinator: CheckpointCoordinator) {
+        coordinator.register(&self.id);
+        self.checkpoint = Some(coordinator);
+    }
+
     pub fn from_physical_plan(id: impl Into<String>, plan: Arc<PhysicalPlan>) -> Option<Self> {
         match plan.as_ref() {
             PhysicalPlan::TumblingWindow(window) => Some(Self::new(id, Arc::new(window.clone()))),
@@ -64,10 +72,16 @@ impl Processor for TumblingWindowProcessor {
         let control_output = self.control_output.clone();
 
         // Local state captured by the task.
-        let len_secs = self.window_length.as_secs().max(1);
-        let mut state = ProcessingState::new(len_secs, output.clone());
+        let len_ms = (self.window_length.as_millis() as u64).max(1);
+        let mut state = ProcessingState::new(len_ms, output.clone());
+        let checkpoint = self.checkpoint.clone();
 
         tokio::spawn(async move {
+            if let Some(snapshot) =
+                take_restored_state::<TumblingSnapshot>(checkpoint.as_ref(), &id, &output).await?
+            {
+                state.restore(snapshot);
+            }
             loop {
                 tokio::select! {
                     biased;
@@ -95,6 +109,9 @@ impl Processor for TumblingWindowProcessor {
                                 state.flush_up_to(ts).await?;
                             }
                             Some(Ok(StreamData::Control(signal))) => {
+                                if let (ControlSignal::Checkpoint { checkpoint_id }, Some(checkpoint)) = (&signal, &checkpoint) {
+                                    report_state(checkpoint, *checkpoint_id, &id, &state.snapshot(), &output).await?;
+                                }
                                 let is_terminal = signal.is_terminal();
                                 let is_graceful = matches!(signal, ControlSignal::StreamGracefulEnd);
                                 send_with_backpressure(&output, StreamData::control(signal)).await?;
@@ -138,6 +155,10 @@ impl Processor for TumblingWindowProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -151,22 +172,42 @@ impl Processor for TumblingWindowProcessor {
     }
 }
 
+/// Checkpointed buffer of a tumbling window.
+#[derive(Serialize, Deserialize)]
+struct TumblingSnapshot {
+    rows: Vec<TupleSnapshot>,
+}
+
 /// Processing-time window state: assumes timestamps are non-decreasing, buffers rows in order.
 struct ProcessingState {
     rows: VecDeque<crate::model::Tuple>,
-    len_secs: u64,
+    len_ms: u64,
     output: broadcast::Sender<StreamData>,
 }
 
 impl ProcessingState {
-    fn new(len_secs: u64, output: broadcast::Sender<StreamData>) -> Self {
+    fn new(len_ms: u64, output: broadcast::Sender<StreamData>) -> Self {
         Self {
             rows: VecDeque::new(),
-            len_secs,
+            len_ms,
             output,
         }
     }
 
+    fn snapshot(&self) -> TumblingSnapshot {
+        TumblingSnapshot {
+            rows: self.rows.iter().map(TupleSnapshot::from).collect(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: TumblingSnapshot) {
+        self.rows = snapshot
+            .rows
+            .into_iter()
+            .map(TupleSnapshot::into_tuple)
+            .collect();
+    }
+
     async fn add_collection(
         &mut self,
         collection: Box<dyn crate::model::Collection>,
@@ -183,16 +224,16 @@ impl ProcessingState {
     async fn flush_up_to(&mut self, watermark: SystemTime) -> Result<(), ProcessorError> {
         // Flush whole windows whose end <= watermark.
         while let Some(front) = self.rows.front() {
-            let window_start = window_start_secs(front.timestamp, self.len_secs)?;
+            let window_start = window_start_ms(front.timestamp, self.len_ms)?;
             let window_end = SystemTime::UNIX_EPOCH
-                + Duration::from_secs(window_start.saturating_add(self.len_secs));
+                + Duration::from_millis(window_start.saturating_add(self.len_ms));
             if window_end > watermark {
                 break;
             }
 
             let mut current_rows = Vec::new();
             while let Some(row) = self.rows.front() {
-                let row_start = window_start_secs(row.timestamp, self.len_secs)?;
+                let row_start = window_start_ms(row.timestamp, self.len_ms)?;
                 if row_start != window_start {
                     break;
                 }
@@ -211,10 +252,10 @@ impl ProcessingState {
 
     async fn flush_all(&mut self) -> Result<(), ProcessorError> {
         while let Some(front) = self.rows.front() {
-            let window_start = window_start_secs(front.timestamp, self.len_secs)?;
+            let window_start = window_start_ms(front.timestamp, self.len_ms)?;
             let mut current_rows = Vec::new();
             while let Some(row) = self.rows.front() {
-                let row_start = window_start_secs(row.timestamp, self.len_secs)?;
+                let row_start = window_start_ms(row.timestamp, self.len_ms)?;
                 if row_start != window_start {
                     break;
                 }
@@ -231,11 +272,11 @@ impl ProcessingState {
     }
 }
 
-fn window_start_secs(ts: SystemTime, len_secs: u64) -> Result<u64, ProcessorError> {
+fn window_start_ms(ts: SystemTime, len_ms: u64) -> Result<u64, ProcessorError> {
     let epoch = ts
         .duration_since(UNIX_EPOCH)
         .map_err(|e| ProcessorError::ProcessingError(format!("invalid timestamp: {e}")))?;
-    let secs = epoch.as_secs();
-    let len = len_secs.max(1);
-    Ok(secs / len * len)
+    let ms = epoch.as_millis() as u64;
+    let len = len_ms.max(1);
+    Ok(ms / len * len)
 }
@@ -35,7 +35,10 @@ impl WatermarkProcessor {
     pub fn from_physical_plan(id: impl Into<String>, plan: Arc<PhysicalPlan>) -> Option<Self> {
         match plan.as_ref() {
             PhysicalPlan::ProcessTimeWatermark(watermark) => match &watermark.config {
-                WatermarkConfig::Tumbling { .. } => Some(WatermarkProcessor::ProcessTime(
+                // Hopping and session windows only need a periodic tick as well.
+                WatermarkConfig::Tumbling { .. }
+                | WatermarkConfig::Hopping { .. }
+                | WatermarkConfig::Session { .. } => Some(WatermarkProcessor::ProcessTime(
                     ProcessTimeWatermarkProcessor::Tumbling(TumblingWatermarkProcessor::new(
                         id,
                         Arc::new(watermark.clone()),
@@ -78,6 +81,13 @@ impl Processor for WatermarkProcessor {
         }
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        match self {
+            WatermarkProcessor::ProcessTime(p) => p.output_queue_len(),
+            WatermarkProcessor::Eventtime(p) => p.output_queue_len(),
+        }
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         match self {
             WatermarkProcessor::ProcessTime(p) => p.subscribe_control_output(),
@@ -122,6 +132,13 @@ impl Processor for ProcessTimeWatermarkProcessor {
         }
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        match self {
+            ProcessTimeWatermarkProcessor::Tumbling(p) => p.output_queue_len(),
+            ProcessTimeWatermarkProcessor::Sliding(p) => p.output_queue_len(),
+        }
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         match self {
             ProcessTimeWatermarkProcessor::Tumbling(p) => p.subscribe_control_output(),
@@ -270,6 +287,10 @@ impl Processor for TumblingWatermarkProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -316,13 +337,13 @@ impl SlidingWatermarkProcessor {
         let (control_output, _) = broadcast::channel(DEFAULT_CHANNEL_CAPACITY);
         let (lookahead, strategy) = match &physical.config {
             WatermarkConfig::Sliding {
+                time_unit,
                 lookahead,
                 strategy,
                 ..
-            } => (*lookahead, strategy),
+            } => (lookahead.map(|value| time_unit.duration(value)), strategy),
             _ => panic!("SlidingWatermarkProcessor requires WatermarkConfig::Sliding"),
         };
-        let lookahead = lookahead.map(Duration::from_secs);
         let ticker = strategy.interval_duration().map(|duration| {
             let mut ticker = interval(duration);
             ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
@@ -504,6 +525,10 @@ impl Processor for SlidingWatermarkProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -737,6 +762,10 @@ impl Processor for EventtimeWatermarkProcessor {
         Some(self.output.subscribe())
     }
 
+    fn output_queue_len(&self) -> Option<usize> {
+        Some(self.output.len())
+    }
+
     fn subscribe_control_output(&self) -> Option<broadcast::Receiver<ControlSignal>> {
         Some(self.control_output.subscribe())
     }
@@ -923,10 +952,8 @@ mod tests {
                 .expect("recv");
             match msg {
                 StreamData::Collection(_) => saw_collection = true,
-                StreamData::Watermark(ts) => {
-                    if ts >= expected_deadline {
-                        saw_deadline_or_later = true;
-                    }
+                StreamData::Watermark(ts) if ts >= expected_deadline => {
+                    saw_deadline_or_later = true;
                 }
                 _ => {}
             }
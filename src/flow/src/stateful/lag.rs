@@ -31,6 +31,20 @@ impl StatefulFunctionInstance for LagInstance {
         self.prev = Some(args[0].clone());
         Ok(out)
     }
+    fn state(&self) -> Vec<Value> {
+        self.prev.iter().cloned().collect()
+    }
+
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        if state.len() > 1 {
+            return Err(format!(
+                "lag() expects at most 1 state value, got {}",
+                state.len()
+            ));
+        }
+        self.prev = state.into_iter().next();
+        Ok(())
+    }
 }
 
 impl StatefulFunction for LagFunction {
@@ -68,6 +82,17 @@ mod tests {
         assert_eq!(instance.eval(&[Value::Int64(3)]).unwrap(), Value::Int64(2));
     }
 
+    #[test]
+    fn lag_restores_previous_value() {
+        let function = LagFunction::new();
+        let mut instance = function.create_instance();
+        instance.eval(&[Value::Int64(7)]).unwrap();
+
+        let mut restored = function.create_instance();
+        restored.restore(instance.state()).unwrap();
+        assert_eq!(restored.eval(&[Value::Int64(8)]).unwrap(), Value::Int64(7));
+    }
+
     #[test]
     fn lag_type_matches_input() {
         let function = LagFunction::new();
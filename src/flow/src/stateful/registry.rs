@@ -6,6 +6,18 @@ use super::lag::LagFunction;
 
 pub trait StatefulFunctionInstance: Send + Sync {
     fn eval(&mut self, args: &[Value]) -> Result<Value, String>;
+    /// State carried between rows, captured by checkpoints.
+    fn state(&self) -> Vec<Value> {
+        Vec::new()
+    }
+    /// Restore the state produced by [`StatefulFunctionInstance::state`].
+    fn restore(&mut self, state: Vec<Value>) -> Result<(), String> {
+        if state.is_empty() {
+            Ok(())
+        } else {
+            Err("stateful function does not support restoring state".to_string())
+        }
+    }
 }
 
 pub trait StatefulFunction: Send + Sync {
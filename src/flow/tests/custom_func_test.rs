@@ -1,4 +1,7 @@
-use datatypes::{ColumnSchema, ConcreteDatatype, Schema, Value};
+use datatypes::{
+    ColumnSchema, ConcreteDatatype, ListValue, Schema, StringType, Timestamp, TimestampPrecision,
+    Value,
+};
 use flow::catalog::{MockStreamProps, StreamDecoderConfig, StreamDefinition, StreamProps};
 use flow::expr::custom_func::CustomFunc;
 use flow::expr::func::EvalError;
@@ -130,3 +133,236 @@ async fn custom_func_registry_allows_external_registration() {
 
     pipeline.close().await.expect("close");
 }
+
+#[tokio::test]
+async fn time_functions_evaluate_in_pipeline() {
+    let instance = FlowInstance::new();
+    install_stream_schema(&instance).await;
+
+    let mut pipeline = instance
+        .build_pipeline_with_log_sink(
+            "SELECT date_trunc('minute', CAST(a AS TIMESTAMP)) AS minute, \
+             EXTRACT(HOUR FROM CAST(a AS TIMESTAMP)) AS hour, \
+             CAST(a AS TIMESTAMP) + 1000 AS later \
+             FROM stream WHERE CAST(a AS TIMESTAMP) > TIMESTAMP '2024-05-16 00:00:00'",
+            true,
+        )
+        .expect("create pipeline");
+    pipeline.start();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let batch = batch_from_columns_simple(vec![
+        (
+            "stream".to_string(),
+            "a".to_string(),
+            vec![
+                Value::String("2024-05-16T13:47:21.345Z".to_string()),
+                Value::String("2024-05-15T23:59:59Z".to_string()),
+            ],
+        ),
+        (
+            "stream".to_string(),
+            "b".to_string(),
+            vec![
+                Value::String("ignored".to_string()),
+                Value::String("ignored".to_string()),
+            ],
+        ),
+    ])
+    .expect("create batch");
+    pipeline
+        .send_stream_data("stream", StreamData::collection(Box::new(batch)))
+        .await
+        .expect("send data");
+
+    let mut output = pipeline.take_output().expect("output receiver");
+    let received = timeout(Duration::from_secs(5), output.recv())
+        .await
+        .expect("timeout")
+        .expect("missing output");
+    let ts = |text: &str| {
+        Value::Timestamp(
+            Timestamp::parse_rfc3339(text, TimestampPrecision::Millisecond).expect("timestamp"),
+        )
+    };
+    match received {
+        StreamData::Collection(collection) => {
+            let rows = collection.rows();
+            assert_eq!(rows.len(), 1);
+            let row = &rows[0];
+            assert_eq!(
+                row.value_by_name("", "minute"),
+                Some(&ts("2024-05-16T13:47:00Z"))
+            );
+            assert_eq!(row.value_by_name("", "hour"), Some(&Value::Int64(13)));
+            assert_eq!(
+                row.value_by_name("", "later"),
+                Some(&ts("2024-05-16T13:47:22.345Z"))
+            );
+        }
+        other => panic!("expected collection, got {}", other.description()),
+    }
+
+    pipeline.close().await.expect("close");
+}
+
+#[tokio::test]
+async fn string_functions_evaluate_in_pipeline() {
+    let instance = FlowInstance::new();
+    install_stream_schema(&instance).await;
+
+    let mut pipeline = instance
+        .build_pipeline_with_log_sink(
+            "SELECT upper(TRIM(a)) AS name, SUBSTRING(b FROM 2 FOR 3) AS part, \
+             lpad(CAST(length(b) AS STRING), 3, '0') AS len, \
+             format('%s/%s', TRIM(LEADING 'x' FROM a), replace(b, '-', '_')) AS label, \
+             split(b, '-') AS parts \
+             FROM stream WHERE b LIKE 's%-_' AND a NOT ILIKE '%SKIP%'",
+            true,
+        )
+        .expect("create pipeline");
+    pipeline.start();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let batch = batch_from_columns_simple(vec![
+        (
+            "stream".to_string(),
+            "a".to_string(),
+            vec![
+                Value::String("xkitchen ".to_string()),
+                Value::String("skip me".to_string()),
+                Value::String("hall".to_string()),
+            ],
+        ),
+        (
+            "stream".to_string(),
+            "b".to_string(),
+            vec![
+                Value::String("sensor-1".to_string()),
+                Value::String("sensor-2".to_string()),
+                Value::String("sensor-12".to_string()),
+            ],
+        ),
+    ])
+    .expect("create batch");
+    pipeline
+        .send_stream_data("stream", StreamData::collection(Box::new(batch)))
+        .await
+        .expect("send data");
+
+    let mut output = pipeline.take_output().expect("output receiver");
+    let received = timeout(Duration::from_secs(5), output.recv())
+        .await
+        .expect("timeout")
+        .expect("missing output");
+    let s = |text: &str| Value::String(text.to_string());
+    match received {
+        StreamData::Collection(collection) => {
+            let rows = collection.rows();
+            assert_eq!(rows.len(), 1);
+            let row = &rows[0];
+            assert_eq!(row.value_by_name("", "name"), Some(&s("XKITCHEN")));
+            assert_eq!(row.value_by_name("", "part"), Some(&s("ens")));
+            assert_eq!(row.value_by_name("", "len"), Some(&s("008")));
+            assert_eq!(
+                row.value_by_name("", "label"),
+                Some(&s("kitchen /sensor_1"))
+            );
+            assert_eq!(
+                row.value_by_name("", "parts"),
+                Some(&Value::List(ListValue::new(
+                    vec![s("sensor"), s("1")],
+                    Arc::new(ConcreteDatatype::String(StringType)),
+                )))
+            );
+        }
+        other => panic!("expected collection, got {}", other.description()),
+    }
+
+    pipeline.close().await.expect("close");
+}
+
+#[tokio::test]
+async fn regex_functions_evaluate_in_pipeline() {
+    let instance = FlowInstance::new();
+    install_stream_schema(&instance).await;
+
+    let err = instance
+        .build_pipeline_with_log_sink("SELECT regexp_match(a, '(unclosed') FROM stream", true)
+        .err()
+        .expect("invalid literal pattern is rejected when planning");
+    assert!(err.to_string().contains("regexp_match"), "{}", err);
+
+    let mut pipeline = instance
+        .build_pipeline_with_log_sink(
+            "SELECT regexp_extract(a, 'dev-([0-9]+)', 1) AS device, \
+             regexp_replace(a, 'rack-([0-9]+)', 'r$1') AS short, \
+             a ~* '^DEV' AS is_device, \
+             regexp_match(a, b) AS dynamic \
+             FROM stream WHERE a SIMILAR TO '(dev|gw)-%/rack-[0-9]+' AND a !~ 'decommissioned'",
+            true,
+        )
+        .expect("create pipeline");
+    pipeline.start();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let batch = batch_from_columns_simple(vec![
+        (
+            "stream".to_string(),
+            "a".to_string(),
+            vec![
+                Value::String("dev-0042/rack-7".to_string()),
+                Value::String("gw-3/rack-1".to_string()),
+                Value::String("dev-9/rack-x".to_string()),
+            ],
+        ),
+        (
+            "stream".to_string(),
+            "b".to_string(),
+            vec![
+                Value::String("rack-7$".to_string()),
+                Value::String("rack-7$".to_string()),
+                Value::String(".*".to_string()),
+            ],
+        ),
+    ])
+    .expect("create batch");
+    pipeline
+        .send_stream_data("stream", StreamData::collection(Box::new(batch)))
+        .await
+        .expect("send data");
+
+    let mut output = pipeline.take_output().expect("output receiver");
+    let received = timeout(Duration::from_secs(5), output.recv())
+        .await
+        .expect("timeout")
+        .expect("missing output");
+    let s = |text: &str| Value::String(text.to_string());
+    match received {
+        StreamData::Collection(collection) => {
+            let rows = collection.rows();
+            assert_eq!(rows.len(), 2);
+            let column = |name: &str| {
+                rows.iter()
+                    .map(|row| row.value_by_name("", name).cloned())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(column("device"), vec![Some(s("0042")), Some(Value::Null)]);
+            assert_eq!(
+                column("short"),
+                vec![Some(s("dev-0042/r7")), Some(s("gw-3/r1"))]
+            );
+            assert_eq!(
+                column("is_device"),
+                vec![Some(Value::Bool(true)), Some(Value::Bool(false))]
+            );
+            assert_eq!(
+                column("dynamic"),
+                vec![Some(Value::Bool(true)), Some(Value::Bool(false))]
+            );
+        }
+        other => panic!("expected collection, got {}", other.description()),
+    }
+
+    pipeline.close().await.expect("close");
+}
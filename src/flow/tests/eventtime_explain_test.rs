@@ -48,6 +48,7 @@ fn explain_pipeline_with_eventtime_enabled_prints_plans() {
             enabled: true,
             late_tolerance: Duration::from_secs(5),
         },
+        ..Default::default()
     };
 
     let sink_connector = PipelineSinkConnector::new(
@@ -0,0 +1,107 @@
+use flate2::read::GzDecoder;
+use flow::connector::{
+    FileSinkConfig, FileSinkConnector, FileSyncPolicy, SinkConnector, SinkConnectorError,
+};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("flow-{name}-{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&dir).expect("create scratch dir");
+    dir
+}
+
+fn rotated_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = fs::read_dir(dir)
+        .expect("list scratch dir")
+        .map(|entry| entry.expect("dir entry").path())
+        .filter(|path| path.file_name().and_then(|n| n.to_str()) != Some("out.json"))
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+#[tokio::test]
+async fn file_sink_writes_newline_delimited_payloads() {
+    let dir = scratch_dir("file-sink");
+    let path = dir.join("nested").join("out.json");
+    let config = FileSinkConfig::new("sink", &path).with_sync(FileSyncPolicy::Always);
+    let mut connector = FileSinkConnector::new("file_sink", config);
+
+    connector.ready().await.expect("ready");
+    connector.send(br#"[{"a":1}]"#).await.expect("send");
+    connector.send(br#"[{"a":2}]"#).await.expect("send");
+    connector.close().await.expect("close");
+
+    let written = fs::read_to_string(&path).expect("read sink file");
+    assert_eq!(written, "[{\"a\":1}]\n[{\"a\":2}]\n");
+    fs::remove_dir_all(dir).ok();
+}
+
+#[tokio::test]
+async fn file_sink_rotates_by_size_and_compresses() {
+    let dir = scratch_dir("file-sink-rotate");
+    let path = dir.join("out.json");
+    let config = FileSinkConfig::new("sink", &path)
+        .with_max_file_bytes(6)
+        .with_compress(true);
+    let mut connector = FileSinkConnector::new("file_sink", config);
+
+    connector.ready().await.expect("ready");
+    for payload in ["one", "two", "three"] {
+        connector.send(payload.as_bytes()).await.expect("send");
+    }
+    connector.close().await.expect("close");
+
+    assert_eq!(fs::read_to_string(&path).expect("active file"), "three\n");
+    let rotated = rotated_files(&dir);
+    assert_eq!(rotated.len(), 2, "rotated files: {rotated:?}");
+    let mut contents = Vec::new();
+    for file in rotated {
+        assert_eq!(file.extension().and_then(|e| e.to_str()), Some("gz"));
+        let mut text = String::new();
+        GzDecoder::new(fs::File::open(&file).expect("open rotated file"))
+            .read_to_string(&mut text)
+            .expect("decompress rotated file");
+        contents.push(text);
+    }
+    contents.sort();
+    assert_eq!(contents, vec!["one\n".to_string(), "two\n".to_string()]);
+    fs::remove_dir_all(dir).ok();
+}
+
+#[tokio::test]
+async fn file_sink_reopens_after_failed_open() {
+    let dir = scratch_dir("file-sink-reopen");
+    // A plain file where the sink's directory should be makes every open fail.
+    let blocker = dir.join("nested");
+    fs::write(&blocker, "").expect("write blocker");
+    let path = blocker.join("out.json");
+    let mut connector = FileSinkConnector::new("file_sink", FileSinkConfig::new("sink", &path));
+
+    assert!(connector.ready().await.is_err());
+    assert!(matches!(
+        connector.send(b"lost").await,
+        Err(SinkConnectorError::Unavailable(_))
+    ));
+
+    fs::remove_file(&blocker).expect("remove blocker");
+    connector.send(b"kept").await.expect("send after reopen");
+    connector.close().await.expect("close");
+    assert_eq!(fs::read_to_string(&path).expect("read sink file"), "kept\n");
+    fs::remove_dir_all(dir).ok();
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn file_sink_reports_write_failures_as_unavailable() {
+    // Every write to /dev/full fails with ENOSPC.
+    let config = FileSinkConfig::new("sink", "/dev/full").with_sync(FileSyncPolicy::Always);
+    let mut connector = FileSinkConnector::new("file_sink", config);
+    connector.ready().await.expect("ready");
+    assert!(matches!(
+        connector.send(b"payload").await,
+        Err(SinkConnectorError::Unavailable(_))
+    ));
+}
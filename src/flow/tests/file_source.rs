@@ -0,0 +1,137 @@
+use flow::connector::{
+    ConnectorEvent, FileFraming, FileReadMode, FileSourceConfig, FileSourceConnector,
+    SourceConnector,
+};
+use futures::StreamExt;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use tokio::time::{timeout, Duration};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("flow-{name}-{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&dir).expect("create scratch dir");
+    dir
+}
+
+async fn next_event(stream: &mut flow::connector::ConnectorStream) -> ConnectorEvent {
+    timeout(Duration::from_secs(2), stream.next())
+        .await
+        .expect("file source timeout")
+        .expect("file source stream ended")
+        .expect("file source error")
+}
+
+async fn next_payload(stream: &mut flow::connector::ConnectorStream) -> Vec<u8> {
+    match next_event(stream).await {
+        ConnectorEvent::Payload(payload) => payload,
+        ConnectorEvent::EndOfStream => panic!("unexpected end of stream"),
+    }
+}
+
+#[tokio::test]
+async fn file_source_reads_matching_files_once_in_name_order() {
+    let dir = scratch_dir("file-source");
+    fs::write(dir.join("b.json"), "{\"a\":3}\n").expect("write b");
+    fs::write(dir.join("a.json"), "{\"a\":1}\n\n{\"a\":2}").expect("write a");
+    fs::write(dir.join("ignored.txt"), "nope\n").expect("write ignored");
+
+    let pattern = dir.join("*.json").display().to_string();
+    let mut connector =
+        FileSourceConnector::new("file_source", FileSourceConfig::new("source", pattern));
+    let mut stream = connector.subscribe().expect("subscribe");
+
+    for expected in [r#"{"a":1}"#, r#"{"a":2}"#, r#"{"a":3}"#] {
+        assert_eq!(next_payload(&mut stream).await, expected.as_bytes());
+    }
+    assert!(matches!(
+        next_event(&mut stream).await,
+        ConnectorEvent::EndOfStream
+    ));
+    connector.close().expect("close");
+    fs::remove_dir_all(dir).ok();
+}
+
+#[tokio::test]
+async fn file_source_streams_lines_across_read_chunks() {
+    let dir = scratch_dir("file-source-large");
+    let path = dir.join("large.json");
+    // Several 64KiB read chunks, with lines straddling every chunk boundary.
+    let lines: Vec<String> = (0..20_000)
+        .map(|i| format!("{{\"a\":{i},\"pad\":\"xxxxxxxxxxxxxxxxxxxx\"}}"))
+        .collect();
+    fs::write(&path, lines.join("\n")).expect("write large file");
+
+    let mut connector = FileSourceConnector::new(
+        "file_source",
+        FileSourceConfig::new("source", path.display().to_string()),
+    );
+    let mut stream = connector.subscribe().expect("subscribe");
+
+    for expected in &lines {
+        assert_eq!(next_payload(&mut stream).await, expected.as_bytes());
+    }
+    assert!(matches!(
+        next_event(&mut stream).await,
+        ConnectorEvent::EndOfStream
+    ));
+    connector.close().expect("close");
+    fs::remove_dir_all(dir).ok();
+}
+
+#[tokio::test]
+async fn file_source_splits_length_prefixed_payloads() {
+    let dir = scratch_dir("file-source-framed");
+    let path = dir.join("data.bin");
+    let mut bytes = Vec::new();
+    for payload in [&b"first"[..], &b"line\nbreak"[..]] {
+        bytes.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(payload);
+    }
+    fs::write(&path, bytes).expect("write framed file");
+
+    let config = FileSourceConfig::new("source", path.display().to_string())
+        .with_framing(FileFraming::LengthPrefixed);
+    let mut connector = FileSourceConnector::new("file_source", config);
+    let mut stream = connector.subscribe().expect("subscribe");
+
+    assert_eq!(next_payload(&mut stream).await, b"first");
+    assert_eq!(next_payload(&mut stream).await, b"line\nbreak");
+    assert!(matches!(
+        next_event(&mut stream).await,
+        ConnectorEvent::EndOfStream
+    ));
+    connector.close().expect("close");
+    fs::remove_dir_all(dir).ok();
+}
+
+#[tokio::test]
+async fn file_source_follows_appended_lines() {
+    let dir = scratch_dir("file-source-follow");
+    let path = dir.join("events.log");
+    fs::write(&path, "one\n").expect("write log");
+
+    let config = FileSourceConfig::new("source", path.display().to_string()).with_mode(
+        FileReadMode::Follow {
+            poll_interval: Duration::from_millis(10),
+        },
+    );
+    let mut connector = FileSourceConnector::new("file_source", config);
+    let mut stream = connector.subscribe().expect("subscribe");
+    assert_eq!(next_payload(&mut stream).await, b"one");
+
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .expect("open log");
+    // A line is only emitted once its newline arrives.
+    file.write_all(b"tw").expect("append");
+    file.flush().expect("flush");
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    file.write_all(b"o\n").expect("append");
+    file.flush().expect("flush");
+    assert_eq!(next_payload(&mut stream).await, b"two");
+
+    connector.close().expect("close");
+    fs::remove_dir_all(dir).ok();
+}
@@ -0,0 +1,151 @@
+use flow::connector::{
+    push_http_payload, ConnectorEvent, HttpPushError, HttpSinkConfig, HttpSinkConnector,
+    HttpSourceConfig, HttpSourceConnector, SinkConnector, SinkConnectorError, SourceConnector,
+};
+use futures::StreamExt;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+use tokio::time::{timeout, Duration};
+
+/// A request captured by [`serve_once`]: lowercase header lines and the body.
+struct CapturedRequest {
+    request_line: String,
+    headers: Vec<String>,
+    body: Vec<u8>,
+}
+
+/// Answer a single HTTP request with `status` on a local port; returns the URL to post to.
+fn serve_once(status: &'static str) -> (String, mpsc::Receiver<CapturedRequest>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind webhook");
+    let url = format!("http://{}/hook", listener.local_addr().expect("local addr"));
+    let (captured_tx, captured_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let (stream, _) = listener.accept().expect("accept webhook");
+        let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).expect("request line");
+        let mut headers = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).expect("header line");
+            let line = line.trim_end().to_ascii_lowercase();
+            if line.is_empty() {
+                break;
+            }
+            headers.push(line);
+        }
+        let length = headers
+            .iter()
+            .find_map(|h| h.strip_prefix("content-length: "))
+            .and_then(|len| len.parse::<usize>().ok())
+            .unwrap_or(0);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).expect("request body");
+        let mut stream = stream;
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+        )
+        .expect("write response");
+        let _ = captured_tx.send(CapturedRequest {
+            request_line: request_line.trim_end().to_string(),
+            headers,
+            body,
+        });
+    });
+    (url, captured_rx)
+}
+
+#[tokio::test]
+async fn http_source_receives_posted_payloads() {
+    let mut connector = HttpSourceConnector::new(
+        "http_source",
+        HttpSourceConfig::new("source", "/devices/telemetry/"),
+    );
+    let mut stream = connector.subscribe().expect("subscribe");
+
+    let delivered = push_http_payload("devices/telemetry", br#"{"a":1}"#.to_vec())
+        .await
+        .expect("push payload");
+    assert_eq!(delivered, 1);
+    let event = timeout(Duration::from_secs(2), stream.next())
+        .await
+        .expect("http source timeout")
+        .expect("http source ended")
+        .expect("http source error");
+    assert!(matches!(event, ConnectorEvent::Payload(payload) if payload == br#"{"a":1}"#));
+
+    connector.close().expect("close");
+    assert!(matches!(
+        push_http_payload("/devices/telemetry", b"{}".to_vec()).await,
+        Err(HttpPushError::NoRoute(_))
+    ));
+}
+
+#[tokio::test]
+async fn http_source_close_keeps_other_subscribers_with_same_id() {
+    // Pipelines reading the same stream build connectors with identical ids.
+    let config = HttpSourceConfig::new("source", "/shared/ingest");
+    let mut first = HttpSourceConnector::new("PhysicalDataSource_0_http", config.clone());
+    let mut second = HttpSourceConnector::new("PhysicalDataSource_0_http", config);
+    let _first_stream = first.subscribe().expect("subscribe first");
+    let mut second_stream = second.subscribe().expect("subscribe second");
+
+    first.close().expect("close first");
+    let delivered = push_http_payload("/shared/ingest", b"{}".to_vec())
+        .await
+        .expect("push payload");
+    assert_eq!(delivered, 1);
+    let event = timeout(Duration::from_secs(2), second_stream.next())
+        .await
+        .expect("http source timeout")
+        .expect("http source ended")
+        .expect("http source error");
+    assert!(matches!(event, ConnectorEvent::Payload(payload) if payload == b"{}"));
+    second.close().expect("close second");
+}
+
+#[tokio::test]
+async fn http_sink_posts_payload_with_headers() {
+    let (url, captured) = serve_once("200 OK");
+    let config = HttpSinkConfig::new("sink", url)
+        .with_header("content-type", "application/json")
+        .with_header("x-api-key", "secret")
+        .with_timeout(Duration::from_secs(2));
+    let mut connector = HttpSinkConnector::new("http_sink", config);
+
+    connector.ready().await.expect("ready");
+    connector.send(br#"[{"a":1}]"#).await.expect("send");
+    connector.close().await.expect("close");
+
+    let request = captured
+        .recv_timeout(std::time::Duration::from_secs(2))
+        .expect("captured request");
+    assert_eq!(request.request_line, "POST /hook HTTP/1.1");
+    assert!(request
+        .headers
+        .contains(&"content-type: application/json".to_string()));
+    assert!(request.headers.contains(&"x-api-key: secret".to_string()));
+    assert_eq!(request.body, br#"[{"a":1}]"#);
+}
+
+#[tokio::test]
+async fn http_sink_classifies_failures_for_retry() {
+    let (url, _captured) = serve_once("503 Service Unavailable");
+    let mut connector = HttpSinkConnector::new("http_sink", HttpSinkConfig::new("sink", url));
+    connector.ready().await.expect("ready");
+    assert!(matches!(
+        connector.send(b"{}").await,
+        Err(SinkConnectorError::Unavailable(_))
+    ));
+
+    let (url, _captured) = serve_once("400 Bad Request");
+    let mut connector = HttpSinkConnector::new("http_sink", HttpSinkConfig::new("sink", url));
+    connector.ready().await.expect("ready");
+    assert!(matches!(
+        connector.send(b"{}").await,
+        Err(SinkConnectorError::Other(_))
+    ));
+}
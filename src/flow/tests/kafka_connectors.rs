@@ -0,0 +1,143 @@
+use flow::connector::{
+    ConnectorEvent, ConnectorStream, KafkaSinkConfig, KafkaSinkConnector, KafkaSourceConfig,
+    KafkaSourceConnector, KafkaStartOffset, SinkConnector, SourceConnector,
+};
+use futures::StreamExt;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{Consumer, StreamConsumer};
+use rdkafka::message::{Headers, Message};
+use rdkafka::mocking::MockCluster;
+use rdkafka::producer::DefaultProducerContext;
+use tokio::time::{timeout, Duration};
+
+/// In-process librdkafka broker stand-in with a two-partition `topic`.
+fn mock_cluster(topic: &str) -> MockCluster<'static, DefaultProducerContext> {
+    let cluster = MockCluster::new(1).expect("start mock cluster");
+    cluster.create_topic(topic, 2, 1).expect("create topic");
+    cluster
+}
+
+async fn produce(brokers: &str, topic: &str, partition: Option<i32>, payloads: &[&str]) {
+    let mut config = KafkaSinkConfig::new("sink", brokers, topic);
+    if let Some(partition) = partition {
+        config = config.with_partition(partition);
+    }
+    let mut sink = KafkaSinkConnector::new("kafka_sink", config);
+    sink.ready().await.expect("sink ready");
+    for payload in payloads {
+        sink.send(payload.as_bytes()).await.expect("produce");
+    }
+    sink.close().await.expect("sink close");
+}
+
+fn start_source(config: KafkaSourceConfig) -> (KafkaSourceConnector, ConnectorStream) {
+    let mut source = KafkaSourceConnector::new("kafka_source", config);
+    let stream = source.subscribe().expect("subscribe");
+    (source, stream)
+}
+
+/// Close the source and wait until its consumer left the group, while the broker is still up.
+async fn stop_source(mut source: KafkaSourceConnector, mut stream: ConnectorStream) {
+    source.close().expect("close");
+    while stream.next().await.is_some() {}
+}
+
+async fn next_payload(stream: &mut ConnectorStream) -> Option<String> {
+    match timeout(Duration::from_secs(20), stream.next()).await {
+        Ok(Some(Ok(ConnectorEvent::Payload(payload)))) => {
+            Some(String::from_utf8(payload).expect("utf8 payload"))
+        }
+        Ok(Some(Ok(ConnectorEvent::EndOfStream))) | Ok(None) | Err(_) => None,
+        Ok(Some(Err(err))) => panic!("kafka source error: {err}"),
+    }
+}
+
+#[tokio::test]
+async fn kafka_sink_keys_rows_by_column_and_adds_headers() {
+    let cluster = mock_cluster("readings");
+    let brokers = cluster.bootstrap_servers();
+    let config = KafkaSinkConfig::new("sink", &brokers, "readings")
+        .with_key_column("device")
+        .with_header("origin", "edge");
+    let mut sink = KafkaSinkConnector::new("kafka_sink", config);
+    sink.ready().await.expect("sink ready");
+    sink.send(br#"[{"device":"a","v":1},{"device":"b","v":2}]"#)
+        .await
+        .expect("produce");
+    sink.close().await.expect("sink close");
+
+    let consumer: StreamConsumer = ClientConfig::new()
+        .set("bootstrap.servers", &brokers)
+        .set("group.id", "verify")
+        .set("auto.offset.reset", "earliest")
+        .create()
+        .expect("create consumer");
+    consumer.subscribe(&["readings"]).expect("subscribe");
+    let mut records = Vec::new();
+    for _ in 0..2 {
+        let message = timeout(Duration::from_secs(10), consumer.recv())
+            .await
+            .expect("consume timeout")
+            .expect("consume");
+        let headers = message.headers().expect("record headers");
+        let origin = headers.get(0);
+        assert_eq!(origin.key, "origin");
+        assert_eq!(origin.value, Some(&b"edge"[..]));
+        records.push((
+            String::from_utf8(message.key().expect("record key").to_vec()).unwrap(),
+            String::from_utf8(message.payload().expect("record value").to_vec()).unwrap(),
+        ));
+    }
+    records.sort();
+    assert_eq!(
+        records,
+        vec![
+            ("a".to_string(), r#"{"device":"a","v":1}"#.to_string()),
+            ("b".to_string(), r#"{"device":"b","v":2}"#.to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn kafka_source_reads_assigned_partitions_only() {
+    let cluster = mock_cluster("events");
+    let brokers = cluster.bootstrap_servers();
+    produce(&brokers, "events", Some(0), &["p0"]).await;
+    produce(&brokers, "events", Some(1), &["p1"]).await;
+
+    let config = KafkaSourceConfig::new("source", &brokers, "events", "assigned")
+        .with_partitions(vec![1])
+        .with_start_offset(KafkaStartOffset::Earliest);
+    let (source, mut stream) = start_source(config);
+    assert_eq!(next_payload(&mut stream).await.as_deref(), Some("p1"));
+    assert!(
+        timeout(Duration::from_millis(500), stream.next())
+            .await
+            .is_err(),
+        "partition 0 must not be read"
+    );
+    stop_source(source, stream).await;
+}
+
+#[tokio::test]
+async fn kafka_source_resumes_from_committed_group_offsets() {
+    let cluster = mock_cluster("orders");
+    let brokers = cluster.bootstrap_servers();
+    produce(&brokers, "orders", Some(0), &["1", "2"]).await;
+
+    // The stand-in rebalances only once the previous member's session expired.
+    let config = KafkaSourceConfig::new("source", &brokers, "orders", "resume")
+        .with_start_offset(KafkaStartOffset::Earliest)
+        .with_property("session.timeout.ms", "6000")
+        .with_property("heartbeat.interval.ms", "500");
+    let (source, mut stream) = start_source(config.clone());
+    assert_eq!(next_payload(&mut stream).await.as_deref(), Some("1"));
+    assert_eq!(next_payload(&mut stream).await.as_deref(), Some("2"));
+    // Leaving the group commits the offsets of the delivered records.
+    stop_source(source, stream).await;
+
+    produce(&brokers, "orders", Some(0), &["3"]).await;
+    let (source, mut stream) = start_source(config);
+    assert_eq!(next_payload(&mut stream).await.as_deref(), Some("3"));
+    stop_source(source, stream).await;
+}
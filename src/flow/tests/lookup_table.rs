@@ -0,0 +1,126 @@
+use datatypes::{ColumnSchema, ConcreteDatatype, Int64Type, Schema, StringType, Value};
+use flow::catalog::{
+    decode_table_json, Catalog, MockStreamProps, StreamDecoderConfig, StreamDefinition,
+    StreamProps, TableStreamProps,
+};
+use flow::connector::{take_mock_source_handle, MqttClientManager};
+use flow::planner::sink::{
+    NopSinkConfig, PipelineSink, PipelineSinkConnector, SinkConnectorConfig, SinkEncoderConfig,
+};
+use flow::processor::StreamData;
+use flow::{create_pipeline_with_attached_sources, shared_stream_registry, PipelineRegistries};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::time::{timeout, Duration};
+
+async fn next_name(output: &mut mpsc::Receiver<StreamData>) -> Value {
+    loop {
+        let item = timeout(Duration::from_secs(5), output.recv())
+            .await
+            .expect("timeout waiting output")
+            .expect("missing output");
+        if let StreamData::Collection(collection) = item {
+            assert_eq!(collection.num_rows(), 1);
+            return collection.rows()[0]
+                .value_by_name("devices", "name")
+                .cloned()
+                .expect("name column");
+        }
+    }
+}
+
+#[tokio::test]
+async fn stream_joins_lookup_table_and_sees_reload() {
+    let catalog = Arc::new(Catalog::new());
+    let registry = shared_stream_registry();
+    let mqtt_manager = MqttClientManager::new();
+    let registries = PipelineRegistries::new_with_builtin();
+
+    let stream_schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+        "events".to_string(),
+        "device_id".to_string(),
+        ConcreteDatatype::Int64(Int64Type),
+    )]));
+    catalog.upsert(StreamDefinition::new(
+        "events".to_string(),
+        stream_schema,
+        StreamProps::Mock(MockStreamProps::default()),
+        StreamDecoderConfig::json(),
+    ));
+
+    let table_schema = Arc::new(Schema::new(vec![
+        ColumnSchema::new(
+            "devices".to_string(),
+            "id".to_string(),
+            ConcreteDatatype::Int64(Int64Type),
+        ),
+        ColumnSchema::new(
+            "devices".to_string(),
+            "name".to_string(),
+            ConcreteDatatype::String(StringType),
+        ),
+    ]));
+    let table_props = TableStreamProps::api();
+    let table = table_props.data().clone();
+    table.replace(
+        decode_table_json(
+            "devices",
+            Arc::clone(&table_schema),
+            br#"[{"id": 1, "name": "kitchen"}]"#,
+        )
+        .unwrap(),
+    );
+    catalog.upsert(StreamDefinition::new(
+        "devices".to_string(),
+        Arc::clone(&table_schema),
+        StreamProps::Table(table_props),
+        StreamDecoderConfig::json(),
+    ));
+
+    let connector = PipelineSinkConnector::new(
+        "test_sink_connector",
+        SinkConnectorConfig::Nop(NopSinkConfig),
+        SinkEncoderConfig::json(),
+    );
+    let sink = PipelineSink::new("test_sink", connector).with_forward_to_result(true);
+
+    let mut pipeline = create_pipeline_with_attached_sources(
+        "SELECT d.name FROM events JOIN devices AS d ON events.device_id = d.id",
+        vec![sink],
+        &catalog,
+        registry,
+        mqtt_manager.clone(),
+        &registries,
+    )
+    .expect("create pipeline with attached sources");
+
+    let key = format!("{}:events:PhysicalDataSource_0", pipeline.pipeline_id());
+    let handle = take_mock_source_handle(&key).expect("take mock handle");
+    let mut output = pipeline
+        .take_output()
+        .expect("pipeline should expose an output receiver");
+    pipeline.start();
+
+    handle
+        .send(r#"{"device_id": 1}"#)
+        .await
+        .expect("send payload");
+    assert_eq!(
+        next_name(&mut output).await,
+        Value::String("kitchen".to_string())
+    );
+
+    table.replace(
+        decode_table_json("devices", table_schema, br#"[{"id": 1, "name": "pantry"}]"#).unwrap(),
+    );
+    handle
+        .send(r#"{"device_id": 1}"#)
+        .await
+        .expect("send payload");
+    assert_eq!(
+        next_name(&mut output).await,
+        Value::String("pantry".to_string())
+    );
+
+    pipeline.close().await.expect("close pipeline");
+}
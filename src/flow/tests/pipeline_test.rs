@@ -3,14 +3,18 @@
 //! This module exercises both `create_pipeline_with_log_sink` (default/testing)
 //! and the customizable `create_pipeline` API that accepts user-defined sinks.
 
-use datatypes::{ColumnSchema, ConcreteDatatype, Schema, Value};
+use datatypes::{ColumnSchema, ConcreteDatatype, Int64Type, Schema, Value};
 use flow::catalog::{MockStreamProps, StreamDecoderConfig, StreamDefinition, StreamProps};
+use flow::codec::{JsonDecoder, JsonEncoder};
+use flow::connector::MockSinkConnector;
 use flow::model::batch_from_columns_simple;
 use flow::planner::sink::{
     CommonSinkProps, NopSinkConfig, PipelineSink, PipelineSinkConnector, SinkConnectorConfig,
     SinkEncoderConfig,
 };
-use flow::processor::StreamData;
+use flow::processor::{
+    DeadLetterProcessor, DecoderProcessor, ErrorPolicy, Processor, ProcessorPipeline, StreamData,
+};
 use flow::FlowInstance;
 use std::sync::Arc;
 use tokio::time::{timeout, Duration};
@@ -42,10 +46,7 @@ async fn run_test_case(test_case: TestCase) {
     // Create pipeline from SQL
     let mut pipeline = instance
         .build_pipeline_with_log_sink(test_case.sql, true)
-        .expect(&format!(
-            "Failed to create pipeline for: {}",
-            test_case.name
-        ));
+        .unwrap_or_else(|_| panic!("Failed to create pipeline for: {}", test_case.name));
 
     pipeline.start();
     tokio::time::sleep(Duration::from_millis(100)).await;
@@ -57,16 +58,14 @@ async fn run_test_case(test_case: TestCase) {
         .map(|(col_name, values)| ("stream".to_string(), col_name, values))
         .collect();
 
-    let test_batch = batch_from_columns_simple(columns).expect(&format!(
-        "Failed to create test RecordBatch for: {}",
-        test_case.name
-    ));
+    let test_batch = batch_from_columns_simple(columns)
+        .unwrap_or_else(|_| panic!("Failed to create test RecordBatch for: {}", test_case.name));
 
     let stream_data = StreamData::collection(Box::new(test_batch));
     pipeline
         .send_stream_data("stream", stream_data)
         .await
-        .expect(&format!("Failed to send test data for: {}", test_case.name));
+        .unwrap_or_else(|_| panic!("Failed to send test data for: {}", test_case.name));
 
     // Receive and verify results
     let mut output = pipeline
@@ -173,10 +172,10 @@ async fn run_test_case(test_case: TestCase) {
         }
     }
 
-    pipeline.close().await.expect(&format!(
-        "Failed to close pipeline for test: {}",
-        test_case.name
-    ));
+    pipeline
+        .close()
+        .await
+        .unwrap_or_else(|_| panic!("Failed to close pipeline for test: {}", test_case.name));
 }
 
 /// Test create_pipeline with various SQL queries using table-driven approach
@@ -326,6 +325,180 @@ async fn test_create_pipeline_various_queries() {
             ],
             sort_by_fields: None,
         },
+        TestCase {
+            name: "filter_with_and_or",
+            sql: "SELECT a FROM stream WHERE (a > 15 AND b < 300) OR a = 10",
+            input_data: vec![
+                (
+                    "a".to_string(),
+                    vec![Value::Int64(10), Value::Int64(20), Value::Int64(30)],
+                ),
+                (
+                    "b".to_string(),
+                    vec![Value::Int64(100), Value::Int64(200), Value::Int64(300)],
+                ),
+            ],
+            expected_rows: 2,
+            expected_columns: 1,
+            column_checks: vec![ColumnCheck {
+                expected_name: "a".to_string(),
+                expected_values: vec![Value::Int64(10), Value::Int64(20)],
+            }],
+            sort_by_fields: None,
+        },
+        TestCase {
+            name: "between_in_and_xor",
+            sql: "SELECT a BETWEEN 15 AND 25, a IN (10, 30), (b > 150) XOR (a > 15) FROM stream",
+            input_data: vec![
+                (
+                    "a".to_string(),
+                    vec![Value::Int64(10), Value::Int64(20), Value::Null],
+                ),
+                (
+                    "b".to_string(),
+                    vec![Value::Int64(100), Value::Int64(100), Value::Int64(300)],
+                ),
+            ],
+            expected_rows: 3,
+            expected_columns: 3,
+            column_checks: vec![
+                ColumnCheck {
+                    expected_name: "a BETWEEN 15 AND 25".to_string(),
+                    expected_values: vec![Value::Bool(false), Value::Bool(true), Value::Bool(false)],
+                },
+                ColumnCheck {
+                    expected_name: "a IN (10, 30)".to_string(),
+                    expected_values: vec![Value::Bool(true), Value::Bool(false), Value::Bool(false)],
+                },
+                ColumnCheck {
+                    expected_name: "(b > 150) XOR (a > 15)".to_string(),
+                    expected_values: vec![Value::Bool(false), Value::Bool(true), Value::Bool(true)],
+                },
+            ],
+            sort_by_fields: None,
+        },
+        TestCase {
+            name: "case_when",
+            sql: "SELECT CASE WHEN a > 25 THEN 'high' WHEN a > 15 THEN 'mid' ELSE 'low' END AS level, CASE b WHEN 100 THEN 1 END AS hundred FROM stream",
+            input_data: vec![
+                (
+                    "a".to_string(),
+                    vec![Value::Int64(10), Value::Int64(20), Value::Int64(30)],
+                ),
+                (
+                    "b".to_string(),
+                    vec![Value::Int64(100), Value::Int64(200), Value::Int64(300)],
+                ),
+            ],
+            expected_rows: 3,
+            expected_columns: 2,
+            column_checks: vec![
+                ColumnCheck {
+                    expected_name: "level".to_string(),
+                    expected_values: vec![
+                        Value::String("low".to_string()),
+                        Value::String("mid".to_string()),
+                        Value::String("high".to_string()),
+                    ],
+                },
+                ColumnCheck {
+                    expected_name: "hundred".to_string(),
+                    expected_values: vec![Value::Int64(1), Value::Null, Value::Null],
+                },
+            ],
+            sort_by_fields: None,
+        },
+        TestCase {
+            name: "unary_minus_and_math_functions",
+            sql: "SELECT -a AS neg, abs(b) AS magnitude, round(a, 1) AS rounded, floor(a) AS floored, pow(b, 2) AS squared, sqrt(pow(b, 2)) AS root, greatest(a, b, 0) AS top FROM stream",
+            input_data: vec![
+                (
+                    "a".to_string(),
+                    vec![Value::Float64(1.25), Value::Float64(-2.5)],
+                ),
+                ("b".to_string(), vec![Value::Int64(3), Value::Int64(-4)]),
+            ],
+            expected_rows: 2,
+            expected_columns: 7,
+            column_checks: vec![
+                ColumnCheck {
+                    expected_name: "neg".to_string(),
+                    expected_values: vec![Value::Float64(-1.25), Value::Float64(2.5)],
+                },
+                ColumnCheck {
+                    expected_name: "magnitude".to_string(),
+                    expected_values: vec![Value::Int64(3), Value::Int64(4)],
+                },
+                ColumnCheck {
+                    expected_name: "rounded".to_string(),
+                    expected_values: vec![Value::Float64(1.3), Value::Float64(-2.5)],
+                },
+                ColumnCheck {
+                    expected_name: "floored".to_string(),
+                    expected_values: vec![Value::Float64(1.0), Value::Float64(-3.0)],
+                },
+                ColumnCheck {
+                    expected_name: "squared".to_string(),
+                    expected_values: vec![Value::Int64(9), Value::Int64(16)],
+                },
+                ColumnCheck {
+                    expected_name: "root".to_string(),
+                    expected_values: vec![Value::Float64(3.0), Value::Float64(4.0)],
+                },
+                ColumnCheck {
+                    expected_name: "top".to_string(),
+                    expected_values: vec![Value::Float64(3.0), Value::Float64(0.0)],
+                },
+            ],
+            sort_by_fields: None,
+        },
+        TestCase {
+            name: "null_handling_functions",
+            sql: "SELECT coalesce(a, 100 / b, -1) AS first, ifnull(a, 0) AS filled, nullif(b, 0) AS nonzero, a IS NOT NULL AS present, a IS DISTINCT FROM b AS differs FROM stream WHERE (b IS NOT DISTINCT FROM 0) OR a IS NULL",
+            input_data: vec![
+                (
+                    "a".to_string(),
+                    vec![Value::Int64(7), Value::Null, Value::Null, Value::Int64(1)],
+                ),
+                (
+                    "b".to_string(),
+                    vec![Value::Int64(0), Value::Int64(4), Value::Null, Value::Int64(2)],
+                ),
+            ],
+            expected_rows: 3,
+            expected_columns: 5,
+            column_checks: vec![
+                ColumnCheck {
+                    expected_name: "first".to_string(),
+                    expected_values: vec![Value::Int64(7), Value::Float64(25.0), Value::Int64(-1)],
+                },
+                ColumnCheck {
+                    expected_name: "filled".to_string(),
+                    expected_values: vec![Value::Int64(7), Value::Int64(0), Value::Int64(0)],
+                },
+                ColumnCheck {
+                    expected_name: "nonzero".to_string(),
+                    expected_values: vec![Value::Null, Value::Int64(4), Value::Null],
+                },
+                ColumnCheck {
+                    expected_name: "present".to_string(),
+                    expected_values: vec![
+                        Value::Bool(true),
+                        Value::Bool(false),
+                        Value::Bool(false),
+                    ],
+                },
+                ColumnCheck {
+                    expected_name: "differs".to_string(),
+                    expected_values: vec![
+                        Value::Bool(true),
+                        Value::Bool(true),
+                        Value::Bool(false),
+                    ],
+                },
+            ],
+            sort_by_fields: None,
+        },
         TestCase {
             name: "filter_all_match",
             sql: "SELECT a FROM stream WHERE a > 5",
@@ -387,6 +560,141 @@ async fn test_create_pipeline_various_queries() {
             ],
             sort_by_fields: Some(vec!["b + 1"]),
         },
+        TestCase {
+            name: "builtin_aggregates_skip_nulls",
+            sql: "SELECT count(*), count(b), avg(a), min(a), max(b), stddev(a) FROM stream GROUP BY countwindow(4)",
+            input_data: vec![
+                (
+                    "a".to_string(),
+                    vec![
+                        Value::Int64(2),
+                        Value::Int64(4),
+                        Value::Int64(4),
+                        Value::Int64(6),
+                    ],
+                ),
+                (
+                    "b".to_string(),
+                    vec![
+                        Value::String("x".to_string()),
+                        Value::Null,
+                        Value::String("z".to_string()),
+                        Value::Null,
+                    ],
+                ),
+            ],
+            expected_rows: 1,
+            expected_columns: 6,
+            column_checks: vec![
+                ColumnCheck {
+                    expected_name: "count(*)".to_string(),
+                    expected_values: vec![Value::Int64(4)],
+                },
+                ColumnCheck {
+                    expected_name: "count(b)".to_string(),
+                    expected_values: vec![Value::Int64(2)],
+                },
+                ColumnCheck {
+                    expected_name: "avg(a)".to_string(),
+                    expected_values: vec![Value::Float64(4.0)],
+                },
+                ColumnCheck {
+                    expected_name: "min(a)".to_string(),
+                    expected_values: vec![Value::Int64(2)],
+                },
+                ColumnCheck {
+                    expected_name: "max(b)".to_string(),
+                    expected_values: vec![Value::String("z".to_string())],
+                },
+                ColumnCheck {
+                    expected_name: "stddev(a)".to_string(),
+                    expected_values: vec![Value::Float64(2.0_f64.sqrt())],
+                },
+            ],
+            sort_by_fields: None,
+        },
+        TestCase {
+            name: "aggregate_distinct_and_filter",
+            sql: "SELECT count(DISTINCT a), sum(DISTINCT a), sum(a) FILTER (WHERE b > 1) FROM stream GROUP BY countwindow(4)",
+            input_data: vec![
+                (
+                    "a".to_string(),
+                    vec![
+                        Value::Int64(5),
+                        Value::Int64(5),
+                        Value::Int64(7),
+                        Value::Null,
+                    ],
+                ),
+                (
+                    "b".to_string(),
+                    vec![
+                        Value::Int64(1),
+                        Value::Int64(2),
+                        Value::Int64(3),
+                        Value::Int64(4),
+                    ],
+                ),
+            ],
+            expected_rows: 1,
+            expected_columns: 3,
+            column_checks: vec![
+                ColumnCheck {
+                    expected_name: "count(DISTINCT a)".to_string(),
+                    expected_values: vec![Value::Int64(2)],
+                },
+                ColumnCheck {
+                    expected_name: "sum(DISTINCT a)".to_string(),
+                    expected_values: vec![Value::Int64(12)],
+                },
+                ColumnCheck {
+                    expected_name: "sum(a) FILTER (WHERE b > 1)".to_string(),
+                    expected_values: vec![Value::Int64(12)],
+                },
+            ],
+            sort_by_fields: None,
+        },
+        TestCase {
+            name: "aggregate_distinct_and_filter_without_streaming_rewrite",
+            sql: "SELECT count(DISTINCT a), sum(DISTINCT a), sum(a) FILTER (WHERE b > 1), ndv(b) FROM stream GROUP BY countwindow(4)",
+            input_data: vec![
+                (
+                    "a".to_string(),
+                    vec![
+                        Value::Int64(5),
+                        Value::Int64(5),
+                        Value::Int64(7),
+                        Value::Null,
+                    ],
+                ),
+                (
+                    "b".to_string(),
+                    vec![
+                        Value::Int64(1),
+                        Value::Int64(2),
+                        Value::Int64(3),
+                        Value::Int64(4),
+                    ],
+                ),
+            ],
+            expected_rows: 1,
+            expected_columns: 4,
+            column_checks: vec![
+                ColumnCheck {
+                    expected_name: "count(DISTINCT a)".to_string(),
+                    expected_values: vec![Value::Int64(2)],
+                },
+                ColumnCheck {
+                    expected_name: "sum(DISTINCT a)".to_string(),
+                    expected_values: vec![Value::Int64(12)],
+                },
+                ColumnCheck {
+                    expected_name: "sum(a) FILTER (WHERE b > 1)".to_string(),
+                    expected_values: vec![Value::Int64(12)],
+                },
+            ],
+            sort_by_fields: None,
+        },
     ];
 
     // Run all test cases
@@ -465,6 +773,7 @@ async fn test_batch_processor_flushes_on_count() {
         .with_common_props(CommonSinkProps {
             batch_count: Some(2),
             batch_duration: None,
+            ..Default::default()
         });
 
     let mut pipeline = instance
@@ -524,6 +833,242 @@ async fn test_batch_processor_flushes_on_count() {
     }
 }
 
+#[tokio::test]
+async fn test_pipeline_metrics_count_rows_per_processor() {
+    let instance = FlowInstance::new();
+    install_stream_schema(&instance, &[("a".to_string(), vec![])]).await;
+
+    let connector = PipelineSinkConnector::new(
+        "metrics_sink_connector",
+        SinkConnectorConfig::Nop(NopSinkConfig),
+        SinkEncoderConfig::json(),
+    );
+    let sink = PipelineSink::new("metrics_sink", connector).with_forward_to_result(true);
+    let mut pipeline = instance
+        .build_pipeline("SELECT a FROM stream WHERE a > 1", vec![sink])
+        .expect("create filter pipeline");
+    assert_eq!(pipeline.metrics().uptime, None);
+    let mut output = pipeline
+        .take_output()
+        .expect("pipeline should expose an output receiver");
+    pipeline.start();
+
+    let columns = vec![(
+        "stream".to_string(),
+        "a".to_string(),
+        vec![Value::Int64(1), Value::Int64(2), Value::Int64(3)],
+    )];
+    let batch = batch_from_columns_simple(columns).expect("create batch");
+    pipeline
+        .send_stream_data("stream", StreamData::collection(Box::new(batch)))
+        .await
+        .expect("send data");
+    loop {
+        let item = timeout(Duration::from_secs(2), output.recv())
+            .await
+            .expect("output timeout")
+            .expect("output missing");
+        if matches!(item, StreamData::Collection(_)) {
+            break;
+        }
+    }
+
+    // Output taps run alongside the processors, so give them a moment to catch up.
+    let filter_metrics = |pipeline: &ProcessorPipeline| {
+        pipeline
+            .metrics()
+            .processors
+            .into_iter()
+            .find(|processor| processor.processor_id.starts_with("PhysicalFilter"))
+            .expect("filter metrics")
+    };
+    let mut filter = filter_metrics(&pipeline);
+    for _ in 0..50 {
+        if filter.records_out == 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        filter = filter_metrics(&pipeline);
+    }
+    let metrics = pipeline.metrics();
+    assert!(metrics.uptime.is_some());
+    let control_source = metrics
+        .processors
+        .iter()
+        .find(|processor| processor.processor_id == "control_source")
+        .expect("control source metrics");
+    assert_eq!(control_source.records_out, 3);
+    assert_eq!(filter.records_in, 3);
+    assert_eq!(filter.records_out, 2);
+    assert_eq!(filter.errors, 0);
+    pipeline.close().await.expect("close pipeline");
+}
+
+#[tokio::test]
+async fn test_dead_letter_policy_delivers_undecodable_payloads() {
+    let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+        "stream".to_string(),
+        "a".to_string(),
+        ConcreteDatatype::Int64(Int64Type),
+    )]));
+    let decoder = Arc::new(JsonDecoder::new("stream", schema, Default::default()));
+    let mut decoder_processor =
+        DecoderProcessor::new("decoder", decoder).with_error_policy(ErrorPolicy::DeadLetter);
+    let (input, input_rx) = tokio::sync::broadcast::channel(16);
+    decoder_processor.add_input(input_rx);
+
+    let (connector, mut handle) = MockSinkConnector::new("dead_letter_connector");
+    let encoder = Arc::new(JsonEncoder::new("dead_letter", Default::default()));
+    let mut dead_letter = DeadLetterProcessor::new("dead_letter", Box::new(connector), encoder);
+    decoder_processor.set_dead_letter_sender(dead_letter.sender().expect("dead-letter queue"));
+    let mut output = decoder_processor
+        .subscribe_output()
+        .expect("decoder output");
+    let dead_letter_handle = dead_letter.start();
+    let decoder_handle = decoder_processor.start();
+
+    assert!(
+        input.send(StreamData::bytes(b"not json".to_vec())).is_ok(),
+        "send invalid payload"
+    );
+    assert!(
+        input
+            .send(StreamData::bytes(br#"[{"a":1}]"#.to_vec()))
+            .is_ok(),
+        "send valid payload"
+    );
+
+    let delivered = timeout(Duration::from_secs(2), handle.recv())
+        .await
+        .expect("dead-letter timeout")
+        .expect("dead-letter payload missing");
+    let records: serde_json::Value =
+        serde_json::from_slice(&delivered).expect("dead-letter record is json");
+    let record = &records[0];
+    assert_eq!(record["processor"], "decoder");
+    assert_eq!(record["payload"], "not json");
+    assert_eq!(record["payload_encoding"], "utf8");
+    assert!(!record["message"].as_str().unwrap_or_default().is_empty());
+
+    // The valid payload after the failed one is still decoded.
+    loop {
+        let item = timeout(Duration::from_secs(2), output.recv())
+            .await
+            .expect("decoder output timeout")
+            .expect("decoder output missing");
+        if let StreamData::Collection(collection) = item {
+            assert_eq!(collection.num_rows(), 1);
+            break;
+        }
+    }
+
+    assert!(
+        input.send(StreamData::stream_end()).is_ok(),
+        "send stream end"
+    );
+    decoder_handle
+        .await
+        .expect("join decoder")
+        .expect("decoder result");
+    dead_letter_handle
+        .await
+        .expect("join dead-letter")
+        .expect("dead-letter result");
+}
+
+#[tokio::test]
+async fn test_dead_letter_keeps_records_while_sink_is_slow() {
+    let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+        "stream".to_string(),
+        "a".to_string(),
+        ConcreteDatatype::Int64(Int64Type),
+    )]));
+    let decoder = Arc::new(JsonDecoder::new("stream", schema, Default::default()));
+    let mut decoder_processor =
+        DecoderProcessor::new("decoder", decoder).with_error_policy(ErrorPolicy::DeadLetter);
+    let (input, input_rx) = tokio::sync::broadcast::channel(4096);
+    decoder_processor.add_input(input_rx);
+
+    let (connector, mut handle) = MockSinkConnector::new("dead_letter_connector");
+    let encoder = Arc::new(JsonEncoder::new("dead_letter", Default::default()));
+    let mut dead_letter = DeadLetterProcessor::new("dead_letter", Box::new(connector), encoder);
+    decoder_processor.set_dead_letter_sender(dead_letter.sender().expect("dead-letter queue"));
+    let mut output = decoder_processor
+        .subscribe_output()
+        .expect("decoder output");
+    tokio::spawn(async move {
+        while !matches!(
+            output.recv().await,
+            Err(tokio::sync::broadcast::error::RecvError::Closed)
+        ) {}
+    });
+    let dead_letter_handle = dead_letter.start();
+    let decoder_handle = decoder_processor.start();
+
+    // More failures than the dead-letter queue holds arrive before the sink is read at all.
+    let failed = 3000;
+    for i in 0..failed {
+        assert!(input
+            .send(StreamData::bytes(format!("bad {i}").into_bytes()))
+            .is_ok());
+    }
+    assert!(input.send(StreamData::stream_end()).is_ok());
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut delivered = 0;
+    while let Ok(Some(_)) = timeout(Duration::from_secs(2), handle.recv()).await {
+        delivered += 1;
+        if delivered == failed {
+            break;
+        }
+    }
+    assert_eq!(delivered, failed);
+    decoder_handle
+        .await
+        .expect("join decoder")
+        .expect("decoder result");
+    dead_letter_handle
+        .await
+        .expect("join dead-letter")
+        .expect("dead-letter result");
+}
+
+#[tokio::test]
+async fn test_fail_policy_stops_decoder_on_first_error() {
+    let schema = Arc::new(Schema::new(vec![ColumnSchema::new(
+        "stream".to_string(),
+        "a".to_string(),
+        ConcreteDatatype::Int64(Int64Type),
+    )]));
+    let decoder = Arc::new(JsonDecoder::new("stream", schema, Default::default()));
+    let mut decoder_processor =
+        DecoderProcessor::new("decoder", decoder).with_error_policy(ErrorPolicy::Fail);
+    let (input, input_rx) = tokio::sync::broadcast::channel(16);
+    decoder_processor.add_input(input_rx);
+    let mut output = decoder_processor
+        .subscribe_output()
+        .expect("decoder output");
+    let decoder_handle = decoder_processor.start();
+
+    assert!(
+        input.send(StreamData::bytes(b"not json".to_vec())).is_ok(),
+        "send invalid payload"
+    );
+
+    let first = timeout(Duration::from_secs(2), output.recv())
+        .await
+        .expect("decoder output timeout")
+        .expect("decoder output missing");
+    assert!(matches!(first, StreamData::Error(_)));
+    let second = timeout(Duration::from_secs(2), output.recv())
+        .await
+        .expect("decoder output timeout")
+        .expect("decoder output missing");
+    assert!(second.is_terminal());
+    let result = decoder_handle.await.expect("join decoder");
+    assert!(result.is_err());
+}
+
 async fn install_stream_schema(instance: &FlowInstance, columns: &[(String, Vec<Value>)]) {
     let schema_columns = columns
         .iter()
@@ -678,6 +1223,74 @@ async fn test_aggregation_with_countwindow() {
     println!("✓ All aggregation with countwindow tests passed!");
 }
 
+#[tokio::test]
+async fn test_countwindow_resumes_from_checkpoint() {
+    let instance = FlowInstance::new();
+    install_stream_schema(&instance, &[("a".to_string(), vec![])]).await;
+
+    let build = || {
+        let connector = PipelineSinkConnector::new(
+            "checkpoint_sink_connector",
+            SinkConnectorConfig::Nop(NopSinkConfig),
+            SinkEncoderConfig::json(),
+        );
+        let sink = PipelineSink::new("checkpoint_sink", connector).with_forward_to_result(true);
+        instance
+            .build_pipeline(
+                "SELECT sum(a) FROM stream GROUP BY countwindow(3)",
+                vec![sink],
+            )
+            .expect("create aggregation pipeline")
+    };
+    let send = |values: Vec<i64>| {
+        let columns = vec![(
+            "stream".to_string(),
+            "a".to_string(),
+            values.into_iter().map(Value::Int64).collect(),
+        )];
+        StreamData::collection(Box::new(
+            batch_from_columns_simple(columns).expect("create batch"),
+        ))
+    };
+
+    // The first two rows of the window are only held in processor state.
+    let mut pipeline = build();
+    pipeline.start();
+    pipeline
+        .send_stream_data("stream", send(vec![10, 20]))
+        .await
+        .expect("send data");
+    let checkpoint = pipeline.checkpoint().await.expect("checkpoint pipeline");
+    assert_eq!(checkpoint.processors.len(), 1);
+    pipeline.close().await.expect("close pipeline");
+
+    let mut restored = build();
+    restored
+        .restore_checkpoint(checkpoint)
+        .expect("restore checkpoint");
+    let mut output = restored
+        .take_output()
+        .expect("pipeline should expose an output receiver");
+    restored.start();
+    restored
+        .send_stream_data("stream", send(vec![30]))
+        .await
+        .expect("send data");
+
+    loop {
+        let item = timeout(Duration::from_secs(2), output.recv())
+            .await
+            .expect("window timeout")
+            .expect("window missing");
+        if let StreamData::Collection(collection) = item {
+            let rows = collection.rows();
+            assert_eq!(rows[0].value_by_name("", "sum(a)"), Some(&Value::Int64(60)));
+            break;
+        }
+    }
+    restored.close().await.expect("close pipeline");
+}
+
 #[tokio::test]
 async fn test_last_row_with_countwindow() {
     let instance = FlowInstance::new();
@@ -238,6 +238,16 @@ fn logical_optimizer_table_driven() {
             sql: "SELECT stream_3.items[a] FROM stream_3",
             expected: r##"{"children":[{"children":[],"id":"DataSource_0","info":["source=stream_3","decoder=json","schema=[items[*][struct{c, d}]]"],"operator":"DataSource"}],"id":"Project_1","info":["fields=[stream_3.items[\"a\"]]"],"operator":"Project"}"##,
         },
+        Case {
+            name: "logical_optimizer_keeps_columns_inside_null_handling",
+            sql: "SELECT coalesce(a, b) AS v FROM users WHERE k1 IS NOT NULL",
+            expected: r##"{"children":[{"children":[{"children":[],"id":"DataSource_0","info":["source=users","decoder=json","schema=[a, b, k1]"],"operator":"DataSource"}],"id":"Filter_1","info":["predicate=k1 IS NOT NULL"],"operator":"Filter"}],"id":"Project_2","info":["fields=[coalesce(a, b)]"],"operator":"Project"}"##,
+        },
+        Case {
+            name: "logical_optimizer_keeps_columns_inside_case_and_between",
+            sql: "SELECT CASE WHEN b > 1 THEN a END AS x FROM users WHERE k1 BETWEEN 1 AND 5",
+            expected: r##"{"children":[{"children":[{"children":[],"id":"DataSource_0","info":["source=users","decoder=json","schema=[a, b, k1]"],"operator":"DataSource"}],"id":"Filter_1","info":["predicate=k1 BETWEEN 1 AND 5"],"operator":"Filter"}],"id":"Project_2","info":["fields=[CASE WHEN b > 1 THEN a END]"],"operator":"Project"}"##,
+        },
     ];
 
     for case in cases {
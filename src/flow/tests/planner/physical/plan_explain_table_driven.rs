@@ -7,7 +7,7 @@ use flow::sql_conversion::{SchemaBinding, SchemaBindingEntry, SourceBindingKind}
 use flow::{
     CommonSinkProps, MqttStreamProps, NopSinkConfig, PipelineExplain, PipelineRegistries,
     PipelineSink, PipelineSinkConnector, SinkConnectorConfig, SinkEncoderConfig,
-    StreamDecoderConfig, StreamDefinition, StreamProps,
+    StreamDecoderConfig, StreamDefinition, StreamProps, TableStreamProps,
 };
 use parser::parse_sql;
 use std::collections::HashMap;
@@ -126,6 +126,30 @@ fn setup_streams() -> HashMap<String, Arc<StreamDefinition>> {
         StreamDecoderConfig::json(),
     );
 
+    let devices_schema = Arc::new(Schema::new(vec![
+        ColumnSchema::new(
+            "devices".to_string(),
+            "id".to_string(),
+            ConcreteDatatype::Int64(Int64Type),
+        ),
+        ColumnSchema::new(
+            "devices".to_string(),
+            "name".to_string(),
+            ConcreteDatatype::String(StringType),
+        ),
+        ColumnSchema::new(
+            "devices".to_string(),
+            "zone".to_string(),
+            ConcreteDatatype::String(StringType),
+        ),
+    ]));
+    let devices_def = StreamDefinition::new(
+        "devices",
+        Arc::clone(&devices_schema),
+        StreamProps::Table(TableStreamProps::api()),
+        StreamDecoderConfig::json(),
+    );
+
     let mut stream_defs = HashMap::new();
     stream_defs.insert("stream".to_string(), Arc::new(stream_def));
     stream_defs.insert("stream_2".to_string(), Arc::new(stream_2_def));
@@ -133,6 +157,7 @@ fn setup_streams() -> HashMap<String, Arc<StreamDefinition>> {
     stream_defs.insert("stream_ab".to_string(), Arc::new(stream_ab_def));
     stream_defs.insert("stream_3".to_string(), Arc::new(stream_3_def));
     stream_defs.insert("stream_4".to_string(), Arc::new(stream_4_def));
+    stream_defs.insert("devices".to_string(), Arc::new(devices_def));
 
     stream_defs
 }
@@ -170,8 +195,10 @@ fn build_nop_json_sink(sink_id: &'static str, batch_count: Option<usize>) -> Pip
     match batch_count {
         None => sink,
         Some(batch_count) => {
-            let mut common_props = CommonSinkProps::default();
-            common_props.batch_count = Some(batch_count);
+            let common_props = CommonSinkProps {
+                batch_count: Some(batch_count),
+                ..Default::default()
+            };
             sink.with_common_props(common_props)
         }
     }
@@ -255,6 +282,21 @@ fn plan_explain_table_driven() {
             sql: "SELECT ndv(a) FROM stream GROUP BY countwindow(4)",
             expected: r##"{"logical":{"children":[{"children":[{"children":[{"children":[],"id":"DataSource_0","info":["source=stream","decoder=json","schema=[a]"],"operator":"DataSource"}],"id":"Window_1","info":["kind=count","count=4"],"operator":"Window"}],"id":"Aggregation_2","info":["aggregates=[ndv(a) -> col_1]"],"operator":"Aggregation"}],"id":"Project_3","info":["fields=[col_1]"],"operator":"Project"},"options":null,"physical":{"children":[{"children":[{"children":[{"children":[{"children":[],"id":"PhysicalDataSource_0","info":["source=stream","schema=[a]"],"operator":"PhysicalDataSource"}],"id":"PhysicalDecoder_1","info":["decoder=json","schema=[a]"],"operator":"PhysicalDecoder"}],"id":"PhysicalCountWindow_2","info":["kind=count","count=4"],"operator":"PhysicalCountWindow"}],"id":"PhysicalAggregation_3","info":["calls=[ndv(a) -> col_1]"],"operator":"PhysicalAggregation"}],"id":"PhysicalProject_4","info":["fields=[col_1]"],"operator":"PhysicalProject"}}"##,
         },
+        Case {
+            name: "explain_ndv_hoppingwindow_non_incremental",
+            sql: "SELECT ndv(a) FROM stream GROUP BY hoppingwindow('ss', 10, 5)",
+            expected: r##"{"logical":{"children":[{"children":[{"children":[{"children":[],"id":"DataSource_0","info":["source=stream","decoder=json","schema=[a]"],"operator":"DataSource"}],"id":"Window_1","info":["kind=hopping","unit=Seconds","size=10","hop=5"],"operator":"Window"}],"id":"Aggregation_2","info":["aggregates=[ndv(a) -> col_1]"],"operator":"Aggregation"}],"id":"Project_3","info":["fields=[col_1]"],"operator":"Project"},"options":null,"physical":{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[],"id":"PhysicalDataSource_0","info":["source=stream","schema=[a]"],"operator":"PhysicalDataSource"}],"id":"PhysicalDecoder_1","info":["decoder=json","schema=[a]"],"operator":"PhysicalDecoder"}],"id":"PhysicalProcessTimeWatermark_2","info":["window=hopping","unit=Seconds","size=10","hop=5","mode=processing_time","interval=5"],"operator":"PhysicalProcessTimeWatermark"}],"id":"PhysicalHoppingWindow_3","info":["kind=hopping","unit=Seconds","size=10","hop=5"],"operator":"PhysicalHoppingWindow"}],"id":"PhysicalAggregation_4","info":["calls=[ndv(a) -> col_1]"],"operator":"PhysicalAggregation"}],"id":"PhysicalProject_5","info":["fields=[col_1]"],"operator":"PhysicalProject"}}"##,
+        },
+        Case {
+            name: "explain_interval_stream_join",
+            sql: "SELECT stream.a, stream_ab.b FROM stream JOIN stream_ab ON stream.a = stream_ab.a AND stream_ab.b > 0 WITHIN INTERVAL '10' SECOND",
+            expected: r##"{"logical":{"children":[{"children":[{"children":[],"id":"DataSource_0","info":["source=stream","decoder=json","schema=[a]"],"operator":"DataSource"},{"children":[],"id":"DataSource_1","info":["source=stream_ab","decoder=json","schema=[a, b]"],"operator":"DataSource"}],"id":"Join_2","info":["kind=inner","left=stream","right=stream_ab","on=stream.a = stream_ab.a AND stream_ab.b > 0","within_ms=10000"],"operator":"Join"}],"id":"Project_3","info":["fields=[stream.a; stream_ab.b]"],"operator":"Project"},"options":null,"physical":{"children":[{"children":[{"children":[{"children":[],"id":"PhysicalDataSource_0","info":["source=stream","schema=[a]"],"operator":"PhysicalDataSource"}],"id":"PhysicalDecoder_1","info":["decoder=json","schema=[a]"],"operator":"PhysicalDecoder"},{"children":[{"children":[],"id":"PhysicalDataSource_2","info":["source=stream_ab","schema=[a, b]"],"operator":"PhysicalDataSource"}],"id":"PhysicalDecoder_3","info":["decoder=json","schema=[a, b]"],"operator":"PhysicalDecoder"}],"id":"PhysicalStreamJoin_4","info":["kind=inner","left=stream","right=stream_ab","keys=[stream.a = stream_ab.a]","on=stream.a = stream_ab.a AND stream_ab.b > 0","within_ms=10000"],"operator":"PhysicalStreamJoin"}],"id":"PhysicalProject_5","info":["fields=[stream.a; stream_ab.b]"],"operator":"PhysicalProject"}}"##,
+        },
+        Case {
+            name: "explain_lookup_table_join",
+            sql: "SELECT stream_ab.a, d.name FROM stream_ab JOIN devices AS d ON stream_ab.a = d.id AND stream_ab.b > 0",
+            expected: r##"{"logical":{"children":[{"children":[{"children":[],"id":"DataSource_0","info":["source=stream_ab","decoder=json","schema=[a, b]"],"operator":"DataSource"}],"id":"LookupJoin_1","info":["kind=inner","stream=stream_ab","table=devices","alias=d","on=stream_ab.a = d.id AND stream_ab.b > 0","schema=[id, name]"],"operator":"LookupJoin"}],"id":"Project_2","info":["fields=[stream_ab.a; d.name]"],"operator":"Project"},"options":null,"physical":{"children":[{"children":[{"children":[{"children":[],"id":"PhysicalDataSource_0","info":["source=stream_ab","schema=[a, b]"],"operator":"PhysicalDataSource"}],"id":"PhysicalDecoder_1","info":["decoder=json","schema=[a, b]"],"operator":"PhysicalDecoder"}],"id":"PhysicalLookupJoin_2","info":["kind=inner","stream=stream_ab","table=devices","keys=[stream_ab.a = d.id]","on=stream_ab.a = d.id AND stream_ab.b > 0","schema=[id, name]"],"operator":"PhysicalLookupJoin"}],"id":"PhysicalProject_3","info":["fields=[stream_ab.a; d.name]"],"operator":"PhysicalProject"}}"##,
+        },
     ];
 
     for case in cases {
@@ -312,6 +354,18 @@ fn plan_explain_optimizer_table_driven() {
             sinks: SINK_NO_BATCH,
             expected: r##"{"logical":{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[],"id":"DataSource_0","info":["source=stream_ab","decoder=json","schema=[a, b]"],"operator":"DataSource"}],"id":"Window_1","info":["kind=state","open=a > 0","emit=a = 4"],"operator":"Window"}],"id":"Aggregation_2","info":["aggregates=[sum(a) -> col_1]","group_by=[b]"],"operator":"Aggregation"}],"id":"Project_3","info":["fields=[col_1]"],"operator":"Project"}],"id":"DataSink_4","info":["sink_id=test_sink","connector=nop","encoder=json"],"operator":"DataSink"}],"id":"Tail_5","info":["sink_count=1"],"operator":"Tail"},"options":null,"physical":{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[],"id":"PhysicalDataSource_0","info":["source=stream_ab","schema=[a, b]"],"operator":"PhysicalDataSource"}],"id":"PhysicalDecoder_1","info":["decoder=json","schema=[a, b]"],"operator":"PhysicalDecoder"}],"id":"PhysicalStreamingAggregation_3","info":["calls=[sum(a) -> col_1]","group_by=[b]","window=state","open=a > 0","emit=a = 4"],"operator":"PhysicalStreamingAggregation"}],"id":"PhysicalProject_4","info":["fields=[col_1]"],"operator":"PhysicalProject"}],"id":"PhysicalEncoder_6","info":["sink_id=test_sink","encoder=json"],"operator":"PhysicalEncoder"}],"id":"PhysicalDataSink_5","info":["sink_id=test_sink","connector=nop"],"operator":"PhysicalDataSink"}],"id":"PhysicalResultCollect_7","info":["sink_count=1"],"operator":"PhysicalResultCollect"}}"##,
         },
+        Case {
+            name: "optimize_rewrites_streaming_agg_for_hopping_window",
+            sql: "SELECT sum(a) FROM stream_ab GROUP BY hoppingwindow('ss', 10, 5),b",
+            sinks: SINK_NO_BATCH,
+            expected: r##"{"logical":{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[],"id":"DataSource_0","info":["source=stream_ab","decoder=json","schema=[a, b]"],"operator":"DataSource"}],"id":"Window_1","info":["kind=hopping","unit=Seconds","size=10","hop=5"],"operator":"Window"}],"id":"Aggregation_2","info":["aggregates=[sum(a) -> col_1]","group_by=[b]"],"operator":"Aggregation"}],"id":"Project_3","info":["fields=[col_1]"],"operator":"Project"}],"id":"DataSink_4","info":["sink_id=test_sink","connector=nop","encoder=json"],"operator":"DataSink"}],"id":"Tail_5","info":["sink_count=1"],"operator":"Tail"},"options":null,"physical":{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[],"id":"PhysicalDataSource_0","info":["source=stream_ab","schema=[a, b]"],"operator":"PhysicalDataSource"}],"id":"PhysicalDecoder_1","info":["decoder=json","schema=[a, b]"],"operator":"PhysicalDecoder"}],"id":"PhysicalProcessTimeWatermark_2","info":["window=hopping","unit=Seconds","size=10","hop=5","mode=processing_time","interval=5"],"operator":"PhysicalProcessTimeWatermark"}],"id":"PhysicalStreamingAggregation_4","info":["calls=[sum(a) -> col_1]","group_by=[b]","window=hopping","unit=Seconds","size=10","hop=5"],"operator":"PhysicalStreamingAggregation"}],"id":"PhysicalProject_5","info":["fields=[col_1]"],"operator":"PhysicalProject"}],"id":"PhysicalEncoder_7","info":["sink_id=test_sink","encoder=json"],"operator":"PhysicalEncoder"}],"id":"PhysicalDataSink_6","info":["sink_id=test_sink","connector=nop"],"operator":"PhysicalDataSink"}],"id":"PhysicalResultCollect_8","info":["sink_count=1"],"operator":"PhysicalResultCollect"}}"##,
+        },
+        Case {
+            name: "optimize_rewrites_streaming_agg_for_partitioned_session_window",
+            sql: "SELECT sum(a) FROM stream_ab GROUP BY sessionwindow('ss', 30, 600) OVER (PARTITION BY b)",
+            sinks: SINK_NO_BATCH,
+            expected: r##"{"logical":{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[],"id":"DataSource_0","info":["source=stream_ab","decoder=json","schema=[a, b]"],"operator":"DataSource"}],"id":"Window_1","info":["kind=session","unit=Seconds","gap=30","max_duration=600","partition_by=b"],"operator":"Window"}],"id":"Aggregation_2","info":["aggregates=[sum(a) -> col_1]"],"operator":"Aggregation"}],"id":"Project_3","info":["fields=[col_1]"],"operator":"Project"}],"id":"DataSink_4","info":["sink_id=test_sink","connector=nop","encoder=json"],"operator":"DataSink"}],"id":"Tail_5","info":["sink_count=1"],"operator":"Tail"},"options":null,"physical":{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[{"children":[],"id":"PhysicalDataSource_0","info":["source=stream_ab","schema=[a, b]"],"operator":"PhysicalDataSource"}],"id":"PhysicalDecoder_1","info":["decoder=json","schema=[a, b]"],"operator":"PhysicalDecoder"}],"id":"PhysicalProcessTimeWatermark_2","info":["window=session","unit=Seconds","gap=30","max_duration=600","mode=processing_time","interval=1"],"operator":"PhysicalProcessTimeWatermark"}],"id":"PhysicalStreamingAggregation_4","info":["calls=[sum(a) -> col_1]","window=session","unit=Seconds","gap=30","max_duration=600","partition_by=b"],"operator":"PhysicalStreamingAggregation"}],"id":"PhysicalProject_5","info":["fields=[col_1]"],"operator":"PhysicalProject"}],"id":"PhysicalEncoder_7","info":["sink_id=test_sink","encoder=json"],"operator":"PhysicalEncoder"}],"id":"PhysicalDataSink_6","info":["sink_id=test_sink","connector=nop"],"operator":"PhysicalDataSink"}],"id":"PhysicalResultCollect_8","info":["sink_count=1"],"operator":"PhysicalResultCollect"}}"##,
+        },
         Case {
             name: "physical_plan_sliding_without_lookahead_includes_watermark_for_gc",
             sql: "SELECT sum(a) FROM stream_ab GROUP BY slidingwindow('ss', 10),b",
@@ -27,16 +27,35 @@ fn individual_registry_defaults_include_builtins() {
     let aggregate_registry = AggregateFunctionRegistry::default();
     assert!(aggregate_registry.is_registered("sum"));
     assert!(aggregate_registry.is_registered("last_row"));
+    for name in [
+        "count",
+        "avg",
+        "min",
+        "max",
+        "first_row",
+        "stddev",
+        "median",
+    ] {
+        assert!(
+            aggregate_registry.is_registered(name),
+            "{name} not registered"
+        );
+    }
 
     let stateful_registry = StatefulFunctionRegistry::default();
     assert!(stateful_registry.is_registered("lag"));
 
     let custom_func_registry = CustomFuncRegistry::default();
     assert!(custom_func_registry.is_registered("concat"));
+    assert!(custom_func_registry.is_registered("now"));
+    assert!(custom_func_registry.is_registered("to_timestamp"));
+    assert!(custom_func_registry.is_registered("date_trunc"));
+    assert!(custom_func_registry.is_registered("extract"));
 
     let eventtime_registry = EventtimeTypeRegistry::default();
     assert!(eventtime_registry.is_registered(BuiltinEventtimeType::UnixtimestampSeconds.key()));
     assert!(eventtime_registry.is_registered(BuiltinEventtimeType::UnixtimestampMillis.key()));
+    assert!(eventtime_registry.is_registered(BuiltinEventtimeType::Timestamp.key()));
 }
 
 #[test]
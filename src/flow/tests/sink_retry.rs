@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use flow::connector::{SinkConnector, SinkConnectorError};
+use flow::planner::sink::SinkRetryProps;
+use flow::processor::{Processor, SinkProcessor, StreamData};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+use tokio::time::{timeout, Duration};
+
+/// Connector that fails while `available` is false, or for its first `fail_first` sends.
+#[derive(Clone, Default)]
+struct FlakyConnector {
+    available: Arc<AtomicBool>,
+    fail_first: Arc<AtomicUsize>,
+    delivered: Arc<Mutex<Vec<Vec<u8>>>>,
+}
+
+#[async_trait]
+impl SinkConnector for FlakyConnector {
+    fn id(&self) -> &str {
+        "flaky"
+    }
+
+    async fn send(&mut self, payload: &[u8]) -> Result<(), SinkConnectorError> {
+        if !self.available.load(Ordering::SeqCst) {
+            return Err(SinkConnectorError::Unavailable("offline".to_string()));
+        }
+        if self
+            .fail_first
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+        {
+            return Err(SinkConnectorError::Other("hiccup".to_string()));
+        }
+        self.delivered.lock().unwrap().push(payload.to_vec());
+        Ok(())
+    }
+}
+
+fn retry_props(max_retries: u32, buffer_capacity: usize) -> SinkRetryProps {
+    SinkRetryProps {
+        max_retries,
+        initial_backoff: Duration::from_millis(5),
+        max_backoff: Duration::from_millis(20),
+        buffer_capacity,
+    }
+}
+
+fn start_sink(
+    connector: &FlakyConnector,
+    retry: SinkRetryProps,
+) -> (
+    broadcast::Sender<StreamData>,
+    broadcast::Receiver<StreamData>,
+    tokio::task::JoinHandle<Result<(), flow::processor::ProcessorError>>,
+) {
+    let mut sink = SinkProcessor::new("sink").with_retry(retry);
+    sink.add_connector(Box::new(connector.clone()));
+    let (input, input_rx) = broadcast::channel(16);
+    sink.add_input(input_rx);
+    let output = sink.subscribe_output().expect("sink output");
+    let handle = sink.start();
+    (input, output, handle)
+}
+
+async fn wait_for_deliveries(connector: &FlakyConnector, count: usize) -> Vec<Vec<u8>> {
+    timeout(Duration::from_secs(2), async {
+        loop {
+            let delivered = connector.delivered.lock().unwrap().clone();
+            if delivered.len() >= count {
+                return delivered;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("deliveries timeout")
+}
+
+#[tokio::test]
+async fn sink_retries_failed_sends_with_backoff() {
+    let connector = FlakyConnector::default();
+    connector.available.store(true, Ordering::SeqCst);
+    connector.fail_first.store(2, Ordering::SeqCst);
+    let (input, _output, handle) = start_sink(&connector, retry_props(3, 0));
+
+    assert!(input.send(StreamData::bytes(b"a".to_vec())).is_ok());
+    assert_eq!(
+        wait_for_deliveries(&connector, 1).await,
+        vec![b"a".to_vec()]
+    );
+
+    assert!(input.send(StreamData::stream_end()).is_ok());
+    handle.await.expect("join sink").expect("sink result");
+}
+
+#[tokio::test]
+async fn sink_reports_error_when_retries_are_exhausted() {
+    let connector = FlakyConnector::default();
+    connector.available.store(true, Ordering::SeqCst);
+    connector.fail_first.store(2, Ordering::SeqCst);
+    let (input, mut output, handle) = start_sink(&connector, retry_props(1, 0));
+
+    assert!(input.send(StreamData::bytes(b"a".to_vec())).is_ok());
+    let reported = timeout(Duration::from_secs(2), output.recv())
+        .await
+        .expect("sink output timeout")
+        .expect("sink output missing");
+    assert!(matches!(reported, StreamData::Error(_)));
+
+    assert!(input.send(StreamData::stream_end()).is_ok());
+    handle.await.expect("join sink").expect("sink result");
+    assert!(connector.delivered.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn sink_buffers_while_connector_is_unavailable() {
+    let connector = FlakyConnector::default();
+    let (input, _output, handle) = start_sink(&connector, retry_props(0, 2));
+
+    for payload in [b"a", b"b", b"c"] {
+        assert!(input.send(StreamData::bytes(payload.to_vec())).is_ok());
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(connector.delivered.lock().unwrap().is_empty());
+
+    // The buffer holds two payloads, so the oldest one was dropped.
+    connector.available.store(true, Ordering::SeqCst);
+    assert_eq!(
+        wait_for_deliveries(&connector, 2).await,
+        vec![b"b".to_vec(), b"c".to_vec()]
+    );
+
+    assert!(input.send(StreamData::stream_end()).is_ok());
+    handle.await.expect("join sink").expect("sink result");
+}
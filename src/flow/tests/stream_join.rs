@@ -0,0 +1,144 @@
+use flow::catalog::{MockStreamProps, StreamDecoderConfig, StreamDefinition, StreamProps};
+use flow::connector::{take_mock_source_handle, MockSourceHandle, MqttClientManager};
+use flow::pipeline::{
+    EventtimeOptions, FileSinkProps, PipelineDefinition, PipelineManager, PipelineOptions,
+    SinkDefinition, SinkProps, SinkType,
+};
+use flow::{
+    shared_stream_registry, Catalog, ColumnSchema, ConcreteDatatype, EventtimeDefinition,
+    Int64Type, PipelineRegistries, Schema,
+};
+use serde_json::Value as JsonValue;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::time::{sleep, Duration};
+
+fn install_stream(catalog: &Catalog, name: &str, key: &str) {
+    let column = |column: &str| {
+        ColumnSchema::new(
+            name.to_string(),
+            column.to_string(),
+            ConcreteDatatype::Int64(Int64Type),
+        )
+    };
+    let schema = Schema::new(vec![column(key), column("ts")]);
+    catalog.upsert(
+        StreamDefinition::new(
+            name.to_string(),
+            Arc::new(schema),
+            StreamProps::Mock(MockStreamProps::default()),
+            StreamDecoderConfig::json(),
+        )
+        .with_eventtime(EventtimeDefinition::new(
+            "ts".to_string(),
+            "unixtimestamp_ms".to_string(),
+        )),
+    );
+}
+
+/// Take the mock source handle of `stream`, whichever plan index its data source received.
+fn mock_handle(pipeline_id: &str, stream: &str) -> MockSourceHandle {
+    (0..16)
+        .find_map(|idx| {
+            take_mock_source_handle(&format!("{pipeline_id}:{stream}:PhysicalDataSource_{idx}"))
+        })
+        .unwrap_or_else(|| panic!("mock handle for {stream}"))
+}
+
+/// Joined `(order id, payment order id)` pairs written to the sink file so far.
+fn joined_pairs(path: &Path) -> Vec<(i64, i64)> {
+    let Ok(text) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let mut pairs = Vec::new();
+    for line in text.lines().filter(|line| !line.is_empty()) {
+        let rows: Vec<JsonValue> = serde_json::from_str(line).expect("sink payload");
+        for row in rows {
+            pairs.push((
+                row["id"].as_i64().expect("id"),
+                row["order_id"].as_i64().expect("order_id"),
+            ));
+        }
+    }
+    pairs.sort();
+    pairs
+}
+
+#[tokio::test]
+async fn stream_join_matches_rows_when_one_side_lags() {
+    let dir = std::env::temp_dir().join(format!("flow-stream-join-{}", uuid::Uuid::new_v4()));
+    let path = dir.join("out.json");
+    let catalog = Arc::new(Catalog::new());
+    install_stream(&catalog, "orders", "id");
+    install_stream(&catalog, "payments", "order_id");
+    let manager = PipelineManager::new(
+        Arc::clone(&catalog),
+        shared_stream_registry(),
+        MqttClientManager::new(),
+        PipelineRegistries::new_with_builtin(),
+    );
+
+    let sink = SinkDefinition::new(
+        "join_sink".to_string(),
+        SinkType::File,
+        SinkProps::File(FileSinkProps::new(path.display().to_string())),
+    );
+    let definition = PipelineDefinition::new(
+        "stream_join_lag".to_string(),
+        "SELECT orders.id, payments.order_id FROM orders JOIN payments \
+         ON orders.id = payments.order_id WITHIN INTERVAL '10' SECOND"
+            .to_string(),
+        vec![sink],
+    )
+    .with_options(PipelineOptions {
+        eventtime: EventtimeOptions {
+            enabled: true,
+            late_tolerance: Duration::ZERO,
+        },
+        ..Default::default()
+    });
+    manager
+        .create_pipeline(definition)
+        .expect("create pipeline");
+    let orders = mock_handle("stream_join_lag", "orders");
+    let payments = mock_handle("stream_join_lag", "payments");
+    manager
+        .start_pipeline("stream_join_lag")
+        .expect("start pipeline");
+
+    // Orders run a minute ahead before the first payment arrives; the lagging payments side
+    // must still find its partners instead of being dropped as late.
+    for payload in [
+        r#"{"id": 1, "ts": 1000}"#,
+        r#"{"id": 2, "ts": 30000}"#,
+        r#"{"id": 3, "ts": 61000}"#,
+    ] {
+        orders.send(payload).await.expect("send order");
+    }
+    sleep(Duration::from_millis(100)).await;
+    for payload in [
+        r#"{"order_id": 1, "ts": 2000}"#,
+        r#"{"order_id": 2, "ts": 45000}"#,
+        r#"{"order_id": 3, "ts": 65000}"#,
+    ] {
+        payments.send(payload).await.expect("send payment");
+    }
+
+    let expected = vec![(1, 1), (3, 3)];
+    let mut pairs = Vec::new();
+    for _ in 0..100 {
+        pairs = joined_pairs(&path);
+        if pairs.len() >= expected.len() {
+            break;
+        }
+        sleep(Duration::from_millis(20)).await;
+    }
+    manager
+        .delete_pipeline("stream_join_lag")
+        .await
+        .expect("delete pipeline");
+    // Order 2 and its payment are 15s apart, outside the join interval.
+    assert_eq!(joined_pairs(&path), expected, "after {pairs:?}");
+    fs::remove_dir_all(dir).ok();
+}
@@ -3,9 +3,10 @@ pub mod storage_bridge;
 mod stream;
 
 use axum::Router;
-use axum::routing::{delete, post};
+use axum::routing::{delete, get, post, put};
 use pipeline::AppState;
 use std::net::SocketAddr;
+use std::time::Duration;
 use storage::StorageManager;
 use tokio::net::TcpListener;
 
@@ -16,6 +17,9 @@ pub(crate) static SOURCE_TOPIC: &str = "/yisa/data";
 pub(crate) static SINK_TOPIC: &str = "/yisa/data2";
 pub(crate) static MQTT_QOS: u8 = 0;
 
+/// How often running pipelines are checkpointed to storage.
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(60);
+
 pub async fn start_server(
     addr: String,
     instance: flow::FlowInstance,
@@ -23,6 +27,21 @@ pub async fn start_server(
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let state = AppState::new(instance, storage);
 
+    let checkpoint_state = state.clone();
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(CHECKPOINT_INTERVAL);
+        // The first tick completes immediately; nothing has run yet at startup.
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            storage_bridge::checkpoint_running_pipelines(
+                &checkpoint_state.storage,
+                &checkpoint_state.instance,
+            )
+            .await;
+        }
+    });
+
     let app = Router::new()
         .route(
             "/pipelines",
@@ -32,12 +51,39 @@ pub async fn start_server(
             "/pipelines/:id/start",
             post(pipeline::start_pipeline_handler),
         )
-        .route("/pipelines/:id", delete(pipeline::delete_pipeline_handler))
+        .route(
+            "/pipelines/:id/status",
+            get(pipeline::pipeline_status_handler),
+        )
+        .route(
+            "/pipelines/:id/explain",
+            get(pipeline::explain_pipeline_handler),
+        )
+        .route("/pipelines/:id/stop", post(pipeline::stop_pipeline_handler))
+        .route(
+            "/pipelines/:id/restart",
+            post(pipeline::restart_pipeline_handler),
+        )
+        .route(
+            "/pipelines/:id/checkpoint",
+            post(pipeline::checkpoint_pipeline_handler),
+        )
+        .route(
+            "/pipelines/:id",
+            put(pipeline::update_pipeline_handler).delete(pipeline::delete_pipeline_handler),
+        )
+        .route("/explain", post(pipeline::explain_sql_handler))
         .route(
             "/streams",
             post(stream::create_stream_handler).get(stream::list_streams),
         )
         .route("/streams/:name", delete(stream::delete_stream_handler))
+        .route(
+            "/streams/:name/rows",
+            put(stream::replace_table_rows_handler),
+        )
+        .route("/streams/:name/reload", post(stream::reload_table_handler))
+        .route("/ingest/*path", post(stream::ingest_http_payload_handler))
         .with_state(state);
 
     let addr: SocketAddr = addr.parse()?;
@@ -7,18 +7,25 @@ use axum::{
 };
 use flow::EncoderRegistry;
 use flow::FlowInstance;
+use flow::connector::FileSyncPolicy;
 use flow::pipeline::{
-    MqttSinkProps, PipelineDefinition, PipelineError, PipelineOptions, PipelineStatus,
-    PlanCacheOptions, SinkDefinition, SinkProps, SinkType,
+    ErrorOptions, FileSinkProps, HttpSinkProps, KafkaSinkProps, MqttSinkProps, PipelineDefinition,
+    PipelineError, PipelineOptions, PipelineRuntimeStatus, PipelineStatus, PlanCacheOptions,
+    SinkDefinition, SinkProps, SinkType,
 };
-use flow::planner::sink::{CommonSinkProps, SinkEncoderConfig};
+use flow::planner::explain::PipelineExplain;
+use flow::planner::sink::{CommonSinkProps, SinkEncoderConfig, SinkRetryProps};
+use flow::processor::{ErrorPolicy, ProcessorErrorRecord, ProcessorMetrics};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map as JsonMap, Value as JsonValue};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use storage::{StorageError, StorageManager};
 
+/// Pipeline id used to plan ad-hoc `POST /explain` requests; nothing is registered under it.
+const EXPLAIN_PIPELINE_ID: &str = "explain";
+
 #[derive(Clone)]
 pub struct AppState {
     pub instance: Arc<FlowInstance>,
@@ -44,6 +51,27 @@ pub struct CreatePipelineRequest {
     pub options: PipelineOptionsRequest,
 }
 
+/// Body of `PUT /pipelines/:id`; the id comes from the path.
+#[derive(Deserialize)]
+pub struct UpdatePipelineRequest {
+    pub sql: String,
+    #[serde(default)]
+    pub sinks: Vec<CreatePipelineSinkRequest>,
+    #[serde(default)]
+    pub options: PipelineOptionsRequest,
+}
+
+impl UpdatePipelineRequest {
+    fn into_create_request(self, id: String) -> CreatePipelineRequest {
+        CreatePipelineRequest {
+            id,
+            sql: self.sql,
+            sinks: self.sinks,
+            options: self.options,
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Clone)]
 #[serde(default)]
 pub struct PipelineOptionsRequest {
@@ -51,6 +79,26 @@ pub struct PipelineOptionsRequest {
     pub plan_cache: PlanCacheOptionsRequest,
     #[serde(default)]
     pub eventtime: EventtimeOptionsRequest,
+    #[serde(default)]
+    pub errors: ErrorOptionsRequest,
+}
+
+/// Handling of records that fail to decode or evaluate: `drop`, `fail` or `dead_letter`.
+#[derive(Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct ErrorOptionsRequest {
+    pub policy: String,
+    /// Dead-letter sink, required when `policy` is `dead_letter`.
+    pub sink: Option<CreatePipelineSinkRequest>,
+}
+
+impl Default for ErrorOptionsRequest {
+    fn default() -> Self {
+        Self {
+            policy: "drop".to_string(),
+            sink: None,
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Default, Clone)]
@@ -73,6 +121,132 @@ pub struct CreatePipelineResponse {
     pub status: String,
 }
 
+#[derive(Serialize)]
+pub struct CheckpointPipelineResponse {
+    pub id: String,
+    pub checkpoint_id: u64,
+}
+
+/// Body of `POST /explain`: SQL to plan without creating a pipeline.
+#[derive(Deserialize)]
+pub struct ExplainRequest {
+    pub sql: String,
+    /// Streams the SQL may read; empty means every registered stream.
+    #[serde(default)]
+    pub streams: Vec<String>,
+    /// Sinks to plan with; a default MQTT sink is used when empty.
+    #[serde(default)]
+    pub sinks: Vec<CreatePipelineSinkRequest>,
+    #[serde(default)]
+    pub options: PipelineOptionsRequest,
+}
+
+#[derive(Serialize)]
+pub struct ExplainResponse {
+    /// Logical and physical plan trees.
+    pub json: JsonValue,
+    /// The same plans rendered as text tables.
+    pub text: String,
+}
+
+impl ExplainResponse {
+    fn from_explain(explain: &PipelineExplain) -> Self {
+        Self {
+            json: explain.to_json(),
+            text: explain.to_pretty_string(),
+        }
+    }
+}
+
+/// Body of `GET /pipelines/:id/status`.
+#[derive(Serialize)]
+pub struct PipelineStatusResponse {
+    pub id: String,
+    pub status: String,
+    /// Milliseconds since the current runtime started; absent when it is not running.
+    pub uptime_ms: Option<u64>,
+    /// Most recent error raised by any processor.
+    pub last_error: Option<ProcessorErrorResponse>,
+    pub processors: Vec<ProcessorStatusResponse>,
+}
+
+#[derive(Serialize)]
+pub struct ProcessorStatusResponse {
+    pub id: String,
+    pub records_in: u64,
+    pub records_out: u64,
+    pub errors: u64,
+    pub last_watermark_ms: Option<u64>,
+    /// Messages emitted by the processor that downstream has not consumed yet.
+    pub queue_depth: Option<usize>,
+    pub last_error: Option<ProcessorErrorResponse>,
+}
+
+#[derive(Serialize)]
+pub struct ProcessorErrorResponse {
+    pub processor: String,
+    pub message: String,
+    pub at_ms: u64,
+}
+
+impl PipelineStatusResponse {
+    fn from_runtime(status: PipelineRuntimeStatus) -> Self {
+        let last_error = status
+            .metrics
+            .processors
+            .iter()
+            .filter_map(|processor| processor.last_error.as_ref())
+            .max_by_key(|error| error.at)
+            .map(ProcessorErrorResponse::from_record);
+        let uptime_ms = match status.snapshot.status {
+            PipelineStatus::Running => status
+                .metrics
+                .uptime
+                .map(|uptime| uptime.as_millis() as u64),
+            PipelineStatus::Created => None,
+        };
+        Self {
+            id: status.snapshot.definition.id().to_string(),
+            status: status_label(status.snapshot.status),
+            uptime_ms,
+            last_error,
+            processors: status
+                .metrics
+                .processors
+                .iter()
+                .map(ProcessorStatusResponse::from_metrics)
+                .collect(),
+        }
+    }
+}
+
+impl ProcessorStatusResponse {
+    fn from_metrics(metrics: &ProcessorMetrics) -> Self {
+        Self {
+            id: metrics.processor_id.clone(),
+            records_in: metrics.records_in,
+            records_out: metrics.records_out,
+            errors: metrics.errors,
+            last_watermark_ms: metrics.last_watermark.map(unix_millis),
+            queue_depth: metrics.queue_len,
+            last_error: metrics
+                .last_error
+                .as_ref()
+                .map(ProcessorErrorResponse::from_record),
+        }
+    }
+}
+
+impl ProcessorErrorResponse {
+    fn from_record(record: &ProcessorErrorRecord) -> Self {
+        Self {
+            processor: record.processor_id.clone(),
+            message: record.message.clone(),
+            at_ms: unix_millis(record.at),
+        }
+    }
+}
+
 #[derive(Serialize)]
 pub struct ListPipelineItem {
     pub id: String,
@@ -131,9 +305,23 @@ impl SinkPropsRequest {
 impl CommonSinkPropsRequest {
     fn to_common_props(&self) -> CommonSinkProps {
         let duration = self.batch_duration_ms.map(Duration::from_millis);
+        let defaults = SinkRetryProps::default();
+        let retry = SinkRetryProps {
+            max_retries: self.max_retries.unwrap_or(defaults.max_retries),
+            initial_backoff: self
+                .retry_backoff_ms
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.initial_backoff),
+            max_backoff: self
+                .max_retry_backoff_ms
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.max_backoff),
+            buffer_capacity: self.buffer_capacity.unwrap_or(defaults.buffer_capacity),
+        };
         CommonSinkProps {
             batch_count: self.batch_count,
             batch_duration: duration,
+            retry,
         }
     }
 }
@@ -149,6 +337,40 @@ pub struct MqttSinkPropsRequest {
     pub connector_key: Option<String>,
 }
 
+#[derive(Deserialize, Serialize, Default, Clone)]
+#[serde(default)]
+pub struct FileSinkPropsRequest {
+    pub path: Option<String>,
+    pub newline_delimited: Option<bool>,
+    pub max_file_bytes: Option<u64>,
+    pub rotate_interval_ms: Option<u64>,
+    pub compress: Option<bool>,
+    /// `never` (default), `always` or `interval`.
+    pub fsync: Option<String>,
+    pub fsync_interval_ms: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Default, Clone)]
+#[serde(default)]
+pub struct HttpSinkPropsRequest {
+    pub url: Option<String>,
+    pub headers: BTreeMap<String, String>,
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Default, Clone)]
+#[serde(default)]
+pub struct KafkaSinkPropsRequest {
+    pub brokers: Option<String>,
+    pub topic: Option<String>,
+    pub partition: Option<i32>,
+    pub key_column: Option<String>,
+    pub headers: BTreeMap<String, String>,
+    pub delivery_timeout_ms: Option<u64>,
+    /// Extra librdkafka producer properties.
+    pub properties: BTreeMap<String, String>,
+}
+
 #[derive(Deserialize, Serialize, Default, Clone)]
 #[serde(default)]
 pub struct CommonSinkPropsRequest {
@@ -156,6 +378,14 @@ pub struct CommonSinkPropsRequest {
     pub batch_count: Option<usize>,
     #[serde(rename = "batchDuration")]
     pub batch_duration_ms: Option<u64>,
+    #[serde(rename = "maxRetries")]
+    pub max_retries: Option<u32>,
+    #[serde(rename = "retryBackoff")]
+    pub retry_backoff_ms: Option<u64>,
+    #[serde(rename = "maxRetryBackoff")]
+    pub max_retry_backoff_ms: Option<u64>,
+    #[serde(rename = "bufferCapacity")]
+    pub buffer_capacity: Option<usize>,
 }
 
 pub async fn create_pipeline_handler(
@@ -283,6 +513,248 @@ pub async fn start_pipeline_handler(
     }
 }
 
+pub async fn stop_pipeline_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    match state.instance.stop_pipeline(&id).await {
+        Ok(_) => {
+            tracing::info!(pipeline_id = %id, "pipeline stopped");
+            (StatusCode::OK, format!("pipeline {id} stopped")).into_response()
+        }
+        Err(PipelineError::NotFound(_)) => {
+            (StatusCode::NOT_FOUND, format!("pipeline {id} not found")).into_response()
+        }
+        Err(err) => (
+            StatusCode::BAD_REQUEST,
+            format!("failed to stop pipeline {id}: {err}"),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn restart_pipeline_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    match state.instance.restart_pipeline(&id).await {
+        Ok(_) => {
+            tracing::info!(pipeline_id = %id, "pipeline restarted");
+            (StatusCode::OK, format!("pipeline {id} restarted")).into_response()
+        }
+        Err(PipelineError::NotFound(_)) => {
+            (StatusCode::NOT_FOUND, format!("pipeline {id} not found")).into_response()
+        }
+        Err(err) => (
+            StatusCode::BAD_REQUEST,
+            format!("failed to restart pipeline {id}: {err}"),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn update_pipeline_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdatePipelineRequest>,
+) -> impl IntoResponse {
+    let req = req.into_create_request(id.clone());
+    if let Err(err) = validate_create_request(&req) {
+        return (StatusCode::BAD_REQUEST, err).into_response();
+    }
+    let encoder_registry = state.instance.encoder_registry();
+    let definition = match build_pipeline_definition(&req, encoder_registry.as_ref()) {
+        Ok(def) => def,
+        Err(err) => return (StatusCode::BAD_REQUEST, err).into_response(),
+    };
+    let stored = match storage_bridge::stored_pipeline_from_request(&req) {
+        Ok(stored) => stored,
+        Err(err) => return (StatusCode::BAD_REQUEST, err).into_response(),
+    };
+
+    let Some(previous) = state.instance.get_pipeline(&id) else {
+        return (StatusCode::NOT_FOUND, format!("pipeline {id} not found")).into_response();
+    };
+    let (snapshot, logical_ir) = match state
+        .instance
+        .update_pipeline_with_logical_ir(definition)
+        .await
+    {
+        Ok(updated) => updated,
+        Err(PipelineError::NotFound(_)) => {
+            return (StatusCode::NOT_FOUND, format!("pipeline {id} not found")).into_response();
+        }
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("failed to update pipeline {id}: {err}"),
+            )
+                .into_response();
+        }
+    };
+    // The stored plan snapshot and checkpoint describe the old SQL; storage drops both.
+    if let Err(err) = state.storage.update_pipeline(stored.clone()) {
+        // Storage still holds the old definition, so the runtime goes back to it.
+        if let Err(rollback_err) = state
+            .instance
+            .update_pipeline(previous.definition.as_ref().clone())
+            .await
+        {
+            tracing::error!(pipeline_id = %id, error = %rollback_err, "failed to restore previous pipeline definition");
+        }
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("failed to persist pipeline {id}, update reverted: {err}"),
+        )
+            .into_response();
+    }
+    if snapshot.definition.options().plan_cache.enabled {
+        // Without a fresh snapshot the next start replans from SQL, so a failure here is not fatal.
+        if let Err(err) = storage_bridge::build_plan_snapshot(
+            state.storage.as_ref(),
+            &stored.id,
+            &stored.raw_json,
+            &snapshot.streams,
+            logical_ir,
+        )
+        .and_then(|record| {
+            state
+                .storage
+                .put_plan_snapshot(record)
+                .map_err(|e| e.to_string())
+        }) {
+            tracing::warn!(pipeline_id = %id, error = %err, "failed to persist plan snapshot");
+        }
+    }
+    tracing::info!(pipeline_id = %id, "pipeline updated");
+    (
+        StatusCode::OK,
+        Json(CreatePipelineResponse {
+            id,
+            status: status_label(snapshot.status),
+        }),
+    )
+        .into_response()
+}
+
+pub async fn checkpoint_pipeline_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let checkpoint = match state.instance.checkpoint_pipeline(&id).await {
+        Ok(checkpoint) => checkpoint,
+        Err(PipelineError::NotFound(_)) => {
+            return (StatusCode::NOT_FOUND, format!("pipeline {id} not found")).into_response();
+        }
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("failed to checkpoint pipeline {id}: {err}"),
+            )
+                .into_response();
+        }
+    };
+    let stored = match storage_bridge::stored_checkpoint_from(&id, &checkpoint) {
+        Ok(stored) => stored,
+        Err(err) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("failed to encode checkpoint of pipeline {id}: {err}"),
+            )
+                .into_response();
+        }
+    };
+    if let Err(err) = state.storage.put_checkpoint(stored) {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("failed to persist checkpoint of pipeline {id}: {err}"),
+        )
+            .into_response();
+    }
+    tracing::info!(pipeline_id = %id, checkpoint_id = checkpoint.checkpoint_id, "pipeline checkpointed");
+    (
+        StatusCode::OK,
+        Json(CheckpointPipelineResponse {
+            id,
+            checkpoint_id: checkpoint.checkpoint_id,
+        }),
+    )
+        .into_response()
+}
+
+pub async fn pipeline_status_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    match state.instance.pipeline_status(&id) {
+        Ok(status) => Json(PipelineStatusResponse::from_runtime(status)).into_response(),
+        Err(PipelineError::NotFound(_)) => {
+            (StatusCode::NOT_FOUND, format!("pipeline {id} not found")).into_response()
+        }
+        Err(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("failed to read status of pipeline {id}: {err}"),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn explain_pipeline_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    match state.instance.explain_pipeline(&id) {
+        Ok(explain) => Json(ExplainResponse::from_explain(&explain)).into_response(),
+        Err(PipelineError::NotFound(_)) => {
+            (StatusCode::NOT_FOUND, format!("pipeline {id} not found")).into_response()
+        }
+        Err(err) => (
+            StatusCode::BAD_REQUEST,
+            format!("failed to explain pipeline {id}: {err}"),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn explain_sql_handler(
+    State(state): State<AppState>,
+    Json(req): Json<ExplainRequest>,
+) -> impl IntoResponse {
+    if req.sql.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST, "sql must not be empty".to_string()).into_response();
+    }
+    let sinks = if req.sinks.is_empty() {
+        vec![CreatePipelineSinkRequest {
+            id: None,
+            sink_type: "mqtt".to_string(),
+            props: SinkPropsRequest::default(),
+            common: CommonSinkPropsRequest::default(),
+            encoder: EncoderConfigRequest::default(),
+        }]
+    } else {
+        req.sinks
+    };
+    let pipeline_req = CreatePipelineRequest {
+        id: EXPLAIN_PIPELINE_ID.to_string(),
+        sql: req.sql,
+        sinks,
+        options: req.options,
+    };
+    let encoder_registry = state.instance.encoder_registry();
+    let definition = match build_pipeline_definition(&pipeline_req, encoder_registry.as_ref()) {
+        Ok(def) => def,
+        Err(err) => return (StatusCode::BAD_REQUEST, err).into_response(),
+    };
+    match state.instance.explain_definition(&definition, &req.streams) {
+        Ok(explain) => Json(ExplainResponse::from_explain(&explain)).into_response(),
+        Err(err) => (
+            StatusCode::BAD_REQUEST,
+            format!("failed to explain sql: {err}"),
+        )
+            .into_response(),
+    }
+}
+
 pub async fn delete_pipeline_handler(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -372,40 +844,28 @@ pub(crate) fn build_pipeline_definition(
             .id
             .clone()
             .unwrap_or_else(|| format!("{}_sink_{index}", req.id));
-        let sink_type = sink_req.sink_type.to_ascii_lowercase();
-        let sink_definition = match sink_type.as_str() {
-            "mqtt" => {
-                let mqtt_props: MqttSinkPropsRequest =
-                    serde_json::from_value(sink_req.props.to_value())
-                        .map_err(|err| format!("invalid mqtt sink props: {err}"))?;
-                let broker = mqtt_props
-                    .broker_url
-                    .unwrap_or_else(|| DEFAULT_BROKER_URL.to_string());
-                let topic = mqtt_props.topic.unwrap_or_else(|| SINK_TOPIC.to_string());
-                let qos = mqtt_props.qos.unwrap_or(MQTT_QOS);
-                let retain = mqtt_props.retain.unwrap_or(false);
-
-                let mut props = MqttSinkProps::new(broker, topic, qos).with_retain(retain);
-                if let Some(client_id) = mqtt_props.client_id {
-                    props = props.with_client_id(client_id);
-                }
-                if let Some(connector_key) = mqtt_props.connector_key {
-                    props = props.with_connector_key(connector_key);
-                }
-                SinkDefinition::new(sink_id.clone(), SinkType::Mqtt, SinkProps::Mqtt(props))
-            }
-            other => return Err(format!("unsupported sink type: {other}")),
-        };
-        let encoder_kind = sink_req.encoder.encode_type.clone();
-        if !encoder_registry.is_registered(&encoder_kind) {
-            return Err(format!("encoder kind `{encoder_kind}` not registered"));
-        }
-        let encoder_config = SinkEncoderConfig::new(encoder_kind, sink_req.encoder.props.clone());
-        let sink_definition = sink_definition
-            .with_encoder(encoder_config)
-            .with_common_props(sink_req.common.to_common_props());
-        sinks.push(sink_definition);
+        sinks.push(build_sink_definition(sink_id, sink_req, encoder_registry)?);
     }
+    let policy = ErrorPolicy::parse(&req.options.errors.policy)
+        .ok_or_else(|| format!("unsupported error policy: {}", req.options.errors.policy))?;
+    let dead_letter = match (&req.options.errors.sink, policy) {
+        (Some(sink_req), ErrorPolicy::DeadLetter) => {
+            let sink_id = sink_req
+                .id
+                .clone()
+                .unwrap_or_else(|| format!("{}_dead_letter", req.id));
+            Some(build_sink_definition(sink_id, sink_req, encoder_registry)?)
+        }
+        (None, ErrorPolicy::DeadLetter) => {
+            return Err("dead_letter error policy requires errors.sink".to_string());
+        }
+        (Some(_), _) => {
+            return Err(
+                "errors.sink is only allowed with the dead_letter error policy".to_string(),
+            );
+        }
+        (None, _) => None,
+    };
     let options = PipelineOptions {
         plan_cache: PlanCacheOptions {
             enabled: req.options.plan_cache.enabled,
@@ -414,13 +874,135 @@ pub(crate) fn build_pipeline_definition(
             enabled: req.options.eventtime.enabled,
             late_tolerance: Duration::from_millis(req.options.eventtime.late_tolerance_ms),
         },
+        errors: ErrorOptions {
+            policy,
+            dead_letter,
+        },
     };
     Ok(PipelineDefinition::new(req.id.clone(), req.sql.clone(), sinks).with_options(options))
 }
 
+fn build_sink_definition(
+    sink_id: String,
+    sink_req: &CreatePipelineSinkRequest,
+    encoder_registry: &EncoderRegistry,
+) -> Result<SinkDefinition, String> {
+    let sink_type = sink_req.sink_type.to_ascii_lowercase();
+    let sink_definition = match sink_type.as_str() {
+        "mqtt" => {
+            let mqtt_props: MqttSinkPropsRequest =
+                serde_json::from_value(sink_req.props.to_value())
+                    .map_err(|err| format!("invalid mqtt sink props: {err}"))?;
+            let broker = mqtt_props
+                .broker_url
+                .unwrap_or_else(|| DEFAULT_BROKER_URL.to_string());
+            let topic = mqtt_props.topic.unwrap_or_else(|| SINK_TOPIC.to_string());
+            let qos = mqtt_props.qos.unwrap_or(MQTT_QOS);
+            let retain = mqtt_props.retain.unwrap_or(false);
+
+            let mut props = MqttSinkProps::new(broker, topic, qos).with_retain(retain);
+            if let Some(client_id) = mqtt_props.client_id {
+                props = props.with_client_id(client_id);
+            }
+            if let Some(connector_key) = mqtt_props.connector_key {
+                props = props.with_connector_key(connector_key);
+            }
+            SinkDefinition::new(sink_id, SinkType::Mqtt, SinkProps::Mqtt(props))
+        }
+        "file" => {
+            let file_props: FileSinkPropsRequest =
+                serde_json::from_value(sink_req.props.to_value())
+                    .map_err(|err| format!("invalid file sink props: {err}"))?;
+            let path = file_props
+                .path
+                .ok_or_else(|| "file sink requires props.path".to_string())?;
+            let sync = match file_props.fsync.as_deref().unwrap_or("never") {
+                "never" => FileSyncPolicy::Never,
+                "always" => FileSyncPolicy::Always,
+                "interval" => FileSyncPolicy::Interval(Duration::from_millis(
+                    file_props.fsync_interval_ms.unwrap_or(1000),
+                )),
+                other => return Err(format!("unsupported file sink fsync policy: {other}")),
+            };
+            let mut props = FileSinkProps::new(path)
+                .with_compress(file_props.compress.unwrap_or(false))
+                .with_sync(sync);
+            if let Some(newline_delimited) = file_props.newline_delimited {
+                props = props.with_newline_delimited(newline_delimited);
+            }
+            if let Some(max_file_bytes) = file_props.max_file_bytes {
+                props = props.with_max_file_bytes(max_file_bytes);
+            }
+            if let Some(interval_ms) = file_props.rotate_interval_ms {
+                props = props.with_rotate_interval(Duration::from_millis(interval_ms));
+            }
+            SinkDefinition::new(sink_id, SinkType::File, SinkProps::File(props))
+        }
+        "http" => {
+            let http_props: HttpSinkPropsRequest =
+                serde_json::from_value(sink_req.props.to_value())
+                    .map_err(|err| format!("invalid http sink props: {err}"))?;
+            let url = http_props
+                .url
+                .ok_or_else(|| "http sink requires props.url".to_string())?;
+            let mut props = HttpSinkProps::new(url);
+            for (name, value) in http_props.headers {
+                props = props.with_header(name, value);
+            }
+            if let Some(timeout_ms) = http_props.timeout_ms {
+                props = props.with_timeout(Duration::from_millis(timeout_ms));
+            }
+            SinkDefinition::new(sink_id, SinkType::Http, SinkProps::Http(props))
+        }
+        "kafka" => {
+            let kafka_props: KafkaSinkPropsRequest =
+                serde_json::from_value(sink_req.props.to_value())
+                    .map_err(|err| format!("invalid kafka sink props: {err}"))?;
+            let brokers = kafka_props
+                .brokers
+                .ok_or_else(|| "kafka sink requires props.brokers".to_string())?;
+            let topic = kafka_props
+                .topic
+                .ok_or_else(|| "kafka sink requires props.topic".to_string())?;
+            let mut props = KafkaSinkProps::new(brokers, topic);
+            if let Some(partition) = kafka_props.partition {
+                props = props.with_partition(partition);
+            }
+            if let Some(key_column) = kafka_props.key_column {
+                props = props.with_key_column(key_column);
+            }
+            for (name, value) in kafka_props.headers {
+                props = props.with_header(name, value);
+            }
+            if let Some(timeout_ms) = kafka_props.delivery_timeout_ms {
+                props = props.with_delivery_timeout(Duration::from_millis(timeout_ms));
+            }
+            for (key, value) in kafka_props.properties {
+                props = props.with_property(key, value);
+            }
+            SinkDefinition::new(sink_id, SinkType::Kafka, SinkProps::Kafka(props))
+        }
+        other => return Err(format!("unsupported sink type: {other}")),
+    };
+    let encoder_kind = sink_req.encoder.encode_type.clone();
+    if !encoder_registry.is_registered(&encoder_kind) {
+        return Err(format!("encoder kind `{encoder_kind}` not registered"));
+    }
+    let encoder_config = SinkEncoderConfig::new(encoder_kind, sink_req.encoder.props.clone());
+    Ok(sink_definition
+        .with_encoder(encoder_config)
+        .with_common_props(sink_req.common.to_common_props()))
+}
+
 fn status_label(status: PipelineStatus) -> String {
     match status {
         PipelineStatus::Created => "created".to_string(),
         PipelineStatus::Running => "running".to_string(),
     }
 }
+
+fn unix_millis(ts: SystemTime) -> u64 {
+    ts.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
@@ -5,10 +5,12 @@ use crate::stream::{
 use flow::catalog::EventtimeDefinition;
 use flow::catalog::StreamDefinition;
 use flow::connector::SharedMqttClientConfig;
-use flow::pipeline::PipelineDefinition;
-use flow::{DecoderRegistry, EncoderRegistry};
+use flow::pipeline::{PipelineDefinition, PipelineStatus};
+use flow::{DecoderRegistry, EncoderRegistry, PipelineCheckpoint};
 use std::sync::Arc;
-use storage::{StorageManager, StoredMqttClientConfig, StoredPipeline, StoredStream};
+use storage::{
+    StorageManager, StoredCheckpoint, StoredMqttClientConfig, StoredPipeline, StoredStream,
+};
 
 fn fnv1a_64_hex(input: &str) -> String {
     const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
@@ -126,6 +128,56 @@ pub fn stored_mqtt_from_config(cfg: &SharedMqttClientConfig) -> StoredMqttClient
     }
 }
 
+pub fn stored_checkpoint_from(
+    pipeline_id: &str,
+    checkpoint: &PipelineCheckpoint,
+) -> Result<StoredCheckpoint, String> {
+    Ok(StoredCheckpoint {
+        pipeline_id: pipeline_id.to_string(),
+        checkpoint_id: checkpoint.checkpoint_id,
+        state: checkpoint.encode().map_err(|err| err.to_string())?,
+    })
+}
+
+pub fn checkpoint_from_stored(stored: &StoredCheckpoint) -> Result<PipelineCheckpoint, String> {
+    PipelineCheckpoint::decode(&stored.state).map_err(|err| {
+        format!(
+            "decode checkpoint of pipeline {}: {err}",
+            stored.pipeline_id
+        )
+    })
+}
+
+/// Checkpoint a running pipeline and persist the result as its latest checkpoint.
+pub async fn checkpoint_pipeline(
+    storage: &StorageManager,
+    instance: &flow::FlowInstance,
+    pipeline_id: &str,
+) -> Result<StoredCheckpoint, String> {
+    let checkpoint = instance
+        .checkpoint_pipeline(pipeline_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let stored = stored_checkpoint_from(pipeline_id, &checkpoint)?;
+    storage
+        .put_checkpoint(stored.clone())
+        .map_err(|e| e.to_string())?;
+    Ok(stored)
+}
+
+/// Checkpoint every running pipeline; failures are logged and do not stop the others.
+pub async fn checkpoint_running_pipelines(storage: &StorageManager, instance: &flow::FlowInstance) {
+    for snapshot in instance.list_pipelines() {
+        if !matches!(snapshot.status, PipelineStatus::Running) {
+            continue;
+        }
+        let pipeline_id = snapshot.definition.id();
+        if let Err(err) = checkpoint_pipeline(storage, instance, pipeline_id).await {
+            tracing::warn!(pipeline_id = %pipeline_id, error = %err, "periodic checkpoint failed");
+        }
+    }
+}
+
 /// Load persisted resources into the running FlowInstance.
 pub async fn load_from_storage(
     storage: &StorageManager,
@@ -192,6 +244,22 @@ pub async fn load_from_storage(
             )
             .map_err(|e| e.to_string())?;
 
+        // A checkpoint that no longer matches the pipeline only costs its state, so it must not
+        // prevent the pipeline from loading.
+        if let Some(stored) = storage
+            .get_checkpoint(&pipeline.id)
+            .map_err(|e| e.to_string())?
+        {
+            match checkpoint_from_stored(&stored) {
+                Ok(checkpoint) => instance
+                    .restore_pipeline_checkpoint(&pipeline.id, checkpoint)
+                    .map_err(|e| e.to_string())?,
+                Err(err) => {
+                    tracing::warn!(pipeline_id = %pipeline.id, error = %err, "ignoring stored checkpoint")
+                }
+            }
+        }
+
         if let Some(logical_ir) = result.logical_plan_ir {
             let stored_snapshot = build_plan_snapshot(
                 storage,
@@ -342,7 +410,7 @@ mod tests {
             &storage,
             &stored_pipeline.id,
             &stored_pipeline.raw_json,
-            &vec!["s1".to_string()],
+            &["s1".to_string()],
             logical_ir,
         )
         .unwrap();
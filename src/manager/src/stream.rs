@@ -3,24 +3,31 @@ use crate::storage_bridge;
 use crate::{DEFAULT_BROKER_URL, MQTT_QOS, SOURCE_TOPIC};
 use axum::{
     Json,
+    body::Bytes,
     extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
 };
 use flow::DecoderRegistry;
-use flow::catalog::{CatalogError, EventtimeDefinition, MqttStreamProps, StreamDecoderConfig};
+use flow::catalog::{
+    CatalogError, EventtimeDefinition, FileStreamProps, HttpStreamProps, KafkaStreamProps,
+    MqttStreamProps, StreamDecoderConfig, TableFileFormat, TableStreamProps,
+};
+use flow::connector::{
+    FileFraming, FileReadMode, HttpPushError, KafkaStartOffset, push_http_payload,
+};
 use flow::shared_stream::{SharedStreamError, SharedStreamInfo, SharedStreamStatus};
 use flow::{FlowInstanceError, Schema, StreamDefinition, StreamProps, StreamRuntimeInfo};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map as JsonMap, Value as JsonValue};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, OnceLock, RwLock};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use flow::{
     BooleanType, ColumnSchema, ConcreteDatatype, Float32Type, Float64Type, Int8Type, Int16Type,
-    Int32Type, Int64Type, ListType, StringType, StructField, StructType, Uint8Type, Uint16Type,
-    Uint32Type, Uint64Type,
+    Int32Type, Int64Type, ListType, StringType, StructField, StructType, TimestampPrecision,
+    TimestampType, Uint8Type, Uint16Type, Uint32Type, Uint64Type,
 };
 use storage::StorageError;
 
@@ -191,6 +198,49 @@ pub struct MqttStreamPropsRequest {
     pub connector_key: Option<String>,
 }
 
+#[derive(Deserialize, Serialize, Default, Clone)]
+#[serde(default)]
+pub struct TableStreamPropsRequest {
+    pub path: Option<String>,
+    pub format: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Default, Clone)]
+#[serde(default)]
+pub struct HttpStreamPropsRequest {
+    /// Path under `/ingest` that payloads are posted to.
+    pub path: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Default, Clone)]
+#[serde(default)]
+pub struct KafkaStreamPropsRequest {
+    pub brokers: Option<String>,
+    pub topic: Option<String>,
+    pub group_id: Option<String>,
+    /// Partitions to read; empty lets the consumer group assign them.
+    pub partitions: Vec<i32>,
+    /// `earliest` or `latest` (default), used when the group has no committed offset.
+    pub start_offset: Option<String>,
+    /// Extra librdkafka consumer properties.
+    pub properties: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, Serialize, Default, Clone)]
+#[serde(default)]
+pub struct FileStreamPropsRequest {
+    /// File path; the last component may contain `*` and `?` to read several files.
+    pub path: Option<String>,
+    /// `lines` (default) or `length_prefixed`.
+    pub framing: Option<String>,
+    /// `once` (default), `follow` or `replay`.
+    pub mode: Option<String>,
+    /// Polling interval of `follow` mode.
+    pub poll_interval_ms: Option<u64>,
+    /// Payloads per second emitted by `replay` mode.
+    pub rate: Option<u32>,
+}
+
 #[derive(Serialize)]
 pub struct StreamInfo {
     pub name: String,
@@ -380,6 +430,53 @@ pub async fn delete_stream_handler(
     }
 }
 
+pub async fn replace_table_rows_handler(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    body: Bytes,
+) -> impl IntoResponse {
+    match state.instance.replace_table_rows(&name, &body) {
+        Ok(count) => {
+            tracing::info!(table_name = %name, rows = count, "table rows replaced");
+            (StatusCode::OK, format!("table {name} loaded {count} rows")).into_response()
+        }
+        Err(err) => map_flow_instance_error(err),
+    }
+}
+
+pub async fn reload_table_handler(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    match state.instance.reload_table(&name) {
+        Ok(count) => {
+            tracing::info!(table_name = %name, rows = count, "table reloaded");
+            (
+                StatusCode::OK,
+                format!("table {name} reloaded {count} rows"),
+            )
+                .into_response()
+        }
+        Err(err) => map_flow_instance_error(err),
+    }
+}
+
+/// Feed a posted body to the HTTP streams of running pipelines listening on `path`.
+pub async fn ingest_http_payload_handler(
+    Path(path): Path<String>,
+    body: Bytes,
+) -> impl IntoResponse {
+    match push_http_payload(&path, body.to_vec()).await {
+        Ok(_) => StatusCode::ACCEPTED.into_response(),
+        Err(err @ HttpPushError::NoRoute(_)) => {
+            (StatusCode::NOT_FOUND, err.to_string()).into_response()
+        }
+        Err(err @ HttpPushError::Closed(_)) => {
+            (StatusCode::SERVICE_UNAVAILABLE, err.to_string()).into_response()
+        }
+    }
+}
+
 fn build_stream_info(info: StreamRuntimeInfo) -> StreamInfo {
     let schema = info.definition.schema();
     let shared_item = info.shared_info.map(into_shared_stream_item);
@@ -497,6 +594,91 @@ pub(crate) fn build_stream_props(
                 connector_key: mqtt_props.connector_key,
             }))
         }
+        "table" => {
+            let table_props: TableStreamPropsRequest = serde_json::from_value(props.to_value())
+                .map_err(|err| format!("invalid table props: {}", err))?;
+            let Some(path) = table_props.path else {
+                return Ok(StreamProps::Table(TableStreamProps::api()));
+            };
+            let path = std::path::PathBuf::from(path);
+            let format = match table_props.format.as_deref() {
+                Some(format) => TableFileFormat::parse(format)
+                    .ok_or_else(|| format!("unsupported table format: {format}"))?,
+                None => TableFileFormat::from_path(&path).ok_or_else(|| {
+                    format!(
+                        "cannot infer table format from {}; set props.format to csv or json",
+                        path.display()
+                    )
+                })?,
+            };
+            Ok(StreamProps::Table(TableStreamProps::file(path, format)))
+        }
+        "file" => {
+            let file_props: FileStreamPropsRequest = serde_json::from_value(props.to_value())
+                .map_err(|err| format!("invalid file props: {}", err))?;
+            let path = file_props
+                .path
+                .ok_or_else(|| "file stream requires props.path".to_string())?;
+            let framing = match file_props.framing.as_deref() {
+                Some(framing) => FileFraming::parse(framing)
+                    .ok_or_else(|| format!("unsupported file framing: {framing}"))?,
+                None => FileFraming::default(),
+            };
+            let mode = match file_props.mode.as_deref().unwrap_or("once") {
+                "once" => FileReadMode::Once,
+                "follow" => FileReadMode::Follow {
+                    poll_interval: Duration::from_millis(
+                        file_props.poll_interval_ms.unwrap_or(500),
+                    ),
+                },
+                "replay" => FileReadMode::Replay {
+                    payloads_per_second: file_props
+                        .rate
+                        .filter(|rate| *rate > 0)
+                        .ok_or_else(|| "replay mode requires a positive props.rate".to_string())?,
+                },
+                other => return Err(format!("unsupported file read mode: {other}")),
+            };
+            Ok(StreamProps::File(
+                FileStreamProps::new(path)
+                    .with_framing(framing)
+                    .with_mode(mode),
+            ))
+        }
+        "http" => {
+            let http_props: HttpStreamPropsRequest = serde_json::from_value(props.to_value())
+                .map_err(|err| format!("invalid http props: {}", err))?;
+            let path = http_props
+                .path
+                .filter(|path| !path.trim_matches('/').is_empty())
+                .ok_or_else(|| "http stream requires props.path".to_string())?;
+            Ok(StreamProps::Http(HttpStreamProps::new(path)))
+        }
+        "kafka" => {
+            let kafka_props: KafkaStreamPropsRequest = serde_json::from_value(props.to_value())
+                .map_err(|err| format!("invalid kafka props: {}", err))?;
+            let brokers = kafka_props
+                .brokers
+                .ok_or_else(|| "kafka stream requires props.brokers".to_string())?;
+            let topic = kafka_props
+                .topic
+                .ok_or_else(|| "kafka stream requires props.topic".to_string())?;
+            let start_offset = match kafka_props.start_offset.as_deref() {
+                Some(offset) => KafkaStartOffset::parse(offset)
+                    .ok_or_else(|| format!("unsupported kafka start_offset: {offset}"))?,
+                None => KafkaStartOffset::default(),
+            };
+            let mut stream_props = KafkaStreamProps::new(brokers, topic)
+                .with_partitions(kafka_props.partitions)
+                .with_start_offset(start_offset);
+            if let Some(group_id) = kafka_props.group_id {
+                stream_props = stream_props.with_group_id(group_id);
+            }
+            for (key, value) in kafka_props.properties {
+                stream_props = stream_props.with_property(key, value);
+            }
+            Ok(StreamProps::Kafka(stream_props))
+        }
         other => Err(format!("unsupported stream type: {other}")),
     }
 }
@@ -540,6 +722,13 @@ fn parse_datatype(column: &StreamColumnRequest) -> Result<ConcreteDatatype, Stri
         "float32" => Ok(ConcreteDatatype::Float32(Float32Type)),
         "float64" => Ok(ConcreteDatatype::Float64(Float64Type)),
         "string" => Ok(ConcreteDatatype::String(StringType)),
+        "timestamp" => Ok(ConcreteDatatype::Timestamp(TimestampType::default())),
+        other if other.starts_with("timestamp(") && other.ends_with(')') => {
+            let unit = &other["timestamp(".len()..other.len() - 1];
+            let precision = TimestampPrecision::parse(unit.trim())
+                .ok_or_else(|| format!("unsupported timestamp precision: {unit}"))?;
+            Ok(ConcreteDatatype::Timestamp(TimestampType::new(precision)))
+        }
         "list" => {
             let element = column.element.as_deref().ok_or_else(|| {
                 format!("list column {} requires element definition", column.name)
@@ -585,10 +774,40 @@ fn datatype_name(datatype: &ConcreteDatatype) -> String {
         ConcreteDatatype::Struct(_) => "struct",
         ConcreteDatatype::List(_) => "list",
         ConcreteDatatype::Bool(_) => "bool",
+        ConcreteDatatype::Timestamp(timestamp_type) => {
+            return format!("timestamp({})", timestamp_type.precision().name());
+        }
     }
     .to_string()
 }
 
+fn into_shared_stream_item(info: SharedStreamInfo) -> SharedStreamItem {
+    let (status, status_message) = shared_stream_status_label(&info.status);
+    SharedStreamItem {
+        id: info.name,
+        status,
+        status_message,
+        connector_id: info.connector_id,
+        subscribers: info.subscriber_count,
+        created_at_secs: unix_timestamp_secs(info.created_at),
+    }
+}
+
+fn shared_stream_status_label(status: &SharedStreamStatus) -> (String, Option<String>) {
+    match status {
+        SharedStreamStatus::Starting => ("starting".to_string(), None),
+        SharedStreamStatus::Running => ("running".to_string(), None),
+        SharedStreamStatus::Stopped => ("stopped".to_string(), None),
+        SharedStreamStatus::Failed(msg) => ("failed".to_string(), Some(msg.clone())),
+    }
+}
+
+fn unix_timestamp_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -688,31 +907,51 @@ mod tests {
         };
         assert!(parse_datatype(&missing_element).is_err());
     }
-}
 
-fn into_shared_stream_item(info: SharedStreamInfo) -> SharedStreamItem {
-    let (status, status_message) = shared_stream_status_label(&info.status);
-    SharedStreamItem {
-        id: info.name,
-        status,
-        status_message,
-        connector_id: info.connector_id,
-        subscribers: info.subscriber_count,
-        created_at_secs: unix_timestamp_secs(info.created_at),
+    #[test]
+    fn parse_timestamp_datatype_roundtrips_name() {
+        let column = |data_type: &str| StreamColumnRequest {
+            name: "ts".to_string(),
+            data_type: data_type.to_string(),
+            fields: None,
+            element: None,
+        };
+        let default = parse_datatype(&column("timestamp")).expect("timestamp");
+        assert_eq!(datatype_name(&default), "timestamp(ms)");
+        let seconds = parse_datatype(&column("TIMESTAMP(s)")).expect("timestamp(s)");
+        assert_eq!(
+            seconds,
+            ConcreteDatatype::Timestamp(TimestampType::new(TimestampPrecision::Second))
+        );
+        assert_eq!(
+            parse_datatype(&column(&datatype_name(&seconds))).unwrap(),
+            seconds
+        );
+        assert!(parse_datatype(&column("timestamp(days)")).is_err());
     }
-}
 
-fn shared_stream_status_label(status: &SharedStreamStatus) -> (String, Option<String>) {
-    match status {
-        SharedStreamStatus::Starting => ("starting".to_string(), None),
-        SharedStreamStatus::Running => ("running".to_string(), None),
-        SharedStreamStatus::Stopped => ("stopped".to_string(), None),
-        SharedStreamStatus::Failed(msg) => ("failed".to_string(), Some(msg.clone())),
-    }
-}
+    #[test]
+    fn build_table_props_from_path_or_api() {
+        let props = |value: JsonValue| StreamPropsRequest {
+            fields: value.as_object().cloned().unwrap_or_default(),
+        };
 
-fn unix_timestamp_secs(time: SystemTime) -> u64 {
-    time.duration_since(UNIX_EPOCH)
-        .map(|duration| duration.as_secs())
-        .unwrap_or(0)
+        match build_stream_props("table", &props(serde_json::json!({"path": "/tmp/d.csv"}))) {
+            Ok(StreamProps::Table(table)) => assert_eq!(
+                table.source,
+                flow::TableSource::File {
+                    path: "/tmp/d.csv".into(),
+                    format: TableFileFormat::Csv,
+                }
+            ),
+            _ => panic!("expected file-backed table props"),
+        }
+        match build_stream_props("table", &props(serde_json::json!({}))) {
+            Ok(StreamProps::Table(table)) => assert_eq!(table.source, flow::TableSource::Api),
+            _ => panic!("expected api table props"),
+        }
+        assert!(
+            build_stream_props("table", &props(serde_json::json!({"path": "/tmp/d.txt"}))).is_err()
+        );
+    }
 }
@@ -27,5 +27,20 @@ impl AggregateRegistry for StaticAggregateRegistry {
 }
 
 pub fn default_aggregate_registry() -> Arc<dyn AggregateRegistry> {
-    Arc::new(StaticAggregateRegistry::new(["sum", "last_row", "ndv"]))
+    Arc::new(StaticAggregateRegistry::new([
+        "sum",
+        "count",
+        "avg",
+        "min",
+        "max",
+        "first_row",
+        "last_row",
+        "ndv",
+        "variance",
+        "var_samp",
+        "stddev",
+        "stddev_samp",
+        "percentile",
+        "median",
+    ]))
 }
@@ -1,11 +1,14 @@
 use sqlparser::ast::{Expr, GroupByExpr, SetExpr, Statement};
-use sqlparser::parser::ParserError;
+use sqlparser::parser::{Parser, ParserError};
 
+use super::join;
 use super::window;
 pub use window::{Window, parse_window_expr, window_to_expr};
 
 /// Stream processing dialect that supports window functions in GROUP BY clauses
-/// Supported windows: tumblingwindow, slidingwindow, countwindow, statewindow
+/// Supported windows: tumblingwindow, hoppingwindow, slidingwindow, countwindow, sessionwindow,
+/// statewindow
+/// Stream joins are bounded with `JOIN ... ON <cond> WITHIN INTERVAL '<n>' <unit>`
 #[derive(Debug, Clone)]
 pub struct StreamDialect {}
 
@@ -41,6 +44,26 @@ impl sqlparser::dialect::Dialect for StreamDialect {
     fn supports_group_by_expr(&self) -> bool {
         true
     }
+
+    /// Parse the `WITHIN INTERVAL ...` bound that closes a stream JOIN's ON condition
+    fn parse_infix(
+        &self,
+        parser: &mut Parser,
+        expr: &Expr,
+        _precedence: u8,
+    ) -> Option<Result<Expr, ParserError>> {
+        if join::peek_within(parser) {
+            return Some(join::parse_within(parser, expr));
+        }
+        None
+    }
+
+    fn get_next_precedence(&self, parser: &Parser) -> Option<Result<u8, ParserError>> {
+        if join::peek_within(parser) {
+            return Some(Ok(join::WITHIN_PRECEDENCE));
+        }
+        None
+    }
 }
 
 /// Collect window + remaining GROUP BY expressions present in a parsed statement
@@ -0,0 +1,234 @@
+use std::time::Duration;
+
+use sqlparser::ast::{
+    DateTimeField, Expr, Function, FunctionArg, FunctionArgExpr, Ident, Interval, JoinConstraint,
+    JoinOperator, ObjectName, Select, TableFactor, Value,
+};
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::{Parser, ParserError};
+use sqlparser::tokenizer::Token;
+
+use crate::select_stmt::SourceInfo;
+
+/// Name of the marker function the dialect wraps `<cond> WITHIN <interval>` into.
+const WITHIN_MARKER: &str = "__within";
+
+/// Precedence used for the `WITHIN` infix; lower than OR so it binds the whole condition.
+pub(crate) const WITHIN_PRECEDENCE: u8 = 1;
+
+/// Interval-bounded inner join between two streams:
+/// `FROM a JOIN b ON <condition> WITHIN INTERVAL '<n>' <unit>`.
+///
+/// Two rows join when `condition` holds and their timestamps differ by at most `within`.
+/// A JOIN without a WITHIN bound still parses; the planner rejects it as unbounded.
+#[derive(Debug, Clone)]
+pub struct StreamJoin {
+    /// Left (first) stream in the FROM clause
+    pub left: SourceInfo,
+    /// Right (joined) stream
+    pub right: SourceInfo,
+    /// ON condition, without the WITHIN bound
+    pub condition: Expr,
+    /// Maximum timestamp distance between matching rows
+    pub within: Option<Duration>,
+}
+
+/// Returns true when the parser is positioned at a join `WITHIN` (and not `WITHIN GROUP`).
+pub(crate) fn peek_within(parser: &Parser) -> bool {
+    let is_within = matches!(
+        parser.peek_token().token,
+        Token::Word(ref w) if w.keyword == Keyword::WITHIN
+    );
+    let is_within_group = matches!(
+        parser.peek_nth_token(1).token,
+        Token::Word(ref w) if w.keyword == Keyword::GROUP
+    );
+    is_within && !is_within_group
+}
+
+/// Parse `WITHIN INTERVAL ...` following `condition` into a marker expression.
+pub(crate) fn parse_within(parser: &mut Parser, condition: &Expr) -> Result<Expr, ParserError> {
+    parser.expect_keyword(Keyword::WITHIN)?;
+    parser.expect_keyword(Keyword::INTERVAL)?;
+    let interval = parser.parse_interval()?;
+    Ok(Expr::Function(Function {
+        name: ObjectName(vec![Ident::new(WITHIN_MARKER)]),
+        args: vec![
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(condition.clone())),
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(interval)),
+        ],
+        over: None,
+        distinct: false,
+        order_by: vec![],
+        filter: None,
+        null_treatment: None,
+        special: false,
+    }))
+}
+
+/// Extract the stream join declared in the FROM clause, if any.
+pub fn extract_stream_join(select: &Select) -> Result<Option<StreamJoin>, String> {
+    let Some(from) = select.from.first() else {
+        return Ok(None);
+    };
+    if from.joins.is_empty() {
+        return Ok(None);
+    }
+    if select.from.len() > 1 || from.joins.len() > 1 {
+        return Err("Only a single JOIN between two streams is supported".to_string());
+    }
+
+    let join = &from.joins[0];
+    let on_expr = match &join.join_operator {
+        JoinOperator::Inner(JoinConstraint::On(expr)) => expr,
+        JoinOperator::Inner(_) => {
+            return Err("Stream JOIN requires an ON condition".to_string());
+        }
+        other => {
+            return Err(format!(
+                "Unsupported join type {:?}: only inner stream joins are supported",
+                other
+            ));
+        }
+    };
+
+    let (condition, within) = split_within(on_expr)?;
+    let left = source_info_from_factor(&from.relation)?;
+    let right = source_info_from_factor(&join.relation)?;
+    if left.name == right.name {
+        return Err(format!(
+            "Stream JOIN requires two distinct streams, got {} twice",
+            left.name
+        ));
+    }
+
+    Ok(Some(StreamJoin {
+        left,
+        right,
+        condition,
+        within,
+    }))
+}
+
+fn source_info_from_factor(factor: &TableFactor) -> Result<SourceInfo, String> {
+    match factor {
+        TableFactor::Table { name, alias, .. } => Ok(SourceInfo {
+            name: name.to_string(),
+            alias: alias.as_ref().map(|a| a.name.value.clone()),
+        }),
+        other => Err(format!("Unsupported JOIN relation: {}", other)),
+    }
+}
+
+fn split_within(expr: &Expr) -> Result<(Expr, Option<Duration>), String> {
+    let func = match expr {
+        Expr::Function(func) if func.name.to_string() == WITHIN_MARKER => func,
+        _ => return Ok((expr.clone(), None)),
+    };
+    if func.args.len() != 2 {
+        return Err("Invalid WITHIN bound in JOIN condition".to_string());
+    }
+
+    let arg_expr = |arg: &FunctionArg| match arg {
+        FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => Some(expr.clone()),
+        _ => None,
+    };
+    let condition = arg_expr(&func.args[0])
+        .ok_or_else(|| "Invalid JOIN condition before WITHIN".to_string())?;
+    let interval = match arg_expr(&func.args[1]) {
+        Some(Expr::Interval(interval)) => interval,
+        _ => return Err("WITHIN expects an INTERVAL literal".to_string()),
+    };
+    let within = interval_to_duration(&interval)?;
+    Ok((condition, Some(within)))
+}
+
+fn interval_to_duration(interval: &Interval) -> Result<Duration, String> {
+    let raw = match interval.value.as_ref() {
+        Expr::Value(Value::SingleQuotedString(s)) | Expr::Value(Value::Number(s, _)) => s.trim(),
+        other => return Err(format!("Unsupported WITHIN interval value: {}", other)),
+    };
+    let amount: u64 = raw.parse().map_err(|_| {
+        format!(
+            "WITHIN interval must be a non-negative integer, got {}",
+            raw
+        )
+    })?;
+
+    let millis_per_unit: u64 = match interval.leading_field {
+        Some(DateTimeField::Millisecond) | Some(DateTimeField::Milliseconds) => 1,
+        Some(DateTimeField::Second) => 1_000,
+        Some(DateTimeField::Minute) => 60_000,
+        Some(DateTimeField::Hour) => 3_600_000,
+        Some(DateTimeField::Day) => 86_400_000,
+        None => {
+            return Err("WITHIN interval requires a unit, e.g. INTERVAL '10' SECOND".to_string());
+        }
+        Some(ref other) => return Err(format!("Unsupported WITHIN interval unit: {}", other)),
+    };
+
+    amount
+        .checked_mul(millis_per_unit)
+        .map(Duration::from_millis)
+        .ok_or_else(|| "WITHIN interval is too large".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialect::StreamDialect;
+    use sqlparser::ast::{SetExpr, Statement};
+
+    fn parse_select(sql: &str) -> Select {
+        let statements = Parser::parse_sql(&StreamDialect::new(), sql).unwrap();
+        match statements.into_iter().next().unwrap() {
+            Statement::Query(query) => match *query.body {
+                SetExpr::Select(select) => *select,
+                _ => panic!("expected select"),
+            },
+            _ => panic!("expected query"),
+        }
+    }
+
+    #[test]
+    fn extract_join_with_within_bound() {
+        let select = parse_select(
+            "SELECT a.x FROM s1 AS a JOIN s2 AS b ON a.id = b.id AND a.x > 1 WITHIN INTERVAL '10' SECOND",
+        );
+        let join = extract_stream_join(&select).unwrap().expect("join");
+        assert_eq!(join.left.name, "s1");
+        assert_eq!(join.left.alias.as_deref(), Some("a"));
+        assert_eq!(join.right.name, "s2");
+        assert_eq!(join.condition.to_string(), "a.id = b.id AND a.x > 1");
+        assert_eq!(join.within, Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn within_group_is_left_to_sqlparser() {
+        let dialect = StreamDialect::new();
+        let sql = "SELECT listagg(a) WITHIN GROUP (ORDER BY a) FROM s1";
+        assert!(Parser::parse_sql(&dialect, sql).is_ok());
+    }
+
+    #[test]
+    fn join_without_within_has_no_bound() {
+        let select = parse_select("SELECT * FROM s1 JOIN s2 ON s1.id = s2.id");
+        let join = extract_stream_join(&select).unwrap().expect("join");
+        assert_eq!(join.condition.to_string(), "s1.id = s2.id");
+        assert!(join.within.is_none());
+    }
+
+    #[test]
+    fn reject_outer_join_and_self_join() {
+        let select = parse_select(
+            "SELECT * FROM s1 LEFT JOIN s2 ON s1.id = s2.id WITHIN INTERVAL '1' MINUTE",
+        );
+        assert!(extract_stream_join(&select).is_err());
+
+        let select = parse_select(
+            "SELECT * FROM s1 AS a JOIN s1 AS b ON a.id = b.id WITHIN INTERVAL '1' MINUTE",
+        );
+        let err = extract_stream_join(&select).unwrap_err();
+        assert!(err.contains("distinct"), "{err}");
+    }
+}
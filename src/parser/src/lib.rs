@@ -3,6 +3,7 @@ pub mod aggregate_transformer;
 pub mod col_placeholder_allocator;
 pub mod dialect;
 pub mod expression_extractor;
+pub mod join;
 pub mod parser;
 pub mod select_stmt;
 pub mod stateful_registry;
@@ -20,6 +21,7 @@ pub use expression_extractor::{
     ExpressionAnalysis, analyze_sql_expressions, extract_expressions_from_sql,
     extract_select_expressions_simple,
 };
+pub use join::StreamJoin;
 pub use parser::{StreamSqlParser, parse_sql, parse_sql_with_registries};
 pub use select_stmt::{SelectField, SelectStmt};
 pub use stateful_registry::{StatefulRegistry, StaticStatefulRegistry, default_stateful_registry};
@@ -5,6 +5,7 @@ use crate::aggregate_registry::{AggregateRegistry, default_aggregate_registry};
 use crate::aggregate_transformer::transform_aggregate_functions;
 use crate::col_placeholder_allocator::ColPlaceholderAllocator;
 use crate::dialect::StreamDialect;
+use crate::join::extract_stream_join;
 use crate::select_stmt::{SelectField, SelectStmt};
 use crate::stateful_registry::{StatefulRegistry, default_stateful_registry};
 use crate::stateful_transformer::transform_stateful_functions;
@@ -140,10 +141,12 @@ impl StreamSqlParser {
         let mut table_visitor = TableInfoVisitor::new();
         let _ = select.visit(&mut table_visitor);
         let source_infos = table_visitor.get_sources();
+        let join = extract_stream_join(select)?;
 
         let mut select_stmt =
             SelectStmt::with_fields_and_conditions(select_fields, where_condition, having);
         select_stmt.source_infos = source_infos;
+        select_stmt.join = join;
 
         Ok(select_stmt)
     }
@@ -315,6 +318,28 @@ mod source_info_tests {
         assert_eq!(select_stmt.source_infos[0].alias, Some("u".to_string()));
         assert_eq!(select_stmt.source_infos[1].name, "orders");
         assert_eq!(select_stmt.source_infos[1].alias, Some("o".to_string()));
+        assert!(select_stmt.join.is_none());
+    }
+
+    #[test]
+    fn test_parse_select_with_interval_join() {
+        let parser = StreamSqlParser::new();
+        let select_stmt = parser
+            .parse(
+                "SELECT u.a, o.b FROM users AS u JOIN orders AS o ON u.id = o.uid WITHIN INTERVAL '5' MINUTE WHERE u.a > 1",
+            )
+            .unwrap();
+
+        assert_eq!(select_stmt.source_infos.len(), 2);
+        let join = select_stmt.join.expect("join");
+        assert_eq!(join.left.name, "users");
+        assert_eq!(join.right.alias, Some("o".to_string()));
+        assert_eq!(join.condition.to_string(), "u.id = o.uid");
+        assert_eq!(join.within, Some(std::time::Duration::from_secs(300)));
+        assert_eq!(
+            select_stmt.where_condition.map(|e| e.to_string()),
+            Some("u.a > 1".to_string())
+        );
     }
 
     #[test]
@@ -372,6 +397,29 @@ mod source_info_tests {
         }
     }
 
+    #[test]
+    fn parse_group_by_hopping_window() {
+        let parser = StreamSqlParser::new();
+        let result = parser.parse("SELECT * FROM stream GROUP BY hoppingwindow('ss', 300, 60), b");
+
+        assert!(result.is_ok(), "parse failed: {:?}", result);
+        let select_stmt = result.unwrap();
+        assert_eq!(select_stmt.group_by_exprs.len(), 1);
+
+        match select_stmt.window {
+            Some(Window::Hopping {
+                time_unit,
+                size,
+                hop,
+            }) => {
+                assert_eq!(time_unit, crate::window::TimeUnit::Seconds);
+                assert_eq!(size, 300);
+                assert_eq!(hop, 60);
+            }
+            other => panic!("Expected hopping window, got {:?}", other),
+        }
+    }
+
     #[test]
     fn reject_multiple_windows() {
         let parser = StreamSqlParser::new();
@@ -528,4 +576,29 @@ mod source_info_tests {
             other => panic!("expected state window, got {:?}", other),
         }
     }
+
+    #[test]
+    fn parse_group_by_session_window_over_partition_by() {
+        let parser = StreamSqlParser::new();
+        let sql = "SELECT * FROM stream GROUP BY sessionwindow('ss', 30, 600) OVER (PARTITION BY device_id)";
+        let result = parser.parse(sql);
+
+        assert!(result.is_ok(), "parse failed: {:?}", result);
+        let select_stmt = result.unwrap();
+
+        match select_stmt.window {
+            Some(Window::Session {
+                gap,
+                max_duration,
+                partition_by,
+                ..
+            }) => {
+                assert_eq!(gap, 30);
+                assert_eq!(max_duration, Some(600));
+                assert_eq!(partition_by.len(), 1);
+                assert_eq!(partition_by[0].to_string(), "device_id");
+            }
+            other => panic!("expected session window, got {:?}", other),
+        }
+    }
 }
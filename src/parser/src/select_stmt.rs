@@ -1,6 +1,7 @@
 use sqlparser::ast::Expr;
 use std::collections::HashMap;
 
+use crate::join::StreamJoin;
 use crate::window::Window;
 
 /// Represents information about a data source (table)
@@ -31,6 +32,8 @@ pub struct SelectStmt {
     pub stateful_mappings: HashMap<String, Expr>,
     /// Information about the data sources (tables) accessed
     pub source_infos: Vec<SourceInfo>,
+    /// Optional stream-stream join declared in FROM
+    pub join: Option<StreamJoin>,
 }
 
 /// Represents a single select field/expression
@@ -56,6 +59,7 @@ impl SelectStmt {
             aggregate_mappings: HashMap::new(),
             stateful_mappings: HashMap::new(),
             source_infos: Vec::new(),
+            join: None,
         }
     }
 
@@ -70,6 +74,7 @@ impl SelectStmt {
             aggregate_mappings: HashMap::new(),
             stateful_mappings: HashMap::new(),
             source_infos: Vec::new(),
+            join: None,
         }
     }
 
@@ -88,6 +93,7 @@ impl SelectStmt {
             aggregate_mappings: HashMap::new(),
             stateful_mappings: HashMap::new(),
             source_infos: Vec::new(),
+            join: None,
         }
     }
 }
@@ -8,6 +8,13 @@ use sqlparser::parser::ParserError;
 pub enum Window {
     /// Fixed-size, non-overlapping window defined by time unit + length
     Tumbling { time_unit: TimeUnit, length: u64 },
+    /// Fixed-size window that advances by `hop`, so consecutive windows overlap
+    /// when `hop < size`. Window starts are aligned to multiples of `hop`.
+    Hopping {
+        time_unit: TimeUnit,
+        size: u64,
+        hop: u64,
+    },
     /// Fixed-size window defined by number of rows
     Count { count: u64 },
     /// Sliding window triggered by each received record.
@@ -20,6 +27,17 @@ pub enum Window {
         lookback: u64,
         lookahead: Option<u64>,
     },
+    /// Session window that closes after `gap` without new records.
+    ///
+    /// When `max_duration` is set, a session is also closed once it has been open that long.
+    Session {
+        time_unit: TimeUnit,
+        gap: u64,
+        max_duration: Option<u64>,
+        /// Optional partition keys extracted from `OVER (PARTITION BY ...)`.
+        /// When empty, the window is global (single partition).
+        partition_by: Vec<Expr>,
+    },
     /// State window driven by two boolean conditions:
     /// - `open`: when the window starts collecting state
     /// - `emit`: when the window emits its current state
@@ -35,7 +53,16 @@ pub enum Window {
 /// Supported time units for window definitions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TimeUnit {
+    /// `ms`
+    Milliseconds,
+    /// `ss`
     Seconds,
+    /// `mi`
+    Minutes,
+    /// `hh`
+    Hours,
+    /// `dd`
+    Days,
 }
 
 impl Window {
@@ -43,6 +70,14 @@ impl Window {
         Window::Tumbling { time_unit, length }
     }
 
+    pub fn hopping(time_unit: TimeUnit, size: u64, hop: u64) -> Self {
+        Window::Hopping {
+            time_unit,
+            size,
+            hop,
+        }
+    }
+
     pub fn count(count: u64) -> Self {
         Window::Count { count }
     }
@@ -55,6 +90,24 @@ impl Window {
         }
     }
 
+    pub fn session(time_unit: TimeUnit, gap: u64, max_duration: Option<u64>) -> Self {
+        Window::session_partitioned(time_unit, gap, max_duration, Vec::new())
+    }
+
+    pub fn session_partitioned(
+        time_unit: TimeUnit,
+        gap: u64,
+        max_duration: Option<u64>,
+        partition_by: Vec<Expr>,
+    ) -> Self {
+        Window::Session {
+            time_unit,
+            gap,
+            max_duration,
+            partition_by,
+        }
+    }
+
     pub fn state(open: Expr, emit: Expr) -> Self {
         Window::state_partitioned(open, emit, Vec::new())
     }
@@ -70,8 +123,10 @@ impl Window {
     fn function_name(&self) -> &'static str {
         match self {
             Window::Tumbling { .. } => "tumblingwindow",
+            Window::Hopping { .. } => "hoppingwindow",
             Window::Count { .. } => "countwindow",
             Window::Sliding { .. } => "slidingwindow",
+            Window::Session { .. } => "sessionwindow",
             Window::State { .. } => "statewindow",
         }
     }
@@ -88,12 +143,14 @@ pub fn parse_window_expr(expr: &Expr) -> Result<Option<Window>, ParserError> {
     Ok(None)
 }
 
-/// Parse a window function (tumblingwindow/countwindow) into a Window enum
+/// Parse a window function (tumblingwindow/hoppingwindow/countwindow/...) into a Window enum
 pub fn parse_window_function(function: &Function) -> Result<Window, ParserError> {
     match function.name.to_string().to_lowercase().as_str() {
         "tumblingwindow" => parse_tumbling_window(function),
+        "hoppingwindow" => parse_hopping_window(function),
         "countwindow" => parse_count_window(function),
         "slidingwindow" => parse_sliding_window(function),
+        "sessionwindow" => parse_session_window(function),
         "statewindow" => parse_state_window(function),
         name => Err(ParserError::ParserError(format!(
             "Unsupported window function: {}",
@@ -111,6 +168,15 @@ pub fn window_to_expr(window: &Window) -> Expr {
                 make_number_arg(*length),
             ]
         }
+        Window::Hopping {
+            time_unit,
+            size,
+            hop,
+        } => vec![
+            make_string_arg(time_unit.as_str()),
+            make_number_arg(*size),
+            make_number_arg(*hop),
+        ],
         Window::Count { count } => vec![make_number_arg(*count)],
         Window::Sliding {
             time_unit,
@@ -126,6 +192,18 @@ pub fn window_to_expr(window: &Window) -> Expr {
             }
             args
         }
+        Window::Session {
+            time_unit,
+            gap,
+            max_duration,
+            ..
+        } => {
+            let mut args = vec![make_string_arg(time_unit.as_str()), make_number_arg(*gap)];
+            if let Some(max_duration) = max_duration {
+                args.push(make_number_arg(*max_duration));
+            }
+            args
+        }
         Window::State { open, emit, .. } => vec![
             make_expr_arg(open.as_ref().clone()),
             make_expr_arg(emit.as_ref().clone()),
@@ -133,7 +211,9 @@ pub fn window_to_expr(window: &Window) -> Expr {
     };
 
     let over = match window {
-        Window::State { partition_by, .. } if !partition_by.is_empty() => {
+        Window::State { partition_by, .. } | Window::Session { partition_by, .. }
+            if !partition_by.is_empty() =>
+        {
             Some(WindowType::WindowSpec(WindowSpec {
                 partition_by: partition_by.clone(),
                 order_by: Vec::new(),
@@ -171,6 +251,34 @@ fn parse_tumbling_window(function: &Function) -> Result<Window, ParserError> {
     Ok(Window::tumbling(time_unit, length))
 }
 
+fn parse_hopping_window(function: &Function) -> Result<Window, ParserError> {
+    ensure_no_over(function, "hoppingwindow")?;
+    if function.args.len() != 3 {
+        return Err(ParserError::ParserError(
+            "hoppingwindow requires 3 arguments: (time_unit, size, hop)".to_string(),
+        ));
+    }
+
+    let time_unit = parse_string_arg(&function.args[0], "hoppingwindow", "time unit")?;
+    let size = parse_number_arg(&function.args[1], "hoppingwindow", "size")?;
+    let hop = parse_number_arg(&function.args[2], "hoppingwindow", "hop")?;
+
+    if size == 0 || hop == 0 {
+        return Err(ParserError::ParserError(
+            "hoppingwindow size and hop must be greater than 0".to_string(),
+        ));
+    }
+    if hop > size {
+        return Err(ParserError::ParserError(format!(
+            "hoppingwindow hop ({hop}) must not exceed size ({size})"
+        )));
+    }
+
+    let time_unit = TimeUnit::try_from_str(&time_unit)?;
+
+    Ok(Window::hopping(time_unit, size, hop))
+}
+
 fn parse_count_window(function: &Function) -> Result<Window, ParserError> {
     ensure_no_over(function, "countwindow")?;
     if function.args.len() != 1 {
@@ -209,6 +317,43 @@ fn parse_sliding_window(function: &Function) -> Result<Window, ParserError> {
     Ok(Window::sliding(time_unit, lookback, lookahead))
 }
 
+fn parse_session_window(function: &Function) -> Result<Window, ParserError> {
+    if function.args.len() != 2 && function.args.len() != 3 {
+        return Err(ParserError::ParserError(
+            "sessionwindow requires 2 or 3 arguments: (time_unit, gap [, max_duration])"
+                .to_string(),
+        ));
+    }
+
+    let time_unit = parse_string_arg(&function.args[0], "sessionwindow", "time unit")?;
+    let gap = parse_number_arg(&function.args[1], "sessionwindow", "gap")?;
+    let max_duration = if function.args.len() == 3 {
+        Some(parse_number_arg(
+            &function.args[2],
+            "sessionwindow",
+            "max_duration",
+        )?)
+    } else {
+        None
+    };
+
+    if gap == 0 || max_duration == Some(0) {
+        return Err(ParserError::ParserError(
+            "sessionwindow gap and max_duration must be greater than 0".to_string(),
+        ));
+    }
+
+    let time_unit = TimeUnit::try_from_str(&time_unit)?;
+    let partition_by = parse_over_partition_by(function, "sessionwindow")?;
+
+    Ok(Window::session_partitioned(
+        time_unit,
+        gap,
+        max_duration,
+        partition_by,
+    ))
+}
+
 fn parse_state_window(function: &Function) -> Result<Window, ParserError> {
     if function.args.len() != 2 {
         return Err(ParserError::ParserError(
@@ -314,16 +459,25 @@ fn parse_number_arg(
 pub(crate) fn is_supported_window_function(name: &str) -> bool {
     matches!(
         name.to_lowercase().as_str(),
-        "tumblingwindow" | "countwindow" | "slidingwindow" | "statewindow"
+        "tumblingwindow"
+            | "hoppingwindow"
+            | "countwindow"
+            | "slidingwindow"
+            | "sessionwindow"
+            | "statewindow"
     )
 }
 
 impl TimeUnit {
     fn try_from_str(raw: &str) -> Result<Self, ParserError> {
         match raw.to_ascii_lowercase().as_str() {
+            "ms" => Ok(TimeUnit::Milliseconds),
             "ss" => Ok(TimeUnit::Seconds),
+            "mi" => Ok(TimeUnit::Minutes),
+            "hh" => Ok(TimeUnit::Hours),
+            "dd" => Ok(TimeUnit::Days),
             other => Err(ParserError::ParserError(format!(
-                "unsupported time unit `{}` (only `ss` allowed)",
+                "unsupported time unit `{}` (expected one of `ms`, `ss`, `mi`, `hh`, `dd`)",
                 other
             ))),
         }
@@ -331,7 +485,11 @@ impl TimeUnit {
 
     fn as_str(&self) -> &'static str {
         match self {
+            TimeUnit::Milliseconds => "ms",
             TimeUnit::Seconds => "ss",
+            TimeUnit::Minutes => "mi",
+            TimeUnit::Hours => "hh",
+            TimeUnit::Days => "dd",
         }
     }
 }
@@ -385,6 +543,23 @@ mod tests {
         })
     }
 
+    fn hopping_expr(size: u64, hop: u64) -> Expr {
+        Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("hoppingwindow")]),
+            args: vec![
+                make_string_arg("ss"),
+                make_number_arg(size),
+                make_number_arg(hop),
+            ],
+            over: None,
+            distinct: false,
+            order_by: vec![],
+            filter: None,
+            null_treatment: None,
+            special: false,
+        })
+    }
+
     fn count_expr() -> Expr {
         Expr::Function(Function {
             name: ObjectName(vec![Ident::new("countwindow")]),
@@ -461,6 +636,60 @@ mod tests {
         assert_eq!(parsed, Some(Window::tumbling(TimeUnit::Seconds, 10)));
     }
 
+    #[test]
+    fn parse_window_expr_time_units() {
+        for (raw, unit) in [
+            ("ms", TimeUnit::Milliseconds),
+            ("SS", TimeUnit::Seconds),
+            ("mi", TimeUnit::Minutes),
+            ("hh", TimeUnit::Hours),
+            ("dd", TimeUnit::Days),
+        ] {
+            let expr = Expr::Function(Function {
+                name: ObjectName(vec![Ident::new("tumblingwindow")]),
+                args: vec![make_string_arg(raw), make_number_arg(200)],
+                over: None,
+                distinct: false,
+                order_by: vec![],
+                filter: None,
+                null_treatment: None,
+                special: false,
+            });
+            let parsed = parse_window_expr(&expr).unwrap();
+            assert_eq!(parsed, Some(Window::tumbling(unit, 200)));
+        }
+    }
+
+    #[test]
+    fn parse_window_expr_rejects_unknown_time_unit() {
+        let expr = Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("tumblingwindow")]),
+            args: vec![make_string_arg("weeks"), make_number_arg(1)],
+            over: None,
+            distinct: false,
+            order_by: vec![],
+            filter: None,
+            null_treatment: None,
+            special: false,
+        });
+        let err = parse_window_expr(&expr).unwrap_err();
+        assert!(err.to_string().contains("unsupported time unit `weeks`"));
+    }
+
+    #[test]
+    fn parse_hopping_window_expr() {
+        let parsed = parse_window_expr(&hopping_expr(300, 60)).unwrap();
+        assert_eq!(parsed, Some(Window::hopping(TimeUnit::Seconds, 300, 60)));
+    }
+
+    #[test]
+    fn parse_hopping_window_rejects_invalid_hop() {
+        let err = parse_window_expr(&hopping_expr(10, 20)).unwrap_err();
+        assert!(err.to_string().contains("must not exceed size"));
+        let err = parse_window_expr(&hopping_expr(10, 0)).unwrap_err();
+        assert!(err.to_string().contains("greater than 0"));
+    }
+
     #[test]
     fn parse_count_window_expr() {
         let parsed = parse_window_expr(&count_expr()).unwrap();
@@ -501,6 +730,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_session_window_expr_partitioned_by() {
+        let expr = Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("sessionwindow")]),
+            args: vec![make_string_arg("ss"), make_number_arg(30)],
+            over: Some(WindowType::WindowSpec(WindowSpec {
+                partition_by: vec![Expr::Identifier(Ident::new("device_id"))],
+                order_by: Vec::new(),
+                window_frame: None,
+            })),
+            distinct: false,
+            order_by: vec![],
+            filter: None,
+            null_treatment: None,
+            special: false,
+        });
+        let parsed = parse_window_expr(&expr).unwrap();
+        assert_eq!(
+            parsed,
+            Some(Window::session_partitioned(
+                TimeUnit::Seconds,
+                30,
+                None,
+                vec![Expr::Identifier(Ident::new("device_id"))],
+            ))
+        );
+    }
+
+    #[test]
+    fn session_window_round_trip_back_to_expr() {
+        let window = Window::session_partitioned(
+            TimeUnit::Seconds,
+            30,
+            Some(600),
+            vec![Expr::Identifier(Ident::new("k1"))],
+        );
+        let expr = window_to_expr(&window);
+        let parsed = parse_window_expr(&expr).unwrap();
+        assert_eq!(parsed, Some(window));
+    }
+
     #[test]
     fn parse_window_expr_non_window() {
         let expr = Expr::Identifier(Ident::new("a"));
@@ -516,6 +786,14 @@ mod tests {
         assert_eq!(parsed, Some(window));
     }
 
+    #[test]
+    fn hopping_window_round_trip_back_to_expr() {
+        let window = Window::hopping(TimeUnit::Seconds, 10, 5);
+        let expr = window_to_expr(&window);
+        let parsed = parse_window_expr(&expr).unwrap();
+        assert_eq!(parsed, Some(window));
+    }
+
     #[test]
     fn sliding_window_round_trip_back_to_expr() {
         let window = Window::sliding(TimeUnit::Seconds, 10, None);
@@ -532,6 +810,14 @@ mod tests {
         assert_eq!(parsed, Some(window));
     }
 
+    #[test]
+    fn millisecond_window_round_trip_back_to_expr() {
+        let window = Window::sliding(TimeUnit::Milliseconds, 200, Some(50));
+        let expr = window_to_expr(&window);
+        let parsed = parse_window_expr(&expr).unwrap();
+        assert_eq!(parsed, Some(window));
+    }
+
     #[test]
     fn state_window_round_trip_back_to_expr() {
         let window = Window::state(
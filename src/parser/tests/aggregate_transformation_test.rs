@@ -64,3 +64,47 @@ fn test_basic_aggregate_transformation() {
     println!("  ✓ SelectStmt contains aggregate mappings");
     println!("  ✓ Mapping: col_1 -> sum(a)");
 }
+
+#[test]
+fn test_distinct_and_filter_modifiers_are_kept() {
+    let sql = "SELECT count(DISTINCT b), count(b), sum(a) FILTER (WHERE b > 1) FROM t";
+    let select_stmt = parse_sql(sql).expect("Should parse successfully");
+
+    // DISTINCT makes count(DISTINCT b) a separate aggregate from count(b).
+    assert_eq!(select_stmt.aggregate_mappings.len(), 3);
+
+    let mut modifiers: Vec<(bool, Option<String>)> = select_stmt
+        .aggregate_mappings
+        .values()
+        .map(|expr| match expr {
+            Expr::Function(func) => (
+                func.distinct,
+                func.filter.as_ref().map(|filter| filter.to_string()),
+            ),
+            other => panic!("Expected function expression, got {other:?}"),
+        })
+        .collect();
+    modifiers.sort();
+    assert_eq!(
+        modifiers,
+        vec![
+            (false, None),
+            (false, Some("b > 1".to_string())),
+            (true, None),
+        ]
+    );
+
+    let field_names: Vec<_> = select_stmt
+        .select_fields
+        .iter()
+        .map(|field| field.alias.clone().unwrap_or_default())
+        .collect();
+    assert_eq!(
+        field_names,
+        vec![
+            "count(DISTINCT b)",
+            "count(b)",
+            "sum(a) FILTER (WHERE b > 1)"
+        ]
+    );
+}
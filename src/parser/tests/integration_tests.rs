@@ -193,7 +193,7 @@ fn test_stream_dialect_expression_fields() {
     );
 
     // Check each field
-    let expected_expressions = vec![
+    let expected_expressions = [
         "BinaryOp", // a + b
         "Function", // CONCAT
         "Value",    // 42
@@ -229,7 +229,7 @@ fn test_stream_dialect_alias_support() {
     );
 
     // Verify aliases are preserved
-    let expected_aliases = vec![
+    let expected_aliases = [
         Some("total".to_string()),
         Some("full_name".to_string()),
         Some("answer".to_string()),
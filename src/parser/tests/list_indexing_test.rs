@@ -187,13 +187,9 @@ fn test_list_indexing_nested_simple() {
     // This should work - string literal indexing
     let sql2 = "SELECT data['key'] FROM table1";
     let result2 = parse_sql(sql2);
-    if result2.is_ok() {
-        println!("String literal indexing supported");
-    } else {
-        println!(
-            "String literal indexing not supported: {}",
-            result2.unwrap_err()
-        );
+    match result2 {
+        Ok(_) => println!("String literal indexing supported"),
+        Err(err) => println!("String literal indexing not supported: {}", err),
     }
 }
 
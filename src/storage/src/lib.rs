@@ -9,6 +9,7 @@ use thiserror::Error;
 const STREAMS_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("streams");
 const PIPELINES_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("pipelines");
 const PLAN_SNAPSHOTS_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("plan_snapshots");
+const CHECKPOINTS_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("checkpoints");
 const SHARED_MQTT_CONFIGS_TABLE: TableDefinition<&str, &[u8]> =
     TableDefinition::new("shared_mqtt_client_configs");
 
@@ -79,6 +80,16 @@ pub struct StoredPlanSnapshot {
     pub logical_plan_ir: Vec<u8>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StoredCheckpoint {
+    /// Owning pipeline identifier (also used as the storage key).
+    pub pipeline_id: String,
+    /// Identifier of the checkpoint barrier that produced the state.
+    pub checkpoint_id: u64,
+    /// Serialized processor state of the pipeline.
+    pub state: Vec<u8>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct StoredMqttClientConfig {
     pub key: String,
@@ -168,6 +179,49 @@ impl MetadataStorage {
                 .open_table(PLAN_SNAPSHOTS_TABLE)
                 .map_err(StorageError::backend)?;
             let _ = snapshots.remove(id).map_err(StorageError::backend)?;
+
+            let mut checkpoints = txn
+                .open_table(CHECKPOINTS_TABLE)
+                .map_err(StorageError::backend)?;
+            let _ = checkpoints.remove(id).map_err(StorageError::backend)?;
+        }
+        txn.commit().map_err(StorageError::backend)?;
+        Ok(())
+    }
+
+    /// Replace an existing pipeline; its plan snapshot and checkpoint were derived from the old
+    /// definition and are dropped in the same transaction.
+    pub fn update_pipeline(&self, pipeline: StoredPipeline) -> Result<(), StorageError> {
+        let txn = self.db.begin_write().map_err(StorageError::backend)?;
+        {
+            let mut pipelines = txn
+                .open_table(PIPELINES_TABLE)
+                .map_err(StorageError::backend)?;
+            if pipelines
+                .get(pipeline.id.as_str())
+                .map_err(StorageError::backend)?
+                .is_none()
+            {
+                return Err(StorageError::NotFound(pipeline.id));
+            }
+            let encoded = encode_record(&pipeline)?;
+            pipelines
+                .insert(pipeline.id.as_str(), encoded.as_slice())
+                .map_err(StorageError::backend)?;
+
+            let mut snapshots = txn
+                .open_table(PLAN_SNAPSHOTS_TABLE)
+                .map_err(StorageError::backend)?;
+            let _ = snapshots
+                .remove(pipeline.id.as_str())
+                .map_err(StorageError::backend)?;
+
+            let mut checkpoints = txn
+                .open_table(CHECKPOINTS_TABLE)
+                .map_err(StorageError::backend)?;
+            let _ = checkpoints
+                .remove(pipeline.id.as_str())
+                .map_err(StorageError::backend)?;
         }
         txn.commit().map_err(StorageError::backend)?;
         Ok(())
@@ -199,6 +253,33 @@ impl MetadataStorage {
         self.delete_entry(PLAN_SNAPSHOTS_TABLE, pipeline_id)
     }
 
+    /// Store the latest checkpoint of a pipeline, replacing the previous one.
+    pub fn put_checkpoint(&self, checkpoint: StoredCheckpoint) -> Result<(), StorageError> {
+        let txn = self.db.begin_write().map_err(StorageError::backend)?;
+        {
+            let mut table = txn
+                .open_table(CHECKPOINTS_TABLE)
+                .map_err(StorageError::backend)?;
+            let encoded = encode_record(&checkpoint)?;
+            table
+                .insert(checkpoint.pipeline_id.as_str(), encoded.as_slice())
+                .map_err(StorageError::backend)?;
+        }
+        txn.commit().map_err(StorageError::backend)?;
+        Ok(())
+    }
+
+    pub fn get_checkpoint(
+        &self,
+        pipeline_id: &str,
+    ) -> Result<Option<StoredCheckpoint>, StorageError> {
+        self.get_entry(CHECKPOINTS_TABLE, pipeline_id)
+    }
+
+    pub fn delete_checkpoint(&self, pipeline_id: &str) -> Result<(), StorageError> {
+        self.delete_entry(CHECKPOINTS_TABLE, pipeline_id)
+    }
+
     pub fn create_mqtt_config(&self, config: StoredMqttClientConfig) -> Result<(), StorageError> {
         self.insert_if_absent(SHARED_MQTT_CONFIGS_TABLE, &config.key, &config)
     }
@@ -230,6 +311,8 @@ impl MetadataStorage {
             .map_err(StorageError::backend)?;
         txn.open_table(PLAN_SNAPSHOTS_TABLE)
             .map_err(StorageError::backend)?;
+        txn.open_table(CHECKPOINTS_TABLE)
+            .map_err(StorageError::backend)?;
         txn.open_table(SHARED_MQTT_CONFIGS_TABLE)
             .map_err(StorageError::backend)?;
         txn.commit().map_err(StorageError::backend)?;
@@ -360,6 +443,10 @@ impl StorageManager {
         self.metadata.delete_pipeline(id)
     }
 
+    pub fn update_pipeline(&self, pipeline: StoredPipeline) -> Result<(), StorageError> {
+        self.metadata.update_pipeline(pipeline)
+    }
+
     pub fn put_plan_snapshot(&self, snapshot: StoredPlanSnapshot) -> Result<(), StorageError> {
         self.metadata.put_plan_snapshot(snapshot)
     }
@@ -375,6 +462,21 @@ impl StorageManager {
         self.metadata.delete_plan_snapshot(pipeline_id)
     }
 
+    pub fn put_checkpoint(&self, checkpoint: StoredCheckpoint) -> Result<(), StorageError> {
+        self.metadata.put_checkpoint(checkpoint)
+    }
+
+    pub fn get_checkpoint(
+        &self,
+        pipeline_id: &str,
+    ) -> Result<Option<StoredCheckpoint>, StorageError> {
+        self.metadata.get_checkpoint(pipeline_id)
+    }
+
+    pub fn delete_checkpoint(&self, pipeline_id: &str) -> Result<(), StorageError> {
+        self.metadata.delete_checkpoint(pipeline_id)
+    }
+
     pub fn create_mqtt_config(&self, config: StoredMqttClientConfig) -> Result<(), StorageError> {
         self.metadata.create_mqtt_config(config)
     }
@@ -433,6 +535,14 @@ mod tests {
         }
     }
 
+    fn sample_checkpoint(checkpoint_id: u64) -> StoredCheckpoint {
+        StoredCheckpoint {
+            pipeline_id: "pipe_1".to_string(),
+            checkpoint_id,
+            state: vec![checkpoint_id as u8; 4],
+        }
+    }
+
     fn sample_mqtt_config() -> StoredMqttClientConfig {
         StoredMqttClientConfig {
         key: "shared_a".to_string(),
@@ -561,4 +671,48 @@ mod tests {
             .unwrap()
             .is_none());
     }
+
+    #[test]
+    fn checkpoint_put_replaces_and_delete_pipeline_cascades() {
+        let dir = tempdir().unwrap();
+        let storage = StorageManager::new(dir.path()).unwrap();
+
+        let pipeline = sample_pipeline();
+        storage.create_pipeline(pipeline.clone()).unwrap();
+
+        storage.put_checkpoint(sample_checkpoint(1)).unwrap();
+        storage.put_checkpoint(sample_checkpoint(2)).unwrap();
+        assert_eq!(
+            storage.get_checkpoint(&pipeline.id).unwrap(),
+            Some(sample_checkpoint(2))
+        );
+
+        storage.delete_pipeline(&pipeline.id).unwrap();
+        assert!(storage.get_checkpoint(&pipeline.id).unwrap().is_none());
+    }
+
+    #[test]
+    fn update_pipeline_replaces_and_invalidates_derived_state() {
+        let dir = tempdir().unwrap();
+        let storage = StorageManager::new(dir.path()).unwrap();
+
+        let mut pipeline = sample_pipeline();
+        assert!(matches!(
+            storage.update_pipeline(pipeline.clone()),
+            Err(StorageError::NotFound(_))
+        ));
+        storage.create_pipeline(pipeline.clone()).unwrap();
+        storage.put_plan_snapshot(sample_plan_snapshot()).unwrap();
+        storage.put_checkpoint(sample_checkpoint(1)).unwrap();
+
+        pipeline.raw_json =
+            r#"{"id":"pipe_1","sql":"SELECT a FROM stream_1","sinks":[]}"#.to_string();
+        storage.update_pipeline(pipeline.clone()).unwrap();
+        assert_eq!(
+            storage.get_pipeline(&pipeline.id).unwrap(),
+            Some(pipeline.clone())
+        );
+        assert!(storage.get_plan_snapshot(&pipeline.id).unwrap().is_none());
+        assert!(storage.get_checkpoint(&pipeline.id).unwrap().is_none());
+    }
 }